@@ -0,0 +1,613 @@
+## Shared report sections
+
+constants = Konstanten
+pi = Pi
+gravitational-constant = Gravitationskonstante
+orbital-periods = Umlaufzeiten
+orbital-velocities = Bahngeschwindigkeiten
+user-defined = Benutzerdefiniert
+
+sensitivities = Sensitivitäten
+period-per-altitude = dUmlaufzeit/dHöhe
+velocity-per-altitude = dGeschwindigkeit/dHöhe
+
+## Units
+
+seconds = Sekunden
+minutes = Minuten
+years = Jahre
+days = Tage
+
+## Earth
+
+body-mass = Masse ({ $body })
+body-mu = GM ({ $body })
+body-radius = Radius ({ $body })
+body-polar-radius = Polradius ({ $body })
+body-flattening = Abplattung ({ $body })
+body-rotation = Siderische Rotationsperiode ({ $body })
+
+## Validation
+
+validation = Validierung
+validation-pass = BESTANDEN
+validation-fail = FEHLGESCHLAGEN
+validation-values = erwartet { $expected } { $unit }, berechnet { $computed } { $unit }
+validation-error = relativer Fehler { $error } (Toleranz { $tolerance })
+validation-summary = { $passed } bestanden, { $failed } fehlgeschlagen
+
+## Quiz
+
+quiz = Übungsaufgaben (Startwert { $seed })
+quiz-given = Gegeben: GM = { $mu } m^3*s^-2, Körperradius = { $radius } { $unit }
+quiz-period = Ein Satellit befindet sich auf einer Kreisbahn { $altitude } { $unit } über der Erdoberfläche. Wie groß ist seine Umlaufzeit?
+quiz-hohmann = Ein Raumfahrzeug auf einer Kreisbahn in { $from } { $unit } Höhe wechselt per Hohmann-Transfer auf eine Kreisbahn in { $to } { $unit } Höhe. Bestimme beide Manöver, das gesamte Delta-v und die Transferzeit.
+quiz-escape = Wie groß ist die Fluchtgeschwindigkeit { $altitude } { $unit } über der Erdoberfläche?
+quiz-answer-key = Lösungen
+quiz-answers-hint = Mit demselben --seed und --answers erneut ausführen, um die Lösungen anzuzeigen.
+
+## Errors
+
+error-create-file = Fehler: { $path } kann nicht erstellt werden: { $error }
+error-write = Fehler: Ausgabe konnte nicht geschrieben werden: { $error }
+
+## Habitable zone
+
+star = Stern
+star-mass = Masse
+luminosity = Leuchtkraft
+effective-temperature = Effektivtemperatur
+main-sequence-estimate = Hauptreihen-Schätzung
+hab-temperature-warning = Warnung: die Anpassungen der habitablen Zone gelten nur für { $min }-{ $max } K
+habitable-zone = Habitable Zone (Kopparapu et al. 2014)
+hab-optimistic-inner = Optimistischer Innenrand
+hab-conservative-inner = Konservativer Innenrand
+hab-conservative-outer = Konservativer Außenrand
+hab-optimistic-outer = Optimistischer Außenrand
+hab-recent-venus = junge Venus
+hab-runaway-greenhouse = galoppierender Treibhauseffekt
+hab-maximum-greenhouse = maximaler Treibhauseffekt
+hab-early-mars = früher Mars
+hab-at-distance = Bei { $distance } AE
+stellar-flux = Sternstrahlung
+equilibrium-temperature = Gleichgewichtstemperatur
+bond-albedo = Bond-Albedo { $albedo }
+orbital-period = Umlaufzeit
+zone = Zone
+hab-too-hot = näher als die habitable Zone (zu heiß)
+hab-zone-optimistic = optimistische habitable Zone
+hab-zone-conservative = konservative habitable Zone
+hab-too-cold = außerhalb der habitablen Zone (zu kalt)
+
+## Launch dispersion
+
+nominal-orbit = Nominale Bahn
+perigee-altitude = Perigäumshöhe
+apogee-altitude = Apogäumshöhe
+inclination = Inklination
+dispersion-escape = Der nominale Brennschlusszustand ist nicht an den Körper gebunden; es gibt kein Apogäum zu streuen.
+dispersion-linearized = Linearisierte 1-Sigma-Fehler
+dispersion-monte-carlo = Monte Carlo ({ $samples } Stichproben, Startwert { $seed })
+dispersion-escaped = { $count } Stichproben sind entkommen und fehlen in der Apogäumsstatistik
+dispersion-statistics = Mittelwert { $mean } { $unit }, 1-Sigma { $sigma } { $unit } (Bereich { $min } .. { $max })
+
+## Disposal compliance
+
+compliance = Entsorgung nach Missionsende ({ $altitude } { $unit } kreisförmig, BC { $bc } kg/m^2)
+natural-lifetime = Natürliche Lebensdauer
+rule-25-year = 25-Jahres-Regel
+rule-5-year = 5-Jahres-Regel (FCC 2022)
+compliance-lower-perigee = Perigäum auf { $perigee } absenken: Delta-v { $delta_v }
+
+## Thermal environment
+
+thermal = Thermische Umgebung ({ $altitude } { $unit } kreisförmig, Beta { $beta } Grad)
+sunlit-fraction = Sonnenlicht
+eclipse-fraction = Finsternis
+critical-beta = Keine Finsternis oberhalb von Beta
+orbit-averaged-flux = Bahngemittelter Fluss
+solar-flux = Solarer Fluss
+average-solar = Solar, sonnenzugewandte Fläche
+average-albedo = Albedo, nadirzugewandte Fläche
+earth-infrared = Erd-Infrarot, nadirzugewandte Fläche
+
+## Solar array power
+
+power = Solargenerator ({ $altitude } { $unit } kreisförmig, Beta { $beta } Grad)
+array-output = Generatorleistung
+peak-power = Spitzenleistung
+sunlit-average-power = Mittlere Leistung im Sonnenlicht
+orbit-average-power = Bahngemittelte Leistung
+energy-per-orbit = Energie pro Umlauf
+
+## Plots
+
+circular-velocity = Kreisbahngeschwindigkeit
+plot-no-data = Fehler: in diesem Höhenbereich gibt es keine Punkte zum Zeichnen
+plot-altitude-axis = Höhe (km)
+plot-period-axis = Umlaufzeit (Minuten)
+plot-velocity-axis = Geschwindigkeit (km/s)
+plot-coverage-title = Abdeckungsradius ({ $elevation } Grad Mindestelevation)
+plot-coverage-axis = Bodenradius (km)
+plot-decay-title = Bahnlebensdauer (BC { $bc } kg/m^2)
+plot-decay-axis = Lebensdauer (Jahre)
+
+## Diagram
+
+diagram = Maßstabsgetreue Bahnen
+earth = Erde
+perigee = Perigäum
+apogee = Apogäum
+diagram-unknown-orbit = Fehler: unbekannte Bahn '{ $name }', erwartet vleo, leo, meo, geo, gto oder eine Höhe in km
+
+## glTF
+
+ground-track = Bodenspur, { $orbit }
+
+## Animate
+
+animate-title = { $altitude } km, { $inclination }°, t = { $minutes } min
+animate-written = { $count } Bilder nach { $dir } geschrieben
+longitude = Länge (Grad)
+latitude = Breite (Grad)
+
+## Phasing
+
+phasing = Walker { $total }/{ $planes }/{ $phasing } in { $altitude } { $unit }
+phasing-invalid = Fehler: { $total }/{ $planes }/{ $phasing } ist kein Walker-Muster; die Satelliten müssen sich gleichmäßig auf die Ebenen verteilen, die Phasierung muss kleiner als die Ebenenzahl sein und der Driftversatz darf nicht null sein
+phasing-per-plane = Satelliten pro Ebene
+phasing-in-plane = Abstand in der Ebene
+phasing-raan = Ebenenabstand (RAAN)
+phasing-offset = Phasierung zwischen Ebenen
+phasing-drift = Positionierung nach einem Start, Driftbahn { $offset } { $unit } (Position 0 bleibt auf Station)
+phasing-drift-rate = Relative Drift
+phasing-slot-time = Driftzeit pro Position
+phasing-delta-v = Delta-v in die Driftbahn und zurück
+phasing-slot = Position
+phasing-target = Winkel (Grad)
+phasing-days = Drift (Tage)
+
+## Makeup
+
+makeup = Widerstandsausgleich in { $altitude } { $unit }, BC { $bc } kg/m^2
+makeup-density = Dichte
+makeup-decay = Höhenverlust ohne Ausgleich
+makeup-delta-v = Delta-v pro Jahr
+day = Tag
+
+## Radiation
+
+radiation = Strahlungsindikator in { $altitude } { $unit }, { $inclination } Grad über { $orbits } Umläufe (exzentrischer Dipol, IGRF 2020)
+radiation-saa-fraction = Zeit in der Südatlantischen Anomalie
+radiation-saa-per-day = SAA-Durchgänge pro Tag
+radiation-inner-belt = Zeit mit Zugang gefangener Teilchen, innerer Gürtel L { $low } bis { $high }
+radiation-outer-belt = Zeit mit Zugang gefangener Teilchen, äußerer Gürtel L { $low } bis { $high }
+radiation-no-saa = Keine Durchgänge durch die Südatlantische Anomalie.
+radiation-crossings = SAA-Durchgänge (Zeiten in Minuten ab Beginn)
+radiation-orbit = Umlauf
+radiation-entry = Eintritt
+radiation-exit = Austritt
+radiation-duration = Dauer
+radiation-min-field = B min (uT)
+
+## Bodies
+
+sun = Sonne
+mercury = Merkur
+venus = Venus
+moon = Mond
+mars = Mars
+jupiter = Jupiter
+saturn = Saturn
+uranus = Uranus
+neptune = Neptun
+pluto = Pluto
+preset-low-orbit = Niedrige Umlaufbahn
+preset-synchronous = Synchrone Umlaufbahn
+
+## Elliptical orbits
+
+at-periapsis = in der Periapsis
+at-apoapsis = in der Apoapsis
+error-apsides = Fehler: die Periapsis darf nicht über der Apoapsis liegen
+error-elements = Fehler: die große Halbachse muss positiv und die Exzentrizität mindestens 0 und kleiner als 1 sein
+
+## Elements
+
+elements = Keplersche Bahnelemente (erdzentriert inertial)
+semi-major-axis = Große Halbachse
+eccentricity = Exzentrizität
+raan = Rektaszension des aufsteigenden Knotens
+argument-of-periapsis = Argument der Periapsis
+true-anomaly = Wahre Anomalie
+periapsis-altitude = Periapsishöhe
+apoapsis-altitude = Apoapsishöhe
+state-vector = Zustandsvektor
+position = Position
+velocity = Geschwindigkeit
+error-keplerian = Fehler: die Exzentrizität muss nichtnegativ und ungleich 1 sein, mit positiver großer Halbachse für geschlossene und negativer für hyperbolische Bahnen
+
+## Transfers
+
+transfer-hohmann = Hohmann-Transfer um { $body }: { $from } { $unit } nach { $to } { $unit }
+transfer-first-burn = Erster Schub
+transfer-second-burn = Zweiter Schub
+transfer-total = Gesamt-Delta-v
+transfer-time = Transferzeit
+hours = Stunden
+error-transfer-altitude = Fehler: Transferhöhen dürfen nicht negativ sein
+transfer-bielliptic = Bi-elliptischer Transfer um { $body }: { $from } { $unit } nach { $to } { $unit } über { $apogee } { $unit }
+transfer-third-burn = Dritter Schub
+transfer-hohmann-total = Gesamt-Delta-v nach Hohmann
+transfer-ratio = Radienverhältnis
+transfer-bielliptic-wins = Bi-elliptisch spart { $saving } gegenüber Hohmann
+transfer-hohmann-wins = Hohmann ist um { $saving } günstiger
+transfer-ratio-hohmann = Unter einem Verhältnis von 11,94 ist Hohmann für jede Apoapsis günstiger.
+transfer-ratio-depends = Zwischen den Verhältnissen 11,94 und 15,58 hängt der günstigere Transfer von der Apoapsis ab.
+transfer-ratio-bielliptic = Über einem Verhältnis von 15,58 ist bi-elliptisch für jede Apoapsis jenseits der Zielbahn günstiger.
+error-bielliptic-apogee = Fehler: die Zwischen-Apoapsis darf unter keiner der beiden Bahnen liegen
+
+## Sweep
+
+sweep = Höhenreihe kreisförmiger Bahnen ({ $body })
+sweep-altitude = Höhe ({ $unit })
+sweep-period = Periode (min)
+sweep-velocity = Geschwindigkeit ({ $unit })
+orbits = Bahnen
+table-orbit = Bahn
+table-altitude = Höhe ({ $unit })
+table-period = Periode ({ $unit })
+table-velocity = Geschwindigkeit ({ $unit })
+
+## Period solver
+
+period-solution = Kreisbahn mit einer Periode von { $period } s ({ $body })
+period-altitude = Höhe
+period-below-surface = Warnung: diese Bahn liegt unter der Oberfläche von { $body }
+
+## Custom bodies
+
+custom-body = Benutzerdefinierter Körper
+error-custom-body = Fehler: Masse, Gravitationsparameter, Oberflächenschwerkraft und Radius müssen positiv sein
+error-no-orbit = Fehler: Höhe, Apsiden, Elemente, --sweep oder --period angeben; dieser Körper hat keine voreingestellten Bahnen
+
+## Body definitions
+
+error-no-config = Fehler: kein Home-Verzeichnis für bodies.toml gefunden; --config angeben
+error-config = Fehler: { $path }: { $error }
+error-unknown-body = kein Körper namens { $name } (definiert: { $names })
+
+## Escape
+
+escape = Flucht
+surface-escape-velocity = Fluchtgeschwindigkeit an der Oberfläche
+escape-velocity = Fluchtgeschwindigkeit
+departure-burn = Abflugschub
+
+## J2
+
+j2 = Säkulare J2-Raten (Inklination { $inclination } Grad)
+nodal-regression = Knotenregression
+apsidal-rotation = Apsidendrehung
+j2-unknown = Der J2-Koeffizient von { $body } ist unbekannt.
+
+## TLE
+
+tle = { $name } (NORAD { $catalog })
+tle-epoch = Epoche: { $year }, Tag { $day }
+mean-motion = Mittlere Bewegung
+mean-anomaly = Mittlere Anomalie
+bstar = B*-Widerstandsterm (1/Erdradien)
+error-read = Fehler: { $source }: { $error }
+
+## Propagation
+
+since-epoch = { $days } Tage seit der Epoche des Elementsatzes
+position-teme = Position (TEME)
+velocity-teme = Geschwindigkeit (TEME)
+geodetic-altitude = Höhe über WGS-84
+czml-document = Propagierte Bahnen
+error-propagate = Fehler: Propagation fehlgeschlagen: { $error }
+
+## Ground tracks
+
+groundtrack = Bodenspur: { $name }
+groundtrack-elements = Kepler-Bahn
+groundtrack-time = Zeit (UTC)
+groundtrack-altitude = Höhe ({ $unit })
+groundtrack-footprint = Sichtbereich von { $name }
+groundtrack-footprint-radius = Sichtbereich ({ $unit })
+groundtrack-document = Bodenspuren
+error-groundtrack = Fehler: { $error }
+
+## Passes
+
+passes = Überflüge von { $name } über { $latitude }, { $longitude } für { $days } d ab { $start }
+passes-none = Keine Überflüge über { $elevation } Grad Elevation.
+passes-aos = AOS (UTC)
+passes-culmination = Kulmination (UTC)
+passes-los = LOS (UTC)
+passes-duration = Dauer (min)
+passes-max-elevation = Max. El.
+passes-azimuth = Azimut
+
+## Time
+
+time-convert = Zeit: { $time }
+tai-minus-utc = TAI - UTC
+julian-date = Julianisches Datum
+modified-julian-date = Modifiziertes Julianisches Datum
+julian-centuries = Seit J2000 (TT)
+julian-centuries-unit = julianische Jahrhunderte
+gmst = Mittlere Greenwich-Sternzeit
+error-time = Fehler: { $error }
+
+## Frames
+
+frame = Umrechnung des Bezugssystems ({ $from } nach { $to })
+frame-eci = ECI (J2000)
+frame-teme = TEME
+frame-ecef = ECEF
+frame-geodetic = geodätisch
+frame-epoch = Epoche (UTC)
+frame-input = Eingabe
+frame-output = Ausgabe
+error-frame = Fehler: { $error }
+
+## Ellipsoid
+
+ellipsoid-altitudes = Höhen über dem Ellipsoid von { $body }
+over-equator = Über dem Äquator
+over-poles = Über den Polen
+at-equator = am Äquator
+at-poles = an den Polen
+
+## Vis-viva
+
+visviva = Vis-viva bei { $radius } { $unit } auf einer Bahn mit großer Halbachse { $sma } { $unit } ({ $body })
+visviva-apsis = (ohne Exzentrizität: der Radius gilt als Periapsis oder Apoapsis)
+visviva-apsides = Periapsis- / Apoapsisradius
+flight-path-angle = Bahnneigungswinkel
+specific-energy = Spezifische Bahnenergie
+specific-angular-momentum = Spezifischer Drehimpuls
+visviva-below-surface = Warnung: die Periapsis liegt unter der Oberfläche von { $body }
+error-visviva = Fehler: große Halbachse und Radius müssen positiv sein, die Exzentrizität von 0 bis ausschließlich 1 und der Radius zwischen Periapsis und Apoapsis
+
+## Anomalies
+
+anomaly-elliptic = Anomalien auf einer Ellipse (e = { $eccentricity })
+anomaly-hyperbolic = Anomalien auf einer Hyperbel (e = { $eccentricity })
+eccentric-anomaly = Exzentrische Anomalie
+hyperbolic-anomaly = Hyperbolische Anomalie
+asymptote-anomaly = Wahre Anomalie der Asymptoten
+error-anomaly = Fehler: { $error }
+
+## Two-body and numerical propagation
+
+kepler-ephemeris = Zweikörper-Ephemeride um { $body } (inertial)
+kepler-elapsed = Vergangen (s)
+error-kepler-duration = Fehler: Hyperbelbahnen haben keine Umlaufzeit; --duration angeben
+numeric-ephemeris = Numerische Ephemeride um { $body } ({ $method }, inertial)
+error-numeric = Fehler: ballistischer Koeffizient und Toleranz müssen positiv sein
+error-drag-earth = Fehler: der Luftwiderstand nutzt die Erdatmosphäre und erfordert --body earth
+
+## Decay
+
+decay = Bahnzerfall bei { $perigee } x { $apogee } { $unit }, BC { $bc } kg/m^2
+decay-density = Dichte im Perigäum
+decay-rate = Änderung der großen Halbachse
+decay-period-rate = Änderung der Umlaufzeit
+decay-note = Die Lebensdauer endet, wenn das Perigäum unter { $reentry } { $unit } sinkt, mit einer statischen Exponentialatmosphäre bei mittlerer Sonnenaktivität.
+error-decay = Fehler: ballistischer Koeffizient, B*, Masse, Fläche und Widerstandsbeiwert müssen positiv sein, und die Höhe darf --apoapsis nicht übersteigen
+error-decay-earth = Fehler: der Zerfall nutzt die Erdatmosphäre und ist nur als orbit earth decay verfügbar
+error-decay-reentry = Fehler: das Perigäum muss über der Wiedereintrittshöhe von { $reentry } km liegen
+
+## Delta-v budget
+
+budget = Delta-v-Budget um { $body }
+budget-maneuver = Manöver
+budget-delta-v = Delta-v ({ $unit })
+budget-subtotal = Manöver
+budget-margin = Reserve ({ $margin } %)
+budget-total = Gesamtes Delta-v
+maneuver-hohmann = Hohmann-Transfer von { $from } { $unit } auf { $to } { $unit }
+maneuver-plane-change = Bahnebenenänderung um { $inclination } Grad in { $altitude } { $unit }
+maneuver-circularize = Zirkularisieren von { $periapsis } x { $apoapsis } { $unit } im Apoapsis
+maneuver-deorbit = Deorbit aus { $altitude } { $unit } auf ein Perigäum von { $perigee } { $unit }
+error-budget-margin = Fehler: die Reserve darf nicht negativ sein
+
+## Plane change
+
+plane-change = Bahnebenenänderung um { $angle } Grad in { $altitude } { $unit } ({ $body })
+plane-change-delta-v = Delta-v der Bahnebenenänderung
+plane-change-transfer = Bahnebenenänderung um { $angle } Grad mit einem Hohmann-Transfer von { $from } { $unit } auf { $to } { $unit } ({ $body })
+plane-change-separate = Transfer, danach die Bahnebenenänderung als eigene Zündung
+plane-change-at-second = Ganze Bahnebenenänderung mit der zweiten Zündung kombiniert
+plane-change-optimal = Bahnebenenänderung aufgeteilt für das geringste Delta-v
+plane-change-saving = Spart { $saving } gegenüber getrennten Zündungen
+
+## Rocket equation
+
+rocket = Raketengrundgleichung (Isp { $isp } s, Trockenmasse { $dry } kg)
+rocket-exhaust-velocity = Effektive Ausströmgeschwindigkeit
+rocket-wet-mass = Startmasse
+rocket-propellant = Treibstoff
+rocket-mass-ratio = Massenverhältnis
+rocket-delta-v = Delta-v
+rocket-target = Für ein Delta-v von { $delta_v }
+rocket-enough = Die Stufe trägt { $spare } kg mehr Treibstoff als nötig.
+rocket-short = Der Stufe fehlen { $short } kg Treibstoff.
+budget-propellant = Treibstoff für die Summe (Isp { $isp } s, Trockenmasse { $dry } kg)
+error-rocket = Fehler: Isp und Trockenmasse müssen positiv sein, die Startmasse darf nicht unter der Trockenmasse liegen und das Delta-v nicht negativ sein
+
+## Phasing and launch windows
+
+phase = Phasenlage für einen Hohmann-Transfer von { $from } { $unit } auf { $to } { $unit } ({ $body })
+phase-planets = Startfenster für einen Hohmann-Transfer von { $from } zu { $to }
+phase-angle = Nötiger Phasenwinkel (Ziel voraus)
+synodic-period = Synodische Periode
+phase-same-orbit = Beide Bahnen haben dieselbe Umlaufzeit, ihre Phase ändert sich also nie.
+phase-date = Datum
+phase-longitude = Mittlere Länge von { $body }
+phase-current = Aktueller Phasenwinkel
+phase-next-window = Nächstes Fenster
+phase-in = in { $time }
+phase-note = Kreisförmige, koplanare Bahnen; Planeten auf mittleren Bahnen, auf wenige Wochen genau.
+error-phase-mixed = Fehler: zwei Höhen um einen Körper oder zwei Planeten angeben
+error-phase-same = Fehler: zwei verschiedene Bahnen für die Phasenlage angeben
+
+## Synodic period
+
+synodic = Synodische Periode
+synodic-first = Umlaufzeit der ersten Bahn
+synodic-second = Umlaufzeit der zweiten Bahn
+synodic-revolutions = Umläufe pro synodischer Periode (erste / zweite)
+
+## Lambert's problem
+
+lambert = Lambert-Transfer ({ $revolutions } volle Umläufe)
+lambert-time-of-flight = Flugzeit
+lambert-departure = Abfluggeschwindigkeit
+lambert-arrival = Ankunftsgeschwindigkeit
+error-lambert = Fehler: kein Transferbogen: { $error }
+
+## Porkchop
+
+porkchop-title = Transfers von { $from } nach { $to }
+porkchop-depart-axis = Tage nach Abflug { $date }
+porkchop-arrive-axis = Tage nach Ankunft { $date }
+porkchop-cells = Datumspaare mit Transfer
+porkchop-lowest-c3 = Geringste Startenergie
+porkchop-lowest-total = Geringste Summe der Überschussgeschwindigkeiten bei Abflug und Ankunft
+porkchop-depart = Abflug
+porkchop-arrive = Ankunft
+porkchop-time-of-flight = Flugzeit
+porkchop-c3 = C3
+porkchop-arrival-v-inf = V-unendlich bei Ankunft
+error-porkchop = Fehler: zwei verschiedene Planeten außer Sonne und Mond und eine positive Schrittweite angeben
+error-porkchop-empty = Fehler: kein Ankunftsdatum liegt nach einem Abflugdatum
+
+## Planet positions
+
+where = { $body } am { $date }
+where-heliocentric = Heliozentrisch, Ekliptik J2000
+where-geocentric = Geozentrisch, Äquator J2000
+where-ecliptic-longitude = Ekliptikale Länge
+where-ecliptic-latitude = Ekliptikale Breite
+where-sun-distance = Abstand von der Sonne
+where-right-ascension = Rektaszension
+where-declination = Deklination
+where-earth-distance = Abstand von der Erde
+where-light-time = Lichtlaufzeit
+where-note = Mittlere Bahnelemente (JPL, 1800-2050); die Erde ist der Schwerpunkt von Erde und Mond.
+error-where = Fehler: Positionen gibt es für die Sonne und die Planeten, nicht für den Mond
+
+## Interplanetary transfer
+
+interplanetary = Transfer von { $from } nach { $to }
+interplanetary-departure = Abflug von { $body }
+interplanetary-arrival = Ankunft bei { $body }
+interplanetary-v-inf = Hyperbolische Überschussgeschwindigkeit
+interplanetary-soi = Radius der Einflusssphäre
+interplanetary-injection = Injektionsmanöver aus einer { $altitude } { $unit } Parkbahn
+interplanetary-capture = Einfangmanöver in eine { $periapsis } x { $apoapsis } { $unit } Bahn
+error-interplanetary = Fehler: zwei verschiedene Planeten außer Sonne und Mond, eine Ankunft nach dem Abflug und eine Einfang-Apoapsis nicht unter der Periapsis angeben
+
+## Sphere of influence
+
+soi = Einflusssphäre von { $body }
+soi-parent = Umkreist
+soi-distance = Mittlerer Abstand
+soi-hill = Hill-Radius
+soi-radii = Körperradien
+soi-note = Weiter außen überwiegt die Schwerkraft des Zentralkörpers, und Zweikörperbahnen um { $body } sind kein gutes Modell mehr.
+error-soi = Fehler: die Sonne umkreist nichts und hat hier daher keine Einflusssphäre
+
+## Lagrange points
+
+lagrange = Lagrange-Punkte { $primary }-{ $secondary }
+lagrange-separation = Abstand der Primärkörper
+lagrange-mass-ratio = Massenverhältnis
+lagrange-from = Abstand von { $body }
+lagrange-note = Positionen im rotierenden System vom Schwerpunkt aus, x zeigt von { $primary } weg; eingeschränktes kreisförmiges Dreikörperproblem.
+
+## Rendezvous
+
+rendezvous = Relativbewegung nach Clohessy-Wiltshire (Zielbahn { $altitude } { $unit })
+rendezvous-coast = Relativzustand nach dem Freiflug
+rendezvous-range = Entfernung
+rendezvous-transfer = Rendezvous mit zwei Manövern
+rendezvous-first-burn = Erstes Manöver
+rendezvous-second-burn = Zweites Manöver
+rendezvous-singular = Kein Transfer erreicht das Ziel in genau dieser Zeit; eine andere Flugzeit versuchen.
+rendezvous-note = System: x radial (nach außen), y in Flugrichtung, z Bahnnormale; linearisierte Bewegung nahe einer Kreisbahn.
+
+## Orbit determination
+
+error-determine = Fehler: Bahn nicht bestimmbar: { $error }
+
+## Coverage
+
+coverage = Abdeckung aus { $altitude } { $unit } bei { $inclination } Grad
+coverage-central-angle = Zentriwinkel des Erfassungsbereichs
+coverage-radius = Radius des Erfassungsbereichs am Boden
+coverage-area = Fläche des Erfassungsbereichs
+coverage-per-orbit = Überstrichene Fläche pro Umlauf
+coverage-orbits-per-day = Umläufe pro Tag
+coverage-revisit = Mittlere Wiederkehr bei Breite { $latitude }
+coverage-never = nie sichtbar
+coverage-note = Kugelförmiger Körper; mittlere Wiederkehr unter der Annahme, dass sich Streifen nie überlappen.
+
+## Eclipse
+
+eclipse = Finsternisse um { $body } in { $altitude } { $unit }, Beta { $beta } Grad
+eclipse-longest = Längste Finsternis (Beta 0)
+eclipse-note = Zylindrischer Schatten ohne Halbschatten; Finsterniszeit pro Umlauf.
+
+## Beta angle
+
+beta = Betawinkel ({ $inclination } Grad, RAAN { $raan } Grad) am { $date }
+beta-sweep = Betawinkel ({ $inclination } Grad, RAAN { $raan } Grad) vom { $start } bis { $end }
+beta-angle = Betawinkel
+beta-minimum = Minimum
+beta-maximum = Maximum
+beta-nodal-drift = Knotendrift (J2)
+beta-seasons = Finsternisperioden (|Beta| unter dem kritischen Winkel):
+beta-no-seasons = keine, durchgehend beleuchtet
+error-beta = Fehler: der Schritt zwischen den Daten muss positiv sein
+
+## Repeat ground tracks
+
+repeat = Wiederholte Bodenspuren ({ $revs } Umläufe pro { $days } d)
+repeat-inclination = Inklination (Grad)
+repeat-nodal-period = Periode (min)
+repeat-nodal-day = Knotentag (h)
+repeat-sso = SSO
+repeat-below-surface = unter der Oberfläche
+repeat-spacing = Spurabstand am Äquator
+repeat-shorter = Die Spur wiederholt sich schon nach { $revs } Umläufen pro { $days } d.
+error-repeat = Fehler: mindestens ein Umlauf und ein Tag sind nötig
+error-repeat-earth = Fehler: wiederholte Bodenspuren gibt es nur als orbit earth repeat
+
+## Satellites
+
+sat = { $name }
+sat-catalog = Eingebaute Satelliten
+sat-source-nominal = Nominale Bahn; --tle mit einem aktuellen Elementsatz liefert die heutige Bahn.
+sat-source-tle = Aus dem Elementsatz von NORAD { $catalog } zur Epoche { $year }, Tag { $day }.
+error-sat-tle = Fehler: kein Elementsatz für { $name } in der Eingabe
+
+## Fetch
+
+error-fetch = Fehler: Elementsätze konnten nicht geladen werden: { $error }
+
+## Element set cache
+
+cache = Zwischengespeicherte Elementsätze ({ $path })
+cache-empty = Der Zwischenspeicher ist leer.
+cache-sets = { $count } TLE
+cache-fresh = aktuell
+cache-expired = abgelaufen
+cache-cleared = Entfernte zwischengespeicherte Downloads: { $count }
+warning-stale-tle = Warnung: Download fehlgeschlagen, verwende { $hours } h alte zwischengespeicherte Elementsätze
+error-no-cache = Fehler: kein Cache-Verzeichnis; HOME oder XDG_CACHE_HOME setzen
@@ -0,0 +1,613 @@
+## Shared report sections
+
+constants = Constants
+pi = Pi
+gravitational-constant = Gravitational Constant
+orbital-periods = Orbital Periods
+orbital-velocities = Orbital Velocities
+user-defined = User Defined
+
+sensitivities = Sensitivities
+period-per-altitude = dPeriod/dAltitude
+velocity-per-altitude = dVelocity/dAltitude
+
+## Units
+
+seconds = seconds
+minutes = minutes
+years = years
+days = days
+
+## Earth
+
+body-mass = { $body } Mass
+body-mu = { $body } GM
+body-radius = { $body } Radius
+body-polar-radius = { $body } Polar Radius
+body-flattening = { $body } Flattening
+body-rotation = { $body } Sidereal Rotation Period
+
+## Validation
+
+validation = Validation
+validation-pass = PASS
+validation-fail = FAIL
+validation-values = expected { $expected } { $unit }, computed { $computed } { $unit }
+validation-error = relative error { $error } (tolerance { $tolerance })
+validation-summary = { $passed } passed, { $failed } failed
+
+## Quiz
+
+quiz = Practice Problems (seed { $seed })
+quiz-given = Given: GM = { $mu } m^3*s^-2, body radius = { $radius } { $unit }
+quiz-period = A satellite is in a circular orbit { $altitude } { $unit } above Earth's surface. What is its orbital period?
+quiz-hohmann = A spacecraft in a circular { $from } { $unit } orbit moves to a circular { $to } { $unit } orbit with a Hohmann transfer. Find both burns, the total delta-v and the transfer time.
+quiz-escape = What is the escape velocity from { $altitude } { $unit } above Earth's surface?
+quiz-answer-key = Answer Key
+quiz-answers-hint = Run again with the same --seed and --answers to reveal the answer key.
+
+## Errors
+
+error-create-file = error: cannot create { $path }: { $error }
+error-write = error: failed to write output: { $error }
+
+## Habitable zone
+
+star = Star
+star-mass = Mass
+luminosity = Luminosity
+effective-temperature = Effective Temperature
+main-sequence-estimate = main-sequence estimate
+hab-temperature-warning = warning: the habitable-zone fits are only valid for { $min }-{ $max } K
+habitable-zone = Habitable Zone (Kopparapu et al. 2014)
+hab-optimistic-inner = Optimistic inner edge
+hab-conservative-inner = Conservative inner edge
+hab-conservative-outer = Conservative outer edge
+hab-optimistic-outer = Optimistic outer edge
+hab-recent-venus = recent Venus
+hab-runaway-greenhouse = runaway greenhouse
+hab-maximum-greenhouse = maximum greenhouse
+hab-early-mars = early Mars
+hab-at-distance = At { $distance } AU
+stellar-flux = Stellar Flux
+equilibrium-temperature = Equilibrium Temperature
+bond-albedo = Bond albedo { $albedo }
+orbital-period = Orbital Period
+zone = Zone
+hab-too-hot = closer than the habitable zone (too hot)
+hab-zone-optimistic = optimistic habitable zone
+hab-zone-conservative = conservative habitable zone
+hab-too-cold = outside the habitable zone (too cold)
+
+## Launch dispersion
+
+nominal-orbit = Nominal Orbit
+perigee-altitude = Perigee Altitude
+apogee-altitude = Apogee Altitude
+inclination = Inclination
+dispersion-escape = The nominal burnout state is not bound to the body; there is no apogee to disperse.
+dispersion-linearized = Linearized 1-sigma Errors
+dispersion-monte-carlo = Monte Carlo ({ $samples } samples, seed { $seed })
+dispersion-escaped = { $count } samples escaped and are excluded from the apogee statistics
+dispersion-statistics = mean { $mean } { $unit }, 1-sigma { $sigma } { $unit } (range { $min } .. { $max })
+
+## Disposal compliance
+
+compliance = Post-Mission Disposal ({ $altitude } { $unit } circular, BC { $bc } kg/m^2)
+natural-lifetime = Natural lifetime
+rule-25-year = 25-year rule
+rule-5-year = 5-year rule (FCC 2022)
+compliance-lower-perigee = Lower perigee to { $perigee }: delta-v { $delta_v }
+
+## Thermal environment
+
+thermal = Thermal Environment ({ $altitude } { $unit } circular, beta { $beta } deg)
+sunlit-fraction = Sunlight
+eclipse-fraction = Eclipse
+critical-beta = No eclipses above beta
+orbit-averaged-flux = Orbit-Averaged Flux
+solar-flux = Solar flux
+average-solar = Solar, sun-facing surface
+average-albedo = Albedo, nadir-facing surface
+earth-infrared = Earth infrared, nadir-facing surface
+
+## Solar array power
+
+power = Solar Array ({ $altitude } { $unit } circular, beta { $beta } deg)
+array-output = Array Output
+peak-power = Peak power
+sunlit-average-power = Average power in sunlight
+orbit-average-power = Orbit-average power
+energy-per-orbit = Energy per orbit
+
+## Plots
+
+circular-velocity = Circular Velocity
+plot-no-data = error: no points to plot in this altitude range
+plot-altitude-axis = Altitude (km)
+plot-period-axis = Period (minutes)
+plot-velocity-axis = Velocity (km/s)
+plot-coverage-title = Footprint Radius ({ $elevation } deg minimum elevation)
+plot-coverage-axis = Ground radius (km)
+plot-decay-title = Orbital Lifetime (BC { $bc } kg/m^2)
+plot-decay-axis = Lifetime (years)
+
+## Diagram
+
+diagram = Orbits to Scale
+earth = Earth
+perigee = perigee
+apogee = apogee
+diagram-unknown-orbit = error: unknown orbit '{ $name }', expected vleo, leo, meo, geo, gto or an altitude in km
+
+## glTF
+
+ground-track = Ground track, { $orbit }
+
+## Animate
+
+animate-title = { $altitude } km, { $inclination }°, t = { $minutes } min
+animate-written = Wrote { $count } frames to { $dir }
+longitude = Longitude (deg)
+latitude = Latitude (deg)
+
+## Phasing
+
+phasing = Walker { $total }/{ $planes }/{ $phasing } at { $altitude } { $unit }
+phasing-invalid = error: { $total }/{ $planes }/{ $phasing } is not a Walker pattern; satellites must split evenly across planes, phasing must be below the plane count and the drift offset must be non-zero
+phasing-per-plane = Satellites per plane
+phasing-in-plane = In-plane spacing
+phasing-raan = Plane spacing (RAAN)
+phasing-offset = Inter-plane phasing
+phasing-drift = Slot acquisition from one launch, drift orbit { $offset } { $unit } (slot 0 stays on station)
+phasing-drift-rate = Relative drift
+phasing-slot-time = Time to drift one slot
+phasing-delta-v = Delta-v into and out of the drift orbit
+phasing-slot = Slot
+phasing-target = Angle (deg)
+phasing-days = Drift (days)
+
+## Makeup
+
+makeup = Drag makeup at { $altitude } { $unit }, BC { $bc } kg/m^2
+makeup-density = Density
+makeup-decay = Altitude loss without makeup
+makeup-delta-v = Delta-v per year
+day = day
+
+## Radiation
+
+radiation = Radiation proxy at { $altitude } { $unit }, { $inclination } deg over { $orbits } orbits (IGRF 2020 eccentric dipole)
+radiation-saa-fraction = Time in the South Atlantic Anomaly
+radiation-saa-per-day = SAA passes per day
+radiation-inner-belt = Time with trapped-particle access, inner belt L { $low } to { $high }
+radiation-outer-belt = Time with trapped-particle access, outer belt L { $low } to { $high }
+radiation-no-saa = No South Atlantic Anomaly crossings.
+radiation-crossings = SAA crossings (times in minutes from the start)
+radiation-orbit = Orbit
+radiation-entry = Entry
+radiation-exit = Exit
+radiation-duration = Duration
+radiation-min-field = Min B (uT)
+
+## Bodies
+
+sun = Sun
+mercury = Mercury
+venus = Venus
+moon = Moon
+mars = Mars
+jupiter = Jupiter
+saturn = Saturn
+uranus = Uranus
+neptune = Neptune
+pluto = Pluto
+preset-low-orbit = Low orbit
+preset-synchronous = Synchronous orbit
+
+## Elliptical orbits
+
+at-periapsis = at periapsis
+at-apoapsis = at apoapsis
+error-apsides = error: periapsis must not be above apoapsis
+error-elements = error: semi-major axis must be positive and eccentricity from 0 up to but excluding 1
+
+## Elements
+
+elements = Keplerian Elements (Earth-centred inertial)
+semi-major-axis = Semi-major axis
+eccentricity = Eccentricity
+raan = Right ascension of the ascending node
+argument-of-periapsis = Argument of periapsis
+true-anomaly = True anomaly
+periapsis-altitude = Periapsis altitude
+apoapsis-altitude = Apoapsis altitude
+state-vector = State Vector
+position = Position
+velocity = Velocity
+error-keplerian = error: eccentricity must be non-negative and not 1, with a positive semi-major axis for closed orbits and a negative one for hyperbolic orbits
+
+## Transfers
+
+transfer-hohmann = Hohmann Transfer around { $body }: { $from } { $unit } to { $to } { $unit }
+transfer-first-burn = First burn
+transfer-second-burn = Second burn
+transfer-total = Total delta-v
+transfer-time = Transfer time
+hours = hours
+error-transfer-altitude = error: transfer altitudes must not be negative
+transfer-bielliptic = Bi-elliptic Transfer around { $body }: { $from } { $unit } to { $to } { $unit } via { $apogee } { $unit }
+transfer-third-burn = Third burn
+transfer-hohmann-total = Hohmann total delta-v
+transfer-ratio = Ratio of radii
+transfer-bielliptic-wins = Bi-elliptic saves { $saving } over Hohmann
+transfer-hohmann-wins = Hohmann is cheaper by { $saving }
+transfer-ratio-hohmann = Below a ratio of 11.94 Hohmann is cheaper for any apoapsis.
+transfer-ratio-depends = Between ratios of 11.94 and 15.58 the cheaper transfer depends on the apoapsis.
+transfer-ratio-bielliptic = Above a ratio of 15.58 bi-elliptic is cheaper for any apoapsis beyond the final orbit.
+error-bielliptic-apogee = error: the intermediate apoapsis must not be below either orbit
+
+## Sweep
+
+sweep = Circular Altitude Sweep ({ $body })
+sweep-altitude = Altitude ({ $unit })
+sweep-period = Period (min)
+sweep-velocity = Velocity ({ $unit })
+orbits = Orbits
+table-orbit = Orbit
+table-altitude = Altitude ({ $unit })
+table-period = Period ({ $unit })
+table-velocity = Velocity ({ $unit })
+
+## Period solver
+
+period-solution = Circular Orbit with a Period of { $period } s ({ $body })
+period-altitude = Altitude
+period-below-surface = warning: this orbit lies below the surface of { $body }
+
+## Custom bodies
+
+custom-body = Custom Body
+error-custom-body = error: mass, gravitational parameter, surface gravity and radius must be positive
+error-no-orbit = error: give an altitude, apsides, elements, --sweep or --period; this body has no preset orbits
+
+## Body definitions
+
+error-no-config = error: no home directory to find bodies.toml in; pass --config
+error-config = error: { $path }: { $error }
+error-unknown-body = no body named { $name } (defined: { $names })
+
+## Escape
+
+escape = Escape
+surface-escape-velocity = Escape velocity at the surface
+escape-velocity = Escape velocity
+departure-burn = Departure burn
+
+## J2
+
+j2 = J2 Secular Rates (inclination { $inclination } deg)
+nodal-regression = Nodal regression
+apsidal-rotation = Apsidal rotation
+j2-unknown = The J2 coefficient of { $body } is unknown.
+
+## TLE
+
+tle = { $name } (NORAD { $catalog })
+tle-epoch = Epoch: { $year }, day { $day }
+mean-motion = Mean motion
+mean-anomaly = Mean anomaly
+bstar = B* drag term (1/earth radii)
+error-read = error: { $source }: { $error }
+
+## Propagation
+
+since-epoch = { $days } days from the element set epoch
+position-teme = Position (TEME)
+velocity-teme = Velocity (TEME)
+geodetic-altitude = Altitude above WGS-84
+czml-document = Propagated orbits
+error-propagate = error: propagation failed: { $error }
+
+## Ground tracks
+
+groundtrack = Ground Track: { $name }
+groundtrack-elements = Keplerian orbit
+groundtrack-time = Time (UTC)
+groundtrack-altitude = Altitude ({ $unit })
+groundtrack-footprint = { $name } footprint
+groundtrack-footprint-radius = Footprint ({ $unit })
+groundtrack-document = Ground tracks
+error-groundtrack = error: { $error }
+
+## Passes
+
+passes = Passes of { $name } over { $latitude }, { $longitude } for { $days } d from { $start }
+passes-none = No passes above { $elevation } deg elevation.
+passes-aos = AOS (UTC)
+passes-culmination = Culmination (UTC)
+passes-los = LOS (UTC)
+passes-duration = Dur (min)
+passes-max-elevation = Max el
+passes-azimuth = Azimuth
+
+## Time
+
+time-convert = Time: { $time }
+tai-minus-utc = TAI - UTC
+julian-date = Julian date
+modified-julian-date = Modified Julian date
+julian-centuries = Since J2000 (TT)
+julian-centuries-unit = Julian centuries
+gmst = Greenwich mean sidereal time
+error-time = error: { $error }
+
+## Frames
+
+frame = Frame Conversion ({ $from } to { $to })
+frame-eci = ECI (J2000)
+frame-teme = TEME
+frame-ecef = ECEF
+frame-geodetic = geodetic
+frame-epoch = Epoch (UTC)
+frame-input = Input
+frame-output = Output
+error-frame = error: { $error }
+
+## Ellipsoid
+
+ellipsoid-altitudes = Altitudes Above the Ellipsoid of { $body }
+over-equator = Over the equator
+over-poles = Over the poles
+at-equator = at the equator
+at-poles = at the poles
+
+## Vis-viva
+
+visviva = Vis-viva at { $radius } { $unit } on an Orbit with a Semi-major Axis of { $sma } { $unit } ({ $body })
+visviva-apsis = (no eccentricity given: the radius is taken as periapsis or apoapsis)
+visviva-apsides = Periapsis / apoapsis radius
+flight-path-angle = Flight path angle
+specific-energy = Specific orbital energy
+specific-angular-momentum = Specific angular momentum
+visviva-below-surface = warning: periapsis lies below the surface of { $body }
+error-visviva = error: the semi-major axis and radius must be positive, the eccentricity from 0 up to but excluding 1, and the radius between periapsis and apoapsis
+
+## Anomalies
+
+anomaly-elliptic = Anomalies on an Ellipse (e = { $eccentricity })
+anomaly-hyperbolic = Anomalies on a Hyperbola (e = { $eccentricity })
+eccentric-anomaly = Eccentric anomaly
+hyperbolic-anomaly = Hyperbolic anomaly
+asymptote-anomaly = True anomaly of the asymptotes
+error-anomaly = error: { $error }
+
+## Two-body and numerical propagation
+
+kepler-ephemeris = Two-body Ephemeris around { $body } (inertial)
+kepler-elapsed = Elapsed (s)
+error-kepler-duration = error: hyperbolic orbits have no period; give --duration
+numeric-ephemeris = Numerical Ephemeris around { $body } ({ $method }, inertial)
+error-numeric = error: the ballistic coefficient and tolerance must be positive
+error-drag-earth = error: drag uses Earth's atmosphere and needs --body earth
+
+## Decay
+
+decay = Orbital Decay at { $perigee } x { $apogee } { $unit }, BC { $bc } kg/m^2
+decay-density = Density at perigee
+decay-rate = Semi-major axis change
+decay-period-rate = Period change
+decay-note = Lifetime runs until perigee drops below { $reentry } { $unit }, through a static exponential atmosphere at moderate solar activity.
+error-decay = error: the ballistic coefficient, B*, mass, area and drag coefficient must be positive, and the altitude must not be above --apoapsis
+error-decay-earth = error: decay uses Earth's atmosphere and is only available as orbit earth decay
+error-decay-reentry = error: the perigee must be above the { $reentry } km reentry altitude
+
+## Delta-v budget
+
+budget = Delta-v Budget around { $body }
+budget-maneuver = Maneuver
+budget-delta-v = Delta-v ({ $unit })
+budget-subtotal = Maneuvers
+budget-margin = Margin ({ $margin } %)
+budget-total = Total delta-v
+maneuver-hohmann = Hohmann transfer from { $from } { $unit } to { $to } { $unit }
+maneuver-plane-change = Plane change of { $inclination } deg at { $altitude } { $unit }
+maneuver-circularize = Circularize { $periapsis } x { $apoapsis } { $unit } at apoapsis
+maneuver-deorbit = Deorbit from { $altitude } { $unit } to a { $perigee } { $unit } perigee
+error-budget-margin = error: the margin must not be negative
+
+## Plane change
+
+plane-change = Plane Change of { $angle } deg at { $altitude } { $unit } ({ $body })
+plane-change-delta-v = Plane change delta-v
+plane-change-transfer = Plane Change of { $angle } deg with a Hohmann Transfer from { $from } { $unit } to { $to } { $unit } ({ $body })
+plane-change-separate = Transfer, then the plane change as a separate burn
+plane-change-at-second = Whole plane change combined with the second burn
+plane-change-optimal = Plane change split for the least delta-v
+plane-change-saving = Saves { $saving } over separate burns
+
+## Rocket equation
+
+rocket = Rocket Equation (Isp { $isp } s, dry mass { $dry } kg)
+rocket-exhaust-velocity = Effective exhaust velocity
+rocket-wet-mass = Wet mass
+rocket-propellant = Propellant
+rocket-mass-ratio = Mass ratio
+rocket-delta-v = Delta-v
+rocket-target = For a delta-v of { $delta_v }
+rocket-enough = The stage carries { $spare } kg more propellant than needed.
+rocket-short = The stage is { $short } kg of propellant short.
+budget-propellant = Propellant for the total (Isp { $isp } s, dry mass { $dry } kg)
+error-rocket = error: Isp and dry mass must be positive, the wet mass must not be below the dry mass and the delta-v must not be negative
+
+## Phasing and launch windows
+
+phase = Phasing for a Hohmann Transfer from { $from } { $unit } to { $to } { $unit } ({ $body })
+phase-planets = Launch Window for a Hohmann Transfer from { $from } to { $to }
+phase-angle = Required phase angle (target ahead)
+synodic-period = Synodic period
+phase-same-orbit = Both orbits have the same period, so their phase never changes.
+phase-date = Date
+phase-longitude = Mean longitude of { $body }
+phase-current = Current phase angle
+phase-next-window = Next window
+phase-in = in { $time }
+phase-note = Circular coplanar orbits; planets on mean orbits, good to a few weeks.
+error-phase-mixed = error: give two altitudes around one body or two planets
+error-phase-same = error: give two different orbits to phase between
+
+## Synodic period
+
+synodic = Synodic Period
+synodic-first = First orbit period
+synodic-second = Second orbit period
+synodic-revolutions = Revolutions per synodic period (first / second)
+
+## Lambert's problem
+
+lambert = Lambert Transfer ({ $revolutions } full revolutions)
+lambert-time-of-flight = Time of flight
+lambert-departure = Departure velocity
+lambert-arrival = Arrival velocity
+error-lambert = error: no transfer arc: { $error }
+
+## Porkchop
+
+porkchop-title = { $from } to { $to } transfers
+porkchop-depart-axis = Days after departure { $date }
+porkchop-arrive-axis = Days after arrival { $date }
+porkchop-cells = Date pairs with a transfer
+porkchop-lowest-c3 = Lowest launch energy
+porkchop-lowest-total = Lowest departure plus arrival excess speed
+porkchop-depart = Departure
+porkchop-arrive = Arrival
+porkchop-time-of-flight = Time of flight
+porkchop-c3 = C3
+porkchop-arrival-v-inf = Arrival v-infinity
+error-porkchop = error: give two different planets other than the Sun and the Moon, and a positive step
+error-porkchop-empty = error: no arrival date falls after a departure date
+
+## Planet positions
+
+where = { $body } at { $date }
+where-heliocentric = Heliocentric, J2000 ecliptic
+where-geocentric = Geocentric, J2000 equatorial
+where-ecliptic-longitude = Ecliptic longitude
+where-ecliptic-latitude = Ecliptic latitude
+where-sun-distance = Distance from the Sun
+where-right-ascension = Right ascension
+where-declination = Declination
+where-earth-distance = Distance from Earth
+where-light-time = Light time
+where-note = Mean orbital elements (JPL, 1800-2050); Earth is the Earth-Moon barycentre.
+error-where = error: positions are available for the Sun and the planets, not the Moon
+
+## Interplanetary transfer
+
+interplanetary = { $from } to { $to } Transfer
+interplanetary-departure = Departure from { $body }
+interplanetary-arrival = Arrival at { $body }
+interplanetary-v-inf = Hyperbolic excess speed
+interplanetary-soi = Sphere of influence radius
+interplanetary-injection = Injection burn from a { $altitude } { $unit } parking orbit
+interplanetary-capture = Capture burn into a { $periapsis } x { $apoapsis } { $unit } orbit
+error-interplanetary = error: give two different planets other than the Sun and the Moon, an arrival after the departure and a capture apoapsis no lower than the periapsis
+
+## Sphere of influence
+
+soi = Sphere of Influence of { $body }
+soi-parent = Orbits
+soi-distance = Mean distance from it
+soi-hill = Hill radius
+soi-radii = body radii
+soi-note = Farther out the parent's gravity dominates, and two-body orbits around { $body } stop being a good model.
+error-soi = error: the Sun orbits nothing, so it has no sphere of influence here
+
+## Lagrange points
+
+lagrange = { $primary }-{ $secondary } Lagrange Points
+lagrange-separation = Separation of the primaries
+lagrange-mass-ratio = Mass ratio
+lagrange-from = Distance from { $body }
+lagrange-note = Positions in the rotating frame from the barycentre, with x pointing away from { $primary }; circular restricted three-body problem.
+
+## Rendezvous
+
+rendezvous = Clohessy-Wiltshire Relative Motion ({ $altitude } { $unit } target orbit)
+rendezvous-coast = Relative state after coasting
+rendezvous-range = Range
+rendezvous-transfer = Two-burn rendezvous
+rendezvous-first-burn = First burn
+rendezvous-second-burn = Second burn
+rendezvous-singular = No transfer reaches the target in exactly this time; try another time of flight.
+rendezvous-note = Frame: x radial (outward), y along track, z orbit normal; linearised motion near a circular orbit.
+
+## Orbit determination
+
+error-determine = error: cannot determine an orbit: { $error }
+
+## Coverage
+
+coverage = Coverage from { $altitude } { $unit } at { $inclination } deg
+coverage-central-angle = Footprint central angle
+coverage-radius = Footprint radius on the ground
+coverage-area = Footprint area
+coverage-per-orbit = Surface swept per orbit
+coverage-orbits-per-day = Orbits per day
+coverage-revisit = Average revisit at latitude { $latitude }
+coverage-never = never in view
+coverage-note = Spherical body, average revisit assuming swaths never overlap.
+
+## Eclipse
+
+eclipse = Eclipses around { $body } at { $altitude } { $unit }, beta { $beta } deg
+eclipse-longest = Longest eclipse (beta 0)
+eclipse-note = Cylindrical shadow, no penumbra; eclipse time is per orbit.
+
+## Beta angle
+
+beta = Beta Angle ({ $inclination } deg, RAAN { $raan } deg) on { $date }
+beta-sweep = Beta Angle ({ $inclination } deg, RAAN { $raan } deg) from { $start } to { $end }
+beta-angle = Beta angle
+beta-minimum = Minimum
+beta-maximum = Maximum
+beta-nodal-drift = Nodal drift (J2)
+beta-seasons = Eclipse seasons (|beta| below the critical angle):
+beta-no-seasons = none, sunlit throughout
+error-beta = error: the step between dates must be positive
+
+## Repeat ground tracks
+
+repeat = Repeat Ground Tracks ({ $revs } revolutions per { $days } d)
+repeat-inclination = Inclination (deg)
+repeat-nodal-period = Period (min)
+repeat-nodal-day = Nodal day (h)
+repeat-sso = SSO
+repeat-below-surface = below the surface
+repeat-spacing = Track spacing at the equator
+repeat-shorter = The track already repeats after { $revs } revolutions per { $days } d.
+error-repeat = error: give at least one revolution and one day
+error-repeat-earth = error: repeat ground tracks are only available as orbit earth repeat
+
+## Satellites
+
+sat = { $name }
+sat-catalog = Built-in Satellites
+sat-source-nominal = Nominal orbit; pass --tle with a current element set for the orbit today.
+sat-source-tle = From the element set of NORAD { $catalog } at epoch { $year }, day { $day }.
+error-sat-tle = error: no element set for { $name } in the input
+
+## Fetch
+
+error-fetch = error: could not fetch element sets: { $error }
+
+## Element set cache
+
+cache = Cached Element Sets ({ $path })
+cache-empty = The cache is empty.
+cache-sets = { $count } TLE
+cache-fresh = fresh
+cache-expired = expired
+cache-cleared = Cached downloads removed: { $count }
+warning-stale-tle = warning: download failed, using cached element sets { $hours } h old
+error-no-cache = error: no cache directory; set HOME or XDG_CACHE_HOME
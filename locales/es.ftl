@@ -0,0 +1,613 @@
+## Shared report sections
+
+constants = Constantes
+pi = Pi
+gravitational-constant = Constante gravitacional
+orbital-periods = Periodos orbitales
+orbital-velocities = Velocidades orbitales
+user-defined = Definida por el usuario
+
+sensitivities = Sensibilidades
+period-per-altitude = dPeriodo/dAltitud
+velocity-per-altitude = dVelocidad/dAltitud
+
+## Units
+
+seconds = segundos
+minutes = minutos
+years = años
+days = días
+
+## Earth
+
+body-mass = Masa ({ $body })
+body-mu = GM ({ $body })
+body-radius = Radio ({ $body })
+body-polar-radius = Radio polar ({ $body })
+body-flattening = Achatamiento ({ $body })
+body-rotation = Período de rotación sideral ({ $body })
+
+## Validation
+
+validation = Validación
+validation-pass = CORRECTO
+validation-fail = FALLO
+validation-values = esperado { $expected } { $unit }, calculado { $computed } { $unit }
+validation-error = error relativo { $error } (tolerancia { $tolerance })
+validation-summary = { $passed } correctos, { $failed } fallidos
+
+## Quiz
+
+quiz = Problemas de práctica (semilla { $seed })
+quiz-given = Datos: GM = { $mu } m^3*s^-2, radio del cuerpo = { $radius } { $unit }
+quiz-period = Un satélite está en una órbita circular a { $altitude } { $unit } sobre la superficie de la Tierra. ¿Cuál es su periodo orbital?
+quiz-hohmann = Una nave en una órbita circular de { $from } { $unit } pasa a una órbita circular de { $to } { $unit } mediante una transferencia de Hohmann. Calcula ambos impulsos, el delta-v total y el tiempo de transferencia.
+quiz-escape = ¿Cuál es la velocidad de escape a { $altitude } { $unit } sobre la superficie de la Tierra?
+quiz-answer-key = Soluciones
+quiz-answers-hint = Vuelve a ejecutar con la misma --seed y --answers para ver las soluciones.
+
+## Errors
+
+error-create-file = error: no se puede crear { $path }: { $error }
+error-write = error: no se pudo escribir la salida: { $error }
+
+## Habitable zone
+
+star = Estrella
+star-mass = Masa
+luminosity = Luminosidad
+effective-temperature = Temperatura efectiva
+main-sequence-estimate = estimación de secuencia principal
+hab-temperature-warning = aviso: los ajustes de zona habitable solo son válidos entre { $min } y { $max } K
+habitable-zone = Zona habitable (Kopparapu et al. 2014)
+hab-optimistic-inner = Borde interior optimista
+hab-conservative-inner = Borde interior conservador
+hab-conservative-outer = Borde exterior conservador
+hab-optimistic-outer = Borde exterior optimista
+hab-recent-venus = Venus reciente
+hab-runaway-greenhouse = efecto invernadero desbocado
+hab-maximum-greenhouse = efecto invernadero máximo
+hab-early-mars = Marte primitivo
+hab-at-distance = A { $distance } UA
+stellar-flux = Flujo estelar
+equilibrium-temperature = Temperatura de equilibrio
+bond-albedo = albedo de Bond { $albedo }
+orbital-period = Periodo orbital
+zone = Zona
+hab-too-hot = más cerca que la zona habitable (demasiado caliente)
+hab-zone-optimistic = zona habitable optimista
+hab-zone-conservative = zona habitable conservadora
+hab-too-cold = fuera de la zona habitable (demasiado frío)
+
+## Launch dispersion
+
+nominal-orbit = Órbita nominal
+perigee-altitude = Altitud del perigeo
+apogee-altitude = Altitud del apogeo
+inclination = Inclinación
+dispersion-escape = El estado nominal de fin de combustión no está ligado al cuerpo; no hay apogeo que dispersar.
+dispersion-linearized = Errores 1-sigma linealizados
+dispersion-monte-carlo = Monte Carlo ({ $samples } muestras, semilla { $seed })
+dispersion-escaped = { $count } muestras escaparon y se excluyen de la estadística del apogeo
+dispersion-statistics = media { $mean } { $unit }, 1-sigma { $sigma } { $unit } (rango { $min } .. { $max })
+
+## Disposal compliance
+
+compliance = Retirada tras la misión ({ $altitude } { $unit } circular, BC { $bc } kg/m^2)
+natural-lifetime = Vida orbital natural
+rule-25-year = Regla de 25 años
+rule-5-year = Regla de 5 años (FCC 2022)
+compliance-lower-perigee = Bajar el perigeo a { $perigee }: delta-v { $delta_v }
+
+## Thermal environment
+
+thermal = Entorno térmico ({ $altitude } { $unit } circular, beta { $beta } grados)
+sunlit-fraction = Luz solar
+eclipse-fraction = Eclipse
+critical-beta = Sin eclipses por encima de beta
+orbit-averaged-flux = Flujo promediado en la órbita
+solar-flux = Flujo solar
+average-solar = Solar, superficie orientada al Sol
+average-albedo = Albedo, superficie orientada al nadir
+earth-infrared = Infrarrojo terrestre, superficie orientada al nadir
+
+## Solar array power
+
+power = Panel solar ({ $altitude } { $unit } circular, beta { $beta } grados)
+array-output = Producción del panel
+peak-power = Potencia máxima
+sunlit-average-power = Potencia media con luz solar
+orbit-average-power = Potencia media en la órbita
+energy-per-orbit = Energía por órbita
+
+## Plots
+
+circular-velocity = Velocidad circular
+plot-no-data = error: no hay puntos que representar en este rango de altitudes
+plot-altitude-axis = Altitud (km)
+plot-period-axis = Periodo (minutos)
+plot-velocity-axis = Velocidad (km/s)
+plot-coverage-title = Radio de cobertura (elevación mínima { $elevation } grados)
+plot-coverage-axis = Radio en tierra (km)
+plot-decay-title = Vida orbital (BC { $bc } kg/m^2)
+plot-decay-axis = Vida orbital (años)
+
+## Diagram
+
+diagram = Órbitas a escala
+earth = Tierra
+perigee = perigeo
+apogee = apogeo
+diagram-unknown-orbit = error: órbita desconocida '{ $name }', se esperaba vleo, leo, meo, geo, gto o una altitud en km
+
+## glTF
+
+ground-track = Traza terrestre, { $orbit }
+
+## Animate
+
+animate-title = { $altitude } km, { $inclination }°, t = { $minutes } min
+animate-written = Se escribieron { $count } fotogramas en { $dir }
+longitude = Longitud (grados)
+latitude = Latitud (grados)
+
+## Phasing
+
+phasing = Walker { $total }/{ $planes }/{ $phasing } a { $altitude } { $unit }
+phasing-invalid = error: { $total }/{ $planes }/{ $phasing } no es un patrón Walker; los satélites deben repartirse por igual entre los planos, el faseo debe ser menor que el número de planos y el desplazamiento de deriva no puede ser cero
+phasing-per-plane = Satélites por plano
+phasing-in-plane = Separación en el plano
+phasing-raan = Separación entre planos (RAAN)
+phasing-offset = Faseo entre planos
+phasing-drift = Adquisición de posiciones desde un lanzamiento, órbita de deriva { $offset } { $unit } (la posición 0 permanece en su sitio)
+phasing-drift-rate = Deriva relativa
+phasing-slot-time = Tiempo para derivar una posición
+phasing-delta-v = Delta-v de entrada y salida de la órbita de deriva
+phasing-slot = Posición
+phasing-target = Ángulo (grados)
+phasing-days = Deriva (días)
+
+## Makeup
+
+makeup = Compensación de arrastre a { $altitude } { $unit }, BC { $bc } kg/m^2
+makeup-density = Densidad
+makeup-decay = Pérdida de altitud sin compensación
+makeup-delta-v = Delta-v por año
+day = día
+
+## Radiation
+
+radiation = Indicador de radiación a { $altitude } { $unit }, { $inclination } grados durante { $orbits } órbitas (dipolo excéntrico IGRF 2020)
+radiation-saa-fraction = Tiempo en la Anomalía del Atlántico Sur
+radiation-saa-per-day = Pasos por la AAS al día
+radiation-inner-belt = Tiempo con acceso de partículas atrapadas, cinturón interior L { $low } a { $high }
+radiation-outer-belt = Tiempo con acceso de partículas atrapadas, cinturón exterior L { $low } a { $high }
+radiation-no-saa = Sin cruces de la Anomalía del Atlántico Sur.
+radiation-crossings = Cruces de la AAS (tiempos en minutos desde el inicio)
+radiation-orbit = Órbita
+radiation-entry = Entrada
+radiation-exit = Salida
+radiation-duration = Duración
+radiation-min-field = B mín (uT)
+
+## Bodies
+
+sun = Sol
+mercury = Mercurio
+venus = Venus
+moon = Luna
+mars = Marte
+jupiter = Júpiter
+saturn = Saturno
+uranus = Urano
+neptune = Neptuno
+pluto = Plutón
+preset-low-orbit = Órbita baja
+preset-synchronous = Órbita síncrona
+
+## Elliptical orbits
+
+at-periapsis = en el periapsis
+at-apoapsis = en el apoapsis
+error-apsides = error: el periapsis no puede estar por encima del apoapsis
+error-elements = error: el semieje mayor debe ser positivo y la excentricidad de 0 a menos de 1
+
+## Elements
+
+elements = Elementos keplerianos (inercial centrado en la Tierra)
+semi-major-axis = Semieje mayor
+eccentricity = Excentricidad
+raan = Ascensión recta del nodo ascendente
+argument-of-periapsis = Argumento del periapsis
+true-anomaly = Anomalía verdadera
+periapsis-altitude = Altitud del periapsis
+apoapsis-altitude = Altitud del apoapsis
+state-vector = Vector de estado
+position = Posición
+velocity = Velocidad
+error-keplerian = error: la excentricidad debe ser no negativa y distinta de 1, con semieje mayor positivo para órbitas cerradas y negativo para órbitas hiperbólicas
+
+## Transfers
+
+transfer-hohmann = Transferencia de Hohmann alrededor de { $body }: de { $from } { $unit } a { $to } { $unit }
+transfer-first-burn = Primer encendido
+transfer-second-burn = Segundo encendido
+transfer-total = Delta-v total
+transfer-time = Tiempo de transferencia
+hours = horas
+error-transfer-altitude = error: las altitudes de transferencia no pueden ser negativas
+transfer-bielliptic = Transferencia bielíptica alrededor de { $body }: de { $from } { $unit } a { $to } { $unit } vía { $apogee } { $unit }
+transfer-third-burn = Tercer encendido
+transfer-hohmann-total = Delta-v total de Hohmann
+transfer-ratio = Razón de radios
+transfer-bielliptic-wins = La bielíptica ahorra { $saving } frente a Hohmann
+transfer-hohmann-wins = Hohmann es más barata por { $saving }
+transfer-ratio-hohmann = Con una razón menor que 11,94 Hohmann es más barata para cualquier apoapsis.
+transfer-ratio-depends = Con razones entre 11,94 y 15,58 la transferencia más barata depende de la apoapsis.
+transfer-ratio-bielliptic = Con una razón mayor que 15,58 la bielíptica es más barata para cualquier apoapsis más allá de la órbita final.
+error-bielliptic-apogee = error: la apoapsis intermedia no puede estar por debajo de ninguna de las órbitas
+
+## Sweep
+
+sweep = Barrido de altitud circular ({ $body })
+sweep-altitude = Altitud ({ $unit })
+sweep-period = Período (min)
+sweep-velocity = Velocidad ({ $unit })
+orbits = Órbitas
+table-orbit = Órbita
+table-altitude = Altitud ({ $unit })
+table-period = Período ({ $unit })
+table-velocity = Velocidad ({ $unit })
+
+## Period solver
+
+period-solution = Órbita circular con un período de { $period } s ({ $body })
+period-altitude = Altitud
+period-below-surface = aviso: esta órbita queda por debajo de la superficie de { $body }
+
+## Custom bodies
+
+custom-body = Cuerpo personalizado
+error-custom-body = error: la masa, el parámetro gravitacional, la gravedad superficial y el radio deben ser positivos
+error-no-orbit = error: indique una altitud, ápsides, elementos, --sweep o --period; este cuerpo no tiene órbitas predefinidas
+
+## Body definitions
+
+error-no-config = error: no hay directorio personal donde buscar bodies.toml; use --config
+error-config = error: { $path }: { $error }
+error-unknown-body = no hay ningún cuerpo llamado { $name } (definidos: { $names })
+
+## Escape
+
+escape = Escape
+surface-escape-velocity = Velocidad de escape en la superficie
+escape-velocity = Velocidad de escape
+departure-burn = Encendido de salida
+
+## J2
+
+j2 = Tasas seculares de J2 (inclinación { $inclination } grados)
+nodal-regression = Regresión nodal
+apsidal-rotation = Rotación del periapsis
+j2-unknown = Se desconoce el coeficiente J2 de { $body }.
+
+## TLE
+
+tle = { $name } (NORAD { $catalog })
+tle-epoch = Época: { $year }, día { $day }
+mean-motion = Movimiento medio
+mean-anomaly = Anomalía media
+bstar = Término de arrastre B* (1/radios terrestres)
+error-read = error: { $source }: { $error }
+
+## Propagation
+
+since-epoch = { $days } días desde la época del conjunto de elementos
+position-teme = Posición (TEME)
+velocity-teme = Velocidad (TEME)
+geodetic-altitude = Altitud sobre WGS-84
+czml-document = Órbitas propagadas
+error-propagate = error: la propagación falló: { $error }
+
+## Ground tracks
+
+groundtrack = Traza terrestre: { $name }
+groundtrack-elements = Órbita kepleriana
+groundtrack-time = Hora (UTC)
+groundtrack-altitude = Altitud ({ $unit })
+groundtrack-footprint = Huella de { $name }
+groundtrack-footprint-radius = Huella ({ $unit })
+groundtrack-document = Trazas terrestres
+error-groundtrack = error: { $error }
+
+## Passes
+
+passes = Pasos de { $name } sobre { $latitude }, { $longitude } durante { $days } d desde { $start }
+passes-none = No hay pasos por encima de { $elevation } grados de elevación.
+passes-aos = AOS (UTC)
+passes-culmination = Culminación (UTC)
+passes-los = LOS (UTC)
+passes-duration = Dur. (min)
+passes-max-elevation = Elev máx
+passes-azimuth = Acimut
+
+## Time
+
+time-convert = Tiempo: { $time }
+tai-minus-utc = TAI - UTC
+julian-date = Fecha juliana
+modified-julian-date = Fecha juliana modificada
+julian-centuries = Desde J2000 (TT)
+julian-centuries-unit = siglos julianos
+gmst = Tiempo sidéreo medio de Greenwich
+error-time = error: { $error }
+
+## Frames
+
+frame = Conversión de sistema de referencia ({ $from } a { $to })
+frame-eci = ECI (J2000)
+frame-teme = TEME
+frame-ecef = ECEF
+frame-geodetic = geodésico
+frame-epoch = Época (UTC)
+frame-input = Entrada
+frame-output = Salida
+error-frame = error: { $error }
+
+## Ellipsoid
+
+ellipsoid-altitudes = Altitudes sobre el elipsoide de { $body }
+over-equator = Sobre el ecuador
+over-poles = Sobre los polos
+at-equator = en el ecuador
+at-poles = en los polos
+
+## Vis-viva
+
+visviva = Vis-viva a { $radius } { $unit } en una órbita con semieje mayor de { $sma } { $unit } ({ $body })
+visviva-apsis = (sin excentricidad: el radio se toma como periapsis o apoapsis)
+visviva-apsides = Radio de periapsis / apoapsis
+flight-path-angle = Ángulo de trayectoria
+specific-energy = Energía orbital específica
+specific-angular-momentum = Momento angular específico
+visviva-below-surface = aviso: el periapsis está bajo la superficie de { $body }
+error-visviva = error: el semieje mayor y el radio deben ser positivos, la excentricidad desde 0 hasta 1 sin incluirlo, y el radio entre periapsis y apoapsis
+
+## Anomalies
+
+anomaly-elliptic = Anomalías en una elipse (e = { $eccentricity })
+anomaly-hyperbolic = Anomalías en una hipérbola (e = { $eccentricity })
+eccentric-anomaly = Anomalía excéntrica
+hyperbolic-anomaly = Anomalía hiperbólica
+asymptote-anomaly = Anomalía verdadera de las asíntotas
+error-anomaly = error: { $error }
+
+## Two-body and numerical propagation
+
+kepler-ephemeris = Efemérides de dos cuerpos alrededor de { $body } (inercial)
+kepler-elapsed = Transcurrido (s)
+error-kepler-duration = error: las órbitas hiperbólicas no tienen periodo; indique --duration
+numeric-ephemeris = Efemérides numéricas alrededor de { $body } ({ $method }, inercial)
+error-numeric = error: el coeficiente balístico y la tolerancia deben ser positivos
+error-drag-earth = error: el arrastre usa la atmósfera terrestre y requiere --body earth
+
+## Decay
+
+decay = Decaimiento orbital a { $perigee } x { $apogee } { $unit }, BC { $bc } kg/m^2
+decay-density = Densidad en el perigeo
+decay-rate = Cambio del semieje mayor
+decay-period-rate = Cambio del periodo
+decay-note = La vida útil termina cuando el perigeo baja de { $reentry } { $unit }, con una atmósfera exponencial estática y actividad solar moderada.
+error-decay = error: el coeficiente balístico, B*, la masa, el área y el coeficiente de arrastre deben ser positivos, y la altitud no debe superar --apoapsis
+error-decay-earth = error: el decaimiento usa la atmósfera terrestre y solo está disponible como orbit earth decay
+error-decay-reentry = error: el perigeo debe estar por encima de la altitud de reentrada de { $reentry } km
+
+## Delta-v budget
+
+budget = Presupuesto de delta-v alrededor de { $body }
+budget-maneuver = Maniobra
+budget-delta-v = Delta-v ({ $unit })
+budget-subtotal = Maniobras
+budget-margin = Margen ({ $margin } %)
+budget-total = Delta-v total
+maneuver-hohmann = Transferencia de Hohmann de { $from } { $unit } a { $to } { $unit }
+maneuver-plane-change = Cambio de plano de { $inclination } grados a { $altitude } { $unit }
+maneuver-circularize = Circularizar { $periapsis } x { $apoapsis } { $unit } en el apoapsis
+maneuver-deorbit = Reentrada desde { $altitude } { $unit } a un perigeo de { $perigee } { $unit }
+error-budget-margin = error: el margen no puede ser negativo
+
+## Plane change
+
+plane-change = Cambio de plano de { $angle } grados a { $altitude } { $unit } ({ $body })
+plane-change-delta-v = Delta-v del cambio de plano
+plane-change-transfer = Cambio de plano de { $angle } grados con una transferencia de Hohmann de { $from } { $unit } a { $to } { $unit } ({ $body })
+plane-change-separate = Transferencia y después el cambio de plano como encendido aparte
+plane-change-at-second = Todo el cambio de plano combinado con el segundo encendido
+plane-change-optimal = Cambio de plano repartido para el menor delta-v
+plane-change-saving = Ahorra { $saving } frente a encendidos separados
+
+## Rocket equation
+
+rocket = Ecuación del cohete (Isp { $isp } s, masa en seco { $dry } kg)
+rocket-exhaust-velocity = Velocidad de escape efectiva
+rocket-wet-mass = Masa inicial
+rocket-propellant = Propelente
+rocket-mass-ratio = Relación de masas
+rocket-delta-v = Delta-v
+rocket-target = Para un delta-v de { $delta_v }
+rocket-enough = La etapa lleva { $spare } kg de propelente más de lo necesario.
+rocket-short = A la etapa le faltan { $short } kg de propelente.
+budget-propellant = Propelente para el total (Isp { $isp } s, masa en seco { $dry } kg)
+error-rocket = error: el Isp y la masa en seco deben ser positivos, la masa inicial no puede ser menor que la masa en seco y el delta-v no puede ser negativo
+
+## Phasing and launch windows
+
+phase = Fase para una transferencia de Hohmann de { $from } { $unit } a { $to } { $unit } ({ $body })
+phase-planets = Ventana de lanzamiento para una transferencia de Hohmann de { $from } a { $to }
+phase-angle = Ángulo de fase necesario (objetivo adelante)
+synodic-period = Periodo sinódico
+phase-same-orbit = Ambas órbitas tienen el mismo periodo, así que su fase no cambia.
+phase-date = Fecha
+phase-longitude = Longitud media de { $body }
+phase-current = Ángulo de fase actual
+phase-next-window = Próxima ventana
+phase-in = dentro de { $time }
+phase-note = Órbitas circulares y coplanares; planetas en órbitas medias, con error de pocas semanas.
+error-phase-mixed = error: indique dos altitudes alrededor de un cuerpo o dos planetas
+error-phase-same = error: indique dos órbitas diferentes entre las que ajustar la fase
+
+## Synodic period
+
+synodic = Periodo sinódico
+synodic-first = Periodo de la primera órbita
+synodic-second = Periodo de la segunda órbita
+synodic-revolutions = Revoluciones por periodo sinódico (primera / segunda)
+
+## Lambert's problem
+
+lambert = Transferencia de Lambert ({ $revolutions } revoluciones completas)
+lambert-time-of-flight = Tiempo de vuelo
+lambert-departure = Velocidad de salida
+lambert-arrival = Velocidad de llegada
+error-lambert = error: no hay arco de transferencia: { $error }
+
+## Porkchop
+
+porkchop-title = Transferencias de { $from } a { $to }
+porkchop-depart-axis = Días desde la salida { $date }
+porkchop-arrive-axis = Días desde la llegada { $date }
+porkchop-cells = Pares de fechas con transferencia
+porkchop-lowest-c3 = Menor energía de lanzamiento
+porkchop-lowest-total = Menor suma de velocidades de exceso de salida y llegada
+porkchop-depart = Salida
+porkchop-arrive = Llegada
+porkchop-time-of-flight = Tiempo de vuelo
+porkchop-c3 = C3
+porkchop-arrival-v-inf = V-infinito de llegada
+error-porkchop = error: indique dos planetas diferentes, distintos del Sol y la Luna, y un paso positivo
+error-porkchop-empty = error: ninguna fecha de llegada es posterior a una fecha de salida
+
+## Planet positions
+
+where = { $body } el { $date }
+where-heliocentric = Heliocéntrica, eclíptica J2000
+where-geocentric = Geocéntrica, ecuatorial J2000
+where-ecliptic-longitude = Longitud eclíptica
+where-ecliptic-latitude = Latitud eclíptica
+where-sun-distance = Distancia al Sol
+where-right-ascension = Ascensión recta
+where-declination = Declinación
+where-earth-distance = Distancia a la Tierra
+where-light-time = Tiempo de luz
+where-note = Elementos orbitales medios (JPL, 1800-2050); la Tierra es el baricentro Tierra-Luna.
+error-where = error: hay posiciones para el Sol y los planetas, no para la Luna
+
+## Interplanetary transfer
+
+interplanetary = Transferencia de { $from } a { $to }
+interplanetary-departure = Salida de { $body }
+interplanetary-arrival = Llegada a { $body }
+interplanetary-v-inf = Velocidad hiperbólica de exceso
+interplanetary-soi = Radio de la esfera de influencia
+interplanetary-injection = Maniobra de inyección desde una órbita de aparcamiento de { $altitude } { $unit }
+interplanetary-capture = Maniobra de captura a una órbita de { $periapsis } x { $apoapsis } { $unit }
+error-interplanetary = error: indique dos planetas diferentes, distintos del Sol y la Luna, una llegada posterior a la salida y un apoapsis de captura no inferior al periapsis
+
+## Sphere of influence
+
+soi = Esfera de influencia de { $body }
+soi-parent = Orbita alrededor de
+soi-distance = Distancia media
+soi-hill = Radio de Hill
+soi-radii = radios del cuerpo
+soi-note = Más allá domina la gravedad del cuerpo central, y las órbitas de dos cuerpos alrededor de { $body } dejan de ser un buen modelo.
+error-soi = error: el Sol no orbita nada, así que aquí no tiene esfera de influencia
+
+## Lagrange points
+
+lagrange = Puntos de Lagrange { $primary }-{ $secondary }
+lagrange-separation = Separación de los primarios
+lagrange-mass-ratio = Razón de masas
+lagrange-from = Distancia a { $body }
+lagrange-note = Posiciones en el sistema rotante desde el baricentro, con x en sentido opuesto a { $primary }; problema restringido circular de tres cuerpos.
+
+## Rendezvous
+
+rendezvous = Movimiento relativo de Clohessy-Wiltshire (órbita objetivo de { $altitude } { $unit })
+rendezvous-coast = Estado relativo tras el vuelo libre
+rendezvous-range = Distancia
+rendezvous-transfer = Encuentro con dos maniobras
+rendezvous-first-burn = Primera maniobra
+rendezvous-second-burn = Segunda maniobra
+rendezvous-singular = Ninguna transferencia llega al objetivo en exactamente este tiempo; pruebe otro tiempo de vuelo.
+rendezvous-note = Sistema: x radial (hacia fuera), y a lo largo de la trayectoria, z normal a la órbita; movimiento linealizado cerca de una órbita circular.
+
+## Orbit determination
+
+error-determine = error: no se puede determinar la órbita: { $error }
+
+## Coverage
+
+coverage = Cobertura desde { $altitude } { $unit } a { $inclination } grados
+coverage-central-angle = Ángulo central de la huella
+coverage-radius = Radio de la huella en el suelo
+coverage-area = Área de la huella
+coverage-per-orbit = Superficie barrida por órbita
+coverage-orbits-per-day = Órbitas por día
+coverage-revisit = Revisita media a latitud { $latitude }
+coverage-never = nunca visible
+coverage-note = Cuerpo esférico; revisita media suponiendo que las franjas nunca se solapan.
+
+## Eclipse
+
+eclipse = Eclipses alrededor de { $body } a { $altitude } { $unit }, beta { $beta } grados
+eclipse-longest = Eclipse más largo (beta 0)
+eclipse-note = Sombra cilíndrica, sin penumbra; tiempo de eclipse por órbita.
+
+## Beta angle
+
+beta = Ángulo beta ({ $inclination } grados, RAAN { $raan } grados) el { $date }
+beta-sweep = Ángulo beta ({ $inclination } grados, RAAN { $raan } grados) del { $start } al { $end }
+beta-angle = Ángulo beta
+beta-minimum = Mínimo
+beta-maximum = Máximo
+beta-nodal-drift = Deriva nodal (J2)
+beta-seasons = Temporadas de eclipses (|beta| por debajo del ángulo crítico):
+beta-no-seasons = ninguna, siempre iluminado
+error-beta = error: el paso entre fechas debe ser positivo
+
+## Repeat ground tracks
+
+repeat = Trazas terrestres repetidas ({ $revs } revoluciones por { $days } d)
+repeat-inclination = Inclinación (grados)
+repeat-nodal-period = Periodo (min)
+repeat-nodal-day = Día nodal (h)
+repeat-sso = SSO
+repeat-below-surface = bajo la superficie
+repeat-spacing = Separación de trazas en el ecuador
+repeat-shorter = La traza ya se repite tras { $revs } revoluciones por { $days } d.
+error-repeat = error: indica al menos una revolución y un día
+error-repeat-earth = error: las trazas repetidas solo están disponibles como orbit earth repeat
+
+## Satellites
+
+sat = { $name }
+sat-catalog = Satélites incorporados
+sat-source-nominal = Órbita nominal; usa --tle con un conjunto de elementos actual para la órbita de hoy.
+sat-source-tle = Del conjunto de elementos de NORAD { $catalog } en la época { $year }, día { $day }.
+error-sat-tle = error: no hay conjunto de elementos para { $name } en la entrada
+
+## Fetch
+
+error-fetch = error: no se pudieron descargar los conjuntos de elementos: { $error }
+
+## Element set cache
+
+cache = Conjuntos de elementos en caché ({ $path })
+cache-empty = La caché está vacía.
+cache-sets = { $count } TLE
+cache-fresh = vigente
+cache-expired = caducado
+cache-cleared = Descargas en caché eliminadas: { $count }
+warning-stale-tle = aviso: la descarga falló, se usan conjuntos de elementos en caché de hace { $hours } h
+error-no-cache = error: no hay directorio de caché; define HOME o XDG_CACHE_HOME
@@ -0,0 +1,123 @@
+use std::{
+    f64::consts::PI,
+    path::{Path, PathBuf},
+};
+
+use plotters::prelude::*;
+
+use crate::sample::{lat_lon, Trajectory};
+
+// One SVG per time step: the orbit seen from above the north pole on the
+// left, and the ground track traced so far on an equirectangular map on the
+// right. Frames are numbered so tools such as ffmpeg or ImageMagick can join
+// them into a movie.
+
+pub struct Labels {
+    pub title: String, // for this frame
+    pub longitude: String,
+    pub latitude: String,
+}
+
+pub fn frame_path(dir: &Path, index: usize) -> PathBuf {
+    dir.join(format!("frame_{index:04}.svg"))
+}
+
+/// Split a track where it wraps around the map edge, so no line crosses the
+/// whole map.
+fn map_segments(points: &[(f64, f64)]) -> Vec<Vec<(f64, f64)>> {
+    let mut segments: Vec<Vec<(f64, f64)>> = vec![];
+    let mut previous: Option<f64> = None;
+    for &(longitude, latitude) in points {
+        if previous.is_none_or(|p| (longitude - p).abs() > 180.0) {
+            segments.push(vec![]);
+        }
+        segments.last_mut().unwrap().push((longitude, latitude));
+        previous = Some(longitude);
+    }
+    segments
+}
+
+pub fn render_frame(
+    path: &Path,
+    body_radius: f64,
+    trajectory: &Trajectory,
+    track: &Trajectory,
+    index: usize,
+    labels: &Labels,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let root = SVGBackend::new(path, (1200, 520)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let root = root.titled(&labels.title, ("sans-serif", 22))?;
+    let (left, right) = root.split_horizontally(480);
+
+    let extent = trajectory
+        .positions
+        .iter()
+        .map(|[x, y, _]| x.hypot(*y))
+        .fold(body_radius, f64::max)
+        * 1.1;
+    let mut view = ChartBuilder::on(&left)
+        .margin(16)
+        .build_cartesian_2d(-extent..extent, -extent..extent)?;
+    let disk = (0..=180).map(|k| {
+        let angle = 2.0 * PI * k as f64 / 180.0;
+        (body_radius * angle.cos(), body_radius * angle.sin())
+    });
+    view.draw_series(std::iter::once(Polygon::new(
+        disk.collect::<Vec<_>>(),
+        RGBColor(70, 110, 180).filled(),
+    )))?;
+    let mut orbit: Vec<(f64, f64)> = trajectory.positions.iter().map(|p| (p[0], p[1])).collect();
+    orbit.push(orbit[0]);
+    view.draw_series(std::iter::once(PathElement::new(orbit, BLACK.mix(0.4))))?;
+    let [x, y, _] = trajectory.positions[index];
+    view.draw_series(std::iter::once(Circle::new((x, y), 6, RED.filled())))?;
+
+    let mut map = ChartBuilder::on(&right)
+        .margin(16)
+        .x_label_area_size(40)
+        .y_label_area_size(48)
+        .build_cartesian_2d(-180.0..180.0, -90.0..90.0)?;
+    map.configure_mesh()
+        .x_desc(&labels.longitude)
+        .y_desc(&labels.latitude)
+        .x_labels(13)
+        .y_labels(7)
+        .draw()?;
+    let points: Vec<(f64, f64)> = track.positions[..=index]
+        .iter()
+        .map(|&p| {
+            let (latitude, longitude) = lat_lon(p);
+            (longitude, latitude)
+        })
+        .collect();
+    for segment in map_segments(&points) {
+        map.draw_series(std::iter::once(PathElement::new(
+            segment,
+            RED.stroke_width(2),
+        )))?;
+    }
+    map.draw_series(std::iter::once(Circle::new(points[index], 5, RED.filled())))?;
+
+    root.present()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_track_splits_at_map_edge() {
+        let points = [(170.0, 0.0), (179.0, 1.0), (-179.0, 2.0), (-170.0, 3.0)];
+        let segments = map_segments(&points);
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[1][0], (-179.0, 2.0));
+    }
+
+    #[test]
+    fn test_frame_names_sort() {
+        let dir = Path::new("frames");
+        assert!(frame_path(dir, 9) < frame_path(dir, 10));
+    }
+}
@@ -0,0 +1,175 @@
+use std::f64::consts::PI;
+
+// Kepler's equation and the conversions between mean, eccentric and true
+// anomaly (Vallado, algorithms 2 to 6). The mean anomaly grows uniformly
+// with time; Kepler's equation ties it to the eccentric anomaly E through
+// M = E - e sin E on an ellipse and to the hyperbolic anomaly H through
+// M = e sinh H - H on a hyperbola. Both are solved by Newton-Raphson. Angles
+// are radians, and elliptic anomalies are reduced to [0, 2 pi). Parabolic
+// orbits follow Barker's equation instead and are not handled here.
+
+const TOLERANCE: f64 = 1e-12;
+const MAX_ITERATIONS: usize = 50;
+
+/// Mean, eccentric (or hyperbolic) and true anomaly of one point on an orbit.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Anomalies {
+    pub mean: f64,
+    pub eccentric: f64,
+    pub true_anomaly: f64,
+}
+
+fn check(eccentricity: f64) -> Result<(), String> {
+    if eccentricity < 0.0 || !eccentricity.is_finite() {
+        Err("eccentricity must be non-negative".to_string())
+    } else if eccentricity == 1.0 {
+        Err("parabolic orbits (eccentricity 1) have no eccentric anomaly".to_string())
+    } else {
+        Ok(())
+    }
+}
+
+/// Solve Kepler's equation for the eccentric anomaly, or the hyperbolic
+/// anomaly when `eccentricity` is above 1.
+pub fn eccentric_from_mean(mean: f64, eccentricity: f64) -> Result<f64, String> {
+    check(eccentricity)?;
+    let e = eccentricity;
+    let elliptic = e < 1.0;
+    let mean = if elliptic {
+        mean.rem_euclid(2.0 * PI)
+    } else {
+        mean
+    };
+    // E = M + e sin M can overshoot on very eccentric ellipses; pi never
+    // does, and asinh(M / e) starts a hyperbola below the root
+    let mut anomaly = match (elliptic, e < 0.8) {
+        (true, true) => mean + e * mean.sin(),
+        (true, false) => PI,
+        (false, _) => (mean / e).asinh(),
+    };
+    for _ in 0..MAX_ITERATIONS {
+        let step = if elliptic {
+            (anomaly - e * anomaly.sin() - mean) / (1.0 - e * anomaly.cos())
+        } else {
+            (e * anomaly.sinh() - anomaly - mean) / (e * anomaly.cosh() - 1.0)
+        };
+        anomaly -= step;
+        if step.abs() < TOLERANCE * anomaly.abs().max(1.0) {
+            return Ok(anomaly);
+        }
+    }
+    Err(format!(
+        "Kepler's equation did not converge for mean anomaly {mean} and eccentricity {e}"
+    ))
+}
+
+/// Mean anomaly of an eccentric (or hyperbolic) anomaly.
+pub fn mean_from_eccentric(eccentric: f64, eccentricity: f64) -> f64 {
+    if eccentricity < 1.0 {
+        (eccentric - eccentricity * eccentric.sin()).rem_euclid(2.0 * PI)
+    } else {
+        eccentricity * eccentric.sinh() - eccentric
+    }
+}
+
+/// True anomaly of an eccentric (or hyperbolic) anomaly.
+pub fn true_from_eccentric(eccentric: f64, eccentricity: f64) -> f64 {
+    let e = eccentricity;
+    if e < 1.0 {
+        let half = eccentric / 2.0;
+        (2.0 * ((1.0 + e).sqrt() * half.sin()).atan2((1.0 - e).sqrt() * half.cos()))
+            .rem_euclid(2.0 * PI)
+    } else {
+        2.0 * (((e + 1.0) / (e - 1.0)).sqrt() * (eccentric / 2.0).tanh()).atan()
+    }
+}
+
+/// Eccentric (or hyperbolic) anomaly of a true anomaly. On a hyperbola the
+/// true anomaly must lie inside the asymptotes, |nu| < acos(-1 / e).
+pub fn eccentric_from_true(true_anomaly: f64, eccentricity: f64) -> Result<f64, String> {
+    check(eccentricity)?;
+    let e = eccentricity;
+    let half = true_anomaly / 2.0;
+    if e < 1.0 {
+        Ok(
+            (2.0 * ((1.0 - e).sqrt() * half.sin()).atan2((1.0 + e).sqrt() * half.cos()))
+                .rem_euclid(2.0 * PI),
+        )
+    } else {
+        let nu = true_anomaly.sin().atan2(true_anomaly.cos());
+        if nu.abs() >= (-1.0 / e).acos() {
+            return Err(format!(
+                "true anomaly {:.4} deg lies beyond the asymptotes of a hyperbola with eccentricity {e}",
+                true_anomaly.to_degrees()
+            ));
+        }
+        Ok(2.0 * (((e - 1.0) / (e + 1.0)).sqrt() * (nu / 2.0).tan()).atanh())
+    }
+}
+
+impl Anomalies {
+    pub fn from_mean(mean: f64, eccentricity: f64) -> Result<Self, String> {
+        Self::from_eccentric(eccentric_from_mean(mean, eccentricity)?, eccentricity)
+    }
+
+    pub fn from_eccentric(eccentric: f64, eccentricity: f64) -> Result<Self, String> {
+        check(eccentricity)?;
+        let eccentric = if eccentricity < 1.0 {
+            eccentric.rem_euclid(2.0 * PI)
+        } else {
+            eccentric
+        };
+        Ok(Anomalies {
+            mean: mean_from_eccentric(eccentric, eccentricity),
+            eccentric,
+            true_anomaly: true_from_eccentric(eccentric, eccentricity),
+        })
+    }
+
+    pub fn from_true(true_anomaly: f64, eccentricity: f64) -> Result<Self, String> {
+        Self::from_eccentric(
+            eccentric_from_true(true_anomaly, eccentricity)?,
+            eccentricity,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vallado_example_2_1() {
+        // M = 235.4 deg, e = 0.4 gives E = 220.512074767522 deg
+        let eccentric = eccentric_from_mean(235.4f64.to_radians(), 0.4).unwrap();
+        assert!((eccentric.to_degrees() - 220.512_074_767_522).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_hyperbolic_kepler() {
+        let (mean, e) = (235.4, 2.4);
+        let hyperbolic = eccentric_from_mean(mean, e).unwrap();
+        assert!((hyperbolic - 5.301_259_875_8).abs() < 1e-9);
+        assert!((mean_from_eccentric(hyperbolic, e) - mean).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_round_trips() {
+        for e in [0.0, 0.1, 0.5, 0.9, 0.99, 1.5, 3.0] {
+            for degrees in [-100.0, -10.0, 0.0, 45.0, 100.0, 170.0, 300.0] {
+                let true_anomaly: f64 = f64::to_radians(degrees);
+                let Ok(anomalies) = Anomalies::from_true(true_anomaly, e) else {
+                    // beyond the asymptotes
+                    assert!(e > 1.0);
+                    continue;
+                };
+                let back = Anomalies::from_mean(anomalies.mean, e).unwrap();
+                let difference = (back.true_anomaly - true_anomaly).sin().abs()
+                    + (1.0 - (back.true_anomaly - true_anomaly).cos());
+                assert!(difference < 1e-9, "e = {e}, nu = {degrees}");
+            }
+        }
+        assert!(Anomalies::from_mean(1.0, 1.0).is_err());
+        assert!(Anomalies::from_true(PI * 0.9, 1.1).is_err());
+    }
+}
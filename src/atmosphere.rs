@@ -0,0 +1,133 @@
+// Exponential atmosphere from Vallado, "Fundamentals of Astrodynamics and
+// Applications", Table 8-4: each band has a base altitude, the nominal
+// density there and a scale height. Values are static (CIRA-72 based,
+// moderate solar activity) and intended for lifetime-level estimates.
+
+use clap::ValueEnum;
+
+const TABLE: [(f64, f64, f64); 28] = [
+    // base altitude km, density kg/m^3, scale height km
+    (0.0, 1.225, 7.249),
+    (25.0, 3.899e-2, 6.349),
+    (30.0, 1.774e-2, 6.682),
+    (40.0, 3.972e-3, 7.554),
+    (50.0, 1.057e-3, 8.382),
+    (60.0, 3.206e-4, 7.714),
+    (70.0, 8.770e-5, 6.549),
+    (80.0, 1.905e-5, 5.799),
+    (90.0, 3.396e-6, 5.382),
+    (100.0, 5.297e-7, 5.877),
+    (110.0, 9.661e-8, 7.263),
+    (120.0, 2.438e-8, 9.473),
+    (130.0, 8.484e-9, 12.636),
+    (140.0, 3.845e-9, 16.149),
+    (150.0, 2.070e-9, 22.523),
+    (180.0, 5.464e-10, 29.740),
+    (200.0, 2.789e-10, 37.105),
+    (250.0, 7.248e-11, 45.546),
+    (300.0, 2.418e-11, 53.628),
+    (350.0, 9.518e-12, 53.298),
+    (400.0, 3.725e-12, 58.515),
+    (450.0, 1.585e-12, 60.828),
+    (500.0, 6.967e-13, 63.822),
+    (600.0, 1.454e-13, 71.835),
+    (700.0, 3.614e-14, 88.667),
+    (800.0, 1.170e-14, 124.64),
+    (900.0, 5.245e-15, 181.05),
+    (1000.0, 3.019e-15, 268.00),
+];
+
+fn band(altitude: f64) -> (f64, f64, f64) {
+    *TABLE
+        .iter()
+        .rev()
+        .find(|(base, _, _)| altitude >= *base)
+        .unwrap_or(&TABLE[0])
+}
+
+/// Density in kg/m^3 at a geometric altitude in km.
+pub fn density(altitude: f64) -> f64 {
+    let (base, density, scale_height) = band(altitude.max(0.0));
+    density * (-(altitude.max(0.0) - base) / scale_height).exp()
+}
+
+/// Local density scale height in km.
+pub fn scale_height(altitude: f64) -> f64 {
+    band(altitude.max(0.0)).2
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Activity {
+    /// Solar minimum, F10.7 around 70
+    Low,
+    /// The table's own conditions, F10.7 around 150
+    Moderate,
+    /// Solar maximum, F10.7 around 250
+    High,
+}
+
+// Rough thermospheric density relative to moderate activity, (altitude km,
+// low, high). The spread grows from almost nothing at 100 km to more than an
+// order of magnitude above 500 km.
+const ACTIVITY: [(f64, f64, f64); 8] = [
+    (100.0, 1.0, 1.0),
+    (200.0, 0.6, 1.5),
+    (300.0, 0.4, 2.2),
+    (400.0, 0.3, 3.0),
+    (500.0, 0.22, 3.8),
+    (600.0, 0.17, 4.5),
+    (800.0, 0.12, 5.0),
+    (1000.0, 0.10, 5.0),
+];
+
+impl Activity {
+    /// Density multiplier at an altitude in km, interpolated linearly.
+    pub fn factor(self, altitude: f64) -> f64 {
+        let pick = |&(_, low, high): &(f64, f64, f64)| match self {
+            Activity::Low => low,
+            Activity::Moderate => 1.0,
+            Activity::High => high,
+        };
+        let first = ACTIVITY[0];
+        let last = ACTIVITY[ACTIVITY.len() - 1];
+        if altitude <= first.0 {
+            return pick(&first);
+        }
+        if altitude >= last.0 {
+            return pick(&last);
+        }
+        let upper = ACTIVITY.iter().position(|row| row.0 > altitude).unwrap();
+        let (below, above) = (&ACTIVITY[upper - 1], &ACTIVITY[upper]);
+        let fraction = (altitude - below.0) / (above.0 - below.0);
+        pick(below) + fraction * (pick(above) - pick(below))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_density_at_band_bases() {
+        assert_eq!(density(0.0), 1.225);
+        assert_eq!(density(400.0), 3.725e-12);
+    }
+
+    #[test]
+    fn test_activity_factor() {
+        assert_eq!(Activity::Moderate.factor(400.0), 1.0);
+        assert_eq!(Activity::High.factor(400.0), 3.0);
+        assert!((Activity::Low.factor(450.0) - 0.26).abs() < 1e-12);
+        assert_eq!(Activity::Low.factor(50.0), 1.0);
+    }
+
+    #[test]
+    fn test_density_decreases_with_altitude() {
+        let mut previous = density(0.0);
+        for altitude in (1..1500).map(|h| h as f64) {
+            let current = density(altitude);
+            assert!(current < previous, "density rises at {altitude} km");
+            previous = current;
+        }
+    }
+}
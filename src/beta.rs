@@ -0,0 +1,102 @@
+use crate::{bodies::Named, ephemeris};
+
+// Solar beta angle: the angle between an Earth orbit's plane and the
+// direction to the Sun, positive on the side of the orbit normal. The Sun's
+// direction is the reverse of the Earth's heliocentric position from its mean
+// elements, turned into J2000 equatorial coordinates, which is good to a few
+// hundredths of a degree over this century. Over a sweep the node may drift
+// at a constant rate, such as the J2 nodal regression; the inclination is
+// held fixed.
+
+/// One sample of a sweep.
+#[derive(Debug, Clone, Copy)]
+pub struct Sample {
+    pub days: f64, // since J2000
+    pub beta: f64, // deg
+}
+
+/// Unit vector from the Earth to the Sun in J2000 equatorial coordinates at
+/// `days` since J2000.
+pub fn sun_direction(days: f64) -> [f64; 3] {
+    let earth = ephemeris::position(Named::Earth, days).expect("the Earth has mean elements");
+    let sun = ephemeris::equatorial(earth.map(|v| -v));
+    let length = (sun[0] * sun[0] + sun[1] * sun[1] + sun[2] * sun[2]).sqrt();
+    sun.map(|v| v / length)
+}
+
+/// Beta angle in degrees of an orbit with `inclination` and `raan` (deg) at
+/// `days` since J2000.
+pub fn beta_angle(inclination: f64, raan: f64, days: f64) -> f64 {
+    let (sin_i, cos_i) = inclination.to_radians().sin_cos();
+    let (sin_raan, cos_raan) = raan.to_radians().sin_cos();
+    let normal = [sin_raan * sin_i, -cos_raan * sin_i, cos_i];
+    let sun = sun_direction(days);
+    let projection = normal[0] * sun[0] + normal[1] * sun[1] + normal[2] * sun[2];
+    projection.clamp(-1.0, 1.0).asin().to_degrees()
+}
+
+/// Beta every `step` days from `start` to `end` (days since J2000), with the
+/// node at `raan` on `start` drifting at `nodal_rate` deg/day.
+pub fn sweep(
+    inclination: f64,
+    raan: f64,
+    nodal_rate: f64,
+    (start, end): (f64, f64),
+    step: f64,
+) -> Vec<Sample> {
+    let count = ((end - start) / step).floor() as usize;
+    (0..=count)
+        .map(|k| {
+            let elapsed = k as f64 * step;
+            Sample {
+                days: start + elapsed,
+                beta: beta_angle(inclination, raan + nodal_rate * elapsed, start + elapsed),
+            }
+        })
+        .collect()
+}
+
+/// First and last days of each run of samples with |beta| below `critical`
+/// (deg), when the orbit passes through the shadow.
+pub fn seasons(samples: &[Sample], critical: f64) -> Vec<(f64, f64)> {
+    samples
+        .chunk_by(|a, b| (a.beta.abs() < critical) == (b.beta.abs() < critical))
+        .filter(|run| run[0].beta.abs() < critical)
+        .map(|run| (run[0].days, run[run.len() - 1].days))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time;
+
+    #[test]
+    fn test_beta_angle() {
+        // an equatorial orbit sees the Sun at its declination, which reaches
+        // the obliquity at the June solstice
+        let solstice = time::parse_utc("2026-06-21T08:24:00Z").unwrap();
+        assert!((beta_angle(0.0, 0.0, solstice) - ephemeris::OBLIQUITY).abs() < 0.05);
+        // a polar orbit with its node a quarter turn ahead of the March
+        // equinox Sun faces it square on
+        let equinox = time::parse_utc("2026-03-20T14:46:00Z").unwrap();
+        assert!((beta_angle(90.0, 90.0, equinox) - 90.0).abs() < 0.5);
+        assert!(beta_angle(90.0, 0.0, equinox).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_geostationary_eclipse_seasons() {
+        // the Sun crosses the equator within about 8.7 deg for some 44 days
+        // around each equinox
+        let start = time::parse_utc("2026-01-01").unwrap();
+        let end = time::parse_utc("2027-01-01").unwrap();
+        let samples = sweep(0.0, 0.0, 0.0, (start, end), 1.0);
+        let found = seasons(&samples, 8.7);
+        assert_eq!(found.len(), 2);
+        let equinoxes = ["2026-03-20", "2026-09-23"].map(|date| time::parse_utc(date).unwrap());
+        for ((first, last), equinox) in found.iter().zip(equinoxes) {
+            assert!(((first + last) / 2.0 - equinox).abs() < 3.0);
+            assert!((40.0..48.0).contains(&(last - first)));
+        }
+    }
+}
@@ -0,0 +1,434 @@
+use clap::ValueEnum;
+
+use crate::{
+    calculate_hill_radius, calculate_semi_major_axis, calculate_sphere_of_influence,
+    dimension::{GravitationalParameter, Meters, Seconds},
+    ephemeris, habitable, Altitude, Body,
+};
+
+// Gravitational parameters are JPL DE440 values (planet alone where the
+// system value includes moons), radii are IAU 2015 equatorial radii and
+// sidereal rotation periods and J2 follow the NASA planetary fact sheets
+// (Pluto's J2 is unmeasured). Masses are derived from mu, never stored.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Named {
+    Sun,
+    Mercury,
+    Venus,
+    Earth,
+    Moon,
+    Mars,
+    Jupiter,
+    Saturn,
+    Uranus,
+    Neptune,
+    Pluto,
+}
+
+pub const ALL: [Named; 11] = [
+    Named::Sun,
+    Named::Mercury,
+    Named::Venus,
+    Named::Earth,
+    Named::Moon,
+    Named::Mars,
+    Named::Jupiter,
+    Named::Saturn,
+    Named::Uranus,
+    Named::Neptune,
+    Named::Pluto,
+];
+
+pub enum PresetName {
+    /// A generic low orbit, for bodies without a conventional acronym
+    Low,
+    /// The orbit matching the body's rotation, for bodies without a
+    /// conventional name for it
+    Synchronous,
+    Named(&'static str),
+}
+
+pub struct Preset {
+    pub name: PresetName,
+    pub altitude: Altitude,
+    /// Inclination in degrees, for orbits defined by their plane as well
+    pub inclination: Option<f64>,
+}
+
+/// Height in km above GEO of the graveyard orbit. The IADC guideline asks for
+/// 235 km plus a margin for solar radiation pressure, usually about 300 km.
+const GRAVEYARD_MARGIN: f64 = 300.0;
+
+const fn single(name: &'static str, value: f64) -> Preset {
+    Preset {
+        name: PresetName::Named(name),
+        altitude: Altitude::Single { value },
+        inclination: None,
+    }
+}
+
+const fn range(name: &'static str, min: f64, max: f64) -> Preset {
+    Preset {
+        name: PresetName::Named(name),
+        altitude: Altitude::Range { max, min },
+        inclination: None,
+    }
+}
+
+const fn low(min: f64, max: f64) -> Preset {
+    Preset {
+        name: PresetName::Low,
+        altitude: Altitude::Range { max, min },
+        inclination: None,
+    }
+}
+
+const fn elliptical(name: &'static str, altitude: Altitude, inclination: f64) -> Preset {
+    Preset {
+        name: PresetName::Named(name),
+        altitude,
+        inclination: Some(inclination),
+    }
+}
+
+const fn inclined(preset: Preset, inclination: f64) -> Preset {
+    Preset {
+        inclination: Some(inclination),
+        ..preset
+    }
+}
+
+impl Named {
+    /// Lower-case English name, as used for subcommands and message keys.
+    pub fn name(self) -> &'static str {
+        match self {
+            Named::Sun => "sun",
+            Named::Mercury => "mercury",
+            Named::Venus => "venus",
+            Named::Earth => "earth",
+            Named::Moon => "moon",
+            Named::Mars => "mars",
+            Named::Jupiter => "jupiter",
+            Named::Saturn => "saturn",
+            Named::Uranus => "uranus",
+            Named::Neptune => "neptune",
+            Named::Pluto => "pluto",
+        }
+    }
+
+    pub fn body(self) -> Body {
+        // (mu km^3*s^-2, equatorial radius km, sidereal rotation h, J2, flattening)
+        let (mu, radius, rotation, j2, flattening) = match self {
+            Named::Sun => return sun(),
+            Named::Earth => return Body::earth(),
+            Named::Mercury => (22_031.868_551, 2_440.53, 1_407.6, Some(5.03e-5), 0.0009),
+            Named::Venus => (324_858.592, 6_051.8, -5_832.6, Some(4.458e-6), 0.0),
+            Named::Moon => (4_902.800_118, 1_737.4, 655.72, Some(2.033e-4), 0.0012),
+            Named::Mars => (
+                42_828.375_816,
+                3_396.19,
+                24.622_962,
+                Some(1.960_45e-3),
+                0.005_89,
+            ),
+            Named::Jupiter => (126_686_531.9, 71_492.0, 9.925, Some(1.4736e-2), 0.064_87),
+            Named::Saturn => (37_931_206.2, 60_268.0, 10.656, Some(1.6298e-2), 0.097_96),
+            Named::Uranus => (5_793_951.3, 25_559.0, -17.24, Some(3.343_43e-3), 0.022_93),
+            Named::Neptune => (6_835_100.0, 24_764.0, 16.11, Some(3.411e-3), 0.017_08),
+            Named::Pluto => (869.3, 1_188.3, -153.29, None, 0.0),
+        };
+        Body {
+            mu: GravitationalParameter(mu * 1e9),
+            radius: Meters::from_km(radius),
+            rotation_period: Some(Seconds::from_hours(rotation)),
+            j2,
+            flattening,
+        }
+    }
+
+    /// Typical orbits, with altitudes in km. Moons are listed at their mean
+    /// orbital radius less the primary's radius, and the synchronous orbit
+    /// derived from the rotation period comes last, followed for the Earth
+    /// by the graveyard orbit above it.
+    pub fn presets(self) -> Vec<Preset> {
+        // to the nearest 10 m, so km figures print cleanly
+        let rounded = |altitude: f64| (altitude * 100.0).round() / 100.0;
+        let moon = |name: &'static str, orbit_radius: f64| {
+            single(name, rounded(orbit_radius - self.body().radius.km()))
+        };
+        let mut presets = match self {
+            Named::Sun => vec![
+                moon("Parker Solar Probe perihelion", 6_170_000.0),
+                moon("Mercury", 57_909_050.0),
+                moon("1 AU", habitable::ASTRONOMICAL_UNIT / 1000.0),
+            ],
+            Named::Earth => {
+                let earth = self.body();
+                let sidereal_day = earth.rotation_period.expect("the Earth rotates");
+                // apsides for a period and an eccentricity
+                let apsides = |period: Seconds, eccentricity: f64| {
+                    let axis = calculate_semi_major_axis(earth.mu, period).km();
+                    let (periapsis, apoapsis) =
+                        Altitude::from_elements(&earth, axis, eccentricity).bounds();
+                    Altitude::Elliptical {
+                        periapsis: rounded(periapsis),
+                        apoapsis: rounded(apoapsis),
+                    }
+                };
+                vec![
+                    range("VLEO", 100.0, 450.0),
+                    range("LEO", 450.0, 2000.0),
+                    // sun-synchronous: J2 turns the node once a year
+                    inclined(single("SSO", 800.0), 98.6),
+                    range("MEO", 2000.0, 36000.0),
+                    elliptical(
+                        "GTO",
+                        Altitude::Elliptical {
+                            periapsis: 250.0,
+                            apoapsis: 35_786.0,
+                        },
+                        27.0,
+                    ),
+                    // both at the critical inclination, where the apogee
+                    // stays over the northern hemisphere
+                    elliptical("Molniya", apsides(sidereal_day / 2.0, 0.74), 63.4),
+                    elliptical("Tundra", apsides(sidereal_day, 0.2684), 63.4),
+                ]
+            }
+            Named::Mercury => vec![low(200.0, 1000.0)],
+            Named::Venus => vec![low(250.0, 1000.0)],
+            Named::Moon => vec![range("LLO", 20.0, 200.0), single("Apollo CSM", 110.0)],
+            Named::Mars => vec![
+                range("LMO", 200.0, 1000.0),
+                moon("Phobos", 9_376.0),
+                moon("Deimos", 23_463.2),
+            ],
+            Named::Jupiter => vec![
+                low(1000.0, 20000.0),
+                moon("Io", 421_700.0),
+                moon("Europa", 671_034.0),
+                moon("Ganymede", 1_070_412.0),
+                moon("Callisto", 1_882_709.0),
+            ],
+            Named::Saturn => vec![
+                low(1000.0, 20000.0),
+                moon("Enceladus", 238_020.0),
+                moon("Titan", 1_221_870.0),
+            ],
+            Named::Uranus => vec![
+                low(1000.0, 20000.0),
+                moon("Miranda", 129_390.0),
+                moon("Titania", 435_910.0),
+            ],
+            Named::Neptune => vec![low(1000.0, 20000.0), moon("Triton", 354_759.0)],
+            Named::Pluto => vec![low(100.0, 1000.0), moon("Charon", 19_596.0)],
+        };
+        if let Some(altitude) = self.body().synchronous_altitude() {
+            let (name, inclination) = match self {
+                Named::Earth => (PresetName::Named("GEO"), Some(0.0)),
+                Named::Mars => (PresetName::Named("Areostationary"), Some(0.0)),
+                _ => (PresetName::Synchronous, None),
+            };
+            presets.push(Preset {
+                name,
+                altitude: Altitude::Single {
+                    value: rounded(altitude),
+                },
+                inclination,
+            });
+            if self == Named::Earth {
+                presets.push(single("Graveyard", rounded(altitude + GRAVEYARD_MARGIN)));
+            }
+        }
+        presets
+    }
+}
+
+/// Mean heliocentric orbit of a planet, taken as circular and in the
+/// ecliptic.
+#[derive(Debug, Clone, Copy)]
+pub struct MeanOrbit {
+    pub semi_major_axis: Meters,
+    pub longitude: f64, // deg, mean longitude at J2000
+    pub rate: f64,      // deg/day
+}
+
+impl MeanOrbit {
+    /// Mean longitude in degrees from 0 to 360 at `days` since J2000.
+    pub fn longitude_at(&self, days: f64) -> f64 {
+        (self.longitude + self.rate * days).rem_euclid(360.0)
+    }
+}
+
+impl Named {
+    /// Mean heliocentric orbit from the JPL approximate elements for
+    /// 1800-2050 (Standish), or `None` for the Sun and the Moon. Earth's is
+    /// that of the Earth-Moon barycentre.
+    pub fn mean_orbit(self) -> Option<MeanOrbit> {
+        let (value, rate) = ephemeris::mean_elements(self)?;
+        Some(MeanOrbit {
+            semi_major_axis: Meters(value.semi_major_axis * habitable::ASTRONOMICAL_UNIT),
+            longitude: value.longitude,
+            rate: rate.longitude / 36_525.0,
+        })
+    }
+
+    /// The body this one orbits, or `None` for the Sun.
+    pub fn parent(self) -> Option<Named> {
+        match self {
+            Named::Sun => None,
+            Named::Moon => Some(Named::Earth),
+            _ => Some(Named::Sun),
+        }
+    }
+
+    /// Semi-major axis of the orbit around the parent, or `None` for the
+    /// Sun.
+    pub fn orbital_distance(self) -> Option<Meters> {
+        match self {
+            Named::Moon => Some(MOON_SEMI_MAJOR_AXIS),
+            _ => self.mean_orbit().map(|orbit| orbit.semi_major_axis),
+        }
+    }
+
+    /// Radius of the sphere of influence within the parent's gravity, or
+    /// `None` for the Sun.
+    pub fn sphere_of_influence(self) -> Option<Meters> {
+        let parent = self.parent()?;
+        Some(calculate_sphere_of_influence(
+            self.orbital_distance()?,
+            self.body().mu,
+            parent.body().mu,
+        ))
+    }
+
+    /// Hill radius, or `None` for the Sun.
+    pub fn hill_radius(self) -> Option<Meters> {
+        let parent = self.parent()?;
+        Some(calculate_hill_radius(
+            self.orbital_distance()?,
+            self.body().mu,
+            parent.body().mu,
+        ))
+    }
+}
+
+const MOON_SEMI_MAJOR_AXIS: Meters = Meters(384_399e3);
+
+fn sun() -> Body {
+    Body {
+        mu: GravitationalParameter(habitable::SUN_MU),
+        radius: Meters(6.957e8),                          // IAU 2015 nominal
+        rotation_period: Some(Seconds::from_days(25.38)), // Carrington
+        j2: Some(2.2e-7),
+        flattening: 9e-6,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::calculate_orbital_period;
+
+    #[test]
+    fn test_derived_masses_match_fact_sheets() {
+        // G is only known to about 2e-5, fact-sheet masses to a few 1e-4
+        let masses = [
+            1.988_47e30,
+            3.3011e23,
+            4.8675e24,
+            5.9722e24,
+            7.346e22,
+            6.4171e23,
+            1.89819e27,
+            5.6834e26,
+            8.6813e25,
+            1.02409e26,
+            1.303e22,
+        ];
+        for (named, mass) in ALL.into_iter().zip(masses) {
+            let ratio = named.body().mass().0 / mass;
+            assert!((ratio - 1.0).abs() < 1e-3, "{}: {ratio}", named.name());
+        }
+    }
+
+    #[test]
+    fn test_mean_orbits_follow_kepler() {
+        // the tabulated rates agree with Kepler's third law around the Sun
+        for named in ALL {
+            let Some(orbit) = named.mean_orbit() else {
+                continue;
+            };
+            let period = calculate_orbital_period(sun().mu, orbit.semi_major_axis);
+            let rate = 360.0 / period.days();
+            assert!((orbit.rate / rate - 1.0).abs() < 2e-3, "{}", named.name());
+        }
+        assert!(Named::Moon.mean_orbit().is_none());
+    }
+
+    #[test]
+    fn test_spheres_of_influence() {
+        // the Moon's sphere of influence reaches about 66,000 km and its
+        // Hill sphere about 61,500 km; the Sun has neither
+        let soi = Named::Moon.sphere_of_influence().unwrap().km();
+        let hill = Named::Moon.hill_radius().unwrap().km();
+        assert!((soi - 66_000.0).abs() < 500.0);
+        assert!((hill - 61_500.0).abs() < 500.0);
+        assert!(Named::Sun.sphere_of_influence().is_none());
+        // the Moon is heavy enough for its sphere of influence to reach
+        // past its Hill sphere, but every planet's Hill sphere is the larger
+        for named in ALL
+            .iter()
+            .filter(|named| named.parent() == Some(Named::Sun))
+        {
+            assert!(named.hill_radius() > named.sphere_of_influence());
+        }
+    }
+
+    #[test]
+    fn test_moon_presets_match_periods() {
+        let mars = Named::Mars.body();
+        let areostationary = Named::Mars
+            .presets()
+            .into_iter()
+            .find(|p| matches!(p.name, PresetName::Named("Areostationary")))
+            .unwrap();
+        let (altitude, _) = areostationary.altitude.bounds();
+        let period = calculate_orbital_period(mars.mu, mars.radius + Meters::from_km(altitude));
+        assert!((period.0 - 88_642.66).abs() < 30.0);
+
+        // the Moon's orbit around Earth is not a preset, but Io's is
+        let jupiter = Named::Jupiter.body();
+        let period = calculate_orbital_period(jupiter.mu, Meters::from_km(421_700.0));
+        assert!((period.days() - 1.769).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_earth_presets() {
+        let earth = Named::Earth.body();
+        let presets = Named::Earth.presets();
+        let find = |name: &str| {
+            presets
+                .iter()
+                .find(|p| matches!(p.name, PresetName::Named(n) if n == name))
+                .unwrap()
+        };
+        let period = |preset: &Preset| {
+            let (low, high) = preset.altitude.bounds();
+            let axis = earth.radius + Meters::from_km((low + high) / 2.0);
+            calculate_orbital_period(earth.mu, axis)
+        };
+        // Molniya and Tundra orbits repeat twice and once a sidereal day
+        let sidereal_day = earth.rotation_period.unwrap();
+        assert!((period(find("Molniya")) - sidereal_day / 2.0).abs() < Seconds(1.0));
+        assert!((period(find("Tundra")) - sidereal_day).abs() < Seconds(1.0));
+        assert_eq!(find("Molniya").inclination, Some(63.4));
+        // GTO reaches GEO, and the graveyard sits above it
+        let (_, apogee) = find("GTO").altitude.bounds();
+        let (geo, _) = find("GEO").altitude.bounds();
+        assert!((apogee - geo).abs() < 1.0);
+        let (graveyard, _) = find("Graveyard").altitude.bounds();
+        assert!((graveyard - geo - GRAVEYARD_MARGIN).abs() < 0.01);
+    }
+}
@@ -0,0 +1,475 @@
+use std::io::{self, Write};
+
+use crate::{
+    elements::StateVector,
+    propagate, time,
+    tle::{self, Tle},
+};
+
+// CCSDS Orbit Data Messages (CCSDS 502.0-B-2) in keyword = value notation,
+// for handing orbits to other flight-dynamics tools. An Orbit Mean-Elements
+// Message (OMM) carries the same SGP4 mean elements as a two-line element
+// set, with the catalog fields as keywords; Space-Track serves sets this way
+// too. An Orbit Ephemeris Message (OEM) lists time-tagged states in km and
+// km/s, in segments each described by a metadata block.
+//
+// Epochs are written as YYYY-MM-DDThh:mm:ss.d in UTC and read in either that
+// form or the day-of-year form YYYY-DDDThh:mm:ss. Units in square brackets
+// after a value are allowed and ignored, as are comments. Covariance blocks
+// in an ephemeris are skipped.
+
+const VERSION: &str = "2.0";
+const ORIGINATOR: &str = "orbit";
+/// Object name for an element set without a title line.
+const UNKNOWN: &str = "UNKNOWN";
+
+/// Keywords and values of a message with their line numbers.
+type Keywords<'a> = Vec<(usize, &'a str, &'a str)>;
+
+/// States of one object from an ephemeris segment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Segment {
+    pub object_name: String,
+    pub object_id: String,
+    pub center_name: String,
+    pub ref_frame: String,
+    pub time_system: String,
+    pub times: Vec<f64>, // days since J2000 on the time system, increasing
+    pub states: Vec<StateVector>,
+}
+
+/// International designator in COSPAR form, 1998-067A for 98067A. Anything
+/// else is passed through.
+fn cospar(designator: &str) -> String {
+    match designator.get(..2).and_then(|yy| yy.parse::<u32>().ok()) {
+        Some(yy) if designator.len() > 5 => {
+            let century = if yy < 57 { 2000 } else { 1900 };
+            format!("{}-{}", century + yy, &designator[2..])
+        }
+        _ => designator.to_string(),
+    }
+}
+
+/// International designator in element set form, the inverse of `cospar`.
+fn designator(object_id: &str) -> String {
+    match object_id.split_once('-') {
+        Some((year, rest)) if year.len() == 4 && year.parse::<u32>().is_ok() => {
+            format!("{}{rest}", &year[2..])
+        }
+        _ => object_id.to_string(),
+    }
+}
+
+/// Parse a CCSDS epoch, in calendar or day-of-year form, as days since
+/// J2000.
+fn parse_epoch(input: &str) -> Result<f64, String> {
+    let (date, clock) = input.split_once('T').unwrap_or((input, "00:00:00"));
+    match date.split_once('-') {
+        Some((year, day)) if !day.contains('-') => {
+            let invalid = || format!("invalid time '{input}'");
+            let year = year.parse::<i64>().map_err(|_| invalid())?;
+            let day = day.parse::<i64>().map_err(|_| invalid())?;
+            if !(1..=366).contains(&day) {
+                return Err(invalid());
+            }
+            let midnight = time::parse_utc(&format!("{year}-01-01T{clock}"))?;
+            Ok(midnight + (day - 1) as f64)
+        }
+        _ => time::parse_utc(input),
+    }
+}
+
+/// Keyword and value of a KVN line, without a trailing unit.
+fn keyword(line: &str) -> Option<(&str, &str)> {
+    let (key, value) = line.split_once('=')?;
+    let value = value.trim();
+    let value = match value.strip_suffix(']').and_then(|v| v.rsplit_once('[')) {
+        Some((value, _)) => value.trim_end(),
+        None => value,
+    };
+    Some((key.trim(), value))
+}
+
+/// Numbered non-blank lines of `text` that are not comments.
+fn lines(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with("COMMENT"))
+}
+
+fn write_header(out: &mut impl Write, kind: &str, created: f64) -> io::Result<()> {
+    writeln!(out, "CCSDS_{kind}_VERS = {VERSION}")?;
+    writeln!(out, "CREATION_DATE = {}", time::format_iso(created, 3))?;
+    writeln!(out, "ORIGINATOR = {ORIGINATOR}")
+}
+
+/// Write an element set as an OMM, created at `created` days since J2000
+/// UTC.
+pub fn write_omm(out: &mut impl Write, set: &Tle, created: f64) -> io::Result<()> {
+    write_header(out, "OMM", created)?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "OBJECT_NAME = {}",
+        set.name.as_deref().unwrap_or(UNKNOWN)
+    )?;
+    writeln!(out, "OBJECT_ID = {}", cospar(&set.international_designator))?;
+    writeln!(out, "CENTER_NAME = EARTH")?;
+    writeln!(out, "REF_FRAME = TEME")?;
+    writeln!(out, "TIME_SYSTEM = UTC")?;
+    writeln!(out, "MEAN_ELEMENT_THEORY = SGP4")?;
+    writeln!(out)?;
+    writeln!(
+        out,
+        "EPOCH = {}",
+        time::format_iso(propagate::epoch(set), 6)
+    )?;
+    writeln!(out, "MEAN_MOTION = {} [rev/day]", set.mean_motion)?;
+    writeln!(out, "ECCENTRICITY = {}", set.eccentricity)?;
+    writeln!(out, "INCLINATION = {} [deg]", set.inclination)?;
+    writeln!(out, "RA_OF_ASC_NODE = {} [deg]", set.raan)?;
+    writeln!(out, "ARG_OF_PERICENTER = {} [deg]", set.argument_of_perigee)?;
+    writeln!(out, "MEAN_ANOMALY = {} [deg]", set.mean_anomaly)?;
+    writeln!(out)?;
+    writeln!(out, "EPHEMERIS_TYPE = 0")?;
+    writeln!(out, "CLASSIFICATION_TYPE = {}", set.classification)?;
+    writeln!(out, "NORAD_CAT_ID = {}", set.catalog_number)?;
+    writeln!(out, "ELEMENT_SET_NO = {}", set.element_set)?;
+    writeln!(out, "REV_AT_EPOCH = {}", set.revolution_number)?;
+    writeln!(out, "BSTAR = {} [1/ER]", set.bstar)?;
+    writeln!(
+        out,
+        "MEAN_MOTION_DOT = {} [rev/day**2]",
+        set.mean_motion_dot
+    )?;
+    writeln!(
+        out,
+        "MEAN_MOTION_DDOT = {} [rev/day**3]",
+        set.mean_motion_ddot
+    )
+}
+
+/// One OMM, as its keywords with their line numbers, starting on line `at`.
+fn parse_message(values: &[(usize, &str, &str)], at: usize) -> Result<Tle, String> {
+    let find = |name: &str| {
+        values
+            .iter()
+            .find(|(_, key, _)| *key == name)
+            .map(|&(line, _, value)| (line, value))
+    };
+    let text = |name: &str| {
+        find(name)
+            .map(|(_, value)| value)
+            .ok_or_else(|| format!("line {at}: message has no {name}"))
+    };
+    let number = |name: &str| {
+        find(name)
+            .map(|(line, value)| {
+                value
+                    .parse::<f64>()
+                    .map_err(|_| format!("line {line}: invalid {name} {value:?}"))
+            })
+            .transpose()
+    };
+    let required =
+        |name: &str| number(name)?.ok_or_else(|| format!("line {at}: message has no {name}"));
+    let optional = |name: &str| number(name).map(|value| value.unwrap_or(0.0));
+
+    let theory = text("MEAN_ELEMENT_THEORY")?;
+    if !theory.contains("SGP4") {
+        return Err(format!(
+            "line {at}: expected SGP4 mean elements, found {theory}"
+        ));
+    }
+    let (line, epoch) = find("EPOCH").ok_or_else(|| format!("line {at}: message has no EPOCH"))?;
+    let epoch = parse_epoch(epoch).map_err(|err| format!("line {line}: {err}"))?;
+    let (year, _, _) = time::civil_from_days((epoch + 0.5).floor() as i64);
+
+    Ok(Tle {
+        name: text("OBJECT_NAME")
+            .ok()
+            .filter(|name| *name != UNKNOWN)
+            .map(String::from),
+        catalog_number: text("NORAD_CAT_ID")?.to_string(),
+        classification: text("CLASSIFICATION_TYPE")
+            .ok()
+            .and_then(|value| value.chars().next())
+            .unwrap_or('U'),
+        international_designator: designator(text("OBJECT_ID")?),
+        epoch_year: year as i32,
+        epoch_day: epoch - (time::civil_days(year, 1, 1) as f64 - 0.5) + 1.0,
+        mean_motion_dot: optional("MEAN_MOTION_DOT")?,
+        mean_motion_ddot: optional("MEAN_MOTION_DDOT")?,
+        bstar: optional("BSTAR")?,
+        element_set: optional("ELEMENT_SET_NO")? as u32,
+        inclination: required("INCLINATION")?,
+        raan: required("RA_OF_ASC_NODE")?,
+        eccentricity: required("ECCENTRICITY")?,
+        argument_of_perigee: required("ARG_OF_PERICENTER")?,
+        mean_anomaly: required("MEAN_ANOMALY")?,
+        mean_motion: required("MEAN_MOTION")?,
+        revolution_number: optional("REV_AT_EPOCH")? as u32,
+    })
+}
+
+/// Parse every OMM in `text` as an element set. Each message starts with
+/// its version line; the mean motion must be given, not the semi-major axis.
+pub fn parse_omm(text: &str) -> Result<Vec<Tle>, String> {
+    let mut messages: Vec<(usize, Keywords)> = vec![];
+    for (at, line) in lines(text) {
+        let Some((key, value)) = keyword(line) else {
+            return Err(format!("line {at}: expected KEYWORD = value"));
+        };
+        if key == "CCSDS_OMM_VERS" {
+            messages.push((at, vec![]));
+        }
+        match messages.last_mut() {
+            Some((_, values)) => values.push((at, key, value)),
+            None => return Err(format!("line {at}: expected CCSDS_OMM_VERS")),
+        }
+    }
+    if messages.is_empty() {
+        return Err("no messages found".to_string());
+    }
+    messages
+        .iter()
+        .map(|(at, values)| parse_message(values, *at))
+        .collect()
+}
+
+/// Parse element sets or OMMs, whichever `text` holds.
+pub fn parse_sets(text: &str) -> Result<Vec<Tle>, String> {
+    if text.trim_start().starts_with("CCSDS_OMM_VERS") {
+        parse_omm(text)
+    } else {
+        tle::parse(text)
+    }
+}
+
+/// Ephemeris segment of an element set's TEME states on UTC.
+pub fn segment(set: &Tle, times: Vec<f64>, states: Vec<StateVector>) -> Segment {
+    Segment {
+        object_name: set.name.clone().unwrap_or_else(|| UNKNOWN.to_string()),
+        object_id: cospar(&set.international_designator),
+        center_name: "EARTH".to_string(),
+        ref_frame: "TEME".to_string(),
+        time_system: "UTC".to_string(),
+        times,
+        states,
+    }
+}
+
+/// Write ephemeris segments as an OEM, created at `created` days since J2000
+/// UTC. Segments without states are left out.
+pub fn write_oem(out: &mut impl Write, segments: &[Segment], created: f64) -> io::Result<()> {
+    write_header(out, "OEM", created)?;
+    for segment in segments {
+        let (Some(start), Some(stop)) = (segment.times.first(), segment.times.last()) else {
+            continue;
+        };
+        writeln!(out)?;
+        writeln!(out, "META_START")?;
+        writeln!(out, "OBJECT_NAME = {}", segment.object_name)?;
+        writeln!(out, "OBJECT_ID = {}", segment.object_id)?;
+        writeln!(out, "CENTER_NAME = {}", segment.center_name)?;
+        writeln!(out, "REF_FRAME = {}", segment.ref_frame)?;
+        writeln!(out, "TIME_SYSTEM = {}", segment.time_system)?;
+        writeln!(out, "START_TIME = {}", time::format_iso(*start, 3))?;
+        writeln!(out, "STOP_TIME = {}", time::format_iso(*stop, 3))?;
+        writeln!(out, "META_STOP")?;
+        writeln!(out)?;
+        for (days, state) in segment.times.iter().zip(&segment.states) {
+            // km to the mm and km/s to the um/s
+            let position = state.position.map(|v| format!("{:.6}", v / 1000.0));
+            let velocity = state.velocity.map(|v| format!("{:.9}", v / 1000.0));
+            writeln!(
+                out,
+                "{} {} {}",
+                time::format_iso(*days, 3),
+                position.join(" "),
+                velocity.join(" ")
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// One state line: an epoch, position and velocity, and perhaps
+/// acceleration, which is dropped.
+fn parse_state(line: &str, at: usize) -> Result<(f64, StateVector), String> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+    if fields.len() != 7 && fields.len() != 10 {
+        return Err(format!("line {at}: expected an epoch and six values"));
+    }
+    let days = parse_epoch(fields[0]).map_err(|err| format!("line {at}: {err}"))?;
+    let mut values = [0.0; 6];
+    for (value, field) in values.iter_mut().zip(&fields[1..7]) {
+        let km = field
+            .parse::<f64>()
+            .map_err(|_| format!("line {at}: invalid value {field:?}"))?;
+        *value = km * 1000.0;
+    }
+    let [x, y, z, vx, vy, vz] = values;
+    Ok((
+        days,
+        StateVector {
+            position: [x, y, z],
+            velocity: [vx, vy, vz],
+        },
+    ))
+}
+
+/// Parse the segments of an OEM.
+pub fn parse_oem(text: &str) -> Result<Vec<Segment>, String> {
+    let mut rows = lines(text);
+    match rows.next().and_then(|(_, line)| keyword(line)) {
+        Some(("CCSDS_OEM_VERS", _)) => {}
+        _ => return Err("expected CCSDS_OEM_VERS on the first line".to_string()),
+    }
+
+    let mut segments: Vec<Segment> = vec![];
+    while let Some((at, line)) = rows.next() {
+        if line == "COVARIANCE_START" {
+            rows.by_ref().find(|(_, line)| *line == "COVARIANCE_STOP");
+        } else if line == "META_START" {
+            let mut values = vec![];
+            for (at, line) in rows.by_ref() {
+                if line == "META_STOP" {
+                    break;
+                }
+                values.push(keyword(line).ok_or(format!("line {at}: expected KEYWORD = value"))?);
+            }
+            let find = |name: &str| {
+                values
+                    .iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+                    .ok_or_else(|| format!("line {at}: metadata has no {name}"))
+            };
+            segments.push(Segment {
+                object_name: find("OBJECT_NAME")?,
+                object_id: find("OBJECT_ID")?,
+                center_name: find("CENTER_NAME")?,
+                ref_frame: find("REF_FRAME")?,
+                time_system: find("TIME_SYSTEM")?,
+                times: vec![],
+                states: vec![],
+            });
+        } else if let Some(segment) = segments.last_mut() {
+            let (days, state) = parse_state(line, at)?;
+            segment.times.push(days);
+            segment.states.push(state);
+        } else if keyword(line).is_none() {
+            // header keywords such as CREATION_DATE come before any segment
+            return Err(format!("line {at}: state before META_START"));
+        }
+    }
+    if segments.is_empty() {
+        return Err("no segments found".to_string());
+    }
+    Ok(segments)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ISS: &str = "ISS (ZARYA)
+1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927
+2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537
+";
+
+    #[test]
+    fn test_omm_round_trip() {
+        let sets = tle::parse(ISS).unwrap();
+        let mut bytes = vec![];
+        write_omm(&mut bytes, &sets[0], 0.0).unwrap();
+        let text = String::from_utf8(bytes).unwrap();
+        assert!(text.contains("OBJECT_ID = 1998-067A\n"));
+        assert!(text.contains("EPOCH = 2008-09-20T12:25:40.104192\n"));
+
+        // messages may follow one another
+        let parsed = parse_sets(&text.repeat(2)).unwrap();
+        assert_eq!(parsed.len(), 2);
+        let (iss, omm) = (&sets[0], &parsed[1]);
+        assert!((omm.epoch_day - iss.epoch_day).abs() < 1e-10);
+        let omm = Tle {
+            epoch_day: iss.epoch_day,
+            ..omm.clone()
+        };
+        assert_eq!(&omm, iss);
+    }
+
+    #[test]
+    fn test_parse_space_track_omm() {
+        let text = "CCSDS_OMM_VERS = 2.0
+COMMENT GENERATED VIA SPACE-TRACK.ORG API
+CREATION_DATE = 2008-09-21T00:00:00
+ORIGINATOR = 18 SPCS
+OBJECT_NAME = ISS (ZARYA)
+OBJECT_ID = 1998-067A
+CENTER_NAME = EARTH
+REF_FRAME = TEME
+TIME_SYSTEM = UTC
+MEAN_ELEMENT_THEORY = SGP4
+EPOCH = 2008-264T12:25:40.104192
+MEAN_MOTION = 15.72125391
+ECCENTRICITY = .0006703
+INCLINATION = 51.6416
+RA_OF_ASC_NODE = 247.4627
+ARG_OF_PERICENTER = 130.5360
+MEAN_ANOMALY = 325.0288
+NORAD_CAT_ID = 25544
+";
+        let set = &parse_omm(text).unwrap()[0];
+        assert_eq!(set.international_designator, "98067A");
+        assert_eq!((set.epoch_year, set.classification), (2008, 'U'));
+        assert!((set.epoch_day - 264.517_825_28).abs() < 1e-10);
+        assert_eq!(set.eccentricity, 0.000_670_3);
+
+        let error = parse_omm(&text.replace("MEAN_MOTION = 15.72125391\n", "")).unwrap_err();
+        assert_eq!(error, "line 1: message has no MEAN_MOTION");
+        let error = parse_omm(&text.replace("51.6416", "north")).unwrap_err();
+        assert!(error.starts_with("line 14: invalid INCLINATION"), "{error}");
+        assert!(parse_omm(&text.replace("SGP4", "DSST")).is_err());
+    }
+
+    #[test]
+    fn test_oem_round_trip() {
+        let iss = &tle::parse(ISS).unwrap()[0];
+        let start = propagate::epoch(iss);
+        let times: Vec<f64> = (0..5).map(|k| start + k as f64 / 1440.0).collect();
+        let states: Vec<StateVector> = times
+            .iter()
+            .map(|&days| propagate::sgp4(iss, days).unwrap())
+            .collect();
+        let written = segment(iss, times, states);
+        let mut bytes = vec![];
+        write_oem(&mut bytes, &[written.clone(), written.clone()], 0.0).unwrap();
+        let text = String::from_utf8(bytes).unwrap();
+        assert!(text.contains("START_TIME = 2008-09-20T12:25:40.104\n"));
+
+        let segments = parse_oem(&text).unwrap();
+        assert_eq!(segments.len(), 2);
+        let read = &segments[1];
+        assert_eq!(read.object_name, "ISS (ZARYA)");
+        assert_eq!(read.ref_frame, "TEME");
+        assert_eq!(read.states.len(), 5);
+        for (a, b) in read.times.iter().zip(&written.times) {
+            // to the millisecond
+            assert!((a - b).abs() * 86_400.0 < 1e-3);
+        }
+        for (a, b) in read.states.iter().zip(&written.states) {
+            for k in 0..3 {
+                assert!((a.position[k] - b.position[k]).abs() < 1e-3);
+                assert!((a.velocity[k] - b.velocity[k]).abs() < 1e-6);
+            }
+        }
+
+        let error = parse_oem(&text.replace("TIME_SYSTEM = UTC\n", "")).unwrap_err();
+        assert!(error.ends_with("metadata has no TIME_SYSTEM"), "{error}");
+        let error = parse_oem("CCSDS_OEM_VERS = 2.0\n2008-09-20T12:25:40 1 2 3 4 5 6\n");
+        assert_eq!(error.unwrap_err(), "line 2: state before META_START");
+    }
+}
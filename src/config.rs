@@ -0,0 +1,195 @@
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{
+    dimension::{GravitationalParameter, Kilograms, Meters, Seconds},
+    Altitude, Body,
+};
+
+// User-defined bodies, for game worlds and hypothetical scenarios. Each table
+// in the file is one body:
+//
+//     [kerbin]
+//     mass = 5.2915158e22      # kg, or give mu (m^3*s^-2) or gravity (m/s^2)
+//     radius = 600000          # m
+//     rotation_period = 21549.425
+//     j2 = 0.0                 # optional
+//     flattening = 0.0         # optional, radius is then equatorial
+//
+//     [[kerbin.presets]]
+//     name = "LKO"
+//     altitude = 80            # km; or min and max, or periapsis and apoapsis
+//     inclination = 6          # deg, optional
+//
+// Exactly one of mass, mu and gravity defines the body's gravity.
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Definition {
+    pub mass: Option<f64>,    // kg
+    pub mu: Option<f64>,      // m^3*s^-2
+    pub gravity: Option<f64>, // m/s^2 at the surface
+    pub radius: f64,          // m
+    /// Sidereal rotation period in s, negative for retrograde rotation.
+    pub rotation_period: Option<f64>,
+    /// Second zonal harmonic, referenced to `radius`.
+    pub j2: Option<f64>,
+    /// Flattening of the reference ellipsoid; `radius` is then equatorial.
+    pub flattening: Option<f64>,
+    #[serde(default)]
+    pub presets: Vec<PresetDefinition>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PresetDefinition {
+    pub name: String,
+    // all in km
+    pub altitude: Option<f64>,
+    pub min: Option<f64>,
+    pub max: Option<f64>,
+    pub periapsis: Option<f64>,
+    pub apoapsis: Option<f64>,
+    pub inclination: Option<f64>, // deg
+}
+
+impl Definition {
+    pub fn body(&self) -> Result<Body, String> {
+        if self.radius <= 0.0 {
+            return Err("radius must be positive".to_string());
+        }
+        if self.rotation_period.is_some_and(|period| period == 0.0) {
+            return Err("rotation_period must not be zero".to_string());
+        }
+        if self
+            .flattening
+            .is_some_and(|flattening| !(0.0..1.0).contains(&flattening))
+        {
+            return Err("flattening must be at least 0 and below 1".to_string());
+        }
+        let radius = Meters(self.radius);
+        let body = match (self.mass, self.mu, self.gravity) {
+            (Some(mass), None, None) if mass > 0.0 => Body::from_mass(Kilograms(mass), radius),
+            (None, Some(mu), None) if mu > 0.0 => Body::from_mu(GravitationalParameter(mu), radius),
+            (None, None, Some(gravity)) if gravity > 0.0 => {
+                Body::from_surface_gravity(gravity, radius)
+            }
+            (None, None, None) => return Err("one of mass, mu or gravity is required".to_string()),
+            (Some(_), None, None) | (None, Some(_), None) | (None, None, Some(_)) => {
+                return Err("mass, mu and gravity must be positive".to_string())
+            }
+            _ => return Err("give only one of mass, mu or gravity".to_string()),
+        };
+        Ok(Body {
+            rotation_period: self.rotation_period.map(Seconds),
+            j2: self.j2,
+            flattening: self.flattening.unwrap_or(0.0),
+            ..body
+        })
+    }
+
+    /// Name, altitude and inclination of each preset.
+    pub fn presets(&self) -> Result<Vec<(String, Altitude, Option<f64>)>, String> {
+        self.presets
+            .iter()
+            .map(|preset| Ok((preset.name.clone(), preset.altitude()?, preset.inclination)))
+            .collect()
+    }
+}
+
+impl PresetDefinition {
+    fn altitude(&self) -> Result<Altitude, String> {
+        let error = |message: &str| Err(format!("preset {:?}: {message}", self.name));
+        match (
+            self.altitude,
+            self.min,
+            self.max,
+            self.periapsis,
+            self.apoapsis,
+        ) {
+            (Some(value), None, None, None, None) => Ok(Altitude::Single { value }),
+            (None, Some(min), Some(max), None, None) if min <= max => {
+                Ok(Altitude::Range { max, min })
+            }
+            (None, None, None, Some(periapsis), Some(apoapsis)) if periapsis <= apoapsis => {
+                Ok(Altitude::Elliptical {
+                    periapsis,
+                    apoapsis,
+                })
+            }
+            (None, Some(_), Some(_), None, None) => error("min must not be above max"),
+            (None, None, None, Some(_), Some(_)) => error("periapsis must not be above apoapsis"),
+            _ => error("give altitude, min and max, or periapsis and apoapsis"),
+        }
+    }
+}
+
+/// `$XDG_CONFIG_HOME/orbit/bodies.toml`, falling back to `~/.config`.
+pub fn default_path() -> Option<PathBuf> {
+    let base = env::var_os("XDG_CONFIG_HOME")
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(base.join("orbit").join("bodies.toml"))
+}
+
+pub fn parse(source: &str) -> Result<BTreeMap<String, Definition>, String> {
+    toml::from_str(source).map_err(|err| err.to_string())
+}
+
+pub fn load(path: &Path) -> Result<BTreeMap<String, Definition>, String> {
+    let source = fs::read_to_string(path).map_err(|err| err.to_string())?;
+    parse(&source)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KERBIN: &str = r#"
+        [kerbin]
+        mu = 3.5316e12
+        radius = 600000
+        rotation_period = 21549.425
+
+        [[kerbin.presets]]
+        name = "LKO"
+        altitude = 80
+
+        [[kerbin.presets]]
+        name = "Transfer"
+        periapsis = 80
+        apoapsis = 2863.33
+        inclination = 6
+    "#;
+
+    #[test]
+    fn test_parse_bodies() {
+        let bodies = parse(KERBIN).unwrap();
+        let kerbin = &bodies["kerbin"];
+        let body = kerbin.body().unwrap();
+        assert_eq!(body.mu, GravitationalParameter(3.5316e12));
+        assert_eq!(body.radius, Meters(600_000.0));
+        // Kerbin's keostationary orbit is at 2863.33 km
+        let altitude = body.synchronous_altitude().unwrap();
+        assert!((altitude - 2_863.33).abs() < 0.01);
+
+        let presets = kerbin.presets().unwrap();
+        assert_eq!(presets.len(), 2);
+        assert_eq!(presets[0].0, "LKO");
+        assert!(matches!(presets[1].1, Altitude::Elliptical { .. }));
+        assert_eq!((presets[0].2, presets[1].2), (None, Some(6.0)));
+    }
+
+    #[test]
+    fn test_reject_ambiguous_gravity() {
+        let bodies = parse("[a]\nmass = 1e22\nmu = 1e12\nradius = 1\n").unwrap();
+        assert!(bodies["a"].body().is_err());
+        assert!(parse("[a]\nradius = 1\ncolour = 3\n").is_err());
+    }
+}
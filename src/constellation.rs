@@ -0,0 +1,89 @@
+use crate::{
+    calculate_orbital_period,
+    dimension::{GravitationalParameter, Meters},
+};
+
+// Walker delta pattern T/P/F: T satellites in P equally spaced planes, with
+// satellites in adjacent planes offset by F * 360 / T degrees of argument of
+// latitude. Slot acquisition after a single launch uses a temporary altitude
+// offset, whose different mean motion drifts each satellite to its slot.
+
+pub struct Walker {
+    pub total: u32,
+    pub planes: u32,
+    pub phasing: u32,
+}
+
+impl Walker {
+    /// T must divide evenly into P planes and F must be below P.
+    pub fn is_valid(&self) -> bool {
+        self.planes > 0 && self.total.is_multiple_of(self.planes) && self.phasing < self.planes
+    }
+
+    pub fn per_plane(&self) -> u32 {
+        self.total / self.planes
+    }
+
+    /// Mean-anomaly spacing between satellites in one plane, degrees.
+    pub fn in_plane_spacing(&self) -> f64 {
+        360.0 / self.per_plane() as f64
+    }
+
+    /// Right ascension spacing between planes, degrees.
+    pub fn plane_spacing(&self) -> f64 {
+        360.0 / self.planes as f64
+    }
+
+    /// Argument-of-latitude offset between adjacent planes, degrees.
+    pub fn phase_offset(&self) -> f64 {
+        self.phasing as f64 * 360.0 / self.total as f64
+    }
+}
+
+/// Along-track drift of an orbit `offset` above (or, if negative, below) a
+/// circular reference orbit, in degrees per day. Lower orbits are faster, so
+/// a negative offset gives a positive (forward) drift.
+pub fn drift_rate(mu: GravitationalParameter, radius: Meters, offset: Meters) -> f64 {
+    let rate = |r: Meters| 360.0 / calculate_orbital_period(mu, r).days();
+    rate(radius + offset) - rate(radius)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Body;
+
+    #[test]
+    fn test_walker_geometry() {
+        // GPS-like 24/6/1
+        let walker = Walker {
+            total: 24,
+            planes: 6,
+            phasing: 1,
+        };
+        assert!(walker.is_valid());
+        assert_eq!(walker.per_plane(), 4);
+        assert_eq!(walker.in_plane_spacing(), 90.0);
+        assert_eq!(walker.plane_spacing(), 60.0);
+        assert_eq!(walker.phase_offset(), 15.0);
+        assert!(!Walker {
+            total: 24,
+            planes: 5,
+            phasing: 0
+        }
+        .is_valid());
+    }
+
+    #[test]
+    fn test_drift_matches_linear_estimate() {
+        // dn/n = -3/2 da/a for small offsets
+        let earth = Body::earth();
+        let radius = Meters(6_928_137.0);
+        let offset = Meters(-1000.0);
+        let n = 360.0 / calculate_orbital_period(earth.mu, radius).days();
+        let linear = -1.5 * n * (offset / radius);
+        let drift = drift_rate(earth.mu, radius, offset);
+        assert!(drift > 0.0);
+        assert!((drift - linear).abs() / linear < 1e-3);
+    }
+}
@@ -0,0 +1,131 @@
+use std::f64::consts::PI;
+
+use crate::{
+    calculate_orbital_period,
+    dimension::{Meters, Seconds},
+    plot, Body,
+};
+
+// Coverage of a circular orbit over a spherical body. A footprint is the
+// spherical cap the sensor sees, measured by its Earth central angle lambda;
+// sweeping it along the ground track covers a strip of half-width lambda.
+// The revisit time at a latitude is an average: the longitude each pass
+// covers along that parallel, summed over the passes of a day, against the
+// full circle, as if successive swaths never overlapped. Body rotation is
+// otherwise ignored.
+
+/// What limits the footprint.
+#[derive(Debug, Clone, Copy)]
+pub enum Sensor {
+    /// Nadir-pointing cone with this half-angle in degrees
+    HalfAngle(f64),
+    /// Swath width on the ground in km
+    Swath(f64),
+    /// Minimum elevation in degrees seen from the ground
+    MinElevation(f64),
+}
+
+/// Footprint radius as an Earth central angle in radians from `altitude`
+/// km. A cone wider than the body's disc stops at the horizon.
+pub fn central_angle(body: &Body, altitude: f64, sensor: Sensor) -> f64 {
+    let radius = body.radius + Meters::from_km(altitude);
+    match sensor {
+        Sensor::HalfAngle(half_angle) => {
+            // angular radius of the body seen from the satellite
+            let disc = (body.radius / radius).asin();
+            let nadir = half_angle.to_radians().min(disc);
+            let elevation = (nadir.sin() / disc.sin()).min(1.0).acos();
+            PI / 2.0 - nadir - elevation
+        }
+        Sensor::Swath(width) => (Meters::from_km(width) / 2.0 / body.radius).min(PI / 2.0),
+        Sensor::MinElevation(elevation) => {
+            Meters::from_km(plot::footprint_radius(body, altitude, elevation)) / body.radius
+        }
+    }
+}
+
+/// Surface area of the body in m^2.
+pub fn surface_area(body: &Body) -> f64 {
+    4.0 * PI * body.radius.0.powi(2)
+}
+
+/// Area of a footprint in m^2.
+pub fn footprint_area(body: &Body, central_angle: f64) -> f64 {
+    2.0 * PI * body.radius.0.powi(2) * (1.0 - central_angle.cos())
+}
+
+/// Fraction of the body's surface one orbit sweeps, at most 1.
+pub fn fraction_per_orbit(central_angle: f64) -> f64 {
+    // a strip of half-width lambda around a great circle covers sin(lambda)
+    // of the sphere
+    central_angle.sin().min(1.0)
+}
+
+/// Average time between views of a point at `latitude` (deg), or `None` if
+/// the footprint never reaches it.
+pub fn revisit(
+    body: &Body,
+    altitude: f64,
+    inclination: f64,
+    central_angle: f64,
+    latitude: f64,
+) -> Option<Seconds> {
+    let period = calculate_orbital_period(body.mu, body.radius + Meters::from_km(altitude));
+    // a retrograde orbit reaches the same latitudes as its supplement
+    let reach = inclination.min(180.0 - inclination).abs().to_radians();
+    let latitude = latitude.abs().to_radians();
+    if latitude > reach + central_angle {
+        return None;
+    }
+    let (crossings, width) = if latitude < reach {
+        // the track crosses the parallel twice an orbit, at heading az from
+        // north with sin(az) = cos(i) / cos(latitude)
+        let sin_heading = reach.cos() / latitude.cos();
+        let cos_heading = (1.0 - sin_heading.powi(2)).max(0.0).sqrt();
+        (2.0, 2.0 * central_angle / (latitude.cos() * cos_heading))
+    } else {
+        // only the footprint around the northernmost point reaches it
+        let cos_spread =
+            (central_angle.cos() - reach.sin() * latitude.sin()) / (reach.cos() * latitude.cos());
+        (1.0, 2.0 * cos_spread.clamp(-1.0, 1.0).acos())
+    };
+    Some(2.0 * PI * period / (crossings * width.min(2.0 * PI)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_footprints() {
+        let earth = Body::earth();
+        // a cone wider than the Earth's disc sees out to the horizon
+        let horizon = central_angle(&earth, 550.0, Sensor::HalfAngle(90.0));
+        let grazing = central_angle(&earth, 550.0, Sensor::MinElevation(0.0));
+        assert!((horizon - grazing).abs() < 1e-9);
+        // a narrow cone sees about altitude * tan(half-angle)
+        let narrow = central_angle(&earth, 550.0, Sensor::HalfAngle(1.0)) * earth.radius;
+        assert!((narrow.km() - 550.0 * 1f64.to_radians().tan()).abs() < 0.1);
+        let swath = central_angle(&earth, 550.0, Sensor::Swath(185.0));
+        assert!(((swath * earth.radius).km() - 92.5).abs() < 1e-9);
+        // a hemisphere is half the surface, of about 510 million km^2
+        assert!((surface_area(&earth) / 1e6 - 5.112e8).abs() < 1e5);
+        let half = footprint_area(&earth, PI / 2.0) / surface_area(&earth);
+        assert!((half - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_revisit() {
+        let earth = Body::earth();
+        let angle = central_angle(&earth, 705.0, Sensor::Swath(185.0));
+        // Landsat's 185 km swath over 14.57 orbits a day, counting passes both
+        // north and south, lays 5,400 km of the 40,000 km equator a day;
+        // imaging only in daylight halves that, against its 16-day cycle
+        let equator = revisit(&earth, 705.0, 98.2, angle, 0.0).unwrap().days();
+        assert!((7.0..8.0).contains(&equator));
+        let north = revisit(&earth, 705.0, 98.2, angle, 60.0).unwrap().days();
+        assert!(north < equator / 1.5);
+        // the ISS never sees the poles
+        assert!(revisit(&earth, 420.0, 51.6, angle, 89.0).is_none());
+    }
+}
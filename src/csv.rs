@@ -0,0 +1,42 @@
+use std::{
+    borrow::Cow,
+    io::{self, Write},
+};
+
+// Minimal RFC 4180 writer: fields containing a comma, quote or line break are
+// quoted with embedded quotes doubled, and records end in CRLF so spreadsheet
+// imports split rows the same way on every platform.
+
+/// Quote `field` if it needs it.
+pub fn escape(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\r', '\n']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
+
+/// Write one record of already formatted fields.
+pub fn write_record<W: Write, S: AsRef<str>>(writer: &mut W, fields: &[S]) -> io::Result<()> {
+    let record: Vec<_> = fields.iter().map(|field| escape(field.as_ref())).collect();
+    write!(writer, "{}\r\n", record.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("LEO"), "LEO");
+        assert_eq!(escape("Low, Earth"), "\"Low, Earth\"");
+        assert_eq!(escape("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_write_record() {
+        let mut out = vec![];
+        write_record(&mut out, &["orbit", "a,b", "1.5"]).unwrap();
+        assert_eq!(out, b"orbit,\"a,b\",1.5\r\n");
+    }
+}
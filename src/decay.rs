@@ -0,0 +1,277 @@
+use std::f64::consts::PI;
+
+use crate::{
+    atmosphere, calculate_circular_orbital_velocity,
+    dimension::{Meters, MetersPerSecond, Seconds},
+    Body,
+};
+
+// Orbit-averaged drag decay. The secular rates of semi-major axis and
+// eccentricity come from Gauss's equations with a purely tangential drag
+// acceleration of -rho * v^2 / (2 * BC), averaged over one revolution by
+// quadrature in eccentric anomaly, through a non-rotating exponential
+// atmosphere. The orbit is considered to have reentered once perigee drops
+// below `REENTRY_ALTITUDE`.
+
+pub const REENTRY_ALTITUDE: f64 = 100.0; // km
+/// Longest lifetime in years that reports follow an orbit for.
+pub const MAX_LIFETIME_YEARS: f64 = 1000.0;
+const QUADRATURE_POINTS: usize = 64;
+const SECONDS_PER_YEAR: f64 = 365.25 * 86_400.0;
+// SGP4 reference density times one earth radius, kg/m^2 per earth radius
+// (Vallado, section 9.7), so that B* = rho0 / (2 * BC).
+const BSTAR_REFERENCE_DENSITY: f64 = 0.157;
+/// Drag coefficient of a compact satellite in free molecular flow.
+pub const DRAG_COEFFICIENT: f64 = 2.2;
+
+/// Ballistic coefficient m / (Cd * A) in kg/m^2 from a mass in kg, a
+/// cross-sectional area in m^2 and a drag coefficient.
+pub fn ballistic_coefficient(mass: f64, area: f64, drag_coefficient: f64) -> f64 {
+    mass / (drag_coefficient * area)
+}
+
+/// Ballistic coefficient in kg/m^2 equivalent to a TLE's B* drag term in
+/// 1/earth radii. B* also soaks up errors in the SGP4 atmosphere, so this is
+/// only as good as the element set's fit.
+pub fn ballistic_coefficient_from_bstar(bstar: f64) -> f64 {
+    BSTAR_REFERENCE_DENSITY / (2.0 * bstar)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Elements {
+    pub semi_major_axis: Meters,
+    pub eccentricity: f64,
+}
+
+impl Elements {
+    pub fn from_altitudes(body: &Body, perigee: f64, apogee: f64) -> Self {
+        // altitudes in km
+        let rp = body.radius + Meters::from_km(perigee);
+        let ra = body.radius + Meters::from_km(apogee);
+        Elements {
+            semi_major_axis: (rp + ra) / 2.0,
+            eccentricity: (ra - rp) / (ra + rp),
+        }
+    }
+
+    pub fn perigee_altitude(&self, body: &Body) -> f64 {
+        (self.semi_major_axis * (1.0 - self.eccentricity) - body.radius).km()
+    }
+}
+
+/// Secular (da/dt, de/dt) in m/s and 1/s for a ballistic coefficient in kg/m^2.
+pub fn rates(body: &Body, elements: &Elements, ballistic_coefficient: f64) -> (f64, f64) {
+    let a = elements.semi_major_axis.0;
+    let e = elements.eccentricity;
+    let (mu, radius) = (body.mu.0, body.radius.0);
+
+    let (mut da, mut de) = (0.0, 0.0);
+    for k in 0..QUADRATURE_POINTS {
+        let eccentric_anomaly = 2.0 * PI * (k as f64 + 0.5) / QUADRATURE_POINTS as f64;
+        let cos_e = eccentric_anomaly.cos();
+        let r = a * (1.0 - e * cos_e);
+        let v = (mu * (2.0 / r - 1.0 / a)).sqrt();
+        let cos_true_anomaly = (cos_e - e) / (1.0 - e * cos_e);
+
+        let drag =
+            -0.5 * atmosphere::density((r - radius) / 1000.0) * v * v / ballistic_coefficient;
+        // dM = (1 - e cos E) dE weights each point by the time spent there
+        let weight = (1.0 - e * cos_e) / QUADRATURE_POINTS as f64;
+
+        da += weight * 2.0 * a * a * v * drag / mu;
+        de += weight * 2.0 * (e + cos_true_anomaly) * drag / v;
+    }
+    (da, de)
+}
+
+/// Time until reentry, or `None` if it takes longer than `max_years`.
+pub fn lifetime(
+    body: &Body,
+    initial: Elements,
+    ballistic_coefficient: f64,
+    max_years: f64,
+) -> Option<Seconds> {
+    let mut elements = initial;
+    let mut time = 0.0;
+
+    while elements.perigee_altitude(body) > REENTRY_ALTITUDE {
+        if time > max_years * SECONDS_PER_YEAR {
+            return None;
+        }
+
+        // step so the semi-major axis moves a small fraction of a scale height
+        let (da, _) = rates(body, &elements, ballistic_coefficient);
+        let scale_height = atmosphere::scale_height(elements.perigee_altitude(body)) * 1000.0;
+        let step = (0.02 * scale_height / da.abs()).min(SECONDS_PER_YEAR);
+
+        // midpoint (RK2) step
+        let advance = |from: &Elements, rates: (f64, f64), dt: f64| Elements {
+            semi_major_axis: from.semi_major_axis + Meters(rates.0 * dt),
+            eccentricity: (from.eccentricity + rates.1 * dt).max(0.0),
+        };
+        let midpoint = advance(
+            &elements,
+            rates(body, &elements, ballistic_coefficient),
+            step / 2.0,
+        );
+        elements = advance(
+            &elements,
+            rates(body, &midpoint, ballistic_coefficient),
+            step,
+        );
+        time += step;
+    }
+
+    Some(Seconds(time))
+}
+
+pub struct Makeup {
+    pub delta_v_per_year: MetersPerSecond,
+    pub decay_per_day: f64, // km, if left alone
+}
+
+/// Station keeping for a circular orbit: the along-track impulse that
+/// cancels drag over a year, and the altitude lost per day without it.
+/// `density` is in kg/m^3, so callers can apply their own activity level.
+pub fn makeup(body: &Body, altitude: f64, ballistic_coefficient: f64, density: f64) -> Makeup {
+    let a = body.radius + Meters::from_km(altitude);
+    let v = calculate_circular_orbital_velocity(body.mu, a).0;
+    let drag = 0.5 * density * v * v / ballistic_coefficient;
+    Makeup {
+        delta_v_per_year: MetersPerSecond(drag * SECONDS_PER_YEAR),
+        // circular orbit: da/dt = -rho * v * a / BC
+        decay_per_day: density * v * a.km() / ballistic_coefficient * 86_400.0,
+    }
+}
+
+pub fn years(time: Seconds) -> f64 {
+    time.0 / SECONDS_PER_YEAR
+}
+
+/// Post-mission disposal limits: the long-standing 25-year guideline and the
+/// FCC's 2022 five-year rule for LEO spacecraft.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisposalRule {
+    TwentyFiveYear,
+    FiveYear,
+}
+
+impl DisposalRule {
+    pub const ALL: [DisposalRule; 2] = [DisposalRule::TwentyFiveYear, DisposalRule::FiveYear];
+
+    /// Longest natural lifetime allowed, in years.
+    pub fn limit(self) -> f64 {
+        match self {
+            DisposalRule::TwentyFiveYear => 25.0,
+            DisposalRule::FiveYear => 5.0,
+        }
+    }
+
+    /// Whether an orbit with a natural lifetime of `years`, or `None` if it
+    /// outlasts `MAX_LIFETIME_YEARS`, reenters in time.
+    pub fn complies(self, years: Option<f64>) -> bool {
+        years.is_some_and(|years| years <= self.limit())
+    }
+}
+
+/// Highest perigee altitude (km) that still reenters within `years`, for an
+/// orbit whose apogee stays at `apogee` km. Found by bisection since the
+/// lifetime grows monotonically with perigee.
+pub fn perigee_for_lifetime(
+    body: &Body,
+    apogee: f64,
+    ballistic_coefficient: f64,
+    years: f64,
+) -> f64 {
+    let (mut low, mut high) = (REENTRY_ALTITUDE, apogee);
+    for _ in 0..40 {
+        let perigee = (low + high) / 2.0;
+        let elements = Elements::from_altitudes(body, perigee, apogee);
+        match lifetime(body, elements, ballistic_coefficient, years) {
+            Some(_) => low = perigee,
+            None => high = perigee,
+        }
+    }
+    low
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_circular_decay_follows_analytic_rate() {
+        // circular orbit: da/dt = -rho * sqrt(mu * a) / BC
+        let body = Body::earth();
+        let elements = Elements::from_altitudes(&body, 400.0, 400.0);
+        let (da, de) = rates(&body, &elements, 50.0);
+        let expected =
+            -atmosphere::density(400.0) * (body.mu.0 * elements.semi_major_axis.0).sqrt() / 50.0;
+        assert!((da / expected - 1.0).abs() < 1e-9);
+        assert!(de.abs() < 1e-15);
+    }
+
+    #[test]
+    fn test_lifetime_grows_with_altitude_and_bc() {
+        let body = Body::earth();
+        let at = |altitude: f64, bc: f64| {
+            lifetime(
+                &body,
+                Elements::from_altitudes(&body, altitude, altitude),
+                bc,
+                1000.0,
+            )
+            .unwrap()
+        };
+        assert!(at(300.0, 50.0) < at(400.0, 50.0));
+        assert!(at(400.0, 50.0) < at(400.0, 100.0));
+        // a dense 400 km satellite comes down within months to a few years
+        let years = years(at(400.0, 50.0));
+        assert!((0.2..5.0).contains(&years), "{years} years");
+    }
+
+    #[test]
+    fn test_disposal_rules() {
+        let body = Body::earth();
+        let at = |altitude: f64| {
+            let elements = Elements::from_altitudes(&body, altitude, altitude);
+            lifetime(&body, elements, 50.0, MAX_LIFETIME_YEARS).map(years)
+        };
+        // a dense 400 km satellite meets both rules, one at 800 km neither
+        for rule in DisposalRule::ALL {
+            assert!(rule.complies(at(400.0)));
+            assert!(!rule.complies(at(800.0)));
+            assert!(!rule.complies(None));
+        }
+        assert!(DisposalRule::TwentyFiveYear.complies(Some(20.0)));
+        assert!(!DisposalRule::FiveYear.complies(Some(20.0)));
+    }
+
+    #[test]
+    fn test_makeup_matches_decay_rate() {
+        let body = Body::earth();
+        let density = atmosphere::density(400.0);
+        let makeup = makeup(&body, 400.0, 50.0, density);
+        let (da, _) = rates(&body, &Elements::from_altitudes(&body, 400.0, 400.0), 50.0);
+        assert!((makeup.decay_per_day + da * 86.4).abs() < 1e-9);
+        // ISS-like spacecraft need tens of m/s per year at moderate activity
+        assert!((5.0..100.0).contains(&makeup.delta_v_per_year.0));
+    }
+
+    #[test]
+    fn test_ballistic_coefficient_from_bstar() {
+        // BC = 1 / (12.741621 * B*) in kg/m^2
+        let bc = ballistic_coefficient_from_bstar(1e-4);
+        assert!((bc / (1.0 / (12.741_621 * 1e-4)) - 1.0).abs() < 1e-3);
+        assert!((ballistic_coefficient(4.0, 0.04, DRAG_COEFFICIENT) - 4.0 / 0.088).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_eccentricity_decays() {
+        let body = Body::earth();
+        let elements = Elements::from_altitudes(&body, 250.0, 1000.0);
+        let (da, de) = rates(&body, &elements, 100.0);
+        assert!(da < 0.0);
+        assert!(de < 0.0);
+    }
+}
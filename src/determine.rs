@@ -0,0 +1,153 @@
+// Initial orbit determination from three position vectors of one object
+// along its orbit (Vallado, algorithms 54 and 55). Gibbs' method finds the
+// velocity at the middle position from the geometry alone and suits
+// positions well apart; Herrick-Gibbs uses the observation times in a
+// Taylor series instead and suits the closely spaced positions of a single
+// pass, where Gibbs loses precision. Positions are in m, times in s.
+
+/// Largest angle in degrees between the first position and the plane of
+/// the other two before the positions are taken as not coplanar.
+const COPLANAR_TOLERANCE: f64 = 3.0;
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn norm(a: [f64; 3]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+/// Weighted sum of three vectors.
+fn combine(weights: [f64; 3], vectors: [[f64; 3]; 3]) -> [f64; 3] {
+    [0, 1, 2].map(|i| (0..3).map(|k| weights[k] * vectors[k][i]).sum())
+}
+
+fn check_coplanar(r: [[f64; 3]; 3]) -> Result<(), String> {
+    let normal = cross(r[1], r[2]);
+    let (length, other) = (norm(normal), norm(r[0]));
+    if length == 0.0 || other == 0.0 {
+        return Err("positions must be distinct and away from the centre".to_string());
+    }
+    let angle = (dot(r[0], normal) / (length * other)).asin().to_degrees();
+    if angle.abs() > COPLANAR_TOLERANCE {
+        return Err(format!(
+            "positions are {:.1} degrees out of a common plane",
+            angle.abs()
+        ));
+    }
+    Ok(())
+}
+
+/// Velocity in m/s at the second of three positions by Gibbs' method.
+pub fn gibbs(mu: f64, r: [[f64; 3]; 3]) -> Result<[f64; 3], String> {
+    check_coplanar(r)?;
+    let radii = r.map(norm);
+    let n = combine(
+        radii,
+        [cross(r[1], r[2]), cross(r[2], r[0]), cross(r[0], r[1])],
+    );
+    let d = combine(
+        [1.0; 3],
+        [cross(r[0], r[1]), cross(r[1], r[2]), cross(r[2], r[0])],
+    );
+    let s = combine(
+        [
+            radii[1] - radii[2],
+            radii[2] - radii[0],
+            radii[0] - radii[1],
+        ],
+        r,
+    );
+    let (n_length, d_length) = (norm(n), norm(d));
+    if n_length * d_length == 0.0 || dot(n, d) <= 0.0 {
+        return Err("positions do not lie on one orbit".to_string());
+    }
+    let scale = (mu / (n_length * d_length)).sqrt();
+    let turned = cross(d, r[1]);
+    Ok([0, 1, 2].map(|i| scale * (turned[i] / radii[1] + s[i])))
+}
+
+/// Velocity in m/s at the second of three positions observed at `times`
+/// (s) by the Herrick-Gibbs method.
+pub fn herrick_gibbs(mu: f64, r: [[f64; 3]; 3], times: [f64; 3]) -> Result<[f64; 3], String> {
+    check_coplanar(r)?;
+    let (dt21, dt32, dt31) = (
+        times[1] - times[0],
+        times[2] - times[1],
+        times[2] - times[0],
+    );
+    if dt21 <= 0.0 || dt32 <= 0.0 {
+        return Err("observation times must increase".to_string());
+    }
+    let term = |radius: [f64; 3]| mu / (12.0 * norm(radius).powi(3));
+    Ok(combine(
+        [
+            -dt32 * (1.0 / (dt21 * dt31) + term(r[0])),
+            (dt32 - dt21) * (1.0 / (dt21 * dt32) + term(r[1])),
+            dt21 * (1.0 / (dt32 * dt31) + term(r[2])),
+        ],
+        r,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{elements::KeplerianElements, propagate, EARTH_MU};
+
+    #[test]
+    fn test_gibbs_curtis_example() {
+        // Curtis, "Orbital Mechanics for Engineering Students", example 5.1,
+        // which goes on to a = 8000 km, e = 0.1 and i = 60 deg
+        let r = [
+            [-294.32e3, 4_265.1e3, 5_986.7e3],
+            [-1_365.5e3, 3_637.6e3, 6_346.8e3],
+            [-2_940.3e3, 2_473.7e3, 6_555.8e3],
+        ];
+        let velocity = gibbs(EARTH_MU, r).unwrap();
+        for (computed, expected) in velocity.iter().zip([-6_217.4, -4_012.2, 1_599.0]) {
+            assert!((computed - expected).abs() < 1.0);
+        }
+        let skewed = [r[0], r[1], [0.0, 0.0, -7_000e3]];
+        assert!(gibbs(EARTH_MU, skewed).is_err());
+    }
+
+    #[test]
+    fn test_recover_propagated_orbit() {
+        let elements = KeplerianElements {
+            semi_major_axis: 7_000e3,
+            eccentricity: 0.01,
+            inclination: 51.6,
+            raan: 30.0,
+            argument_of_periapsis: 60.0,
+            true_anomaly: 0.0,
+        };
+        let states =
+            |times: [f64; 3]| times.map(|t| propagate::kepler(EARTH_MU, &elements, t).unwrap());
+
+        // well apart for Gibbs
+        let wide = states([0.0, 1_200.0, 2_400.0]);
+        let velocity = gibbs(EARTH_MU, wide.map(|state| state.position)).unwrap();
+        for (computed, expected) in velocity.iter().zip(wide[1].velocity) {
+            assert!((computed - expected).abs() < 1e-3);
+        }
+        // a minute apart for Herrick-Gibbs
+        let times = [0.0, 60.0, 120.0];
+        let close = states(times);
+        let velocity = herrick_gibbs(EARTH_MU, close.map(|state| state.position), times).unwrap();
+        for (computed, expected) in velocity.iter().zip(close[1].velocity) {
+            assert!((computed - expected).abs() < 1e-2);
+        }
+        assert!(
+            herrick_gibbs(EARTH_MU, close.map(|state| state.position), [0.0, 0.0, 1.0]).is_err()
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use std::{f64::consts::PI, path::Path};
+
+use plotters::prelude::*;
+
+// To-scale drawing of a central body and orbits around it, in metres from
+// the body's centre. Altitude bands are drawn as shaded rings; transfer
+// ellipses have the body at their focus, with the transfer half drawn solid
+// and the return half faded.
+
+const SEGMENTS: usize = 360;
+
+pub enum Shape {
+    Circle { radius: f64 },                     // m
+    Band { inner: f64, outer: f64 },            // m
+    Transfer { periapsis: f64, apoapsis: f64 }, // m
+}
+
+pub struct Item {
+    pub label: String,
+    pub shape: Shape,
+}
+
+pub struct Labels {
+    pub title: String,
+    pub body: String,
+    pub periapsis: String,
+    pub apoapsis: String,
+}
+
+impl Shape {
+    fn extent(&self) -> f64 {
+        match self {
+            Shape::Circle { radius } => *radius,
+            Shape::Band { outer, .. } => *outer,
+            Shape::Transfer { apoapsis, .. } => *apoapsis,
+        }
+    }
+}
+
+fn circle(radius: f64) -> Vec<(f64, f64)> {
+    (0..=SEGMENTS)
+        .map(|k| {
+            let angle = 2.0 * PI * k as f64 / SEGMENTS as f64;
+            (radius * angle.cos(), radius * angle.sin())
+        })
+        .collect()
+}
+
+/// Points along a focus-centred ellipse for true anomalies in [from, to].
+fn ellipse(periapsis: f64, apoapsis: f64, from: f64, to: f64) -> Vec<(f64, f64)> {
+    let eccentricity = (apoapsis - periapsis) / (apoapsis + periapsis);
+    let semi_latus_rectum = periapsis * (1.0 + eccentricity);
+    (0..=SEGMENTS / 2)
+        .map(|k| {
+            let anomaly = from + (to - from) * k as f64 / (SEGMENTS / 2) as f64;
+            let r = semi_latus_rectum / (1.0 + eccentricity * anomaly.cos());
+            (r * anomaly.cos(), r * anomaly.sin())
+        })
+        .collect()
+}
+
+pub fn render_svg(
+    path: &Path,
+    body_radius: f64,
+    items: &[Item],
+    labels: &Labels,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let extent = items
+        .iter()
+        .map(|item| item.shape.extent())
+        .fold(body_radius, f64::max)
+        * 1.15;
+
+    let root = SVGBackend::new(path, (900, 940)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(&root)
+        .caption(&labels.title, ("sans-serif", 24))
+        .margin(20)
+        .build_cartesian_2d(-extent..extent, -extent..extent)?;
+
+    let font = ("sans-serif", 14).into_font();
+    let ring = |outer: f64, inner: f64| {
+        // outer boundary one way and inner the other leaves the hole unfilled
+        let mut points = circle(outer);
+        points.extend(circle(inner).into_iter().rev());
+        points
+    };
+
+    for (i, item) in items.iter().enumerate() {
+        let color = Palette99::pick(i).to_rgba();
+        let label_at = |radius: f64| {
+            let angle = PI / 4.0 + i as f64 * 0.2;
+            (radius * angle.cos(), radius * angle.sin())
+        };
+        match item.shape {
+            Shape::Circle { radius } => {
+                chart.draw_series(std::iter::once(PathElement::new(
+                    circle(radius),
+                    color.stroke_width(2),
+                )))?;
+                chart.draw_series(std::iter::once(Text::new(
+                    item.label.clone(),
+                    label_at(radius),
+                    font.color(&color),
+                )))?;
+            }
+            Shape::Band { inner, outer } => {
+                chart.draw_series(std::iter::once(Polygon::new(
+                    ring(outer, inner),
+                    color.mix(0.25).filled(),
+                )))?;
+                for radius in [inner, outer] {
+                    chart.draw_series(std::iter::once(PathElement::new(
+                        circle(radius),
+                        color.stroke_width(1),
+                    )))?;
+                }
+                chart.draw_series(std::iter::once(Text::new(
+                    item.label.clone(),
+                    label_at(outer),
+                    font.color(&color),
+                )))?;
+            }
+            Shape::Transfer {
+                periapsis,
+                apoapsis,
+            } => {
+                chart.draw_series(std::iter::once(PathElement::new(
+                    ellipse(periapsis, apoapsis, 0.0, PI),
+                    color.stroke_width(2),
+                )))?;
+                chart.draw_series(std::iter::once(PathElement::new(
+                    ellipse(periapsis, apoapsis, PI, 2.0 * PI),
+                    color.mix(0.35).stroke_width(1),
+                )))?;
+                for (position, name, radius) in [
+                    ((periapsis, 0.0), &labels.periapsis, periapsis),
+                    ((-apoapsis, 0.0), &labels.apoapsis, apoapsis),
+                ] {
+                    chart.draw_series(std::iter::once(Circle::new(position, 4, color.filled())))?;
+                    chart.draw_series(std::iter::once(Text::new(
+                        format!(
+                            "{} {name} {:.0} km",
+                            item.label,
+                            (radius - body_radius) / 1000.0
+                        ),
+                        (position.0, position.1 - extent * 0.03),
+                        font.color(&color),
+                    )))?;
+                }
+            }
+        }
+    }
+
+    chart.draw_series(std::iter::once(Polygon::new(
+        circle(body_radius),
+        RGBColor(70, 110, 180).filled(),
+    )))?;
+    chart.draw_series(std::iter::once(Text::new(
+        labels.body.clone(),
+        (-body_radius * 0.4, 0.0),
+        font.color(&WHITE),
+    )))?;
+
+    root.present()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ellipse_apsides() {
+        let points = ellipse(7.0e6, 42.0e6, 0.0, PI);
+        let (first, last) = (points[0], points[points.len() - 1]);
+        assert!((first.0 - 7.0e6).abs() < 1e-6 && first.1.abs() < 1e-6);
+        assert!((last.0 + 42.0e6).abs() < 1e-3 && last.1.abs() < 1e-3);
+    }
+}
@@ -0,0 +1,222 @@
+use std::{
+    iter::Sum,
+    ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign},
+};
+
+use crate::GRAVITATIONAL_CONSTANT;
+
+// Dimensioned quantities for the two-body calculations. Each is an SI value
+// in a newtype, so passing a speed where a length belongs, or adding seconds
+// to metres, does not compile. Only the products and quotients that occur in
+// orbital mechanics are defined; anything else goes through the raw value,
+// which stays public for the vector and series code that works in plain f64.
+// Kilometres, minutes and hours appear only at the edges, through the named
+// constructors and accessors, and there is no Display: a report formats a
+// quantity through `units` or picks its unit explicitly.
+
+macro_rules! quantity {
+    ($name:ident) => {
+        #[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+        pub struct $name(pub f64);
+
+        impl $name {
+            pub fn abs(self) -> Self {
+                $name(self.0.abs())
+            }
+
+            pub fn min(self, other: Self) -> Self {
+                $name(self.0.min(other.0))
+            }
+
+            pub fn max(self, other: Self) -> Self {
+                $name(self.0.max(other.0))
+            }
+        }
+
+        impl Add for $name {
+            type Output = $name;
+            fn add(self, other: $name) -> $name {
+                $name(self.0 + other.0)
+            }
+        }
+
+        impl Sub for $name {
+            type Output = $name;
+            fn sub(self, other: $name) -> $name {
+                $name(self.0 - other.0)
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, other: $name) {
+                self.0 += other.0;
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, other: $name) {
+                self.0 -= other.0;
+            }
+        }
+
+        impl Neg for $name {
+            type Output = $name;
+            fn neg(self) -> $name {
+                $name(-self.0)
+            }
+        }
+
+        impl Mul<f64> for $name {
+            type Output = $name;
+            fn mul(self, factor: f64) -> $name {
+                $name(self.0 * factor)
+            }
+        }
+
+        impl Mul<$name> for f64 {
+            type Output = $name;
+            fn mul(self, quantity: $name) -> $name {
+                $name(self * quantity.0)
+            }
+        }
+
+        impl Div<f64> for $name {
+            type Output = $name;
+            fn div(self, divisor: f64) -> $name {
+                $name(self.0 / divisor)
+            }
+        }
+
+        /// The ratio of two quantities of the same kind is a plain number.
+        impl Div for $name {
+            type Output = f64;
+            fn div(self, other: $name) -> f64 {
+                self.0 / other.0
+            }
+        }
+
+        impl Sum for $name {
+            fn sum<I: Iterator<Item = $name>>(iter: I) -> $name {
+                $name(iter.map(|quantity| quantity.0).sum())
+            }
+        }
+    };
+}
+
+quantity!(Meters); // m
+quantity!(Seconds); // s
+quantity!(Kilograms); // kg
+quantity!(MetersPerSecond); // m/s
+quantity!(GravitationalParameter); // m^3*s^-2
+
+impl Meters {
+    pub fn from_km(kilometres: f64) -> Self {
+        Meters(kilometres * 1000.0)
+    }
+
+    pub fn km(self) -> f64 {
+        self.0 / 1000.0
+    }
+}
+
+impl Seconds {
+    pub fn from_minutes(minutes: f64) -> Self {
+        Seconds(minutes * 60.0)
+    }
+
+    pub fn from_hours(hours: f64) -> Self {
+        Seconds(hours * 3600.0)
+    }
+
+    pub fn from_days(days: f64) -> Self {
+        Seconds(days * 86_400.0)
+    }
+
+    pub fn minutes(self) -> f64 {
+        self.0 / 60.0
+    }
+
+    pub fn hours(self) -> f64 {
+        self.0 / 3600.0
+    }
+
+    pub fn days(self) -> f64 {
+        self.0 / 86_400.0
+    }
+}
+
+impl MetersPerSecond {
+    pub fn from_km_per_s(speed: f64) -> Self {
+        MetersPerSecond(speed * 1000.0)
+    }
+
+    pub fn km_per_s(self) -> f64 {
+        self.0 / 1000.0
+    }
+}
+
+impl GravitationalParameter {
+    /// G * M for a mass.
+    pub fn from_mass(mass: Kilograms) -> Self {
+        GravitationalParameter(GRAVITATIONAL_CONSTANT * mass.0)
+    }
+
+    /// The mass giving this parameter, carrying the uncertainty of G.
+    pub fn mass(self) -> Kilograms {
+        Kilograms(self.0 / GRAVITATIONAL_CONSTANT)
+    }
+}
+
+impl Div<Seconds> for Meters {
+    type Output = MetersPerSecond;
+    fn div(self, time: Seconds) -> MetersPerSecond {
+        MetersPerSecond(self.0 / time.0)
+    }
+}
+
+impl Div<MetersPerSecond> for Meters {
+    type Output = Seconds;
+    fn div(self, speed: MetersPerSecond) -> Seconds {
+        Seconds(self.0 / speed.0)
+    }
+}
+
+impl Mul<Seconds> for MetersPerSecond {
+    type Output = Meters;
+    fn mul(self, time: Seconds) -> Meters {
+        Meters(self.0 * time.0)
+    }
+}
+
+impl Mul<MetersPerSecond> for Seconds {
+    type Output = Meters;
+    fn mul(self, speed: MetersPerSecond) -> Meters {
+        Meters(self.0 * speed.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let distance = Meters::from_km(7.5);
+        let time = Seconds::from_minutes(0.5);
+        let speed = distance / time;
+        assert_eq!(speed, MetersPerSecond(250.0));
+        assert_eq!(speed * time, distance);
+        assert_eq!(distance / speed, time);
+        assert_eq!(distance / Meters(2500.0), 3.0);
+        assert_eq!(2.0 * distance - Meters(15e3), Meters(0.0));
+        let total: MetersPerSecond = [speed, speed].into_iter().sum();
+        assert_eq!(total.km_per_s(), 0.5);
+    }
+
+    #[test]
+    fn test_mass_round_trip() {
+        let mass = Kilograms(5.9722e24);
+        let mu = GravitationalParameter::from_mass(mass);
+        assert!((mu.mass() / mass - 1.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,208 @@
+use crate::{random::Rng, sensitivity::central_difference, Body};
+
+// Maps launch-vehicle burnout errors to insertion-orbit errors. Burnout
+// velocity is inertial, and the orbit follows from the two-body energy and
+// angular momentum at burnout. Inclination comes from the burnout latitude
+// and inertial azimuth through cos(i) = cos(latitude) * sin(azimuth).
+
+#[derive(Debug, Clone, Copy)]
+pub struct Burnout {
+    pub altitude: f64,          // km
+    pub velocity: f64,          // m/s
+    pub flight_path_angle: f64, // deg above local horizontal
+    pub latitude: f64,          // deg
+    pub azimuth: f64,           // deg from north
+}
+
+/// One-sigma burnout errors, in the same units as `Burnout`.
+#[derive(Debug, Clone, Copy)]
+pub struct Sigmas {
+    pub altitude: f64,
+    pub velocity: f64,
+    pub flight_path_angle: f64,
+    pub azimuth: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Insertion {
+    pub perigee: f64,     // km altitude
+    pub apogee: f64,      // km altitude, infinite when not bound
+    pub inclination: f64, // deg
+}
+
+pub fn insertion(body: &Body, burnout: &Burnout) -> Insertion {
+    let (mu, radius) = (body.mu.0, body.radius.0);
+    let r = radius + burnout.altitude * 1000.0;
+    let v = burnout.velocity;
+    let gamma = burnout.flight_path_angle.to_radians();
+
+    let energy = v * v / 2.0 - mu / r;
+    let momentum = r * v * gamma.cos();
+    let eccentricity = (1.0 + 2.0 * energy * momentum.powi(2) / mu.powi(2))
+        .max(0.0)
+        .sqrt();
+    // perigee from the angular momentum so it stays finite on escape orbits
+    let perigee = momentum.powi(2) / (mu * (1.0 + eccentricity));
+    let apogee = if energy < 0.0 {
+        -mu / (2.0 * energy) * (1.0 + eccentricity)
+    } else {
+        f64::INFINITY
+    };
+
+    let cos_inclination = burnout.latitude.to_radians().cos() * burnout.azimuth.to_radians().sin();
+
+    Insertion {
+        perigee: (perigee - radius) / 1000.0,
+        apogee: (apogee - radius) / 1000.0,
+        inclination: cos_inclination.clamp(-1.0, 1.0).acos().to_degrees(),
+    }
+}
+
+/// First-order one-sigma insertion errors, as the root sum square of each
+/// burnout error times its central-difference partial.
+type Perturbation = fn(&mut Burnout, f64);
+
+pub fn linearized(body: &Body, burnout: &Burnout, sigmas: &Sigmas) -> Insertion {
+    let perturbations: [(f64, Perturbation); 4] = [
+        (sigmas.altitude, |b, d| b.altitude += d),
+        (sigmas.velocity, |b, d| b.velocity += d),
+        (sigmas.flight_path_angle, |b, d| b.flight_path_angle += d),
+        (sigmas.azimuth, |b, d| b.azimuth += d),
+    ];
+
+    let mut variance = [0.0; 3];
+    for (sigma, perturb) in perturbations {
+        if sigma == 0.0 {
+            continue;
+        }
+        let output = |delta: f64| {
+            let mut b = *burnout;
+            perturb(&mut b, delta);
+            insertion(body, &b)
+        };
+        let fields: [fn(&Insertion) -> f64; 3] = [|i| i.perigee, |i| i.apogee, |i| i.inclination];
+        for (k, field) in fields.iter().enumerate() {
+            let partial = central_difference(|d| field(&output(d)), 0.0, sigma * 1e-3);
+            variance[k] += (partial * sigma).powi(2);
+        }
+    }
+
+    Insertion {
+        perigee: variance[0].sqrt(),
+        apogee: variance[1].sqrt(),
+        inclination: variance[2].sqrt(),
+    }
+}
+
+pub struct Statistics {
+    pub mean: f64,
+    pub sigma: f64,
+    pub min: f64,
+    pub max: f64,
+}
+
+impl Statistics {
+    fn from(values: &[f64]) -> Self {
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        let variance = values.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        Statistics {
+            mean,
+            sigma: variance.sqrt(),
+            min: values.iter().cloned().fold(f64::INFINITY, f64::min),
+            max: values.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+        }
+    }
+}
+
+pub struct MonteCarlo {
+    pub perigee: Statistics,
+    pub apogee: Statistics,
+    pub inclination: Statistics,
+    pub escaped: usize, // samples on unbound orbits, excluded from apogee
+}
+
+/// Gaussian burnout dispersions run through the full nonlinear mapping.
+pub fn monte_carlo(
+    body: &Body,
+    burnout: &Burnout,
+    sigmas: &Sigmas,
+    samples: usize,
+    rng: &mut Rng,
+) -> MonteCarlo {
+    let results: Vec<Insertion> = (0..samples)
+        .map(|_| {
+            let sample = Burnout {
+                altitude: burnout.altitude + sigmas.altitude * rng.gaussian(),
+                velocity: burnout.velocity + sigmas.velocity * rng.gaussian(),
+                flight_path_angle: burnout.flight_path_angle
+                    + sigmas.flight_path_angle * rng.gaussian(),
+                latitude: burnout.latitude,
+                azimuth: burnout.azimuth + sigmas.azimuth * rng.gaussian(),
+            };
+            insertion(body, &sample)
+        })
+        .collect();
+
+    let apogees: Vec<f64> = results
+        .iter()
+        .map(|i| i.apogee)
+        .filter(|a| a.is_finite())
+        .collect();
+
+    MonteCarlo {
+        perigee: Statistics::from(&results.iter().map(|i| i.perigee).collect::<Vec<_>>()),
+        apogee: Statistics::from(&apogees),
+        inclination: Statistics::from(&results.iter().map(|i| i.inclination).collect::<Vec<_>>()),
+        escaped: samples - apogees.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calculate_circular_orbital_velocity, dimension::Meters};
+
+    #[test]
+    fn test_circular_burnout() {
+        let body = Body::earth();
+        let velocity =
+            calculate_circular_orbital_velocity(body.mu, body.radius + Meters(200_000.0));
+        let orbit = insertion(
+            &body,
+            &Burnout {
+                altitude: 200.0,
+                velocity: velocity.0,
+                flight_path_angle: 0.0,
+                latitude: 28.5,
+                azimuth: 90.0,
+            },
+        );
+        assert!((orbit.perigee - 200.0).abs() < 1e-6);
+        assert!((orbit.apogee - 200.0).abs() < 1e-6);
+        assert!((orbit.inclination - 28.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_monte_carlo_agrees_with_linearized() {
+        // away from circular the mapping is close to linear
+        let body = Body::earth();
+        let burnout = Burnout {
+            altitude: 200.0,
+            velocity: 8_000.0,
+            flight_path_angle: 1.0,
+            latitude: 28.5,
+            azimuth: 100.0,
+        };
+        let sigmas = Sigmas {
+            altitude: 1.0,
+            velocity: 2.0,
+            flight_path_angle: 0.01,
+            azimuth: 0.1,
+        };
+        let linear = linearized(&body, &burnout, &sigmas);
+        let mc = monte_carlo(&body, &burnout, &sigmas, 20_000, &mut Rng::new(11));
+        assert!((mc.apogee.sigma / linear.apogee - 1.0).abs() < 0.05);
+        assert!((mc.inclination.sigma / linear.inclination - 1.0).abs() < 0.05);
+    }
+}
@@ -0,0 +1,44 @@
+// Cylindrical shadow model for circular orbits. The beta angle is the angle
+// between the orbit plane and the Sun direction; the orbit sees an eclipse
+// whenever |beta| is below the critical angle asin(R / r).
+
+use crate::dimension::Meters;
+
+/// Beta angle in degrees above which a circular orbit never enters the shadow.
+pub fn critical_beta(body_radius: Meters, orbit_radius: Meters) -> f64 {
+    (body_radius / orbit_radius).asin().to_degrees()
+}
+
+/// Fraction of each revolution spent in the body's shadow.
+pub fn eclipse_fraction(body_radius: Meters, orbit_radius: Meters, beta: f64) -> f64 {
+    if beta.abs() >= critical_beta(body_radius, orbit_radius) {
+        return 0.0;
+    }
+    let (body_radius, orbit_radius) = (body_radius.0, orbit_radius.0);
+    let altitude = orbit_radius - body_radius;
+    let cos_beta = beta.to_radians().cos();
+    let x = (altitude.powi(2) + 2.0 * body_radius * altitude).sqrt() / (orbit_radius * cos_beta);
+    x.acos() / std::f64::consts::PI
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EARTH_RADIUS;
+
+    #[test]
+    fn test_eclipse_at_zero_beta() {
+        // at beta 0 the shadow half-angle is asin(R / r)
+        let (body, r) = (Meters(EARTH_RADIUS), Meters(EARTH_RADIUS + 400_000.0));
+        let expected = (body / r).asin() / std::f64::consts::PI;
+        assert!((eclipse_fraction(body, r, 0.0) - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_no_eclipse_above_critical_beta() {
+        let (body, r) = (Meters(EARTH_RADIUS), Meters(EARTH_RADIUS + 550_000.0));
+        let beta = critical_beta(body, r);
+        assert!(eclipse_fraction(body, r, beta - 1.0) > 0.0);
+        assert_eq!(eclipse_fraction(body, r, beta + 0.1), 0.0);
+    }
+}
@@ -0,0 +1,226 @@
+use std::f64::consts::PI;
+
+// Classical orbital elements and their conversion to and from an inertial
+// position and velocity (Vallado, algorithms 9 and 10). Angles are degrees.
+// Where an angle is undefined the conventions are: circular orbits measure
+// the true anomaly from the ascending node (argument of periapsis 0), and
+// equatorial orbits measure the argument of periapsis from the x axis
+// (right ascension of the ascending node 0).
+
+const SMALL: f64 = 1e-11;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StateVector {
+    pub position: [f64; 3], // m
+    pub velocity: [f64; 3], // m/s
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeplerianElements {
+    pub semi_major_axis: f64, // m, negative for hyperbolic orbits
+    pub eccentricity: f64,
+    pub inclination: f64,           // deg
+    pub raan: f64,                  // deg
+    pub argument_of_periapsis: f64, // deg
+    pub true_anomaly: f64,          // deg
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn norm(a: [f64; 3]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+/// Angle between two vectors in [0, 360), reflected when `flip` is true.
+fn angle(a: [f64; 3], b: [f64; 3], flip: bool) -> f64 {
+    let cos = (dot(a, b) / (norm(a) * norm(b))).clamp(-1.0, 1.0);
+    let angle = cos.acos();
+    if flip {
+        (2.0 * PI - angle).to_degrees()
+    } else {
+        angle.to_degrees()
+    }
+}
+
+impl KeplerianElements {
+    pub fn from_state(mu: f64, state: &StateVector) -> Self {
+        let (r, v) = (state.position, state.velocity);
+        let radius = norm(r);
+        let speed = norm(v);
+        let h = cross(r, v);
+        let node = cross([0.0, 0.0, 1.0], h);
+        let radial_velocity = dot(r, v);
+        let e_vector =
+            [0, 1, 2].map(|k| ((speed * speed - mu / radius) * r[k] - radial_velocity * v[k]) / mu);
+        let eccentricity = norm(e_vector);
+
+        let energy = speed * speed / 2.0 - mu / radius;
+        let semi_major_axis = -mu / (2.0 * energy);
+        let inclination = (h[2] / norm(h)).clamp(-1.0, 1.0).acos().to_degrees();
+
+        let circular = eccentricity < SMALL;
+        let equatorial = norm(node) < SMALL * norm(h);
+
+        let raan = if equatorial {
+            0.0
+        } else {
+            angle([1.0, 0.0, 0.0], node, node[1] < 0.0)
+        };
+        let (argument_of_periapsis, true_anomaly) = match (circular, equatorial) {
+            (false, false) => (
+                angle(node, e_vector, e_vector[2] < 0.0),
+                angle(e_vector, r, radial_velocity < 0.0),
+            ),
+            // longitude of periapsis, measured the way the orbit turns
+            (false, true) => (
+                angle(
+                    [1.0, 0.0, 0.0],
+                    e_vector,
+                    (e_vector[1] < 0.0) == (h[2] > 0.0),
+                ),
+                angle(e_vector, r, radial_velocity < 0.0),
+            ),
+            // argument of latitude
+            (true, false) => (0.0, angle(node, r, r[2] < 0.0)),
+            // true longitude
+            (true, true) => (0.0, angle([1.0, 0.0, 0.0], r, (r[1] < 0.0) == (h[2] > 0.0))),
+        };
+
+        KeplerianElements {
+            semi_major_axis,
+            eccentricity,
+            inclination,
+            raan,
+            argument_of_periapsis,
+            true_anomaly,
+        }
+    }
+
+    pub fn to_state(&self, mu: f64) -> StateVector {
+        let e = self.eccentricity;
+        let p = self.semi_major_axis * (1.0 - e * e); // semi-latus rectum
+        let (sin_nu, cos_nu) = self.true_anomaly.to_radians().sin_cos();
+        let radius = p / (1.0 + e * cos_nu);
+        let scale = (mu / p).sqrt();
+
+        // perifocal frame
+        let position = [radius * cos_nu, radius * sin_nu, 0.0];
+        let velocity = [-scale * sin_nu, scale * (e + cos_nu), 0.0];
+
+        let (sin_o, cos_o) = self.raan.to_radians().sin_cos();
+        let (sin_w, cos_w) = self.argument_of_periapsis.to_radians().sin_cos();
+        let (sin_i, cos_i) = self.inclination.to_radians().sin_cos();
+        let rotation = [
+            [
+                cos_o * cos_w - sin_o * sin_w * cos_i,
+                -cos_o * sin_w - sin_o * cos_w * cos_i,
+            ],
+            [
+                sin_o * cos_w + cos_o * sin_w * cos_i,
+                -sin_o * sin_w + cos_o * cos_w * cos_i,
+            ],
+            [sin_w * sin_i, cos_w * sin_i],
+        ];
+        let rotate = |[x, y, _]: [f64; 3]| rotation.map(|[a, b]| a * x + b * y);
+
+        StateVector {
+            position: rotate(position),
+            velocity: rotate(velocity),
+        }
+    }
+
+    /// Periapsis distance from the body's centre, m.
+    pub fn periapsis(&self) -> f64 {
+        self.semi_major_axis * (1.0 - self.eccentricity)
+    }
+
+    /// Apoapsis distance from the body's centre, m, or `None` for open
+    /// orbits.
+    pub fn apoapsis(&self) -> Option<f64> {
+        (self.eccentricity < 1.0).then_some(self.semi_major_axis * (1.0 + self.eccentricity))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EARTH_MU;
+
+    fn km(values: [f64; 3]) -> [f64; 3] {
+        values.map(|v| v * 1000.0)
+    }
+
+    #[test]
+    fn test_vallado_example_2_5() {
+        let state = StateVector {
+            position: km([6_524.834, 6_862.875, 6_448.296]),
+            velocity: km([4.901_327, 5.533_756, -1.976_341]),
+        };
+        let elements = KeplerianElements::from_state(EARTH_MU, &state);
+        assert!((elements.semi_major_axis / 1000.0 - 36_127.343).abs() < 0.5);
+        assert!((elements.eccentricity - 0.832_853).abs() < 1e-5);
+        assert!((elements.inclination - 87.870).abs() < 1e-3);
+        assert!((elements.raan - 227.898).abs() < 1e-3);
+        assert!((elements.argument_of_periapsis - 53.38).abs() < 1e-2);
+        assert!((elements.true_anomaly - 92.335).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let cases = [
+            (7_000e3, 0.01, 51.6, 30.0, 45.0, 300.0),
+            (26_560e3, 0.7, 63.4, 200.0, 270.0, 10.0),
+            (-20_000e3, 1.5, 120.0, 80.0, 10.0, 60.0),
+            // equatorial, where the node is undefined
+            (8_000e3, 0.2, 0.0, 0.0, 110.0, 200.0),
+            (8_000e3, 0.3, 180.0, 0.0, 40.0, 100.0),
+        ];
+        for (a, e, i, raan, w, nu) in cases {
+            let elements = KeplerianElements {
+                semi_major_axis: a,
+                eccentricity: e,
+                inclination: i,
+                raan,
+                argument_of_periapsis: w,
+                true_anomaly: nu,
+            };
+            let back = KeplerianElements::from_state(EARTH_MU, &elements.to_state(EARTH_MU));
+            assert!((back.semi_major_axis / a - 1.0).abs() < 1e-9);
+            assert!((back.eccentricity - e).abs() < 1e-9);
+            for (x, y) in [
+                (back.inclination, i),
+                (back.raan, raan),
+                (back.argument_of_periapsis, w),
+                (back.true_anomaly, nu),
+            ] {
+                assert!((x - y).abs() < 1e-6, "{x} != {y}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_circular_equatorial_uses_true_longitude() {
+        let radius = 42_164e3;
+        let speed = (EARTH_MU / radius).sqrt();
+        let state = StateVector {
+            position: [0.0, -radius, 0.0],
+            velocity: [speed, 0.0, 0.0],
+        };
+        let elements = KeplerianElements::from_state(EARTH_MU, &state);
+        assert!(elements.eccentricity < 1e-12);
+        assert_eq!((elements.raan, elements.argument_of_periapsis), (0.0, 0.0));
+        assert!((elements.true_anomaly - 270.0).abs() < 1e-9);
+        let back = elements.to_state(EARTH_MU);
+        assert!(norm([0, 1, 2].map(|k| back.position[k] - state.position[k])) < 1e-6);
+    }
+}
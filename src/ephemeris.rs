@@ -0,0 +1,326 @@
+use crate::{
+    anomaly::Anomalies,
+    bodies::Named,
+    dimension::Seconds,
+    elements::{KeplerianElements, StateVector},
+    habitable,
+};
+
+// Low-precision planetary positions from the JPL approximate Keplerian
+// elements for 1800-2050 (Standish, table 1): each mean element drifts
+// linearly in time from its J2000 value, and the planet sits on the fixed
+// two-body ellipse those elements describe. Positions are heliocentric in
+// the J2000 ecliptic frame and good to a few thousandths of an AU for the
+// inner planets; Earth's are those of the Earth-Moon barycentre. Times are
+// days since J2000 UTC, which the table's TDB exceeds by about a minute.
+// Rotating about the x axis by the obliquity turns ecliptic vectors into
+// the J2000 equatorial frame of right ascension and declination.
+
+/// Obliquity of the ecliptic at J2000 in degrees (IAU 1976).
+pub const OBLIQUITY: f64 = 23.439_291;
+/// Speed of light in vacuum, m/s (exact).
+pub const SPEED_OF_LIGHT: f64 = 299_792_458.0;
+
+/// J2000 values or rates per Julian century of the mean elements.
+#[derive(Debug, Clone, Copy)]
+pub struct MeanElements {
+    pub semi_major_axis: f64, // AU
+    pub eccentricity: f64,
+    pub inclination: f64,    // deg
+    pub longitude: f64,      // deg, mean longitude
+    pub perihelion: f64,     // deg, longitude of perihelion
+    pub ascending_node: f64, // deg
+}
+
+const fn mean(values: [f64; 6]) -> MeanElements {
+    MeanElements {
+        semi_major_axis: values[0],
+        eccentricity: values[1],
+        inclination: values[2],
+        longitude: values[3],
+        perihelion: values[4],
+        ascending_node: values[5],
+    }
+}
+
+/// J2000 mean elements and their rates per Julian century, or `None` for
+/// the Sun and the Moon.
+pub fn mean_elements(named: Named) -> Option<(MeanElements, MeanElements)> {
+    let (value, rate) = match named {
+        Named::Sun | Named::Moon => return None,
+        Named::Mercury => (
+            [
+                0.387_099_27,
+                0.205_635_93,
+                7.004_979_02,
+                252.250_323_50,
+                77.457_796_28,
+                48.330_765_93,
+            ],
+            [
+                0.000_000_37,
+                0.000_019_06,
+                -0.005_947_49,
+                149_472.674_111_75,
+                0.160_476_89,
+                -0.125_340_81,
+            ],
+        ),
+        Named::Venus => (
+            [
+                0.723_335_66,
+                0.006_776_72,
+                3.394_676_05,
+                181.979_099_50,
+                131.602_467_18,
+                76.679_842_55,
+            ],
+            [
+                0.000_003_90,
+                -0.000_041_07,
+                -0.000_788_90,
+                58_517.815_387_29,
+                0.002_683_29,
+                -0.277_694_18,
+            ],
+        ),
+        Named::Earth => (
+            [
+                1.000_002_61,
+                0.016_711_23,
+                -0.000_015_31,
+                100.464_571_66,
+                102.937_681_93,
+                0.0,
+            ],
+            [
+                0.000_005_62,
+                -0.000_043_92,
+                -0.012_946_68,
+                35_999.372_449_81,
+                0.323_273_64,
+                0.0,
+            ],
+        ),
+        Named::Mars => (
+            [
+                1.523_710_34,
+                0.093_394_10,
+                1.849_691_42,
+                -4.553_432_05,
+                -23.943_629_59,
+                49.559_538_91,
+            ],
+            [
+                0.000_018_47,
+                0.000_078_82,
+                -0.008_131_31,
+                19_140.302_684_99,
+                0.444_410_88,
+                -0.292_573_43,
+            ],
+        ),
+        Named::Jupiter => (
+            [
+                5.202_887_00,
+                0.048_386_24,
+                1.304_396_95,
+                34.396_440_51,
+                14.728_479_83,
+                100.473_909_09,
+            ],
+            [
+                -0.000_116_07,
+                -0.000_132_53,
+                -0.001_837_14,
+                3_034.746_127_75,
+                0.212_526_68,
+                0.204_691_06,
+            ],
+        ),
+        Named::Saturn => (
+            [
+                9.536_675_94,
+                0.053_861_79,
+                2.485_991_87,
+                49.954_244_23,
+                92.598_878_31,
+                113.662_424_48,
+            ],
+            [
+                -0.001_250_60,
+                -0.000_509_91,
+                0.001_936_09,
+                1_222.493_622_01,
+                -0.418_972_16,
+                -0.288_677_94,
+            ],
+        ),
+        Named::Uranus => (
+            [
+                19.189_164_64,
+                0.047_257_44,
+                0.772_637_83,
+                313.238_104_51,
+                170.954_276_30,
+                74.016_925_03,
+            ],
+            [
+                -0.001_961_76,
+                -0.000_043_97,
+                -0.002_429_39,
+                428.482_027_85,
+                0.408_052_81,
+                0.042_405_89,
+            ],
+        ),
+        Named::Neptune => (
+            [
+                30.069_922_76,
+                0.008_590_48,
+                1.770_043_47,
+                -55.120_029_69,
+                44.964_762_27,
+                131.784_225_74,
+            ],
+            [
+                0.000_262_91,
+                0.000_051_05,
+                0.000_353_72,
+                218.459_453_25,
+                -0.322_414_64,
+                -0.005_086_64,
+            ],
+        ),
+        Named::Pluto => (
+            [
+                39.482_116_75,
+                0.248_827_30,
+                17.140_012_06,
+                238.929_038_33,
+                224.068_916_29,
+                110.303_936_84,
+            ],
+            [
+                -0.000_315_96,
+                0.000_051_70,
+                0.000_048_18,
+                145.207_805_15,
+                -0.040_629_42,
+                -0.011_834_82,
+            ],
+        ),
+    };
+    Some((mean(value), mean(rate)))
+}
+
+/// Osculating heliocentric ecliptic elements at `days` since J2000, or
+/// `None` for the Sun and the Moon.
+pub fn elements(named: Named, days: f64) -> Option<KeplerianElements> {
+    let (value, rate) = mean_elements(named)?;
+    let centuries = days / 36_525.0;
+    let at = |value: f64, rate: f64| value + rate * centuries;
+    let eccentricity = at(value.eccentricity, rate.eccentricity);
+    let perihelion = at(value.perihelion, rate.perihelion);
+    let ascending_node = at(value.ascending_node, rate.ascending_node);
+    let mean_anomaly = (at(value.longitude, rate.longitude) - perihelion).to_radians();
+    let true_anomaly = Anomalies::from_mean(mean_anomaly, eccentricity)
+        .expect("planetary eccentricities are below 1")
+        .true_anomaly;
+    Some(KeplerianElements {
+        semi_major_axis: at(value.semi_major_axis, rate.semi_major_axis)
+            * habitable::ASTRONOMICAL_UNIT,
+        eccentricity,
+        inclination: at(value.inclination, rate.inclination),
+        raan: ascending_node.rem_euclid(360.0),
+        argument_of_periapsis: (perihelion - ascending_node).rem_euclid(360.0),
+        true_anomaly: true_anomaly.to_degrees(),
+    })
+}
+
+/// Heliocentric ecliptic position in m and velocity in m/s at `days` since
+/// J2000, or `None` for the Sun and the Moon.
+pub fn state(named: Named, days: f64) -> Option<StateVector> {
+    elements(named, days).map(|elements| elements.to_state(habitable::SUN_MU))
+}
+
+/// Heliocentric ecliptic position in m at `days` since J2000, the origin for
+/// the Sun, or `None` for the Moon.
+pub fn position(named: Named, days: f64) -> Option<[f64; 3]> {
+    match named {
+        Named::Sun => Some([0.0; 3]),
+        _ => state(named, days).map(|state| state.position),
+    }
+}
+
+/// J2000 equatorial vector of a J2000 ecliptic one.
+pub fn equatorial(ecliptic: [f64; 3]) -> [f64; 3] {
+    let (sin, cos) = OBLIQUITY.to_radians().sin_cos();
+    let [x, y, z] = ecliptic;
+    [x, y * cos - z * sin, y * sin + z * cos]
+}
+
+/// Longitude (or right ascension) from 0 to 360 deg, latitude (or
+/// declination) in deg and length of a vector.
+pub fn spherical(vector: [f64; 3]) -> (f64, f64, f64) {
+    let [x, y, z] = vector;
+    let length = (x * x + y * y + z * z).sqrt();
+    (
+        y.atan2(x).to_degrees().rem_euclid(360.0),
+        (z / length).asin().to_degrees(),
+        length,
+    )
+}
+
+/// One-way light time in s over `distance` m.
+pub fn light_time(distance: f64) -> Seconds {
+    Seconds(distance / SPEED_OF_LIGHT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_positions() {
+        let au = habitable::ASTRONOMICAL_UNIT;
+        let distance =
+            |state: StateVector| state.position.iter().map(|v| v * v).sum::<f64>().sqrt() / au;
+        // Earth is at perihelion in early January and aphelion in early July
+        let january = distance(state(Named::Earth, 3.0).unwrap());
+        let july = distance(state(Named::Earth, 185.0).unwrap());
+        assert!((january - 0.9833).abs() < 0.001);
+        assert!((july - 1.0167).abs() < 0.001);
+        // Mars stays within its perihelion and aphelion distances
+        for days in (0..800).step_by(50) {
+            assert!((1.381..1.667).contains(&distance(state(Named::Mars, days as f64).unwrap())));
+        }
+        assert!(state(Named::Moon, 0.0).is_none());
+    }
+
+    #[test]
+    fn test_light_time() {
+        // sunlight takes about 8 minutes 19 seconds to cover 1 AU
+        let time = light_time(habitable::ASTRONOMICAL_UNIT);
+        assert!((time.0 - 499.0).abs() < 0.1);
+        assert_eq!(light_time(0.0), Seconds(0.0));
+    }
+
+    #[test]
+    fn test_sun_seen_from_earth() {
+        let sun = |date: &str| {
+            let days = crate::time::parse_utc(date).unwrap();
+            let earth = position(Named::Earth, days).unwrap();
+            spherical(equatorial(earth.map(|v| -v)))
+        };
+        // at the March equinox the Sun crosses the equator of date; the
+        // precession since J2000 leaves it 0.36 deg short of the J2000
+        // equinox and so a little south of the J2000 equator
+        let (right_ascension, declination, _) = sun("2026-03-20T14:46:00Z");
+        assert!((right_ascension - 359.64).abs() < 0.05);
+        assert!((declination + 0.14).abs() < 0.02);
+        // and at the June solstice it stands a full obliquity north
+        let (_, declination, _) = sun("2026-06-21T08:24:00Z");
+        assert!((declination - OBLIQUITY).abs() < 0.05);
+    }
+}
@@ -0,0 +1,296 @@
+use std::io::{self, Write};
+
+use crate::{gltf::PALETTE, time};
+
+// Map geometry for GIS tools: GeoJSON (RFC 7946) for QGIS and web maps, and
+// KML 2.2 for Google Earth. Coordinates are [longitude, latitude] in degrees
+// on WGS-84, which both formats assume. Features carry string properties,
+// written as GeoJSON properties and as KML ExtendedData.
+//
+// Moving objects go to CZML for CesiumJS: one packet per object with its
+// sampled Earth-fixed positions, interpolated by Cesium between samples.
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Geometry {
+    /// One or more lines, such as a track broken at the antimeridian.
+    Lines(Vec<Vec<[f64; 2]>>),
+    /// A closed ring, first point repeated last.
+    Polygon(Vec<[f64; 2]>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Feature {
+    pub name: String,
+    pub properties: Vec<(&'static str, String)>,
+    pub geometry: Geometry,
+}
+
+/// Earth-fixed positions of one object over time.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ephemeris {
+    pub name: String,
+    pub times: Vec<f64>,          // days since J2000 UTC, increasing
+    pub positions: Vec<[f64; 3]>, // m
+}
+
+fn json_string(text: &str) -> String {
+    let mut quoted = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            c if c.is_control() => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn xml_text(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn json_line(points: &[[f64; 2]]) -> String {
+    let coordinates: Vec<String> = points
+        .iter()
+        .map(|[lon, lat]| format!("[{lon:.5}, {lat:.5}]"))
+        .collect();
+    format!("[{}]", coordinates.join(", "))
+}
+
+fn kml_coordinates(points: &[[f64; 2]]) -> String {
+    let coordinates: Vec<String> = points
+        .iter()
+        .map(|[lon, lat]| format!("{lon:.5},{lat:.5}"))
+        .collect();
+    coordinates.join(" ")
+}
+
+pub fn write_geojson(out: &mut impl Write, features: &[Feature]) -> io::Result<()> {
+    writeln!(out, "{{")?;
+    writeln!(out, "  \"type\": \"FeatureCollection\",")?;
+    writeln!(out, "  \"features\": [")?;
+    for (i, feature) in features.iter().enumerate() {
+        let mut properties = vec![format!("\"name\": {}", json_string(&feature.name))];
+        properties.extend(
+            feature
+                .properties
+                .iter()
+                .map(|(key, value)| format!("{}: {}", json_string(key), json_string(value))),
+        );
+        let geometry = match &feature.geometry {
+            Geometry::Lines(lines) => {
+                let lines: Vec<String> = lines.iter().map(|line| json_line(line)).collect();
+                format!(
+                    "{{\"type\": \"MultiLineString\", \"coordinates\": [{}]}}",
+                    lines.join(", ")
+                )
+            }
+            Geometry::Polygon(ring) => format!(
+                "{{\"type\": \"Polygon\", \"coordinates\": [{}]}}",
+                json_line(ring)
+            ),
+        };
+        writeln!(out, "    {{")?;
+        writeln!(out, "      \"type\": \"Feature\",")?;
+        writeln!(out, "      \"properties\": {{{}}},", properties.join(", "))?;
+        writeln!(out, "      \"geometry\": {geometry}")?;
+        let comma = if i + 1 < features.len() { "," } else { "" };
+        writeln!(out, "    }}{comma}")?;
+    }
+    writeln!(out, "  ]")?;
+    writeln!(out, "}}")
+}
+
+pub fn write_kml(out: &mut impl Write, name: &str, features: &[Feature]) -> io::Result<()> {
+    writeln!(out, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+    writeln!(out, "<kml xmlns=\"http://www.opengis.net/kml/2.2\">")?;
+    writeln!(out, "<Document>")?;
+    writeln!(out, "  <name>{}</name>", xml_text(name))?;
+    // tracks draw as lines, footprints as translucent fills (aabbggrr)
+    writeln!(out, "  <Style id=\"track\"><LineStyle><color>ff00a5ff</color><width>2</width></LineStyle></Style>")?;
+    writeln!(out, "  <Style id=\"footprint\"><LineStyle><color>ffffaa00</color></LineStyle><PolyStyle><color>40ffaa00</color></PolyStyle></Style>")?;
+    for feature in features {
+        writeln!(out, "  <Placemark>")?;
+        writeln!(out, "    <name>{}</name>", xml_text(&feature.name))?;
+        if !feature.properties.is_empty() {
+            writeln!(out, "    <ExtendedData>")?;
+            for (key, value) in &feature.properties {
+                writeln!(
+                    out,
+                    "      <Data name=\"{}\"><value>{}</value></Data>",
+                    xml_text(key),
+                    xml_text(value)
+                )?;
+            }
+            writeln!(out, "    </ExtendedData>")?;
+        }
+        match &feature.geometry {
+            Geometry::Lines(lines) => {
+                writeln!(out, "    <styleUrl>#track</styleUrl>")?;
+                writeln!(out, "    <MultiGeometry>")?;
+                for line in lines {
+                    writeln!(
+                        out,
+                        "      <LineString><tessellate>1</tessellate><coordinates>{}</coordinates></LineString>",
+                        kml_coordinates(line)
+                    )?;
+                }
+                writeln!(out, "    </MultiGeometry>")?;
+            }
+            Geometry::Polygon(ring) => {
+                writeln!(out, "    <styleUrl>#footprint</styleUrl>")?;
+                writeln!(
+                    out,
+                    "    <Polygon><tessellate>1</tessellate><outerBoundaryIs><LinearRing><coordinates>{}</coordinates></LinearRing></outerBoundaryIs></Polygon>",
+                    kml_coordinates(ring)
+                )?;
+            }
+        }
+        writeln!(out, "  </Placemark>")?;
+    }
+    writeln!(out, "</Document>")?;
+    writeln!(out, "</kml>")
+}
+
+/// Write a CZML document whose clock spans every object, with a point,
+/// label and trailing path for each object in the palette colours.
+pub fn write_czml(out: &mut impl Write, name: &str, objects: &[Ephemeris]) -> io::Result<()> {
+    let times = objects
+        .iter()
+        .flat_map(|object| object.times.iter().copied());
+    let start = times.clone().fold(f64::INFINITY, f64::min);
+    let end = times.fold(f64::NEG_INFINITY, f64::max);
+    let interval = |from: f64, to: f64| {
+        json_string(&format!(
+            "{}/{}",
+            time::format_utc(from),
+            time::format_utc(to)
+        ))
+    };
+
+    writeln!(out, "[")?;
+    write!(
+        out,
+        "  {{\"id\": \"document\", \"name\": {}, \"version\": \"1.0\"",
+        json_string(name)
+    )?;
+    if start <= end {
+        write!(
+            out,
+            ", \"clock\": {{\"interval\": {}, \"currentTime\": {}, \"multiplier\": 60, \"range\": \"LOOP_STOP\", \"step\": \"SYSTEM_CLOCK_MULTIPLIER\"}}",
+            interval(start, end),
+            json_string(&time::format_utc(start))
+        )?;
+    }
+    write!(out, "}}")?;
+
+    for (i, object) in objects.iter().enumerate() {
+        let (Some(&first), Some(&last)) = (object.times.first(), object.times.last()) else {
+            continue;
+        };
+        let [r, g, b] = PALETTE[i % PALETTE.len()].map(|c| (c * 255.0).round() as u8);
+        let color = format!("{{\"rgba\": [{r}, {g}, {b}, 255]}}");
+        let samples: Vec<String> = object
+            .times
+            .iter()
+            .zip(&object.positions)
+            .map(|(t, [x, y, z])| format!("{:.3}, {x:.3}, {y:.3}, {z:.3}", (t - first) * 86_400.0))
+            .collect();
+
+        writeln!(out, ",")?;
+        writeln!(out, "  {{")?;
+        writeln!(out, "    \"id\": \"object-{i}\",")?;
+        writeln!(out, "    \"name\": {},", json_string(&object.name))?;
+        writeln!(out, "    \"availability\": {},", interval(first, last))?;
+        writeln!(
+            out,
+            "    \"point\": {{\"pixelSize\": 6, \"color\": {color}, \"outlineColor\": {{\"rgba\": [255, 255, 255, 255]}}, \"outlineWidth\": 1}},"
+        )?;
+        writeln!(
+            out,
+            "    \"label\": {{\"text\": {}, \"font\": \"11pt sans-serif\", \"fillColor\": {color}, \"horizontalOrigin\": \"LEFT\", \"pixelOffset\": {{\"cartesian2\": [8, 0]}}}},",
+            json_string(&object.name)
+        )?;
+        writeln!(
+            out,
+            "    \"path\": {{\"width\": 1, \"leadTime\": 0, \"trailTime\": {:.0}, \"resolution\": 60, \"material\": {{\"solidColor\": {{\"color\": {color}}}}}}},",
+            (last - first) * 86_400.0
+        )?;
+        writeln!(
+            out,
+            "    \"position\": {{\"epoch\": {}, \"referenceFrame\": \"FIXED\", \"interpolationAlgorithm\": \"LAGRANGE\", \"interpolationDegree\": 5, \"cartesian\": [{}]}}",
+            json_string(&time::format_utc(first)),
+            samples.join(", ")
+        )?;
+        write!(out, "  }}")?;
+    }
+    writeln!(out)?;
+    writeln!(out, "]")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn features() -> Vec<Feature> {
+        vec![
+            Feature {
+                name: "Sat \"A\" & B".to_string(),
+                properties: vec![("start", "2025-01-01T00:00:00Z".to_string())],
+                geometry: Geometry::Lines(vec![vec![[170.0, 0.0], [180.0, 5.0]]]),
+            },
+            Feature {
+                name: "Footprint".to_string(),
+                properties: vec![],
+                geometry: Geometry::Polygon(vec![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [0.0, 0.0]]),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_geojson() {
+        let mut out = vec![];
+        write_geojson(&mut out, &features()).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.contains(
+            "\"properties\": {\"name\": \"Sat \\\"A\\\" & B\", \"start\": \"2025-01-01T00:00:00Z\"}"
+        ));
+        assert!(text.contains("\"coordinates\": [[[170.00000, 0.00000], [180.00000, 5.00000]]]"));
+        assert!(text.contains("{\"type\": \"Polygon\", \"coordinates\": [[[0.00000, 0.00000]"));
+        assert!(text.trim_end().ends_with("}\n  ]\n}"));
+    }
+
+    #[test]
+    fn test_kml() {
+        let mut out = vec![];
+        write_kml(&mut out, "Tracks", &features()).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.contains("<name>Sat &quot;A&quot; &amp; B</name>"));
+        assert!(text.contains("<coordinates>170.00000,0.00000 180.00000,5.00000</coordinates>"));
+        assert!(text.contains("<Data name=\"start\"><value>2025-01-01T00:00:00Z</value></Data>"));
+        assert_eq!(text.matches("<Placemark>").count(), 2);
+    }
+
+    #[test]
+    fn test_czml() {
+        let object = Ephemeris {
+            name: "Sat".to_string(),
+            times: vec![0.0, 60.0 / 86_400.0],
+            positions: vec![[7.0e6, 0.0, 0.0], [0.0, 7.0e6, 0.0]],
+        };
+        let mut out = vec![];
+        write_czml(&mut out, "Orbits", &[object]).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text.starts_with("[\n  {\"id\": \"document\", \"name\": \"Orbits\""));
+        assert!(text.contains("\"interval\": \"2000-01-01T12:00:00Z/2000-01-01T12:01:00Z\""));
+        assert!(text.contains(
+            "\"cartesian\": [0.000, 7000000.000, 0.000, 0.000, 60.000, 0.000, 7000000.000, 0.000]"
+        ));
+        assert!(text.ends_with("  }\n]\n"));
+    }
+}
@@ -0,0 +1,273 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use crate::tle;
+
+// Current element sets from CelesTrak's GP service, by catalog number or by
+// group (such as "stations" or "starlink"). CelesTrak refreshes most sets a
+// few times a day and asks clients not to download the same data more often
+// than every two hours, so each download is kept in a cache directory, one
+// file per query, and reused until it expires. Objects that manoeuvre often
+// expire after those two hours; quieter groups such as navigation and
+// geostationary satellites keep for a day. When a download fails the last
+// copy is used however old it is, and its age is reported. Space-Track
+// serves the same sets but needs an account, so it is not queried.
+
+/// Shortest time CelesTrak asks clients to wait before downloading again.
+pub const SHORT_TTL: Duration = Duration::from_secs(2 * 60 * 60);
+/// Expiry of groups whose orbits change slowly.
+pub const LONG_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Groups that keep for `LONG_TTL`.
+const QUIET_GROUPS: [&str; 8] = [
+    "gps-ops", "glo-ops", "galileo", "beidou", "gnss", "geo", "intelsat", "ses",
+];
+
+const URL: &str = "https://celestrak.org/NORAD/elements/gp.php";
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// What to download.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Query {
+    /// One object by NORAD catalog number
+    Catalog(String),
+    /// A CelesTrak group of objects
+    Group(String),
+}
+
+impl Query {
+    pub fn url(&self) -> String {
+        match self {
+            Query::Catalog(number) => format!("{URL}?CATNR={number}&FORMAT=tle"),
+            Query::Group(group) => format!("{URL}?GROUP={group}&FORMAT=tle"),
+        }
+    }
+
+    /// Name of the cached download.
+    pub fn file_name(&self) -> String {
+        match self {
+            Query::Catalog(number) => format!("catnr-{number}.tle"),
+            Query::Group(group) => format!("group-{group}.tle"),
+        }
+    }
+
+    /// The query a cached download was made for.
+    pub fn from_file_name(name: &str) -> Option<Query> {
+        let stem = name.strip_suffix(".tle")?;
+        match stem.split_once('-')? {
+            ("catnr", number) => parse_catalog(number).ok(),
+            ("group", group) => parse_group(group).ok(),
+            _ => None,
+        }
+    }
+
+    /// Age after which a cached download is fetched again.
+    pub fn ttl(&self) -> Duration {
+        match self {
+            Query::Group(group) if QUIET_GROUPS.contains(&group.as_str()) => LONG_TTL,
+            _ => SHORT_TTL,
+        }
+    }
+}
+
+/// Element sets as text, and the age of the cached copy they came from when
+/// the download failed.
+pub struct Download {
+    pub text: String,
+    pub stale: Option<Duration>,
+}
+
+/// One cached download.
+pub struct Entry {
+    pub query: Query,
+    pub age: Duration,
+    pub sets: usize,
+    pub expired: bool,
+}
+
+/// Catalog number, including Alpha-5 numbers such as A0001.
+pub fn parse_catalog(input: &str) -> Result<Query, String> {
+    let number = input.trim();
+    if number.is_empty() || number.len() > 5 || !number.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(format!("invalid catalog number '{input}'"));
+    }
+    Ok(Query::Catalog(number.to_ascii_uppercase()))
+}
+
+/// CelesTrak group name, such as stations, starlink or gps-ops.
+pub fn parse_group(input: &str) -> Result<Query, String> {
+    let group = input.trim().to_ascii_lowercase();
+    if group.is_empty() || !group.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return Err(format!("invalid group '{input}'"));
+    }
+    Ok(Query::Group(group))
+}
+
+/// `$XDG_CACHE_HOME/orbit/tle`, falling back to `~/.cache`.
+pub fn cache_dir() -> Option<PathBuf> {
+    let base = env::var_os("XDG_CACHE_HOME")
+        .filter(|value| !value.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))?;
+    Some(base.join("orbit").join("tle"))
+}
+
+fn age(path: &Path) -> Option<Duration> {
+    let modified = fs::metadata(path).and_then(|meta| meta.modified()).ok()?;
+    Some(
+        SystemTime::now()
+            .duration_since(modified)
+            .unwrap_or_default(),
+    )
+}
+
+/// Cached text for `query` in `dir` and its age.
+fn cached(dir: &Path, query: &Query) -> Option<(String, Duration)> {
+    let path = dir.join(query.file_name());
+    let age = age(&path)?;
+    Some((fs::read_to_string(path).ok()?, age))
+}
+
+fn download(query: &Query) -> Result<String, String> {
+    let text = ureq::get(&query.url())
+        .timeout(TIMEOUT)
+        .call()
+        .map_err(|err| err.to_string())?
+        .into_string()
+        .map_err(|err| err.to_string())?;
+    // CelesTrak answers unknown objects with a message rather than an error
+    tle::parse(&text).map_err(|_| format!("no element sets for {}", query.url()))?;
+    Ok(text)
+}
+
+/// Element sets for `query`, from the cache until it expires, older than
+/// `max_age` if given, and otherwise downloaded and cached. A failed
+/// download falls back on an expired copy. Failing to write the cache is
+/// not an error.
+pub fn fetch(query: &Query, max_age: Option<Duration>) -> Result<Download, String> {
+    let dir = cache_dir();
+    let copy = dir.as_deref().and_then(|dir| cached(dir, query));
+    if let Some((text, age)) = &copy {
+        if *age < max_age.unwrap_or_else(|| query.ttl()) {
+            return Ok(Download {
+                text: text.clone(),
+                stale: None,
+            });
+        }
+    }
+    match download(query) {
+        Ok(text) => {
+            if let Some(dir) = dir {
+                let _ = fs::create_dir_all(&dir)
+                    .and_then(|_| fs::write(dir.join(query.file_name()), &text));
+            }
+            Ok(Download { text, stale: None })
+        }
+        Err(err) => match copy {
+            Some((text, age)) => Ok(Download {
+                text,
+                stale: Some(age),
+            }),
+            None => Err(err),
+        },
+    }
+}
+
+/// Every cached download in `dir`, by file name.
+pub fn entries(dir: &Path) -> Vec<Entry> {
+    let Ok(files) = fs::read_dir(dir) else {
+        return vec![];
+    };
+    let mut entries: Vec<Entry> = files
+        .filter_map(|file| {
+            let path = file.ok()?.path();
+            let query = Query::from_file_name(path.file_name()?.to_str()?)?;
+            let age = age(&path)?;
+            let sets = fs::read_to_string(&path)
+                .ok()
+                .and_then(|text| tle::parse(&text).ok())
+                .map_or(0, |sets| sets.len());
+            Some(Entry {
+                expired: age >= query.ttl(),
+                query,
+                age,
+                sets,
+            })
+        })
+        .collect();
+    entries.sort_by_key(|entry| entry.query.file_name());
+    entries
+}
+
+/// Remove the cached download for `query`, or every one, from `dir`, and
+/// return how many were removed.
+pub fn clear(dir: &Path, query: Option<&Query>) -> io::Result<usize> {
+    let mut removed = 0;
+    for entry in entries(dir) {
+        if query.is_none_or(|query| *query == entry.query) {
+            fs::remove_file(dir.join(entry.query.file_name()))?;
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queries() {
+        let iss = parse_catalog("25544").unwrap();
+        assert_eq!(
+            iss.url(),
+            "https://celestrak.org/NORAD/elements/gp.php?CATNR=25544&FORMAT=tle"
+        );
+        assert_eq!(iss.file_name(), "catnr-25544.tle");
+        assert_eq!(
+            parse_catalog("a0001").unwrap(),
+            Query::Catalog("A0001".into())
+        );
+        assert!(parse_catalog("255440").is_err());
+        assert!(parse_catalog("1&X=2").is_err());
+
+        let starlink = parse_group("Starlink").unwrap();
+        assert_eq!(starlink.file_name(), "group-starlink.tle");
+        assert!(parse_group("../etc").is_err());
+
+        assert_eq!(Query::from_file_name("group-starlink.tle"), Some(starlink));
+        assert_eq!(Query::from_file_name("notes.txt"), None);
+        assert_eq!(iss.ttl(), SHORT_TTL);
+        assert_eq!(parse_group("gps-ops").unwrap().ttl(), LONG_TTL);
+    }
+
+    #[test]
+    fn test_list_and_clear_cache() {
+        let dir = env::temp_dir().join(format!("orbit-cache-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let set = "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927
+2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537
+";
+        fs::write(dir.join("catnr-25544.tle"), set).unwrap();
+        fs::write(dir.join("group-stations.tle"), set.repeat(2)).unwrap();
+        fs::write(dir.join("unrelated.txt"), "").unwrap();
+
+        let listed = entries(&dir);
+        assert_eq!(listed.len(), 2);
+        assert_eq!((listed[0].sets, listed[1].sets), (1, 2));
+        assert!(!listed[0].expired);
+        let (text, _) = cached(&dir, &listed[0].query).unwrap();
+        assert_eq!(text, set);
+
+        let stations = parse_group("stations").unwrap();
+        assert_eq!(clear(&dir, Some(&stations)).unwrap(), 1);
+        assert_eq!(clear(&dir, None).unwrap(), 1);
+        assert!(entries(&dir).is_empty());
+        // files the cache did not write are left alone
+        assert!(dir.join("unrelated.txt").exists());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,192 @@
+use clap::ValueEnum;
+
+// Numbers in reports. Each quantity has a default number of decimals, about
+// what its model justifies, and --precision replaces them all. Scientific
+// and engineering notation write a power of ten; without a precision they
+// keep the resolution of the fixed default, so 6778.137 km becomes
+// 6.778137e3 rather than 6.778e3. Engineering notation keeps the exponent a
+// multiple of three, to read alongside SI prefixes. Values echoed from the
+// command line drop trailing zeros in fixed notation, and physical constants
+// stay in scientific notation unless engineering is asked for. CSV output
+// keeps its fixed columns either way.
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Notation {
+    /// Plain decimals, such as 42164.137
+    #[default]
+    Fixed,
+    /// One digit before the point and a power of ten, such as 4.2164137e4
+    Scientific,
+    /// Powers of ten in multiples of three, such as 42.164137e3
+    Engineering,
+}
+
+/// How numbers in a report are written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Style {
+    pub notation: Notation,
+    /// Decimals of every number, instead of each quantity's default
+    pub precision: Option<usize>,
+}
+
+impl Style {
+    /// `value` with `decimals` places in fixed notation, or as set by the
+    /// style.
+    pub fn number(&self, value: f64, decimals: usize) -> String {
+        let group = match self.notation {
+            Notation::Fixed => return format!("{value:.*}", self.precision.unwrap_or(decimals)),
+            Notation::Scientific => 1,
+            Notation::Engineering => 3,
+        };
+        self.powered(value, decimals as i32, group)
+    }
+
+    /// `value` to at most `decimals` places without trailing zeros in fixed
+    /// notation, such as an altitude given on the command line, or as set by
+    /// the style.
+    pub fn brief(&self, value: f64, decimals: usize) -> String {
+        if self.notation != Notation::Fixed || self.precision.is_some() {
+            return self.number(value, decimals);
+        }
+        let scale = 10f64.powi(decimals as i32);
+        format!("{}", (value * scale).round() / scale)
+    }
+
+    /// `value` in scientific notation with `decimals` places after the
+    /// point, such as a density or a relative error that spans many powers
+    /// of ten, or in engineering notation if the style asks for it.
+    pub fn scientific(&self, value: f64, decimals: usize) -> String {
+        match self.notation {
+            Notation::Engineering => self.powered(value, decimals as i32 - exponent(value, 1), 3),
+            _ => format!("{value:.*e}", self.precision.unwrap_or(decimals)),
+        }
+    }
+
+    /// `value` with every digit it is given to, such as a physical constant,
+    /// in scientific notation unless the style asks for engineering.
+    pub fn exact(&self, value: f64) -> String {
+        let shortest = format!("{value:e}");
+        match (self.notation, self.precision) {
+            (Notation::Fixed | Notation::Scientific, None) => shortest,
+            (Notation::Fixed | Notation::Scientific, Some(precision)) => {
+                self.scientific(value, precision)
+            }
+            (Notation::Engineering, _) => {
+                // the places after the point of the shortest form fix the
+                // resolution, which can be coarser than a unit
+                let (mantissa, exponent) = shortest.split_once('e').expect("`e` formatting");
+                let places = mantissa
+                    .split_once('.')
+                    .map_or(0, |(_, digits)| digits.len());
+                let exponent: i32 = exponent.parse().expect("`e` formatting");
+                self.powered(value, places as i32 - exponent, 3)
+            }
+        }
+    }
+
+    /// `value` to `decimals` places, which can be negative, as a mantissa
+    /// and a power of ten in multiples of `group`.
+    fn powered(&self, value: f64, decimals: i32, group: i32) -> String {
+        if !value.is_finite() {
+            return value.to_string();
+        }
+        let write = |exponent: i32| {
+            let decimals = self
+                .precision
+                .unwrap_or((decimals + exponent).max(0) as usize);
+            (
+                format!("{:.decimals$}", value / 10f64.powi(exponent)),
+                exponent,
+            )
+        };
+        let (mut mantissa, mut exponent) = write(exponent(value, group));
+        // rounding can carry into the next power, as 9.9996 to 10.000
+        let carried = mantissa
+            .trim_start_matches('-')
+            .parse::<f64>()
+            .is_ok_and(|digits| digits >= 10f64.powi(group));
+        if carried {
+            (mantissa, exponent) = write(exponent + group);
+        }
+        format!("{mantissa}e{exponent}")
+    }
+}
+
+/// Power of ten, a multiple of `group`, that leaves `value` with between one
+/// and `group` digits before the point.
+fn exponent(value: f64, group: i32) -> i32 {
+    if value == 0.0 {
+        return 0;
+    }
+    (value.abs().log10().floor() as i32).div_euclid(group) * group
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn style(notation: Notation, precision: Option<usize>) -> Style {
+        Style {
+            notation,
+            precision,
+        }
+    }
+
+    #[test]
+    fn test_fixed() {
+        let default = Style::default();
+        assert_eq!(default.number(42_164.137_2, 3), "42164.137");
+        assert_eq!(default.number(5553.6, 0), "5554");
+        assert_eq!(style(Notation::Fixed, Some(1)).number(92.6812, 2), "92.7");
+    }
+
+    #[test]
+    fn test_scientific_and_engineering() {
+        let scientific = style(Notation::Scientific, None);
+        assert_eq!(scientific.number(42_164.137, 3), "4.2164137e4");
+        assert_eq!(scientific.number(-0.00123, 5), "-1.23e-3");
+        assert_eq!(scientific.number(0.0, 2), "0.00e0");
+        assert_eq!(
+            style(Notation::Scientific, Some(2)).number(9.996, 0),
+            "1.00e1"
+        );
+
+        let engineering = style(Notation::Engineering, None);
+        assert_eq!(engineering.number(42_164.137, 3), "42.164137e3");
+        assert_eq!(engineering.number(7.6686, 4), "7.6686e0");
+        assert_eq!(engineering.number(0.00123, 5), "1.23e-3");
+        let engineering = style(Notation::Engineering, Some(1));
+        assert_eq!(engineering.number(999.96, 0), "1.0e3");
+        assert_eq!(engineering.number(f64::INFINITY, 0), "inf");
+    }
+
+    #[test]
+    fn test_brief_and_exact() {
+        let default = Style::default();
+        assert_eq!(default.brief(400.0, 3), "400");
+        assert_eq!(default.brief(51.64, 3), "51.64");
+        assert_eq!(style(Notation::Fixed, Some(2)).brief(400.0, 3), "400.00");
+        assert_eq!(style(Notation::Scientific, None).brief(400.0, 0), "4.00e2");
+
+        assert_eq!(default.exact(6.6743e-11), "6.6743e-11");
+        assert_eq!(default.exact(-2.5e3), "-2.5e3");
+        assert_eq!(
+            style(Notation::Scientific, Some(2)).exact(3.986004418e14),
+            "3.99e14"
+        );
+        let engineering = style(Notation::Engineering, None);
+        assert_eq!(engineering.exact(6.6743e-11), "66.743e-12");
+        assert_eq!(engineering.exact(6.3781e6), "6.3781e6");
+        assert_eq!(engineering.exact(5.9722e24), "5.9722e24");
+        assert_eq!(engineering.exact(1.989e30), "1.989e30");
+        assert_eq!(engineering.exact(2.5e7), "25e6");
+
+        assert_eq!(default.scientific(1.234e-12, 3), "1.234e-12");
+        assert_eq!(
+            style(Notation::Fixed, Some(1)).scientific(1.234e-12, 3),
+            "1.2e-12"
+        );
+        assert_eq!(engineering.scientific(1.234e-12, 3), "1.234e-12");
+        assert_eq!(engineering.scientific(3.2e-5, 1), "32e-6");
+    }
+}
@@ -0,0 +1,301 @@
+use crate::{time, EARTH_ROTATION_RATE};
+
+// Reference frames for Earth orbits:
+//
+// - eci: the J2000 mean equator and equinox (EME2000), the usual inertial
+//   frame for state vectors.
+// - teme: the True Equator, Mean Equinox frame of SGP4 element sets.
+// - ecef: Earth-fixed, rotating with Greenwich mean sidereal time.
+// - geodetic: latitude and longitude on the WGS-84 ellipsoid, with the
+//   height above it.
+//
+// J2000 reaches TEME through IAU 1976 precession and the four largest terms
+// of the IAU 1980 nutation (Meeus, ch. 22), good to about 0.5 arcseconds or
+// 15 m at GEO. Polar motion (under 15 m at the surface) is ignored, and UTC
+// stands in for UT1 as described in `time`.
+
+/// WGS-84 equatorial radius in m and flattening.
+pub const WGS84_RADIUS: f64 = 6_378_137.0;
+pub const WGS84_FLATTENING: f64 = crate::EARTH_FLATTENING;
+
+const ARCSECOND: f64 = std::f64::consts::PI / 648_000.0; // rad
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Frame {
+    /// J2000 inertial
+    #[value(alias = "j2000")]
+    Eci,
+    /// True Equator, Mean Equinox, as used by SGP4
+    Teme,
+    /// Earth-centred, Earth-fixed
+    Ecef,
+    /// Latitude, longitude and height on WGS-84
+    Geodetic,
+}
+
+impl Frame {
+    /// Whether converting to or from Earth-fixed needs an epoch.
+    pub fn is_inertial(self) -> bool {
+        matches!(self, Frame::Eci | Frame::Teme)
+    }
+}
+
+/// Geodetic position on the WGS-84 ellipsoid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Geodetic {
+    pub latitude: f64,  // deg
+    pub longitude: f64, // deg, east positive, -180 to 180
+    pub altitude: f64,  // m above the ellipsoid
+}
+
+type Matrix = [[f64; 3]; 3];
+
+/// Rotation of the coordinate axes by `angle` (rad) about x.
+fn rotation_x(angle: f64) -> Matrix {
+    let (sin, cos) = angle.sin_cos();
+    [[1.0, 0.0, 0.0], [0.0, cos, sin], [0.0, -sin, cos]]
+}
+
+/// Rotation of the coordinate axes by `angle` (rad) about y.
+fn rotation_y(angle: f64) -> Matrix {
+    let (sin, cos) = angle.sin_cos();
+    [[cos, 0.0, -sin], [0.0, 1.0, 0.0], [sin, 0.0, cos]]
+}
+
+/// Rotation of the coordinate axes by `angle` (rad) about z.
+fn rotation_z(angle: f64) -> Matrix {
+    let (sin, cos) = angle.sin_cos();
+    [[cos, sin, 0.0], [-sin, cos, 0.0], [0.0, 0.0, 1.0]]
+}
+
+fn multiply(a: Matrix, b: Matrix) -> Matrix {
+    let mut product = [[0.0; 3]; 3];
+    for (i, row) in product.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = (0..3).map(|k| a[i][k] * b[k][j]).sum();
+        }
+    }
+    product
+}
+
+fn apply(m: Matrix, v: [f64; 3]) -> [f64; 3] {
+    m.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+}
+
+fn transpose(m: Matrix) -> Matrix {
+    [0, 1, 2].map(|i| [m[0][i], m[1][i], m[2][i]])
+}
+
+/// Rotation from J2000 to TEME at `days` since J2000 UTC.
+fn j2000_to_teme_matrix(days: f64) -> Matrix {
+    let tt = time::convert(days, time::Scale::Utc, time::Scale::Tt);
+    let t = tt / 36_525.0; // Julian centuries
+
+    let zeta = (2306.2181 * t + 0.30188 * t.powi(2) + 0.017998 * t.powi(3)) * ARCSECOND;
+    let theta = (2004.3109 * t - 0.42665 * t.powi(2) - 0.041833 * t.powi(3)) * ARCSECOND;
+    let z = (2306.2181 * t + 1.09468 * t.powi(2) + 0.018203 * t.powi(3)) * ARCSECOND;
+    let precession = multiply(
+        rotation_z(-z),
+        multiply(rotation_y(theta), rotation_z(-zeta)),
+    );
+
+    // longitudes of the Moon's node, the Sun and the Moon
+    let node = (125.044_52 - 1_934.136_261 * t).to_radians();
+    let sun = (280.4665 + 36_000.769_8 * t).to_radians();
+    let moon = (218.3165 + 481_267.881_3 * t).to_radians();
+    let dpsi = (-17.20 * node.sin() - 1.32 * (2.0 * sun).sin() - 0.23 * (2.0 * moon).sin()
+        + 0.21 * (2.0 * node).sin())
+        * ARCSECOND;
+    let deps = (9.20 * node.cos() + 0.57 * (2.0 * sun).cos() + 0.10 * (2.0 * moon).cos()
+        - 0.09 * (2.0 * node).cos())
+        * ARCSECOND;
+    let mean_obliquity =
+        (84_381.448 - 46.8150 * t - 0.000_59 * t.powi(2) + 0.001_813 * t.powi(3)) * ARCSECOND;
+    let obliquity = mean_obliquity + deps;
+    let nutation = multiply(
+        rotation_x(-obliquity),
+        multiply(rotation_z(-dpsi), rotation_x(mean_obliquity)),
+    );
+
+    // TEME sits off the true equinox by the equation of the equinoxes
+    let equinoxes = rotation_z(dpsi * mean_obliquity.cos());
+    multiply(equinoxes, multiply(nutation, precession))
+}
+
+/// TEME position of a J2000 position at `days` since J2000 UTC.
+pub fn j2000_to_teme(position: [f64; 3], days: f64) -> [f64; 3] {
+    apply(j2000_to_teme_matrix(days), position)
+}
+
+/// J2000 position of a TEME position at `days` since J2000 UTC.
+pub fn teme_to_j2000(position: [f64; 3], days: f64) -> [f64; 3] {
+    apply(transpose(j2000_to_teme_matrix(days)), position)
+}
+
+/// Earth-fixed position of a TEME position at `days` since J2000 UTC.
+pub fn teme_to_ecef(position: [f64; 3], days: f64) -> [f64; 3] {
+    apply(rotation_z(time::gmst(days)), position)
+}
+
+/// TEME position of an Earth-fixed position at `days` since J2000 UTC.
+pub fn ecef_to_teme(position: [f64; 3], days: f64) -> [f64; 3] {
+    apply(rotation_z(-time::gmst(days)), position)
+}
+
+/// Earth-fixed velocity in m/s of a TEME state, removing the frame rotation.
+pub fn teme_to_ecef_velocity(position: [f64; 3], velocity: [f64; 3], days: f64) -> [f64; 3] {
+    let [px, py, _] = teme_to_ecef(position, days);
+    let [vx, vy, vz] = teme_to_ecef(velocity, days);
+    [
+        vx + EARTH_ROTATION_RATE * py,
+        vy - EARTH_ROTATION_RATE * px,
+        vz,
+    ]
+}
+
+/// Earth-fixed position in m of a geodetic point.
+pub fn ecef(point: &Geodetic) -> [f64; 3] {
+    let e2 = WGS84_FLATTENING * (2.0 - WGS84_FLATTENING);
+    let (sin_lat, cos_lat) = point.latitude.to_radians().sin_cos();
+    let (sin_lon, cos_lon) = point.longitude.to_radians().sin_cos();
+    let n = WGS84_RADIUS / (1.0 - e2 * sin_lat.powi(2)).sqrt();
+    [
+        (n + point.altitude) * cos_lat * cos_lon,
+        (n + point.altitude) * cos_lat * sin_lon,
+        (n * (1.0 - e2) + point.altitude) * sin_lat,
+    ]
+}
+
+/// Geodetic latitude, longitude and altitude of an Earth-fixed position in
+/// m, by fixed-point iteration on the latitude.
+pub fn geodetic([x, y, z]: [f64; 3]) -> Geodetic {
+    let e2 = WGS84_FLATTENING * (2.0 - WGS84_FLATTENING);
+    let p = x.hypot(y);
+    let mut latitude = z.atan2(p * (1.0 - e2));
+    let mut altitude = 0.0;
+    for _ in 0..10 {
+        let sin = latitude.sin();
+        let n = WGS84_RADIUS / (1.0 - e2 * sin.powi(2)).sqrt();
+        altitude = if latitude.cos().abs() > 1e-10 {
+            p / latitude.cos() - n
+        } else {
+            z.abs() - n * (1.0 - e2)
+        };
+        latitude = z.atan2(p * (1.0 - e2 * n / (n + altitude)));
+    }
+    Geodetic {
+        latitude: latitude.to_degrees(),
+        longitude: y.atan2(x).to_degrees(),
+        altitude,
+    }
+}
+
+/// Convert a position between frames. Cartesian positions are in m, and
+/// geodetic ones are [latitude, longitude, altitude] in degrees and m.
+/// `epoch` (days since J2000 UTC) is needed unless both frames are Earth-fixed.
+pub fn convert(
+    position: [f64; 3],
+    from: Frame,
+    to: Frame,
+    epoch: Option<f64>,
+) -> Result<[f64; 3], String> {
+    if from == to {
+        return Ok(position);
+    }
+    let cartesian = match from {
+        Frame::Geodetic => ecef(&Geodetic {
+            latitude: position[0],
+            longitude: position[1],
+            altitude: position[2],
+        }),
+        _ => position,
+    };
+    let fixed = if from.is_inertial() || to.is_inertial() {
+        let days = epoch.ok_or_else(|| {
+            "an epoch is needed to convert to or from an inertial frame".to_string()
+        })?;
+        let teme = match from {
+            Frame::Eci => j2000_to_teme(cartesian, days),
+            Frame::Teme => cartesian,
+            Frame::Ecef | Frame::Geodetic => ecef_to_teme(cartesian, days),
+        };
+        match to {
+            Frame::Eci => return Ok(teme_to_j2000(teme, days)),
+            Frame::Teme => return Ok(teme),
+            Frame::Ecef | Frame::Geodetic => teme_to_ecef(teme, days),
+        }
+    } else {
+        cartesian
+    };
+    Ok(match to {
+        Frame::Geodetic => {
+            let point = geodetic(fixed);
+            [point.latitude, point.longitude, point.altitude]
+        }
+        _ => fixed,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn km(values: [f64; 3]) -> [f64; 3] {
+        values.map(|v| v * 1000.0)
+    }
+
+    fn distance(a: [f64; 3], b: [f64; 3]) -> f64 {
+        (0..3).map(|i| (a[i] - b[i]).powi(2)).sum::<f64>().sqrt()
+    }
+
+    #[test]
+    fn test_vallado_teme_example() {
+        // Vallado et al. (2006), "Revisiting Spacetrack Report #3", the
+        // example state at 2004-04-06 07:51:28.386009 UTC, UT1 - UTC
+        // -0.4399619 s
+        let days = time::parse_utc("2004-04-06T07:51:28.386009Z").unwrap();
+        let teme = km([5_094.180_162_10, 6_127.644_659_50, 6_380.344_532_70]);
+        let j2000 = km([5_102.508_957_90, 6_123.011_400_70, 6_378.136_928_20]);
+        assert!(distance(teme_to_j2000(teme, days), j2000) < 20.0);
+        assert!(distance(j2000_to_teme(j2000, days), teme) < 20.0);
+
+        // pseudo Earth-fixed, before polar motion
+        let ut1 = days - 0.439_961_9 / 86_400.0;
+        let fixed = km([-1_033.475_031_30, 7_901.305_585_60, 6_380.344_532_70]);
+        assert!(distance(teme_to_ecef(teme, ut1), fixed) < 1.0);
+        assert!(distance(ecef_to_teme(fixed, ut1), teme) < 1.0);
+    }
+
+    #[test]
+    fn test_geodetic() {
+        let pole = geodetic([0.0, 0.0, 6_356_752.314_2 + 1000.0]);
+        assert!((pole.latitude - 90.0).abs() < 1e-9);
+        assert!((pole.altitude - 1000.0).abs() < 1e-3);
+
+        // round trip through 45 deg N, 90 deg E, 500 m up
+        let point = geodetic(ecef(&Geodetic {
+            latitude: 45.0,
+            longitude: 90.0,
+            altitude: 500.0,
+        }));
+        assert!((point.latitude - 45.0).abs() < 1e-9);
+        assert!((point.longitude - 90.0).abs() < 1e-9);
+        assert!((point.altitude - 500.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_convert_round_trip() {
+        let epoch = Some(time::parse_utc("2025-06-01T12:00:00Z").unwrap());
+        let start = km([7_000.0, -1_200.0, 300.0]);
+        let frames = [Frame::Teme, Frame::Ecef, Frame::Geodetic, Frame::Eci];
+        let mut position = start;
+        let mut from = Frame::Eci;
+        for to in frames {
+            position = convert(position, from, to, epoch).unwrap();
+            from = to;
+        }
+        assert!(distance(position, start) < 1e-6);
+        assert!(convert(start, Frame::Eci, Frame::Ecef, None).is_err());
+        assert!(convert(start, Frame::Ecef, Frame::Geodetic, None).is_ok());
+    }
+}
@@ -0,0 +1,146 @@
+// Eccentric dipole approximation of the geomagnetic field, built from the
+// IGRF-13 2020 degree-1 and degree-2 Gauss coefficients (Fraser-Smith, 1987).
+// Shifting the dipole about 590 km towards the western Pacific leaves a
+// region of weak field over South America and the South Atlantic, which is
+// enough to locate the South Atlantic Anomaly and estimate L-shells for
+// trade studies. Positions are Earth-fixed, in metres.
+
+const REFERENCE_RADIUS: f64 = 6.3712e6; // m, IGRF
+
+// IGRF-13 2020 Gauss coefficients, nT
+const G10: f64 = -29_404.8;
+const G11: f64 = -1_450.9;
+const H11: f64 = 4_652.5;
+const G20: f64 = -2_499.6;
+const G21: f64 = 2_982.0;
+const H21: f64 = -2_991.6;
+const G22: f64 = 1_677.0;
+const H22: f64 = -734.6;
+
+/// Particles mirroring below this altitude are lost to the atmosphere.
+const LOSS_ALTITUDE: f64 = 100_000.0; // m
+
+/// Inside the anomaly when the field falls below this fraction of a centred
+/// dipole's equatorial field at the same radius.
+pub const SAA_THRESHOLD: f64 = 0.85;
+
+pub struct Dipole {
+    strength: f64,    // T at the reference radius on the magnetic equator
+    axis: [f64; 3],   // unit vector to the geomagnetic north pole
+    centre: [f64; 3], // m
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+impl Dipole {
+    pub fn igrf_2020() -> Self {
+        let b0_squared = G10 * G10 + G11 * G11 + H11 * H11;
+        let b0 = b0_squared.sqrt();
+        let root3 = 3f64.sqrt();
+
+        let l0 = 2.0 * G10 * G20 + root3 * (G11 * G21 + H11 * H21);
+        let l1 = -G11 * G20 + root3 * (G10 * G21 + G11 * G22 + H11 * H22);
+        let l2 = -H11 * G20 + root3 * (G10 * H21 - H11 * G22 + G11 * H22);
+        let e = (l0 * G10 + l1 * G11 + l2 * H11) / (4.0 * b0_squared);
+        let offset = |l: f64, g: f64| (l - g * e) / (3.0 * b0_squared) * REFERENCE_RADIUS;
+
+        Dipole {
+            strength: b0 * 1e-9,
+            axis: [-G11 / b0, -H11 / b0, -G10 / b0],
+            centre: [offset(l1, G11), offset(l2, H11), offset(l0, G10)],
+        }
+    }
+
+    /// Distance from the dipole centre and sine of the magnetic latitude.
+    fn dipole_coordinates(&self, position: [f64; 3]) -> (f64, f64) {
+        let relative = [0, 1, 2].map(|i| position[i] - self.centre[i]);
+        let r = dot(relative, relative).sqrt();
+        (r, dot(relative, self.axis) / r)
+    }
+
+    /// Field magnitude in T.
+    pub fn field(&self, position: [f64; 3]) -> f64 {
+        let (r, sin_latitude) = self.dipole_coordinates(position);
+        self.strength * (REFERENCE_RADIUS / r).powi(3) * (1.0 + 3.0 * sin_latitude.powi(2)).sqrt()
+    }
+
+    /// McIlwain L of the dipole field line through a position, in reference
+    /// radii.
+    pub fn l_shell(&self, position: [f64; 3]) -> f64 {
+        let (r, sin_latitude) = self.dipole_coordinates(position);
+        r / REFERENCE_RADIUS / (1.0 - sin_latitude.powi(2))
+    }
+
+    /// Whether stably trapped particles can reach a position. Particles
+    /// mirror where the field equals their mirror field and drift in
+    /// longitude around their shell, so they survive only if no point of
+    /// the shell at the loss altitude (on the anomaly side, furthest from
+    /// the dipole centre) has a weaker field than where they mirror.
+    pub fn trapped(&self, position: [f64; 3]) -> bool {
+        let l = self.l_shell(position);
+        let cutoff = REFERENCE_RADIUS + LOSS_ALTITUDE + dot(self.centre, self.centre).sqrt();
+        let cos_squared = cutoff / (l * REFERENCE_RADIUS);
+        if cos_squared >= 1.0 {
+            return false;
+        }
+        let loss_field =
+            self.strength * (REFERENCE_RADIUS / cutoff).powi(3) * (4.0 - 3.0 * cos_squared).sqrt();
+        self.field(position) < loss_field
+    }
+
+    pub fn in_saa(&self, position: [f64; 3]) -> bool {
+        let r = dot(position, position).sqrt();
+        self.field(position) < SAA_THRESHOLD * self.strength * (REFERENCE_RADIUS / r).powi(3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(latitude: f64, longitude: f64, altitude: f64) -> [f64; 3] {
+        let r = REFERENCE_RADIUS + altitude * 1000.0;
+        let (lat, lon) = (latitude.to_radians(), longitude.to_radians());
+        [
+            r * lat.cos() * lon.cos(),
+            r * lat.cos() * lon.sin(),
+            r * lat.sin(),
+        ]
+    }
+
+    #[test]
+    fn test_eccentric_dipole_geometry() {
+        let dipole = Dipole::igrf_2020();
+        let pole_latitude = dipole.axis[2].asin().to_degrees();
+        assert!((pole_latitude - 80.59).abs() < 0.01);
+        let offset = dot(dipole.centre, dipole.centre).sqrt();
+        assert!((offset / 1000.0 - 590.0).abs() < 5.0);
+        assert!(dipole.centre[2] > 0.0 && dipole.centre[1] > 0.0);
+    }
+
+    #[test]
+    fn test_saa_over_south_america() {
+        let dipole = Dipole::igrf_2020();
+        assert!(dipole.in_saa(at(-15.0, -45.0, 500.0)));
+        assert!(!dipole.in_saa(at(15.0, 135.0, 500.0)));
+        assert!(!dipole.in_saa(at(60.0, 0.0, 500.0)));
+    }
+
+    #[test]
+    fn test_inner_belt_reaches_leo_only_in_the_anomaly() {
+        let dipole = Dipole::igrf_2020();
+        assert!(dipole.trapped(at(-20.0, -45.0, 800.0)));
+        assert!(!dipole.trapped(at(20.0, 135.0, 800.0)));
+    }
+
+    #[test]
+    fn test_l_shell_grows_towards_the_poles() {
+        let dipole = Dipole::igrf_2020();
+        let equator = dipole.l_shell(at(0.0, 0.0, 500.0));
+        let auroral = dipole.l_shell(at(65.0, -90.0, 500.0));
+        assert!((1.0..1.3).contains(&equator));
+        assert!(auroral > 4.0);
+    }
+}
@@ -0,0 +1,301 @@
+use std::{
+    f64::consts::PI,
+    io::{self, Write},
+};
+
+// Binary glTF 2.0 (.glb) scene: the central body as a lit UV sphere and each
+// path as a line primitive with its own material. glTF is right-handed with
+// y up, so callers pass positions already converted with `Up::Y` and scaled
+// to scene units.
+
+const GLB_MAGIC: u32 = 0x4654_6C67; // "glTF"
+const JSON_CHUNK: u32 = 0x4E4F_534A; // "JSON"
+const BIN_CHUNK: u32 = 0x004E_4942; // "BIN\0"
+
+const ARRAY_BUFFER: u32 = 34962;
+const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+const FLOAT: u32 = 5126;
+const UNSIGNED_INT: u32 = 5125;
+
+const LINE_LOOP: u32 = 2;
+const LINE_STRIP: u32 = 3;
+const TRIANGLES: u32 = 4;
+
+/// Line colours assigned to paths in order.
+pub const PALETTE: [[f32; 3]; 6] = [
+    [0.90, 0.10, 0.29],
+    [0.24, 0.71, 0.29],
+    [1.00, 0.88, 0.10],
+    [0.96, 0.51, 0.19],
+    [0.57, 0.12, 0.71],
+    [0.27, 0.94, 0.94],
+];
+
+const SPHERE_RINGS: usize = 32;
+const SPHERE_SEGMENTS: usize = 64;
+
+pub struct Path {
+    pub name: String,
+    pub points: Vec<[f64; 3]>,
+    pub closed: bool,
+    pub color: [f32; 3],
+}
+
+#[derive(Default)]
+struct Builder {
+    bin: Vec<u8>,
+    views: Vec<String>,
+    accessors: Vec<String>,
+    materials: Vec<String>,
+    meshes: Vec<String>,
+}
+
+impl Builder {
+    fn view(&mut self, bytes: &[u8], target: u32) -> usize {
+        let offset = self.bin.len();
+        self.bin.extend_from_slice(bytes);
+        // accessor data must start on a 4-byte boundary
+        while !self.bin.len().is_multiple_of(4) {
+            self.bin.push(0);
+        }
+        self.views.push(format!(
+            "{{\"buffer\":0,\"byteOffset\":{offset},\"byteLength\":{},\"target\":{target}}}",
+            bytes.len()
+        ));
+        self.views.len() - 1
+    }
+
+    fn vectors(&mut self, values: &[[f32; 3]], bounds: bool) -> usize {
+        let bytes: Vec<u8> = values
+            .iter()
+            .flatten()
+            .flat_map(|v| v.to_le_bytes())
+            .collect();
+        let view = self.view(&bytes, ARRAY_BUFFER);
+        let mut accessor = format!(
+            "{{\"bufferView\":{view},\"componentType\":{FLOAT},\"count\":{},\"type\":\"VEC3\"",
+            values.len()
+        );
+        if bounds {
+            // required for POSITION accessors
+            let fold = |pick: fn(f32, f32) -> f32, start: f32| {
+                let mut out = [start; 3];
+                for value in values {
+                    for axis in 0..3 {
+                        out[axis] = pick(out[axis], value[axis]);
+                    }
+                }
+                format!("[{},{},{}]", out[0], out[1], out[2])
+            };
+            accessor.push_str(&format!(
+                ",\"min\":{},\"max\":{}",
+                fold(f32::min, f32::INFINITY),
+                fold(f32::max, f32::NEG_INFINITY)
+            ));
+        }
+        accessor.push('}');
+        self.accessors.push(accessor);
+        self.accessors.len() - 1
+    }
+
+    fn indices(&mut self, indices: &[u32]) -> usize {
+        let bytes: Vec<u8> = indices.iter().flat_map(|i| i.to_le_bytes()).collect();
+        let view = self.view(&bytes, ELEMENT_ARRAY_BUFFER);
+        self.accessors.push(format!(
+            "{{\"bufferView\":{view},\"componentType\":{UNSIGNED_INT},\"count\":{},\"type\":\"SCALAR\"}}",
+            indices.len()
+        ));
+        self.accessors.len() - 1
+    }
+
+    fn material(&mut self, name: &str, color: [f32; 3], metallic: f32, roughness: f32) -> usize {
+        self.materials.push(format!(
+            "{{\"name\":{},\"pbrMetallicRoughness\":{{\"baseColorFactor\":[{},{},{},1],\"metallicFactor\":{metallic},\"roughnessFactor\":{roughness}}}}}",
+            quote(name),
+            color[0],
+            color[1],
+            color[2]
+        ));
+        self.materials.len() - 1
+    }
+
+    fn mesh(
+        &mut self,
+        name: &str,
+        attributes: &str,
+        indices: Option<usize>,
+        material: usize,
+        mode: u32,
+    ) {
+        let indices = indices.map_or(String::new(), |i| format!(",\"indices\":{i}"));
+        self.meshes.push(format!(
+            "{{\"name\":{},\"primitives\":[{{\"attributes\":{{{attributes}}}{indices},\"material\":{material},\"mode\":{mode}}}]}}",
+            quote(name)
+        ));
+    }
+}
+
+fn quote(text: &str) -> String {
+    let mut out = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Vertices, normals and triangle indices of a sphere with its poles on y.
+fn sphere(radius: f64) -> (Vec<[f32; 3]>, Vec<[f32; 3]>, Vec<u32>) {
+    let mut normals = vec![];
+    for ring in 0..=SPHERE_RINGS {
+        let (sin_polar, cos_polar) = (PI * ring as f64 / SPHERE_RINGS as f64).sin_cos();
+        for segment in 0..=SPHERE_SEGMENTS {
+            let (sin_az, cos_az) = (2.0 * PI * segment as f64 / SPHERE_SEGMENTS as f64).sin_cos();
+            normals.push([
+                (sin_polar * cos_az) as f32,
+                cos_polar as f32,
+                (-sin_polar * sin_az) as f32,
+            ]);
+        }
+    }
+    let positions = normals
+        .iter()
+        .map(|n| n.map(|v| (v as f64 * radius) as f32))
+        .collect();
+
+    let row = SPHERE_SEGMENTS as u32 + 1;
+    let mut indices = vec![];
+    for ring in 0..SPHERE_RINGS as u32 {
+        for segment in 0..SPHERE_SEGMENTS as u32 {
+            let a = ring * row + segment;
+            let b = a + row;
+            // counter-clockwise seen from outside
+            indices.extend_from_slice(&[a, b, a + 1, a + 1, b, b + 1]);
+        }
+    }
+    (positions, normals, indices)
+}
+
+pub fn write_glb(
+    out: &mut impl Write,
+    body_name: &str,
+    body_radius: f64, // scene units
+    paths: &[Path],
+) -> io::Result<()> {
+    let mut builder = Builder::default();
+
+    let (positions, normals, indices) = sphere(body_radius);
+    let position = builder.vectors(&positions, true);
+    let normal = builder.vectors(&normals, false);
+    let index = builder.indices(&indices);
+    let material = builder.material(body_name, [0.27, 0.43, 0.71], 0.0, 0.9);
+    builder.mesh(
+        body_name,
+        &format!("\"POSITION\":{position},\"NORMAL\":{normal}"),
+        Some(index),
+        material,
+        TRIANGLES,
+    );
+
+    for path in paths {
+        let points: Vec<[f32; 3]> = path.points.iter().map(|p| p.map(|v| v as f32)).collect();
+        let position = builder.vectors(&points, true);
+        let material = builder.material(&path.name, path.color, 0.0, 1.0);
+        let mode = if path.closed { LINE_LOOP } else { LINE_STRIP };
+        builder.mesh(
+            &path.name,
+            &format!("\"POSITION\":{position}"),
+            None,
+            material,
+            mode,
+        );
+    }
+
+    let nodes: Vec<String> = (0..builder.meshes.len())
+        .map(|mesh| format!("{{\"mesh\":{mesh}}}"))
+        .collect();
+    let scene: Vec<String> = (0..nodes.len()).map(|node| node.to_string()).collect();
+    let mut json = format!(
+        "{{\"asset\":{{\"version\":\"2.0\",\"generator\":\"orbit {}\"}},\"scene\":0,\"scenes\":[{{\"nodes\":[{}]}}],\"nodes\":[{}],\"meshes\":[{}],\"materials\":[{}],\"accessors\":[{}],\"bufferViews\":[{}],\"buffers\":[{{\"byteLength\":{}}}]}}",
+        env!("CARGO_PKG_VERSION"),
+        scene.join(","),
+        nodes.join(","),
+        builder.meshes.join(","),
+        builder.materials.join(","),
+        builder.accessors.join(","),
+        builder.views.join(","),
+        builder.bin.len()
+    )
+    .into_bytes();
+    // the JSON chunk is padded with spaces, the binary chunk with zeros
+    while !json.len().is_multiple_of(4) {
+        json.push(b' ');
+    }
+
+    let length = 12 + 8 + json.len() + 8 + builder.bin.len();
+    out.write_all(&GLB_MAGIC.to_le_bytes())?;
+    out.write_all(&2u32.to_le_bytes())?;
+    out.write_all(&(length as u32).to_le_bytes())?;
+    out.write_all(&(json.len() as u32).to_le_bytes())?;
+    out.write_all(&JSON_CHUNK.to_le_bytes())?;
+    out.write_all(&json)?;
+    out.write_all(&(builder.bin.len() as u32).to_le_bytes())?;
+    out.write_all(&BIN_CHUNK.to_le_bytes())?;
+    out.write_all(&builder.bin)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_u32(bytes: &[u8], at: usize) -> u32 {
+        u32::from_le_bytes(bytes[at..at + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn test_glb_layout() {
+        let path = Path {
+            name: "LEO".to_string(),
+            points: vec![[1.0, 0.0, 0.0], [0.0, 0.0, -1.0], [-1.0, 0.0, 0.0]],
+            closed: true,
+            color: [1.0, 0.0, 0.0],
+        };
+        let mut out = vec![];
+        write_glb(&mut out, "Earth", 0.5, &[path]).unwrap();
+
+        assert_eq!(read_u32(&out, 0), GLB_MAGIC);
+        assert_eq!(read_u32(&out, 8) as usize, out.len());
+        let json_length = read_u32(&out, 12) as usize;
+        assert_eq!(read_u32(&out, 16), JSON_CHUNK);
+        assert_eq!(json_length % 4, 0);
+        let bin_header = 20 + json_length;
+        assert_eq!(read_u32(&out, bin_header + 4), BIN_CHUNK);
+        assert_eq!(
+            read_u32(&out, bin_header) as usize,
+            out.len() - bin_header - 8
+        );
+
+        let json = std::str::from_utf8(&out[20..bin_header]).unwrap();
+        assert!(json.contains("\"mode\":2"));
+        assert!(json.contains("\"min\":[-1,0,-1],\"max\":[1,0,0]"));
+    }
+
+    #[test]
+    fn test_sphere_vertices_on_radius() {
+        let (positions, _, indices) = sphere(2.0);
+        for [x, y, z] in positions {
+            assert!(((x * x + y * y + z * z).sqrt() - 2.0).abs() < 1e-5);
+        }
+        assert_eq!(indices.len(), SPHERE_RINGS * SPHERE_SEGMENTS * 6);
+    }
+
+    #[test]
+    fn test_quote_escapes() {
+        assert_eq!(quote("a\"b\\c"), "\"a\\\"b\\\\c\"");
+    }
+}
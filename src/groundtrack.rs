@@ -0,0 +1,225 @@
+use std::f64::consts::PI;
+
+use crate::{
+    dimension::Meters,
+    elements::KeplerianElements,
+    export::{Feature, Geometry},
+    frames::{self, Geodetic},
+    plot, propagate, time,
+    tle::Tle,
+    Body, EARTH_MU,
+};
+
+// Sub-satellite points over a span of time. Element sets go through SGP4;
+// classical elements follow an unperturbed Kepler ellipse, taken to be in the
+// same true-of-date inertial frame SGP4 uses so both rotate into the Earth-
+// fixed frame by Greenwich mean sidereal time. Points are geodetic on the
+// WGS-84 ellipsoid.
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrackPoint {
+    pub time: f64, // days since J2000 UTC
+    pub position: Geodetic,
+}
+
+/// Times in days since J2000 from `start` every `step` s for `duration` s,
+/// including the start and, when it falls on a step, the end.
+pub fn times(start: f64, duration: f64, step: f64) -> Vec<f64> {
+    // the margin keeps an end point lost to rounding, as in 5400 / 60
+    let steps = (duration / step + 1e-9).floor() as usize;
+    (0..=steps)
+        .map(|k| start + k as f64 * step / 86_400.0)
+        .collect()
+}
+
+pub fn from_tle(tle: &Tle, times: &[f64]) -> Result<Vec<TrackPoint>, String> {
+    times
+        .iter()
+        .map(|&time| {
+            let state = propagate::sgp4(tle, time)?;
+            Ok(point(state.position, time))
+        })
+        .collect()
+}
+
+/// Track of an ellipse given by `elements` at `epoch` (days since J2000).
+pub fn from_elements(
+    elements: &KeplerianElements,
+    epoch: f64,
+    times: &[f64],
+) -> Result<Vec<TrackPoint>, String> {
+    let e = elements.eccentricity;
+    if !(0.0..1.0).contains(&e) || elements.semi_major_axis <= 0.0 {
+        return Err("ground tracks need a closed orbit, with eccentricity below 1".to_string());
+    }
+    times
+        .iter()
+        .map(|&time| {
+            let state = propagate::kepler(EARTH_MU, elements, (time - epoch) * 86_400.0)?;
+            Ok(point(state.position, time))
+        })
+        .collect()
+}
+
+fn point(position: [f64; 3], time: f64) -> TrackPoint {
+    TrackPoint {
+        time,
+        position: frames::geodetic(frames::teme_to_ecef(position, time)),
+    }
+}
+
+/// Longitude and latitude lines of a track, broken where it crosses the
+/// antimeridian so maps do not draw a line across the whole world. The
+/// crossing latitude is interpolated and repeated on both edges.
+pub fn segments(points: &[TrackPoint]) -> Vec<Vec<[f64; 2]>> {
+    let mut segments = vec![];
+    let mut current: Vec<[f64; 2]> = vec![];
+    for point in points {
+        let (lon, lat) = (point.position.longitude, point.position.latitude);
+        if let Some(&[last_lon, last_lat]) = current.last() {
+            if (lon - last_lon).abs() > 180.0 {
+                let edge = if last_lon > 0.0 { 180.0 } else { -180.0 };
+                let unwrapped = lon + 2.0 * edge;
+                let fraction = (edge - last_lon) / (unwrapped - last_lon);
+                let crossing = last_lat + fraction * (lat - last_lat);
+                current.push([edge, crossing]);
+                segments.push(std::mem::take(&mut current));
+                current.push([-edge, crossing]);
+            }
+        }
+        current.push([lon, lat]);
+    }
+    if !current.is_empty() {
+        segments.push(current);
+    }
+    segments
+}
+
+/// A track as a map feature carrying its time span.
+pub fn feature(name: &str, points: &[TrackPoint]) -> Feature {
+    let mut properties = vec![];
+    if let (Some(first), Some(last)) = (points.first(), points.last()) {
+        properties.push(("start", time::format_utc(first.time)));
+        properties.push(("end", time::format_utc(last.time)));
+    }
+    Feature {
+        name: name.to_string(),
+        properties,
+        geometry: Geometry::Lines(segments(points)),
+    }
+}
+
+/// Closed ring of `points` vertices at `central_angle` (deg) around a
+/// latitude and longitude, by the spherical destination formula. Longitudes
+/// stay continuous around the centre, so a ring straddling the antimeridian
+/// runs past 180 rather than wrapping; a ring around a pole is not closed
+/// in longitude and is best drawn on a polar map.
+pub fn circle(latitude: f64, longitude: f64, central_angle: f64, points: usize) -> Vec<[f64; 2]> {
+    let (sin_lat, cos_lat) = latitude.to_radians().sin_cos();
+    let (sin_d, cos_d) = central_angle.to_radians().sin_cos();
+    let mut ring: Vec<[f64; 2]> = (0..points)
+        .map(|k| {
+            let bearing = 2.0 * PI * k as f64 / points as f64;
+            let sin_lat2 = sin_lat * cos_d + cos_lat * sin_d * bearing.cos();
+            let lat2 = sin_lat2.clamp(-1.0, 1.0).asin();
+            let dlon = (bearing.sin() * sin_d * cos_lat).atan2(cos_d - sin_lat * sin_lat2);
+            [longitude + dlon.to_degrees(), lat2.to_degrees()]
+        })
+        .collect();
+    ring.push(ring[0]);
+    ring
+}
+
+/// Visibility footprint of a track point above `min_elevation` (deg) as a
+/// map feature, treating the body as a sphere.
+pub fn footprint(body: &Body, point: &TrackPoint, min_elevation: f64, name: &str) -> Feature {
+    let radius = plot::footprint_radius(body, point.position.altitude / 1000.0, min_elevation);
+    let central_angle = (Meters::from_km(radius) / body.radius).to_degrees();
+    Feature {
+        name: name.to_string(),
+        properties: vec![
+            ("time", time::format_utc(point.time)),
+            ("radius_km", format!("{radius:.1}")),
+        ],
+        geometry: Geometry::Polygon(circle(
+            point.position.latitude,
+            point.position.longitude,
+            central_angle,
+            72,
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dimension::GravitationalParameter, EARTH_RADIUS};
+
+    fn at(longitude: f64, latitude: f64) -> TrackPoint {
+        TrackPoint {
+            time: 0.0,
+            position: Geodetic {
+                latitude,
+                longitude,
+                altitude: 0.0,
+            },
+        }
+    }
+
+    #[test]
+    fn test_times() {
+        let times = times(0.0, 5_400.0, 60.0);
+        assert_eq!(times.len(), 91);
+        assert!((times[90] - 5_400.0 / 86_400.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_elements_track_repeats_after_a_period() {
+        // an equatorial orbit stays on the equator and comes back to the
+        // same inertial point, so the longitude drifts by Earth's rotation
+        let elements = KeplerianElements {
+            semi_major_axis: EARTH_RADIUS + 500_000.0,
+            eccentricity: 0.01,
+            inclination: 0.0,
+            raan: 0.0,
+            argument_of_periapsis: 0.0,
+            true_anomaly: 30.0,
+        };
+        let period = crate::calculate_orbital_period(
+            GravitationalParameter(EARTH_MU),
+            Meters(elements.semi_major_axis),
+        )
+        .0;
+        let track = from_elements(&elements, 0.0, &[0.0, period / 86_400.0]).unwrap();
+        assert!(track
+            .iter()
+            .all(|point| point.position.latitude.abs() < 1e-6));
+        let drift = (track[0].position.longitude - track[1].position.longitude).rem_euclid(360.0);
+        let expected = (crate::EARTH_ROTATION_RATE * period).to_degrees();
+        assert!((drift - expected).abs() < 0.01, "{drift} vs {expected}");
+    }
+
+    #[test]
+    fn test_segments_split_at_antimeridian() {
+        let points = [at(170.0, 0.0), at(-170.0, 10.0), at(-160.0, 20.0)];
+        let segments = segments(&points);
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0], vec![[170.0, 0.0], [180.0, 5.0]]);
+        assert_eq!(segments[1][0], [-180.0, 5.0]);
+        assert_eq!(segments[1].len(), 3);
+    }
+
+    #[test]
+    fn test_circle() {
+        // every vertex lies the central angle away from the centre
+        let ring = circle(40.0, -170.0, 15.0, 36);
+        assert_eq!(ring.len(), 37);
+        assert_eq!(ring[0], ring[36]);
+        let (lat0, lon0) = (40f64.to_radians(), -170f64.to_radians());
+        for [lon, lat] in ring {
+            let (lat, lon) = (lat.to_radians(), lon.to_radians());
+            let cos = lat0.sin() * lat.sin() + lat0.cos() * lat.cos() * (lon - lon0).cos();
+            assert!((cos.acos().to_degrees() - 15.0).abs() < 1e-9);
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{
+    calculate_orbital_period,
+    dimension::{GravitationalParameter, Meters, Seconds},
+    quantity,
+};
+
+// Habitable-zone limits from Kopparapu et al. (2014), "Habitable Zones Around
+// Main-sequence Stars: Dependence on Planetary Mass", for a one Earth-mass
+// planet. Each limit is an effective stellar flux polynomial in
+// T = Teff - 5780 K and is fitted for 2600 K <= Teff <= 7200 K.
+
+pub const SOLAR_MASS: f64 = 1.98847e30; // kg
+pub const SUN_MU: f64 = 1.32712440018e20; // m^3*s^-2
+pub const SOLAR_LUMINOSITY: f64 = 3.828e26; // W, IAU 2015 nominal
+pub const SOLAR_TEMPERATURE: f64 = 5772.0; // K, IAU 2015 nominal
+pub const SOLAR_CONSTANT: f64 = 1361.0; // W*m^-2 at 1 AU
+pub const ASTRONOMICAL_UNIT: f64 = 1.495978707e11; // m
+const STEFAN_BOLTZMANN: f64 = 5.670374419e-8; // W*m^-2*K^-4
+
+pub const VALID_TEMPERATURES: (f64, f64) = (2600.0, 7200.0); // K
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Limit {
+    RecentVenus,
+    RunawayGreenhouse,
+    MaximumGreenhouse,
+    EarlyMars,
+}
+
+pub const LIMITS: [Limit; 4] = [
+    Limit::RecentVenus,
+    Limit::RunawayGreenhouse,
+    Limit::MaximumGreenhouse,
+    Limit::EarlyMars,
+];
+
+impl Limit {
+    fn coefficients(self) -> [f64; 5] {
+        match self {
+            Limit::RecentVenus => [1.776, 2.136e-4, 2.533e-8, -1.332e-11, -3.097e-15],
+            Limit::RunawayGreenhouse => [1.107, 1.332e-4, 1.580e-8, -8.308e-12, -1.931e-15],
+            Limit::MaximumGreenhouse => [0.356, 6.171e-5, 1.698e-9, -3.198e-12, -5.575e-16],
+            Limit::EarlyMars => [0.320, 5.547e-5, 1.526e-9, -2.874e-12, -5.011e-16],
+        }
+    }
+
+    /// Effective flux at the limit, relative to the flux Earth receives.
+    pub fn effective_flux(self, temperature: f64) -> f64 {
+        let [s, a, b, c, d] = self.coefficients();
+        let t = temperature - 5780.0;
+        s + a * t + b * t.powi(2) + c * t.powi(3) + d * t.powi(4)
+    }
+
+    /// Distance of the limit in AU.
+    pub fn distance(self, luminosity: f64, temperature: f64) -> f64 {
+        (luminosity / self.effective_flux(temperature)).sqrt()
+    }
+}
+
+pub enum Zone {
+    TooHot,
+    Optimistic,
+    Conservative,
+    TooCold,
+}
+
+pub struct Star {
+    pub mass: f64,        // solar masses
+    pub luminosity: f64,  // solar luminosities
+    pub temperature: f64, // K
+}
+
+impl Star {
+    /// Main-sequence mass-luminosity relation, used when no luminosity is given.
+    pub fn main_sequence_luminosity(mass: f64) -> f64 {
+        if mass < 0.43 {
+            0.23 * mass.powf(2.3)
+        } else if mass < 2.0 {
+            mass.powi(4)
+        } else {
+            1.4 * mass.powf(3.5)
+        }
+    }
+
+    /// Effective temperature from luminosity and a main-sequence radius
+    /// estimate (R ~ M^0.8), used when no temperature is given.
+    pub fn main_sequence_temperature(mass: f64, luminosity: f64) -> f64 {
+        let radius = mass.powf(0.8);
+        SOLAR_TEMPERATURE * (luminosity / radius.powi(2)).powf(0.25)
+    }
+
+    pub fn mu(&self) -> GravitationalParameter {
+        GravitationalParameter(SUN_MU * self.mass)
+    }
+
+    /// Stellar flux at a distance in AU, relative to the flux at Earth.
+    pub fn flux(&self, distance: f64) -> f64 {
+        self.luminosity / distance.powi(2)
+    }
+
+    /// Equilibrium temperature of a fast-rotating planet with the given Bond albedo.
+    pub fn equilibrium_temperature(&self, distance: f64, albedo: f64) -> f64 {
+        (SOLAR_CONSTANT * self.flux(distance) * (1.0 - albedo) / (4.0 * STEFAN_BOLTZMANN))
+            .powf(0.25)
+    }
+
+    /// Orbital period at a distance in AU.
+    pub fn period(&self, distance: f64) -> Seconds {
+        calculate_orbital_period(self.mu(), Meters(distance * ASTRONOMICAL_UNIT))
+    }
+
+    pub fn zone(&self, distance: f64) -> Zone {
+        let limit = |l: Limit| l.distance(self.luminosity, self.temperature);
+        if distance < limit(Limit::RecentVenus) {
+            Zone::TooHot
+        } else if distance > limit(Limit::EarlyMars) {
+            Zone::TooCold
+        } else if distance < limit(Limit::RunawayGreenhouse)
+            || distance > limit(Limit::MaximumGreenhouse)
+        {
+            Zone::Optimistic
+        } else {
+            Zone::Conservative
+        }
+    }
+}
+
+pub fn parse_mass(input: &str) -> Result<f64, String> {
+    // solar masses
+    quantity::parse(input, &[("Msun", 1.0), ("kg", 1.0 / SOLAR_MASS)])
+}
+
+pub fn parse_luminosity(input: &str) -> Result<f64, String> {
+    // solar luminosities
+    quantity::parse(input, &[("Lsun", 1.0), ("W", 1.0 / SOLAR_LUMINOSITY)])
+}
+
+pub fn parse_distance(input: &str) -> Result<f64, String> {
+    // AU
+    quantity::parse(
+        input,
+        &[
+            ("AU", 1.0),
+            ("km", 1000.0 / ASTRONOMICAL_UNIT),
+            ("m", 1.0 / ASTRONOMICAL_UNIT),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sun() -> Star {
+        Star {
+            mass: 1.0,
+            luminosity: 1.0,
+            temperature: 5780.0,
+        }
+    }
+
+    #[test]
+    fn test_solar_limits() {
+        // Kopparapu et al. (2014) quote 0.95 AU and 1.67 AU for the Sun
+        let sun = sun();
+        let inner = Limit::RunawayGreenhouse.distance(sun.luminosity, sun.temperature);
+        let outer = Limit::MaximumGreenhouse.distance(sun.luminosity, sun.temperature);
+        assert!((inner - 0.95).abs() < 0.01);
+        assert!((outer - 1.676).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_earth_equilibrium_temperature() {
+        let temperature = sun().equilibrium_temperature(1.0, 0.306);
+        assert!((temperature - 254.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_suffix_parsing() {
+        assert_eq!(parse_mass("1.1Msun"), Ok(1.1));
+        assert!((parse_mass("1.98847e30kg").unwrap() - 1.0).abs() < 1e-12);
+        assert_eq!(parse_luminosity("1.4Lsun"), Ok(1.4));
+        assert_eq!(parse_distance("1.2"), Ok(1.2));
+    }
+}
@@ -0,0 +1,146 @@
+use std::{env, sync::OnceLock};
+
+use clap::ValueEnum;
+use fluent_bundle::{concurrent::FluentBundle, FluentArgs, FluentResource};
+use unic_langid::LanguageIdentifier;
+
+// User-facing strings live in locales/<lang>.ftl. Lookups fall back to
+// English when a key has not been translated yet, and to the key itself when
+// it is missing everywhere, so a new string never breaks a report.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Lang {
+    En,
+    Es,
+    De,
+}
+
+impl Lang {
+    fn code(self) -> &'static str {
+        match self {
+            Lang::En => "en",
+            Lang::Es => "es",
+            Lang::De => "de",
+        }
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Lang::En => include_str!("../locales/en.ftl"),
+            Lang::Es => include_str!("../locales/es.ftl"),
+            Lang::De => include_str!("../locales/de.ftl"),
+        }
+    }
+
+    /// Pick a language from LC_ALL, LC_MESSAGES or LANG (e.g. "de_DE.UTF-8").
+    pub fn from_env() -> Self {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| {
+                let prefix = value.split(['_', '.', '-']).next().unwrap_or("");
+                Lang::from_str(prefix, true).ok()
+            })
+            .unwrap_or(Lang::En)
+    }
+}
+
+struct Localizer {
+    bundle: FluentBundle<FluentResource>,
+    fallback: FluentBundle<FluentResource>,
+}
+
+static LOCALIZER: OnceLock<Localizer> = OnceLock::new();
+
+fn bundle(lang: Lang) -> FluentBundle<FluentResource> {
+    let id: LanguageIdentifier = lang.code().parse().expect("valid language code");
+    let resource = FluentResource::try_new(lang.source().to_string())
+        .unwrap_or_else(|(_, errors)| panic!("invalid {}.ftl: {errors:?}", lang.code()));
+
+    let mut bundle = FluentBundle::new_concurrent(vec![id]);
+    // Unicode isolation marks only help bidirectional text and show up as
+    // stray characters in most terminals.
+    bundle.set_use_isolating(false);
+    bundle
+        .add_resource(resource)
+        .expect("no duplicate message ids");
+    bundle
+}
+
+/// Select the output language. Later calls are ignored.
+pub fn init(lang: Lang) {
+    LOCALIZER.get_or_init(|| Localizer {
+        bundle: bundle(lang),
+        fallback: bundle(Lang::En),
+    });
+}
+
+fn format(
+    bundle: &FluentBundle<FluentResource>,
+    key: &str,
+    args: Option<&FluentArgs>,
+) -> Option<String> {
+    let pattern = bundle.get_message(key)?.value()?;
+    let mut errors = vec![];
+    Some(
+        bundle
+            .format_pattern(pattern, args, &mut errors)
+            .into_owned(),
+    )
+}
+
+pub fn tr(key: &str, args: Option<&FluentArgs>) -> String {
+    let localizer = LOCALIZER.get_or_init(|| Localizer {
+        bundle: bundle(Lang::En),
+        fallback: bundle(Lang::En),
+    });
+
+    format(&localizer.bundle, key, args)
+        .or_else(|| format(&localizer.fallback, key, args))
+        .unwrap_or_else(|| key.to_string())
+}
+
+/// Look up a localized string, optionally with `name = value` arguments.
+/// Values are passed through `to_string()` so numeric formatting stays under
+/// the caller's control.
+macro_rules! t {
+    ($key:expr) => {
+        $crate::i18n::tr($key, None)
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = fluent_bundle::FluentArgs::new();
+        $(args.set(stringify!($name), $value.to_string());)+
+        $crate::i18n::tr($key, Some(&args))
+    }};
+}
+
+pub(crate) use t;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translations_cover_english_keys() {
+        let english = FluentResource::try_new(Lang::En.source().to_string()).unwrap();
+        for lang in [Lang::Es, Lang::De] {
+            let translated = bundle(lang);
+            for entry in english.entries() {
+                if let fluent_syntax::ast::Entry::Message(message) = entry {
+                    assert!(
+                        translated.has_message(message.id.name),
+                        "{} is missing {}",
+                        lang.code(),
+                        message.id.name
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fallback_to_key() {
+        assert_eq!(tr("no-such-key", None), "no-such-key");
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{
+    bodies::Named,
+    calculate_circular_orbital_velocity, calculate_hyperbolic_velocity,
+    calculate_vis_viva_velocity,
+    dimension::{Meters, MetersPerSecond},
+    porkchop,
+};
+
+// Patched conics between planets. Outside the spheres of influence the
+// spacecraft follows the heliocentric Lambert arc between the planets'
+// ephemeris positions; inside each sphere it follows a hyperbola around the
+// planet whose excess speed matches the arc's speed relative to the planet.
+// The injection burn leaves a circular parking orbit on the departure
+// hyperbola at its periapsis, and the capture burn at the arrival periapsis
+// slows the spacecraft onto the target orbit. Altitudes are in km.
+
+/// A transfer with its burns at both planets.
+#[derive(Debug, Clone, Copy)]
+pub struct Transfer {
+    pub arc: porkchop::Cell,
+    pub injection: MetersPerSecond, // from the parking orbit
+    pub capture: MetersPerSecond,   // into the target orbit
+    pub departure_soi: Meters,
+    pub arrival_soi: Meters,
+}
+
+impl Transfer {
+    /// Total delta-v.
+    pub fn total(&self) -> MetersPerSecond {
+        self.injection + self.capture
+    }
+}
+
+/// The transfer from a circular `parking` orbit around `from` leaving at
+/// `depart` to an orbit with `periapsis` and `apoapsis` around `to`
+/// arriving at `arrive` (days since J2000), or `None` where there is no arc.
+pub fn transfer(
+    from: Named,
+    to: Named,
+    depart: f64,
+    arrive: f64,
+    parking: f64,
+    (periapsis, apoapsis): (f64, f64),
+) -> Option<Transfer> {
+    let arc = porkchop::cell(from, to, depart, arrive)?;
+    let (departure, arrival) = (from.body(), to.body());
+    let parking = departure.radius + Meters::from_km(parking);
+    let departure_v_inf = MetersPerSecond(arc.departure_v_inf);
+    let injection = calculate_hyperbolic_velocity(departure.mu, parking, departure_v_inf)
+        - calculate_circular_orbital_velocity(departure.mu, parking);
+    let periapsis = arrival.radius + Meters::from_km(periapsis);
+    let apoapsis = arrival.radius + Meters::from_km(apoapsis);
+    let arrival_v_inf = MetersPerSecond(arc.arrival_v_inf);
+    let capture = calculate_hyperbolic_velocity(arrival.mu, periapsis, arrival_v_inf)
+        - calculate_vis_viva_velocity(arrival.mu, periapsis, (periapsis + apoapsis) / 2.0);
+    Some(Transfer {
+        arc,
+        injection,
+        capture,
+        departure_soi: from.sphere_of_influence()?,
+        arrival_soi: to.sphere_of_influence()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time;
+
+    #[test]
+    fn test_earth_to_mars() {
+        let depart = time::parse_utc("2026-10-31").unwrap();
+        let arrive = time::parse_utc("2027-08-20").unwrap();
+        let low = transfer(
+            Named::Earth,
+            Named::Mars,
+            depart,
+            arrive,
+            200.0,
+            (400.0, 400.0),
+        )
+        .unwrap();
+        // trans-Mars injection from LEO takes about 3.6 km/s, and capturing
+        // straight into a low circular orbit about 2 km/s
+        assert!((3_400.0..3_800.0).contains(&low.injection.0));
+        assert!((1_800.0..2_500.0).contains(&low.capture.0));
+        assert!((low.arrival_soi.km() - 577_000.0).abs() < 5_000.0);
+
+        // a long ellipse is far cheaper to enter
+        let elliptical = transfer(
+            Named::Earth,
+            Named::Mars,
+            depart,
+            arrive,
+            200.0,
+            (400.0, 30_000.0),
+        )
+        .unwrap();
+        assert!(elliptical.capture < low.capture - MetersPerSecond(500.0));
+        assert_eq!(elliptical.injection, low.injection);
+
+        let home = transfer(
+            Named::Earth,
+            Named::Earth,
+            depart,
+            arrive,
+            200.0,
+            (400.0, 400.0),
+        );
+        assert!(home.is_none());
+    }
+}
@@ -0,0 +1,128 @@
+use clap::ValueEnum;
+
+use crate::bodies::Named;
+
+// Lagrange points of the circular restricted three-body problem. In the
+// frame rotating with two primaries on a circular orbit, with lengths in
+// units of their separation and the origin at the barycentre, the larger
+// sits at -mu and the smaller at 1 - mu for the mass ratio mu = m2 / (m1 +
+// m2). The collinear points L1 to L3 are the roots of Szebehely's quintics
+// in the distance from the nearer primary, solved by Newton-Raphson; L4 and
+// L5 form equilateral triangles with the two primaries.
+
+const TOLERANCE: f64 = 1e-14;
+const MAX_ITERATIONS: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum System {
+    EarthMoon,
+    SunEarth,
+    SunMars,
+    SunJupiter,
+}
+
+impl System {
+    /// The smaller primary, which orbits the larger.
+    pub fn secondary(self) -> Named {
+        match self {
+            System::EarthMoon => Named::Moon,
+            System::SunEarth => Named::Earth,
+            System::SunMars => Named::Mars,
+            System::SunJupiter => Named::Jupiter,
+        }
+    }
+
+    /// The larger primary.
+    pub fn primary(self) -> Named {
+        self.secondary()
+            .parent()
+            .expect("secondaries orbit a primary")
+    }
+
+    /// Mass ratio m2 / (m1 + m2).
+    pub fn mass_ratio(self) -> f64 {
+        let (m1, m2) = (self.primary().body().mu, self.secondary().body().mu);
+        m2 / (m1 + m2)
+    }
+}
+
+/// Positions of L1 to L5 in the rotating frame, in units of the separation
+/// of the primaries, for the mass ratio `mu`.
+pub fn points(mu: f64) -> [[f64; 2]; 5] {
+    // (coefficients from the fifth power down, first guess) in the distance
+    // from the secondary for L1 and L2 and from the primary for L3
+    let hill = (mu / 3.0).cbrt();
+    let l1 = newton([1.0, -(3.0 - mu), 3.0 - 2.0 * mu, -mu, 2.0 * mu, -mu], hill);
+    let l2 = newton([1.0, 3.0 - mu, 3.0 - 2.0 * mu, -mu, -2.0 * mu, -mu], hill);
+    let l3 = newton(
+        [
+            1.0,
+            2.0 + mu,
+            1.0 + 2.0 * mu,
+            -(1.0 - mu),
+            -2.0 * (1.0 - mu),
+            -(1.0 - mu),
+        ],
+        1.0 - 7.0 * mu / 12.0,
+    );
+    let height = 3f64.sqrt() / 2.0;
+    [
+        [1.0 - mu - l1, 0.0],
+        [1.0 - mu + l2, 0.0],
+        [-mu - l3, 0.0],
+        [0.5 - mu, height],
+        [0.5 - mu, -height],
+    ]
+}
+
+fn newton(coefficients: [f64; 6], mut root: f64) -> f64 {
+    for _ in 0..MAX_ITERATIONS {
+        let (value, slope) = coefficients.iter().fold((0.0, 0.0), |(value, slope), c| {
+            (value * root + c, slope * root + value)
+        });
+        let step = value / slope;
+        root -= step;
+        if step.abs() < TOLERANCE {
+            break;
+        }
+    }
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collinear_points_balance() {
+        // gravity of both primaries and the centrifugal term cancel
+        for system in [System::EarthMoon, System::SunEarth, System::SunJupiter] {
+            let mu = system.mass_ratio();
+            for [x, _] in &points(mu)[..3] {
+                let (r1, r2) = ((x + mu).abs(), (x - 1.0 + mu).abs());
+                let force =
+                    x - (1.0 - mu) * (x + mu) / r1.powi(3) - mu * (x - 1.0 + mu) / r2.powi(3);
+                assert!(force.abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_earth_moon_and_sun_earth() {
+        let distance = Named::Moon.orbital_distance().unwrap().km();
+        let mu = System::EarthMoon.mass_ratio();
+        let [l1, l2, l3, l4, _] = points(mu);
+        // L1 about 326,000 km from Earth and L2 64,500 km beyond the Moon
+        assert!(((l1[0] + mu) * distance - 326_400.0).abs() < 1_000.0);
+        assert!(((l2[0] - 1.0 + mu) * distance - 64_500.0).abs() < 1_000.0);
+        assert!((l3[0] + mu + 1.0).abs() < 0.01);
+        assert!(((l4[0] + mu).hypot(l4[1]) - 1.0).abs() < 1e-12);
+
+        // Sun-Earth L1 and L2 lie about 1.5 million km from Earth
+        let distance = Named::Earth.orbital_distance().unwrap().km();
+        let mu = System::SunEarth.mass_ratio();
+        let [l1, l2, ..] = points(mu);
+        assert!(((1.0 - mu - l1[0]) * distance - 1_491_000.0).abs() < 10_000.0);
+        assert!(((l2[0] - 1.0 + mu) * distance - 1_501_000.0).abs() < 10_000.0);
+    }
+}
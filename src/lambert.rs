@@ -0,0 +1,251 @@
+use std::f64::consts::PI;
+
+// Lambert's problem: the two-body arc that leaves position r1 and reaches r2
+// after a given time of flight, solved with universal variables (Vallado,
+// algorithm 58). The time of flight is a function of psi = chi^2 / a, which
+// is negative on hyperbolas, lies in [0, 4 pi^2) on ellipses that make less
+// than one revolution, and in [(2 pi N)^2, (2 pi (N + 1))^2) for N full
+// revolutions. Without revolutions the time grows with psi and bisection
+// finds the one root. With N revolutions it falls and rises again, so there
+// are two arcs, one either side of the minimum time, or none if the flight
+// is shorter than that minimum. Positions are in m, velocities in m/s.
+
+const ITERATIONS: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Direction {
+    /// Counterclockwise seen from +Z, like most orbits
+    Prograde,
+    Retrograde,
+}
+
+/// One transfer arc, with the velocities at both ends.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Solution {
+    pub revolutions: u32,
+    pub departure: [f64; 3], // m/s at r1
+    pub arrival: [f64; 3],   // m/s at r2
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn norm(a: [f64; 3]) -> f64 {
+    dot(a, a).sqrt()
+}
+
+fn cross_z(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[1] - a[1] * b[0]
+}
+
+/// Stumpff functions c2 and c3 of psi.
+fn stumpff(psi: f64) -> (f64, f64) {
+    if psi > 1e-6 {
+        let root = psi.sqrt();
+        ((1.0 - root.cos()) / psi, (root - root.sin()) / (root * psi))
+    } else if psi < -1e-6 {
+        let root = (-psi).sqrt();
+        (
+            (1.0 - root.cosh()) / psi,
+            (root.sinh() - root) / (root * -psi),
+        )
+    } else {
+        // series, to avoid cancellation near zero
+        (
+            1.0 / 2.0 - psi / 24.0 + psi * psi / 720.0,
+            1.0 / 6.0 - psi / 120.0 + psi * psi / 5040.0,
+        )
+    }
+}
+
+struct Geometry {
+    mu: f64,
+    r1: f64,
+    r2: f64,
+    a: f64, // Vallado's A, signed by the way round
+}
+
+impl Geometry {
+    /// The auxiliary y and the time of flight in s at `psi`, or None where y
+    /// is negative and no arc exists.
+    fn time(&self, psi: f64) -> Option<(f64, f64)> {
+        let (c2, c3) = stumpff(psi);
+        let y = self.r1 + self.r2 + self.a * (psi * c3 - 1.0) / c2.sqrt();
+        if y < 0.0 || c2 <= 0.0 {
+            return None;
+        }
+        let chi = (y / c2).sqrt();
+        Some((y, (chi.powi(3) * c3 + self.a * y.sqrt()) / self.mu.sqrt()))
+    }
+
+    /// Bisect for the psi between `low` and `high` that takes `tof` s, with
+    /// the time rising from `low` to `high` if `rising`.
+    fn bisect(&self, mut low: f64, mut high: f64, tof: f64, rising: bool) -> f64 {
+        for _ in 0..ITERATIONS {
+            let psi = (low + high) / 2.0;
+            let short = self.time(psi).map_or(rising, |(_, time)| time < tof);
+            if short == rising {
+                low = psi;
+            } else {
+                high = psi;
+            }
+        }
+        (low + high) / 2.0
+    }
+}
+
+/// Arcs from `r1` to `r2` taking `tof` s around a body with gravitational
+/// parameter `mu`, after `revolutions` full revolutions in the given
+/// direction. Without revolutions there is one arc; with them there are two,
+/// or none if `tof` is too short.
+pub fn solve(
+    mu: f64,
+    r1: [f64; 3],
+    r2: [f64; 3],
+    tof: f64,
+    revolutions: u32,
+    direction: Direction,
+) -> Result<Vec<Solution>, String> {
+    let (radius1, radius2) = (norm(r1), norm(r2));
+    if radius1 == 0.0 || radius2 == 0.0 {
+        return Err("positions must be away from the centre of the body".to_string());
+    }
+    if tof <= 0.0 || !tof.is_finite() {
+        return Err("time of flight must be positive".to_string());
+    }
+    let cos_angle = (dot(r1, r2) / (radius1 * radius2)).clamp(-1.0, 1.0);
+    let short_way = (cross_z(r1, r2) >= 0.0) == (direction == Direction::Prograde);
+    let a = if short_way { 1.0 } else { -1.0 } * (radius1 * radius2 * (1.0 + cos_angle)).sqrt();
+    if a.abs() < 1e-9 * (radius1 * radius2).sqrt() {
+        return Err("positions 180 degrees apart do not fix the plane of the transfer".to_string());
+    }
+    if 1.0 - cos_angle < 1e-12 {
+        return Err("positions 0 degrees apart do not fix the plane of the transfer".to_string());
+    }
+    let geometry = Geometry {
+        mu,
+        r1: radius1,
+        r2: radius2,
+        a,
+    };
+
+    let roots = if revolutions == 0 {
+        // hyperbolas run to psi far below zero for short flights
+        let mut low = -4.0 * PI * PI;
+        while geometry.time(low).is_some_and(|(_, time)| time > tof) {
+            low *= 2.0;
+            if low < -1e6 {
+                return Err("no transfer arc for this time of flight".to_string());
+            }
+        }
+        vec![geometry.bisect(low, 4.0 * PI * PI, tof, true)]
+    } else {
+        let n = revolutions as f64;
+        let (low, high) = ((2.0 * PI * n).powi(2), (2.0 * PI * (n + 1.0)).powi(2));
+        // the time is infinite at both ends and has a single minimum between
+        let time = |psi: f64| geometry.time(psi).map_or(f64::INFINITY, |(_, time)| time);
+        let ratio = (5f64.sqrt() - 1.0) / 2.0;
+        let (mut left, mut right) = (low, high);
+        for _ in 0..ITERATIONS {
+            let inner_left = right - ratio * (right - left);
+            let inner_right = left + ratio * (right - left);
+            if time(inner_left) < time(inner_right) {
+                right = inner_right;
+            } else {
+                left = inner_left;
+            }
+        }
+        let minimum = (left + right) / 2.0;
+        if time(minimum) > tof {
+            return Err(format!(
+                "the time of flight is shorter than the {:.1} s minimum for {revolutions} revolutions",
+                time(minimum)
+            ));
+        }
+        vec![
+            geometry.bisect(low, minimum, tof, false),
+            geometry.bisect(minimum, high, tof, true),
+        ]
+    };
+
+    roots
+        .into_iter()
+        .map(|psi| {
+            let (y, _) = geometry
+                .time(psi)
+                .ok_or("no transfer arc for this time of flight")?;
+            let f = 1.0 - y / radius1;
+            let g = a * (y / mu).sqrt();
+            let g_dot = 1.0 - y / radius2;
+            Ok(Solution {
+                revolutions,
+                departure: [0, 1, 2].map(|i| (r2[i] - f * r1[i]) / g),
+                arrival: [0, 1, 2].map(|i| (g_dot * r2[i] - r1[i]) / g),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{elements::KeplerianElements, propagate, EARTH_MU};
+
+    #[test]
+    fn test_curtis_example() {
+        // Curtis, "Orbital Mechanics for Engineering Students", example 5.2
+        let r1 = [5_000e3, 10_000e3, 2_100e3];
+        let r2 = [-14_600e3, 2_500e3, 7_000e3];
+        let solutions = solve(EARTH_MU, r1, r2, 3_600.0, 0, Direction::Prograde).unwrap();
+        assert_eq!(solutions.len(), 1);
+        let expected = [-5_992.5, 1_925.4, 3_245.6];
+        for (computed, expected) in solutions[0].departure.iter().zip(expected) {
+            assert!((computed - expected).abs() < 1.0);
+        }
+        let expected = [-3_312.5, -4_196.6, -385.29];
+        for (computed, expected) in solutions[0].arrival.iter().zip(expected) {
+            assert!((computed - expected).abs() < 1.0);
+        }
+    }
+
+    #[test]
+    fn test_arcs_reach_the_target() {
+        let r1 = [7_000e3, 0.0, 0.0];
+        let r2 = [-5_000e3, 8_660e3, 1_000e3];
+        let reaches = |solution: &Solution, tof: f64| {
+            let elements = KeplerianElements::from_state(
+                EARTH_MU,
+                &crate::elements::StateVector {
+                    position: r1,
+                    velocity: solution.departure,
+                },
+            );
+            let end = propagate::kepler(EARTH_MU, &elements, tof).unwrap();
+            (0..3).all(|i| (end.position[i] - r2[i]).abs() < 10.0)
+        };
+
+        for direction in [Direction::Prograde, Direction::Retrograde] {
+            let solutions = solve(EARTH_MU, r1, r2, 2_000.0, 0, direction).unwrap();
+            assert!(reaches(&solutions[0], 2_000.0));
+        }
+        // fast enough to need a hyperbola
+        let solutions = solve(EARTH_MU, r1, r2, 600.0, 0, Direction::Prograde).unwrap();
+        assert!(reaches(&solutions[0], 600.0));
+
+        let solutions = solve(EARTH_MU, r1, r2, 30_000.0, 1, Direction::Prograde).unwrap();
+        assert_eq!(solutions.len(), 2);
+        assert_ne!(solutions[0].departure, solutions[1].departure);
+        assert!(solutions.iter().all(|solution| reaches(solution, 30_000.0)));
+        assert!(solve(EARTH_MU, r1, r2, 30_000.0, 5, Direction::Prograde).is_err());
+    }
+
+    #[test]
+    fn test_parallel_positions() {
+        let r1 = [7_000e3, 0.0, 0.0];
+        for r2 in [r1, [9_000e3, 0.0, 0.0], [-9_000e3, 0.0, 0.0]] {
+            assert!(solve(EARTH_MU, r1, r2, 2_000.0, 0, Direction::Prograde).is_err());
+            assert!(solve(EARTH_MU, r1, r2, 9_000.0, 1, Direction::Prograde).is_err());
+        }
+    }
+}
@@ -0,0 +1,1055 @@
+//! Two-body orbital mechanics for quick mission-design estimates.
+//!
+//! The top level holds the core types and closed-form relations (period,
+//! circular and escape velocity, Hohmann transfers). Modules add drag decay,
+//! eclipses, thermal and power environments, launch dispersions, constellation
+//! phasing and exporters for plots, diagrams and 3D scenes. The closed-form
+//! relations take and return dimensioned SI quantities from [`dimension`];
+//! altitudes are plain numbers in km.
+//!
+//! ```
+//! use orbit::{calculate_orbital_period, dimension::Meters, Body};
+//!
+//! let earth = Body::earth();
+//! let period = calculate_orbital_period(earth.mu, earth.radius + Meters::from_km(400.0));
+//! assert!((period.minutes() - 92.6).abs() < 0.1);
+//! ```
+
+use std::{f64::consts::PI, rc::Rc};
+
+use dimension::{GravitationalParameter, Kilograms, Meters, MetersPerSecond, Seconds};
+
+pub mod animate;
+pub mod anomaly;
+pub mod atmosphere;
+pub mod beta;
+pub mod bodies;
+pub mod ccsds;
+pub mod config;
+pub mod constellation;
+pub mod coverage;
+pub mod csv;
+pub mod decay;
+pub mod determine;
+pub mod diagram;
+pub mod dimension;
+pub mod dispersion;
+pub mod eclipse;
+pub mod elements;
+pub mod ephemeris;
+pub mod export;
+#[cfg(feature = "fetch")]
+pub mod fetch;
+pub mod format;
+pub mod frames;
+pub mod geomagnetic;
+pub mod gltf;
+pub mod groundtrack;
+pub mod habitable;
+pub mod interplanetary;
+pub mod lagrange;
+pub mod lambert;
+pub mod maneuver;
+pub mod passes;
+pub mod phase;
+pub mod plot;
+pub mod porkchop;
+pub mod power;
+pub mod propagate;
+pub mod propagator;
+pub mod quantity;
+pub mod quiz;
+pub mod radiation;
+pub mod random;
+pub mod rendezvous;
+pub mod repeat;
+pub mod rocket;
+pub mod sample;
+pub mod satellites;
+pub mod sensitivity;
+pub mod sweep;
+pub mod table;
+pub mod theme;
+pub mod thermal;
+pub mod time;
+pub mod tle;
+pub mod units;
+pub mod validate;
+
+/// Newtonian constant of gravitation, N*m^2*kg^-2 (CODATA 2018).
+pub const GRAVITATIONAL_CONSTANT: f64 = 6.67430e-11;
+/// Earth mass, kg.
+pub const EARTH_MASS: f64 = 5.9722e24;
+/// Jupiter mass, kg.
+pub const JUPITER_MASS: f64 = 1.89813e27;
+/// Geocentric gravitational constant, m^3*s^-2 (IERS 2010).
+pub const EARTH_MU: f64 = 3.986004418e14;
+/// Earth equatorial radius, m.
+pub const EARTH_RADIUS: f64 = 6.3781e6;
+/// Earth rotation rate, rad/s (IERS 2010).
+pub const EARTH_ROTATION_RATE: f64 = 7.292_115e-5;
+/// Earth second zonal harmonic, unnormalized (EGM2008).
+pub const EARTH_J2: f64 = 1.082_626_68e-3;
+/// Earth flattening (WGS-84).
+pub const EARTH_FLATTENING: f64 = 1.0 / 298.257_223_563;
+
+/// Period in s of an orbit with semi-major axis `semi_major_axis` (m) around
+/// a body with gravitational parameter `mu` (m^3*s^-2).
+pub fn calculate_orbital_period(mu: GravitationalParameter, semi_major_axis: Meters) -> Seconds {
+    Seconds(2.0 * PI * ((semi_major_axis.0.powi(3) / mu.0).sqrt()))
+}
+
+/// Semi-major axis in m of an orbit with period `period` (s), inverting
+/// Kepler's third law.
+pub fn calculate_semi_major_axis(mu: GravitationalParameter, period: Seconds) -> Meters {
+    Meters((mu.0 * (period.0 / (2.0 * PI)).powi(2)).cbrt())
+}
+
+/// Parse a period in s, with an optional s, min, hr or day suffix, or sday
+/// for Earth sidereal days.
+pub fn parse_period(input: &str) -> Result<f64, String> {
+    let period = parse_seconds(input)?;
+    if period > 0.0 {
+        Ok(period)
+    } else {
+        Err("period must be positive".to_string())
+    }
+}
+
+/// Parse a time of flight in s, with the same suffixes as a period.
+pub fn parse_time_of_flight(input: &str) -> Result<f64, String> {
+    let tof = parse_seconds(input)?;
+    if tof > 0.0 {
+        Ok(tof)
+    } else {
+        Err("time of flight must be positive".to_string())
+    }
+}
+
+fn parse_seconds(input: &str) -> Result<f64, String> {
+    quantity::parse(
+        input,
+        &[
+            ("s", 1.0),
+            ("min", 60.0),
+            ("hr", 3600.0),
+            ("h", 3600.0),
+            ("day", 86400.0),
+            ("d", 86400.0),
+            ("sday", 86_164.090_5),
+        ],
+    )
+}
+
+/// Parse a mass in kg, with an optional kg, Mearth, Mjup or Msun suffix.
+pub fn parse_mass(input: &str) -> Result<f64, String> {
+    quantity::parse(
+        input,
+        &[
+            ("kg", 1.0),
+            ("Mearth", EARTH_MASS),
+            ("Mjup", JUPITER_MASS),
+            ("Msun", habitable::SOLAR_MASS),
+        ],
+    )
+}
+
+/// Parse a length in km, with an optional m, km, mi (statute miles) or nmi
+/// (nautical miles) suffix. Converted lengths are rounded to the millimetre,
+/// so that 22236mi reads back as 35785.373184 km.
+pub fn parse_length(input: &str) -> Result<f64, String> {
+    if let Ok(km) = input.trim().parse::<f64>() {
+        return Ok(km);
+    }
+    let km = quantity::parse(
+        input,
+        &[("m", 1e-3), ("km", 1.0), ("mi", 1.609_344), ("nmi", 1.852)],
+    )?;
+    Ok((km * 1e6).round() / 1e6)
+}
+
+/// Time in s between successive alignments of two objects orbiting the same
+/// body with periods `first` and `second` (s), or `None` for equal periods,
+/// which never change their relative position.
+pub fn calculate_synodic_period(first: Seconds, second: Seconds) -> Option<Seconds> {
+    let relative = (1.0 / first.0 - 1.0 / second.0).abs();
+    (relative > 0.0).then(|| Seconds(1.0 / relative))
+}
+
+/// Speed in m/s of a circular orbit of radius `semi_major_axis` (m).
+pub fn calculate_circular_orbital_velocity(
+    mu: GravitationalParameter,
+    semi_major_axis: Meters,
+) -> MetersPerSecond {
+    MetersPerSecond((mu.0 / semi_major_axis.0).sqrt())
+}
+
+/// Speed in m/s needed to escape from a distance `radius` (m).
+pub fn calculate_escape_velocity(mu: GravitationalParameter, radius: Meters) -> MetersPerSecond {
+    MetersPerSecond((2.0 * mu.0 / radius.0).sqrt())
+}
+
+/// Speed in m/s at distance `radius` (m) on a hyperbola with excess speed
+/// `v_infinity` (m/s); the square of the excess speed is C3.
+pub fn calculate_hyperbolic_velocity(
+    mu: GravitationalParameter,
+    radius: Meters,
+    v_infinity: MetersPerSecond,
+) -> MetersPerSecond {
+    MetersPerSecond((v_infinity.0.powi(2) + 2.0 * mu.0 / radius.0).sqrt())
+}
+
+/// Radius in m of the sphere of influence (Laplace) of a body with
+/// gravitational parameter `mu` orbiting a parent with `parent_mu` at
+/// `semi_major_axis` (m), inside which the body's gravity dominates.
+pub fn calculate_sphere_of_influence(
+    semi_major_axis: Meters,
+    mu: GravitationalParameter,
+    parent_mu: GravitationalParameter,
+) -> Meters {
+    semi_major_axis * (mu / parent_mu).powf(0.4)
+}
+
+/// Hill radius in m of a body with gravitational parameter `mu` on a near
+/// circular orbit of `semi_major_axis` (m) around a parent with `parent_mu`,
+/// within which it can hold satellites against the parent's tides.
+pub fn calculate_hill_radius(
+    semi_major_axis: Meters,
+    mu: GravitationalParameter,
+    parent_mu: GravitationalParameter,
+) -> Meters {
+    semi_major_axis * (mu / (3.0 * parent_mu)).cbrt()
+}
+
+/// Secular drift of the orbit plane and apsides caused by oblateness.
+#[derive(Debug, Clone, Copy)]
+pub struct J2Rates {
+    pub nodal_regression: f64, // deg/day, rate of change of RAAN
+    pub apsidal_rotation: f64, // deg/day, rate of change of argument of periapsis
+}
+
+/// First-order secular J2 rates for an orbit with semi-major axis
+/// `semi_major_axis` (m), eccentricity and inclination (deg) around a body
+/// of radius `radius` (m).
+pub fn calculate_j2_rates(
+    mu: GravitationalParameter,
+    radius: Meters,
+    j2: f64,
+    semi_major_axis: Meters,
+    eccentricity: f64,
+    inclination: f64,
+) -> J2Rates {
+    let mean_motion = (mu.0 / semi_major_axis.0.powi(3)).sqrt();
+    let semi_latus_rectum = semi_major_axis * (1.0 - eccentricity.powi(2));
+    let factor = 1.5 * mean_motion * j2 * (radius / semi_latus_rectum).powi(2);
+    let cos_i = inclination.to_radians().cos();
+    let per_day = |rate: f64| rate.to_degrees() * 86_400.0;
+
+    J2Rates {
+        nodal_regression: per_day(-factor * cos_i),
+        apsidal_rotation: per_day(factor * (2.5 * cos_i.powi(2) - 0.5)),
+    }
+}
+
+/// Delta-v in m/s to turn the orbit plane by `angle` (deg) at speed
+/// `velocity` (m/s) without changing the speed.
+pub fn calculate_plane_change(velocity: MetersPerSecond, angle: f64) -> MetersPerSecond {
+    2.0 * velocity * (angle.to_radians() / 2.0).sin().abs()
+}
+
+/// Delta-v in m/s of a single burn that changes speed from `from` to `to`
+/// (m/s) while turning the orbit plane by `angle` (deg), by the law of
+/// cosines.
+pub fn calculate_combined_burn(
+    from: MetersPerSecond,
+    to: MetersPerSecond,
+    angle: f64,
+) -> MetersPerSecond {
+    let (from, to) = (from.0, to.0);
+    MetersPerSecond(
+        (from.powi(2) + to.powi(2) - 2.0 * from * to * angle.to_radians().cos())
+            .max(0.0)
+            .sqrt(),
+    )
+}
+
+/// Burns and coast time of a two-impulse transfer between coplanar circular
+/// orbits.
+pub struct HohmannTransfer {
+    pub first_burn: MetersPerSecond,
+    pub second_burn: MetersPerSecond,
+    pub transfer_time: Seconds,
+}
+
+impl HohmannTransfer {
+    /// Total delta-v.
+    pub fn total(&self) -> MetersPerSecond {
+        self.first_burn + self.second_burn
+    }
+}
+
+/// Speed in m/s at distance `radius` (m) on an orbit with semi-major axis
+/// `semi_major_axis` (m), from the vis-viva equation.
+pub fn calculate_vis_viva_velocity(
+    mu: GravitationalParameter,
+    radius: Meters,
+    semi_major_axis: Meters,
+) -> MetersPerSecond {
+    MetersPerSecond((mu.0 * (2.0 / radius.0 - 1.0 / semi_major_axis.0)).sqrt())
+}
+
+/// Specific orbital energy in J/kg (m^2/s^2) of an orbit with semi-major
+/// axis `semi_major_axis` (m), negative for bound orbits.
+pub fn calculate_specific_energy(mu: GravitationalParameter, semi_major_axis: Meters) -> f64 {
+    -mu.0 / (2.0 * semi_major_axis.0)
+}
+
+/// Specific angular momentum in m^2/s of an orbit with semi-major axis
+/// `semi_major_axis` (m) and eccentricity below 1.
+pub fn calculate_specific_angular_momentum(
+    mu: GravitationalParameter,
+    semi_major_axis: Meters,
+    eccentricity: f64,
+) -> f64 {
+    (mu.0 * semi_major_axis.0 * (1.0 - eccentricity.powi(2))).sqrt()
+}
+
+/// Magnitude in degrees of the flight-path angle, between the velocity and
+/// the local horizontal, at distance `radius` (m) moving at `velocity` on an
+/// orbit with specific angular momentum `angular_momentum` (m^2/s).
+pub fn calculate_flight_path_angle(
+    angular_momentum: f64,
+    radius: Meters,
+    velocity: MetersPerSecond,
+) -> f64 {
+    (angular_momentum / (radius.0 * velocity.0))
+        .clamp(-1.0, 1.0)
+        .acos()
+        .to_degrees()
+}
+
+/// A point on an elliptical orbit, all in SI units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VisVivaPoint {
+    pub semi_major_axis: Meters,
+    pub radius: Meters,
+    pub eccentricity: f64,
+    pub velocity: MetersPerSecond,
+    pub energy: f64,
+    pub angular_momentum: f64,
+    pub flight_path_angle: f64, // deg, magnitude; positive climbing away from periapsis
+}
+
+/// The point at distance `radius` (m) on an orbit with semi-major axis
+/// `semi_major_axis` (m), and `eccentricity` or else the least eccentric
+/// orbit through the radius, which has an apsis there. `None` unless the
+/// orbit is an ellipse that reaches the radius.
+pub fn calculate_vis_viva_point(
+    mu: GravitationalParameter,
+    semi_major_axis: Meters,
+    radius: Meters,
+    eccentricity: Option<f64>,
+) -> Option<VisVivaPoint> {
+    let eccentricity = eccentricity.unwrap_or((1.0 - radius / semi_major_axis).abs());
+    let valid = semi_major_axis > Meters(0.0)
+        && radius > Meters(0.0)
+        && (0.0..1.0).contains(&eccentricity)
+        && radius >= semi_major_axis * (1.0 - eccentricity) * (1.0 - 1e-12)
+        && radius <= semi_major_axis * (1.0 + eccentricity) * (1.0 + 1e-12);
+    if !valid {
+        return None;
+    }
+    let velocity = calculate_vis_viva_velocity(mu, radius, semi_major_axis);
+    let angular_momentum = calculate_specific_angular_momentum(mu, semi_major_axis, eccentricity);
+    Some(VisVivaPoint {
+        semi_major_axis,
+        radius,
+        eccentricity,
+        velocity,
+        energy: calculate_specific_energy(mu, semi_major_axis),
+        angular_momentum,
+        flight_path_angle: calculate_flight_path_angle(angular_momentum, radius, velocity),
+    })
+}
+
+/// Hohmann transfer between circular orbits of radii `from_radius` and
+/// `to_radius` (m), in either direction.
+pub fn calculate_hohmann_transfer(
+    mu: GravitationalParameter,
+    from_radius: Meters,
+    to_radius: Meters,
+) -> HohmannTransfer {
+    let transfer_axis = (from_radius + to_radius) / 2.0;
+    let first_burn = calculate_circular_orbital_velocity(mu, from_radius)
+        * ((to_radius / transfer_axis).sqrt() - 1.0);
+    let second_burn = calculate_circular_orbital_velocity(mu, to_radius)
+        * (1.0 - (from_radius / transfer_axis).sqrt());
+
+    HohmannTransfer {
+        first_burn: first_burn.abs(),
+        second_burn: second_burn.abs(),
+        transfer_time: calculate_orbital_period(mu, transfer_axis) / 2.0,
+    }
+}
+
+/// Burns and coast time of a three-impulse bi-elliptic transfer between
+/// coplanar circular orbits.
+pub struct BiellipticTransfer {
+    pub first_burn: MetersPerSecond,
+    pub second_burn: MetersPerSecond,
+    pub third_burn: MetersPerSecond,
+    pub transfer_time: Seconds,
+}
+
+impl BiellipticTransfer {
+    /// Total delta-v.
+    pub fn total(&self) -> MetersPerSecond {
+        self.first_burn + self.second_burn + self.third_burn
+    }
+}
+
+/// Bi-elliptic transfer from `from_radius` to `to_radius` (m) through an
+/// intermediate apoapsis at `apoapsis_radius` (m), which should be at or
+/// beyond both orbits.
+pub fn calculate_bielliptic_transfer(
+    mu: GravitationalParameter,
+    from_radius: Meters,
+    to_radius: Meters,
+    apoapsis_radius: Meters,
+) -> BiellipticTransfer {
+    let first_axis = (from_radius + apoapsis_radius) / 2.0;
+    let second_axis = (to_radius + apoapsis_radius) / 2.0;
+    let first_burn = calculate_vis_viva_velocity(mu, from_radius, first_axis)
+        - calculate_circular_orbital_velocity(mu, from_radius);
+    let second_burn = calculate_vis_viva_velocity(mu, apoapsis_radius, second_axis)
+        - calculate_vis_viva_velocity(mu, apoapsis_radius, first_axis);
+    let third_burn = calculate_vis_viva_velocity(mu, to_radius, second_axis)
+        - calculate_circular_orbital_velocity(mu, to_radius);
+
+    BiellipticTransfer {
+        first_burn: first_burn.abs(),
+        second_burn: second_burn.abs(),
+        third_burn: third_burn.abs(),
+        transfer_time: (calculate_orbital_period(mu, first_axis)
+            + calculate_orbital_period(mu, second_axis))
+            / 2.0,
+    }
+}
+
+// Bi-elliptic transfers cost less than Hohmann for some apoapsis once the
+// final radius exceeds about 11.94 times the initial one, and for every
+// apoapsis beyond the final orbit past about 15.58 (Vallado, section 6.3).
+const BIELLIPTIC_MIN_RATIO: f64 = 11.94;
+const BIELLIPTIC_ALWAYS_RATIO: f64 = 15.58;
+
+/// Whether a bi-elliptic transfer can beat Hohmann between two circular
+/// orbits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BiellipticAdvantage {
+    /// Hohmann is cheaper whatever the apoapsis
+    Never,
+    /// Bi-elliptic is cheaper for a high enough apoapsis
+    Depends,
+    /// Bi-elliptic is cheaper for any apoapsis beyond the final orbit
+    Always,
+}
+
+/// Where the ratio of the radii `from_radius` and `to_radius` (m) falls
+/// between the Hohmann and bi-elliptic regimes, in either direction.
+pub fn calculate_bielliptic_advantage(
+    from_radius: Meters,
+    to_radius: Meters,
+) -> BiellipticAdvantage {
+    let ratio = from_radius.max(to_radius) / from_radius.min(to_radius);
+    if ratio < BIELLIPTIC_MIN_RATIO {
+        BiellipticAdvantage::Never
+    } else if ratio < BIELLIPTIC_ALWAYS_RATIO {
+        BiellipticAdvantage::Depends
+    } else {
+        BiellipticAdvantage::Always
+    }
+}
+
+/// A central body, described by its standard gravitational parameter `mu`
+/// rather than its mass: orbit determination measures mu directly, to ten or
+/// more digits, while G and so any mass are only known to about 2e-5.
+#[derive(Debug, Clone)]
+pub struct Body {
+    pub mu: GravitationalParameter,
+    pub radius: Meters,
+    /// Sidereal rotation period, negative for retrograde rotation.
+    pub rotation_period: Option<Seconds>,
+    /// Second zonal harmonic, referenced to `radius`.
+    pub j2: Option<f64>,
+    /// Flattening of the reference ellipsoid, (equatorial - polar) /
+    /// equatorial radius; 0 for a sphere. `radius` is the equatorial radius.
+    pub flattening: f64,
+}
+
+impl Body {
+    pub fn earth() -> Self {
+        Body {
+            mu: GravitationalParameter(EARTH_MU),
+            radius: Meters(EARTH_RADIUS),
+            rotation_period: Some(Seconds(2.0 * PI / EARTH_ROTATION_RATE)),
+            j2: Some(EARTH_J2),
+            flattening: EARTH_FLATTENING,
+        }
+    }
+
+    /// A spherical, non-rotating body given by mu and radius.
+    pub fn from_mu(mu: GravitationalParameter, radius: Meters) -> Self {
+        Body {
+            mu,
+            radius,
+            rotation_period: None,
+            j2: None,
+            flattening: 0.0,
+        }
+    }
+
+    /// A body known only by its mass and radius, using mu = G * M.
+    pub fn from_mass(mass: Kilograms, radius: Meters) -> Self {
+        Body::from_mu(GravitationalParameter::from_mass(mass), radius)
+    }
+
+    /// A body known by its surface gravity (m/s^2) and radius, using
+    /// g = mu / r^2.
+    pub fn from_surface_gravity(gravity: f64, radius: Meters) -> Self {
+        Body::from_mu(GravitationalParameter(gravity * radius.0.powi(2)), radius)
+    }
+
+    /// Mass derived from mu, carrying the uncertainty of G.
+    pub fn mass(&self) -> Kilograms {
+        self.mu.mass()
+    }
+
+    /// Polar radius of the reference ellipsoid.
+    pub fn polar_radius(&self) -> Meters {
+        self.radius * (1.0 - self.flattening)
+    }
+
+    /// Distance from the centre to the ellipsoid surface at a geodetic
+    /// latitude in degrees.
+    pub fn surface_radius(&self, latitude: f64) -> Meters {
+        let (a, b) = (self.radius.0, self.polar_radius().0);
+        let (sin, cos) = latitude.to_radians().sin_cos();
+        Meters(
+            (((a * a * cos).powi(2) + (b * b * sin).powi(2))
+                / ((a * cos).powi(2) + (b * sin).powi(2)))
+            .sqrt(),
+        )
+    }
+
+    /// The same body as a sphere of its equatorial radius.
+    pub fn spherical(self) -> Self {
+        Body {
+            flattening: 0.0,
+            ..self
+        }
+    }
+
+    /// Altitude in km of the circular orbit whose period matches the
+    /// rotation, or `None` without a rotation period or when that orbit
+    /// would lie below the surface. Slow rotators put it beyond the body's
+    /// sphere of influence, where it exists only in the two-body model.
+    pub fn synchronous_altitude(&self) -> Option<f64> {
+        let axis = calculate_semi_major_axis(self.mu, self.rotation_period?.abs());
+        (axis > self.radius).then(|| (axis - self.radius).km())
+    }
+}
+
+/// Altitude of a circular orbit, a band of them, or the apsides of an
+/// elliptical orbit, in km above the surface.
+#[derive(Debug, Clone, Copy)]
+pub enum Altitude {
+    Single { value: f64 },
+    Range { max: f64, min: f64 },
+    Elliptical { periapsis: f64, apoapsis: f64 },
+}
+
+impl Altitude {
+    /// Elliptical orbit from a semi-major axis in km, measured from the
+    /// body's centre, and an eccentricity. Apsis altitudes are rounded to
+    /// the nearest metre.
+    pub fn from_elements(body: &Body, semi_major_axis: f64, eccentricity: f64) -> Self {
+        let altitude = |radius: f64| ((radius - body.radius.km()) * 1000.0).round() / 1000.0;
+        Altitude::Elliptical {
+            periapsis: altitude(semi_major_axis * (1.0 - eccentricity)),
+            apoapsis: altitude(semi_major_axis * (1.0 + eccentricity)),
+        }
+    }
+
+    /// Lowest and highest altitude in km; equal for a single altitude.
+    pub fn bounds(&self) -> (f64, f64) {
+        match *self {
+            Altitude::Single { value } => (value, value),
+            Altitude::Range { max, min } => (min, max),
+            Altitude::Elliptical {
+                periapsis,
+                apoapsis,
+            } => (periapsis, apoapsis),
+        }
+    }
+}
+
+/// A named orbit, or class of orbits, around a body.
+pub struct Orbit {
+    pub name: String,
+    pub altitude: Altitude,
+    /// Inclination in degrees, when the orbit's plane is part of its
+    /// definition; the eccentricity follows from the altitude.
+    pub inclination: Option<f64>,
+    pub body: Rc<Body>,
+}
+
+impl Orbit {
+    /// Distance from the body's centre at `altitude` km.
+    pub fn radius(&self, altitude: f64) -> Meters {
+        self.body.radius + Meters::from_km(altitude)
+    }
+
+    /// Periods at the lowest and highest altitude. Both are the same for a
+    /// single or elliptical orbit.
+    pub fn periods(&self) -> (Seconds, Seconds) {
+        let (min, max) = self.altitude.bounds();
+        match self.altitude {
+            Altitude::Elliptical { .. } => {
+                let period = calculate_orbital_period(
+                    self.body.mu,
+                    (self.radius(min) + self.radius(max)) / 2.0,
+                );
+                (period, period)
+            }
+            _ => (
+                calculate_orbital_period(self.body.mu, self.radius(min)),
+                calculate_orbital_period(self.body.mu, self.radius(max)),
+            ),
+        }
+    }
+
+    /// Velocities at the lowest and highest altitude: circular velocities,
+    /// or the periapsis and apoapsis speeds of an ellipse.
+    pub fn velocities(&self) -> (MetersPerSecond, MetersPerSecond) {
+        let (min, max) = self.altitude.bounds();
+        match self.altitude {
+            Altitude::Elliptical { .. } => {
+                let axis = (self.radius(min) + self.radius(max)) / 2.0;
+                (
+                    calculate_vis_viva_velocity(self.body.mu, self.radius(min), axis),
+                    calculate_vis_viva_velocity(self.body.mu, self.radius(max), axis),
+                )
+            }
+            _ => (
+                calculate_circular_orbital_velocity(self.body.mu, self.radius(min)),
+                calculate_circular_orbital_velocity(self.body.mu, self.radius(max)),
+            ),
+        }
+    }
+
+    /// Escape velocities at the lowest and highest altitude.
+    pub fn escape_velocities(&self) -> (MetersPerSecond, MetersPerSecond) {
+        let (min, max) = self.altitude.bounds();
+        (
+            calculate_escape_velocity(self.body.mu, self.radius(min)),
+            calculate_escape_velocity(self.body.mu, self.radius(max)),
+        )
+    }
+
+    /// Tangential burns at the lowest and highest altitude that leave on a
+    /// hyperbola with excess speed `v_infinity`.
+    pub fn departure_burns(
+        &self,
+        v_infinity: MetersPerSecond,
+    ) -> (MetersPerSecond, MetersPerSecond) {
+        let (min, max) = self.altitude.bounds();
+        let (low, high) = self.velocities();
+        (
+            calculate_hyperbolic_velocity(self.body.mu, self.radius(min), v_infinity) - low,
+            calculate_hyperbolic_velocity(self.body.mu, self.radius(max), v_infinity) - high,
+        )
+    }
+
+    /// J2 rates at the lowest and highest altitude for inclination
+    /// `inclination` (deg), or `None` when the body's J2 is unknown.
+    pub fn j2_rates(&self, inclination: f64) -> Option<(J2Rates, J2Rates)> {
+        let j2 = self.body.j2?;
+        let (min, max) = self.altitude.bounds();
+        let rates = |semi_major_axis: Meters| {
+            calculate_j2_rates(
+                self.body.mu,
+                self.body.radius,
+                j2,
+                semi_major_axis,
+                self.eccentricity(),
+                inclination,
+            )
+        };
+        Some(match self.altitude {
+            Altitude::Elliptical { .. } => {
+                let rates = rates((self.radius(min) + self.radius(max)) / 2.0);
+                (rates, rates)
+            }
+            _ => (rates(self.radius(min)), rates(self.radius(max))),
+        })
+    }
+
+    /// Zero for circular orbits.
+    pub fn eccentricity(&self) -> f64 {
+        match self.altitude {
+            Altitude::Elliptical {
+                periapsis,
+                apoapsis,
+            } => {
+                let (rp, ra) = (self.radius(periapsis), self.radius(apoapsis));
+                (ra - rp) / (ra + rp)
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// Lowest and highest altitude in km above the poles of the body's
+    /// ellipsoid, for altitudes measured from the equatorial radius.
+    pub fn polar_altitudes(&self) -> (f64, f64) {
+        let offset = (self.body.radius - self.body.polar_radius()).km();
+        let (min, max) = self.altitude.bounds();
+        (min + offset, max + offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EARTH: GravitationalParameter = GravitationalParameter(EARTH_MU);
+
+    #[test]
+    fn test_earth_ellipsoid() {
+        let earth = Body::earth();
+        // WGS-84 polar radius 6356752.3142 m, for a 6378137 m equator
+        let expected = Meters(6_356_752.314_2 * EARTH_RADIUS / 6_378_137.0);
+        assert!((earth.polar_radius() - expected).abs() < Meters(1e-3));
+        assert!((earth.surface_radius(0.0) - earth.radius).abs() < Meters(1e-6));
+        assert!((earth.surface_radius(90.0) - earth.polar_radius()).abs() < Meters(1e-6));
+        let sphere = earth.spherical();
+        assert!((sphere.surface_radius(45.0) - sphere.radius).abs() < Meters(1e-6));
+
+        let orbit = Orbit {
+            name: "LEO".to_string(),
+            altitude: Altitude::Single { value: 500.0 },
+            inclination: None,
+            body: Rc::new(Body::earth()),
+        };
+        let (low, high) = orbit.polar_altitudes();
+        assert!((low - 521.38).abs() < 0.01 && low == high);
+    }
+
+    #[test]
+    fn test_orbital_period() {
+        assert_eq!(
+            calculate_orbital_period(GravitationalParameter(GRAVITATIONAL_CONSTANT), Meters(1.0)),
+            Seconds(769089.7201971824)
+        )
+    }
+
+    #[test]
+    fn test_orbital_velocity() {
+        assert_eq!(
+            calculate_circular_orbital_velocity(
+                GravitationalParameter(GRAVITATIONAL_CONSTANT),
+                Meters(1.0)
+            ),
+            MetersPerSecond(8.169638914909275e-6)
+        )
+    }
+
+    #[test]
+    fn test_escape_velocity() {
+        let velocity = calculate_escape_velocity(EARTH, Meters(6_378_137.0));
+        assert!((velocity.0 - 11_179.875).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_vis_viva() {
+        // at periapsis the velocity is perpendicular to the radius, so
+        // h = r * v, and the energy balances kinetic against potential
+        let (axis, radius) = (Meters(8_000_000.0), Meters(6_778_000.0));
+        let velocity = calculate_vis_viva_velocity(EARTH, radius, axis);
+        let energy = calculate_specific_energy(EARTH, axis);
+        assert!((velocity.0.powi(2) / 2.0 - EARTH_MU / radius.0 - energy).abs() < 1e-6);
+        let momentum = calculate_specific_angular_momentum(EARTH, axis, 1.0 - radius / axis);
+        assert!((momentum / (radius.0 * velocity.0) - 1.0).abs() < 1e-12);
+        // a circle is the special case r = a
+        assert!(
+            (calculate_vis_viva_velocity(EARTH, axis, axis)
+                - calculate_circular_orbital_velocity(EARTH, axis))
+            .abs()
+                < MetersPerSecond(1e-9)
+        );
+    }
+
+    #[test]
+    fn test_vis_viva_point() {
+        let (axis, radius) = (Meters(8_000_000.0), Meters(6_778_000.0));
+        // without an eccentricity the radius is periapsis, where the orbit
+        // is level
+        let apsis = calculate_vis_viva_point(EARTH, axis, radius, None).unwrap();
+        assert!((apsis.eccentricity - 0.15275).abs() < 1e-12);
+        assert!(apsis.flight_path_angle < 1e-6);
+        // at the end of the semi-minor axis, r = a, the angle is asin(e)
+        let point = calculate_vis_viva_point(EARTH, axis, axis, Some(0.2)).unwrap();
+        assert!((point.flight_path_angle - 0.2_f64.asin().to_degrees()).abs() < 1e-9);
+        assert_eq!(point.energy, calculate_specific_energy(EARTH, axis));
+        // the radius must lie between the apsides of a bound orbit
+        assert!(calculate_vis_viva_point(EARTH, axis, radius, Some(0.1)).is_none());
+        assert!(calculate_vis_viva_point(EARTH, axis, axis, Some(1.0)).is_none());
+        assert!(calculate_vis_viva_point(EARTH, Meters(-8e6), radius, None).is_none());
+    }
+
+    #[test]
+    fn test_departure_burn() {
+        // Curtis, Example 8.3: 300 km parking orbit, 2.943 km/s excess speed
+        // for a Hohmann transfer to Mars
+        let orbit = Orbit {
+            name: "parking".to_string(),
+            altitude: Altitude::Single { value: 300.0 },
+            inclination: None,
+            body: Rc::new(Body::earth()),
+        };
+        let (escape, _) = orbit.escape_velocities();
+        assert!((escape.0 - 10_926.0).abs() < 5.0);
+        let (burn, _) = orbit.departure_burns(MetersPerSecond(2_943.0));
+        assert!((burn.0 - 3_590.0).abs() < 5.0);
+    }
+
+    #[test]
+    fn test_j2_rates() {
+        let earth = Body::earth();
+        // a sun-synchronous orbit at 800 km needs about 98.6 deg to precess
+        // eastward once per year
+        let rates = calculate_j2_rates(
+            EARTH,
+            earth.radius,
+            EARTH_J2,
+            earth.radius + Meters::from_km(800.0),
+            0.0,
+            98.6,
+        );
+        assert!((rates.nodal_regression - 0.9856).abs() < 0.01);
+
+        // Molniya orbits sit at the critical inclination, where the apsides
+        // stay put
+        let rates = calculate_j2_rates(
+            EARTH,
+            earth.radius,
+            EARTH_J2,
+            Meters::from_km(26_600.0),
+            0.74,
+            63.435,
+        );
+        assert!(rates.apsidal_rotation.abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_synodic_period() {
+        // Earth and Mars line up every 779.9 days
+        let (earth, mars) = (Seconds::from_days(365.256), Seconds::from_days(686.980));
+        let synodic = calculate_synodic_period(earth, mars).unwrap();
+        assert!((synodic.days() - 779.9).abs() < 0.1);
+        assert_eq!(calculate_synodic_period(mars, earth), Some(synodic));
+        assert!(calculate_synodic_period(Seconds(5400.0), Seconds(5400.0)).is_none());
+    }
+
+    #[test]
+    fn test_sphere_of_influence() {
+        // Earth's sphere of influence reaches about 925,000 km
+        let radius = calculate_sphere_of_influence(
+            Meters(habitable::ASTRONOMICAL_UNIT),
+            EARTH,
+            GravitationalParameter(habitable::SUN_MU),
+        );
+        assert!((radius.km() - 925_000.0).abs() < 5_000.0);
+    }
+
+    #[test]
+    fn test_plane_change() {
+        // turning by 60 degrees costs the speed itself
+        let speed = MetersPerSecond(7_500.0);
+        assert!((calculate_plane_change(speed, 60.0) - speed).abs() < MetersPerSecond(1e-9));
+        assert_eq!(
+            calculate_plane_change(speed, -10.0),
+            calculate_plane_change(speed, 10.0)
+        );
+    }
+
+    #[test]
+    fn test_hohmann_transfer() {
+        // Vallado, Example 6-1: 191.34 km parking orbit to GEO
+        let transfer = calculate_hohmann_transfer(EARTH, Meters(6_569_472.7), Meters(42_159_480.0));
+        assert!((transfer.first_burn.0 - 2_457.0).abs() < 1.0);
+        assert!((transfer.second_burn.0 - 1_478.0).abs() < 1.0);
+        assert!((transfer.total().0 - 3_935.0).abs() < 1.0);
+        assert!((transfer.transfer_time.hours() - 5.256).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_custom_body() {
+        // Mars: G * 6.4171e23 kg is within 0.1 % of the DE440 value
+        let mars = Body::from_mass(Kilograms(6.4171e23), Meters(3.3895e6));
+        assert!((mars.mu / GravitationalParameter(4.282837e13) - 1.0).abs() < 1e-3);
+
+        let earth = Body::earth();
+        let gravity = EARTH_MU / EARTH_RADIUS.powi(2);
+        let body = Body::from_surface_gravity(gravity, earth.radius);
+        assert!((body.mu / EARTH - 1.0).abs() < 1e-12);
+        assert!((body.mass() / Kilograms(EARTH_MASS) - 1.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_synchronous_altitude() {
+        let geo = Body::earth().synchronous_altitude().unwrap();
+        assert!((geo - 35_786.0).abs() < 0.1);
+        assert!(Body::from_mass(Kilograms(EARTH_MASS), Meters(EARTH_RADIUS))
+            .synchronous_altitude()
+            .is_none());
+    }
+
+    #[test]
+    fn test_semi_major_axis_from_period() {
+        // one sidereal day gives the geostationary radius
+        let period = Seconds(parse_period("1sday").unwrap());
+        let axis = calculate_semi_major_axis(EARTH, period);
+        assert!((axis.0 - 42_164_170.0).abs() < 10.0);
+        let period = Seconds(parse_period("90 min").unwrap());
+        let axis = calculate_semi_major_axis(EARTH, period);
+        assert!((calculate_orbital_period(EARTH, axis) - period).abs() < Seconds(1e-6));
+        assert!(parse_period("-5s").is_err());
+        assert_eq!(parse_period("1.5hr"), Ok(5400.0));
+    }
+
+    #[test]
+    fn test_parse_time_of_flight() {
+        assert_eq!(parse_time_of_flight("2day"), Ok(172_800.0));
+        assert_eq!(
+            parse_time_of_flight("0"),
+            Err("time of flight must be positive".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_mass() {
+        assert_eq!(parse_mass("6.4171e23"), Ok(6.4171e23));
+        assert_eq!(parse_mass("6.4171e23kg"), Ok(6.4171e23));
+        assert_eq!(parse_mass("1Mearth"), Ok(EARTH_MASS));
+        assert_eq!(parse_mass("2 Mjup"), Ok(2.0 * JUPITER_MASS));
+        assert_eq!(parse_mass("0.5Msun"), Ok(0.5 * habitable::SOLAR_MASS));
+        assert!(parse_mass("1Mmars").is_err());
+    }
+
+    #[test]
+    fn test_parse_length() {
+        // bare numbers stay in km
+        assert_eq!(parse_length("400"), Ok(400.0));
+        assert_eq!(parse_length("400km"), Ok(400.0));
+        assert_eq!(parse_length("250 mi"), Ok(402.336));
+        assert_eq!(parse_length("-10km"), Ok(-10.0));
+        assert_eq!(parse_length("1000m"), Ok(1.0));
+        assert_eq!(parse_length("100nmi"), Ok(185.2));
+        assert_eq!(parse_length("22236mi"), Ok(35785.373184));
+        assert!(parse_length("400ft").is_err());
+    }
+
+    #[test]
+    fn test_bielliptic_transfer() {
+        // Vallado, Example 6-2: 191.34 km parking orbit to 376310 km through
+        // an apoapsis at 503873 km altitude
+        let (from, to) = (Meters(6_569_481.1), Meters(382_688_137.0));
+        let transfer = calculate_bielliptic_transfer(EARTH, from, to, Meters(510_251_137.0));
+        assert!((transfer.first_burn.0 - 3_156.2).abs() < 1.0);
+        assert!((transfer.second_burn.0 - 677.2).abs() < 1.0);
+        assert!((transfer.third_burn.0 - 70.4).abs() < 1.0);
+        assert!((transfer.total().0 - 3_903.9).abs() < 1.0);
+        assert!((transfer.transfer_time.hours() - 593.9).abs() < 0.1);
+
+        // Same ratio of radii (about 58) is well past the point where the
+        // bi-elliptic route beats Hohmann
+        let hohmann = calculate_hohmann_transfer(EARTH, from, to);
+        assert!(transfer.total() < hohmann.total());
+    }
+
+    #[test]
+    fn test_bielliptic_advantage() {
+        let from = Meters(7_000_000.0);
+        let cost = |ratio: f64, apoapsis: f64| {
+            let to = from * ratio;
+            calculate_bielliptic_transfer(EARTH, from, to, to * apoapsis).total()
+                - calculate_hohmann_transfer(EARTH, from, to).total()
+        };
+        // below the first ratio even a huge apoapsis loses
+        assert_eq!(
+            calculate_bielliptic_advantage(from, from * 11.5),
+            BiellipticAdvantage::Never
+        );
+        assert!(cost(11.5, 1e3) > MetersPerSecond(0.0));
+        // between the two it takes a high apoapsis to win
+        assert_eq!(
+            calculate_bielliptic_advantage(from * 14.0, from),
+            BiellipticAdvantage::Depends
+        );
+        assert!(cost(14.0, 1.01) > MetersPerSecond(0.0));
+        assert!(cost(14.0, 1e3) < MetersPerSecond(0.0));
+        // past the second any apoapsis beyond the final orbit wins
+        assert_eq!(
+            calculate_bielliptic_advantage(from, from * 16.0),
+            BiellipticAdvantage::Always
+        );
+        assert!(cost(16.0, 1.01) < MetersPerSecond(0.0));
+    }
+
+    #[test]
+    fn test_elliptical_orbit() {
+        // GTO: 250 x 35786 km
+        let earth = Rc::new(Body::earth());
+        let orbit = Orbit {
+            name: "GTO".to_string(),
+            altitude: Altitude::Elliptical {
+                periapsis: 250.0,
+                apoapsis: 35786.0,
+            },
+            inclination: None,
+            body: earth.clone(),
+        };
+        let (perigee, apogee) = orbit.velocities();
+        assert!((perigee.0 - 10_195.0).abs() < 1.0);
+        assert!((apogee.0 - 1_602.6).abs() < 1.0);
+        // angular momentum is conserved between the apsides
+        let rp = earth.radius + Meters::from_km(250.0);
+        let ra = earth.radius + Meters::from_km(35_786.0);
+        let momentum = perigee.0 * rp.0;
+        assert!((momentum - apogee.0 * ra.0).abs() / momentum < 1e-12);
+        assert!((orbit.eccentricity() - 0.7283).abs() < 1e-4);
+        let (period, _) = orbit.periods();
+        assert!((period.hours() - 10.53).abs() < 0.01);
+
+        let same = Altitude::from_elements(&earth, (rp + ra).km() / 2.0, orbit.eccentricity());
+        let (low, high) = same.bounds();
+        assert!((low - 250.0).abs() < 1e-6 && (high - 35786.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_orbit_range_bounds() {
+        let orbit = Orbit {
+            name: "LEO".to_string(),
+            altitude: Altitude::Range {
+                max: 2000.0,
+                min: 450.0,
+            },
+            inclination: None,
+            body: Rc::new(Body::earth()),
+        };
+        let (low, high) = orbit.periods();
+        assert!(low < high);
+        let (fast, slow) = orbit.velocities();
+        assert!(fast > slow);
+    }
+}
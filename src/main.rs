@@ -1,239 +1,6304 @@
-use std::{f64, rc::Rc};
+use std::{
+    f64::consts::PI,
+    fs::File,
+    io::{self, BufWriter, Write},
+    path::{Path, PathBuf},
+    rc::Rc,
+    sync::OnceLock,
+};
 
-use clap::{Parser, Subcommand};
+use clap::{builder::RangedU64ValueParser, ArgGroup, Args, Parser, Subcommand, ValueEnum};
 
-// Constants
-const PI: f64 = 3.14159;
-const GRAVITATIONAL_CONSTANT: f64 = 6.67430e-11; // N*m^2*kg^-2
-const SEPARATOR: &str = &"------------------------------------------------------";
+mod i18n;
 
-fn calculate_orbital_period(mass: f64, semi_major_axis: f64) -> f64 {
-    // mass: kg
-    // semi_major_axis: m
-    2.0 * PI * ((semi_major_axis.powi(3) / (GRAVITATIONAL_CONSTANT * mass)).sqrt())
+use i18n::{t, Lang};
+#[cfg(feature = "fetch")]
+use orbit::fetch;
+use orbit::{
+    animate, anomaly, atmosphere, beta,
+    bodies::{Named, PresetName},
+    calculate_bielliptic_advantage, calculate_bielliptic_transfer,
+    calculate_circular_orbital_velocity, calculate_escape_velocity, calculate_hohmann_transfer,
+    calculate_j2_rates, calculate_orbital_period, calculate_plane_change,
+    calculate_semi_major_axis, calculate_synodic_period, calculate_vis_viva_point, ccsds, config,
+    constellation, coverage, csv, decay, determine, diagram,
+    dimension::{GravitationalParameter, Kilograms, Meters, MetersPerSecond, Seconds},
+    dispersion, eclipse,
+    elements::{KeplerianElements, StateVector},
+    ephemeris, export, format, frames, gltf, groundtrack, habitable, interplanetary, lagrange,
+    lambert, maneuver, passes, phase, plot, porkchop, power, propagate, propagator, quiz,
+    quiz::Topic,
+    radiation,
+    random::Rng,
+    rendezvous, repeat, rocket, sample, satellites, sensitivity, sweep,
+    table::{Align, Table},
+    theme, thermal, time, tle, units, validate, Altitude, BiellipticAdvantage, Body, Orbit,
+    VisVivaPoint, EARTH_ROTATION_RATE, GRAVITATIONAL_CONSTANT,
+};
+
+const SEPARATOR: &str = "------------------------------------------------------";
+
+#[derive(Parser)]
+#[command(author, version, about, long_about = None, arg_required_else_help = true)]
+struct Cli {
+    /// Celestial body to calculate orbits for
+    #[command(subcommand)]
+    command: Option<Commands>,
+
+    /// Output language (defaults to LC_ALL/LANG, then English)
+    #[arg(long, global = true, value_enum)]
+    lang: Option<Lang>,
+
+    /// Units of lengths and speeds in reports; canonical scales to the central body
+    #[arg(long, global = true, value_enum, default_value = "km")]
+    units: units::System,
+
+    /// Decimals of every number in reports, instead of each quantity's default
+    #[arg(long, global = true)]
+    precision: Option<usize>,
+
+    /// Notation of numbers in reports
+    #[arg(long, global = true, value_enum, default_value = "fixed")]
+    notation: format::Notation,
+
+    /// Colour headings, units and warnings; auto colours a terminal unless NO_COLOR is set
+    #[arg(long, global = true, value_enum, default_value = "auto")]
+    color: theme::ColorChoice,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Calculate common satellite orbits for Earth
+    Earth(BodyArgs),
+    /// Calculate common orbits around the Sun
+    Sun(BodyArgs),
+    /// Calculate common orbits around Mercury
+    Mercury(BodyArgs),
+    /// Calculate common orbits around Venus
+    Venus(BodyArgs),
+    /// Calculate common orbits around the Moon
+    Moon(BodyArgs),
+    /// Calculate common orbits around Mars
+    Mars(BodyArgs),
+    /// Calculate common orbits around Jupiter
+    Jupiter(BodyArgs),
+    /// Calculate common orbits around Saturn
+    Saturn(BodyArgs),
+    /// Calculate common orbits around Uranus
+    Uranus(BodyArgs),
+    /// Calculate common orbits around Neptune
+    Neptune(BodyArgs),
+    /// Calculate common orbits around Pluto
+    Pluto(BodyArgs),
+    /// Calculate orbits around a body given by its mass, mu or surface gravity and radius
+    Custom(CustomArgs),
+    /// Calculate orbits around a body defined in ~/.config/orbit/bodies.toml
+    Body(ConfigBodyArgs),
+    /// Check the calculations against embedded reference cases
+    Validate,
+    /// Generate randomized practice problems with a hidden answer key
+    Quiz {
+        #[arg(long, value_enum, default_value = "period")]
+        topic: Topic,
+        /// Number of problems
+        #[arg(long, default_value_t = 10)]
+        count: usize,
+        /// Seed for reproducing a problem set
+        #[arg(long)]
+        seed: Option<u64>,
+        /// Reveal the answer key
+        #[arg(long)]
+        answers: bool,
+    },
+    /// Export evenly spaced positions along a circular Earth orbit
+    Sample(SampleArgs),
+    /// Habitable-zone boundaries and insolation around a star
+    Hab(HabArgs),
+    /// Map launch burnout errors to insertion-orbit errors around Earth
+    Dispersion(DispersionArgs),
+    /// Check natural decay against the 25- and 5-year disposal rules
+    Compliance {
+        /// Circular altitude in km
+        #[arg(long, value_parser = orbit::parse_length)]
+        altitude: f64,
+        /// Ballistic coefficient m / (Cd * A) in kg/m^2
+        #[arg(long)]
+        bc: f64,
+    },
+    /// Annual delta-v to hold a circular Earth orbit against drag
+    Makeup {
+        /// Circular altitude in km
+        #[arg(long, value_parser = orbit::parse_length)]
+        altitude: f64,
+        /// Ballistic coefficient m / (Cd * A) in kg/m^2
+        #[arg(long)]
+        bc: f64,
+        /// Solar activity assumed for the thermosphere
+        #[arg(long, value_enum, default_value = "moderate")]
+        solar: atmosphere::Activity,
+    },
+    /// South Atlantic Anomaly crossings and radiation-belt exposure for a circular Earth orbit
+    Radiation(RadiationArgs),
+    /// Sunlight fraction and orbit-averaged heating for a circular Earth orbit
+    Thermal(ThermalArgs),
+    /// Solar array power and energy per orbit for a circular Earth orbit
+    Power(PowerArgs),
+    /// Chart a quantity against circular Earth altitude as SVG
+    Plot(PlotArgs),
+    /// Draw Earth and selected orbits to scale as SVG
+    Diagram {
+        /// Comma-separated presets (vleo, leo, meo, geo, gto) or altitudes in km
+        #[arg(long, value_delimiter = ',', default_value = "leo,geo,gto")]
+        orbits: Vec<String>,
+        /// SVG file to write
+        #[arg(long)]
+        out: PathBuf,
+    },
+    /// Export orbits and the body sphere as a binary glTF (.glb) scene
+    Gltf(GltfArgs),
+    /// Walker constellation spacing and slot-acquisition drift from one launch
+    Phasing(PhasingArgs),
+    /// Convert between an Earth-centred inertial state vector and Keplerian elements
+    Elements(ElementsArgs),
+    /// Convert between mean, eccentric and true anomaly by Kepler's equation
+    Anomaly(AnomalyArgs),
+    /// Delta-v and time of flight between circular orbits
+    Transfer {
+        #[command(subcommand)]
+        kind: TransferKind,
+    },
+    /// Delta-v of single maneuvers
+    Maneuver {
+        #[command(subcommand)]
+        kind: ManeuverKind,
+    },
+    /// Phase angle and next window for a Hohmann transfer between circular orbits or planets
+    Phase(PhaseArgs),
+    /// Synodic period of two orbits given by their periods, altitudes or planets
+    Synodic(SynodicArgs),
+    /// Transfer arc between two positions in a given time, by Lambert's problem
+    Lambert(LambertArgs),
+    /// Grid of launch energy and arrival speed over departure and arrival dates between planets
+    Porkchop(PorkchopArgs),
+    /// Heliocentric and geocentric position of a planet or the Sun on a date
+    Where(WhereArgs),
+    /// Patched-conic transfer between planets, with the injection and capture burns
+    Interplanetary(InterplanetaryArgs),
+    /// Sensor footprint, share of the surface swept per orbit and revisit time at a latitude
+    Coverage(CoverageArgs),
+    /// Fraction and duration of each circular orbit spent in the body's shadow
+    Eclipse(EclipseArgs),
+    /// Solar beta angle of an Earth orbit on a date or across a date range
+    Beta(BetaArgs),
+    /// Orbits of well-known satellites, or the catalog without a name
+    Sat(SatArgs),
+    /// Download current two-line element sets from CelesTrak
+    #[cfg(feature = "fetch")]
+    Fetch(FetchArgs),
+    /// List or clear the downloaded element sets kept in ~/.cache/orbit/tle
+    #[cfg(feature = "fetch")]
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+    },
+    /// Orbit determination from three Earth-centred inertial positions
+    Determine {
+        #[command(subcommand)]
+        kind: DetermineKind,
+    },
+    /// Relative motion and rendezvous burns near a target satellite
+    Rendezvous {
+        #[command(subcommand)]
+        kind: RendezvousKind,
+    },
+    /// Positions of the five Lagrange points of a two-body system
+    Lagrange {
+        #[arg(value_enum)]
+        system: lagrange::System,
+    },
+    /// Sphere of influence and Hill sphere of a planet or the Moon
+    Soi {
+        #[arg(value_enum)]
+        body: Named,
+    },
+    /// Itemized delta-v budget with a margin for a sequence of maneuvers
+    Budget(BudgetArgs),
+    /// Delta-v of a stage from the rocket equation, or the propellant for a target delta-v
+    Rocket(RocketArgs),
+    /// Summarize two-line element sets read from a file or standard input
+    Tle(TleArgs),
+    /// Propagate two-line element sets with SGP4 to a given UTC time, or elements with kepler
+    Propagate(PropagateArgs),
+    /// Sub-satellite points of element sets or Keplerian elements over a time span
+    Groundtrack(GroundtrackArgs),
+    /// Convert a position between inertial, Earth-fixed and geodetic frames
+    Frame(FrameArgs),
+    /// Time scales, Julian dates and sidereal time
+    Time {
+        #[command(subcommand)]
+        kind: TimeKind,
+    },
+    /// Passes of element sets over a ground station, with AOS, LOS and maximum elevation
+    Passes(PassesArgs),
+    /// Write numbered SVG frames of a satellite moving over one orbit
+    Animate {
+        /// Circular orbit as ALT[:INC[:RAAN]] in km and degrees
+        #[arg(long, value_parser = sample::parse_circular)]
+        orbit: sample::Circular,
+        /// Number of frames over one period
+        #[arg(
+            long,
+            default_value_t = 300,
+            value_parser = RangedU64ValueParser::<usize>::new().range(1..)
+        )]
+        frames: usize,
+        /// Directory for the frames, created if missing
+        #[arg(long)]
+        out: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+enum TransferKind {
+    /// Two-burn transfer along half of an ellipse tangent to both orbits
+    Hohmann(TransferArgs),
+    /// Three-burn transfer through an intermediate apoapsis, compared with Hohmann
+    Bielliptic {
+        #[command(flatten)]
+        transfer: TransferArgs,
+        /// Altitude of the intermediate apoapsis in km, at or beyond both orbits
+        #[arg(long, value_parser = orbit::parse_length)]
+        apogee: f64,
+    },
+}
+
+#[derive(Subcommand)]
+enum ManeuverKind {
+    /// Inclination change of a circular orbit, alone or split across a Hohmann transfer
+    PlaneChange(PlaneChangeArgs),
+}
+
+#[derive(Args)]
+struct PlaneChangeArgs {
+    /// Circular altitude in km, or the final altitude of the transfer with --from
+    #[arg(long, value_parser = orbit::parse_length)]
+    altitude: f64,
+    /// Change of inclination in degrees
+    #[arg(long, allow_negative_numbers = true)]
+    delta_inc: f64,
+    /// Altitude in km of a circular orbit to transfer from, e.g. a parking orbit below GEO
+    #[arg(long, value_parser = orbit::parse_length)]
+    from: Option<f64>,
+    /// Central body
+    #[arg(long, value_enum, default_value = "earth")]
+    body: Named,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("sensor").required(true).args(["half_angle", "swath", "min_elevation"])))]
+struct CoverageArgs {
+    /// Circular altitude in km
+    #[arg(long, value_parser = orbit::parse_length)]
+    altitude: f64,
+    /// Inclination in degrees
+    #[arg(long)]
+    inc: f64,
+    /// Half-angle of a nadir-pointing sensor cone in degrees
+    #[arg(long)]
+    half_angle: Option<f64>,
+    /// Swath width on the ground in km
+    #[arg(long, value_parser = orbit::parse_length)]
+    swath: Option<f64>,
+    /// Minimum elevation in degrees for ground stations or users to see the satellite
+    #[arg(long)]
+    min_elevation: Option<f64>,
+    /// Latitude in degrees for the revisit time
+    #[arg(long, default_value_t = 0.0, allow_negative_numbers = true)]
+    lat: f64,
+    /// Central body
+    #[arg(long, value_enum, default_value = "earth")]
+    body: Named,
+}
+
+#[derive(Args)]
+struct EclipseArgs {
+    /// Circular altitude in km
+    #[arg(long, value_parser = orbit::parse_length)]
+    altitude: f64,
+    /// Solar beta angle in degrees
+    #[arg(long, default_value_t = 0.0, allow_negative_numbers = true)]
+    beta: f64,
+    /// Central body
+    #[arg(long, value_enum, default_value = "earth")]
+    body: Named,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("when").args(["date", "range"])))]
+struct BetaArgs {
+    /// Inclination in degrees
+    #[arg(long)]
+    inc: f64,
+    /// Right ascension of the ascending node in degrees, on the date or at the start of the range
+    #[arg(long, allow_negative_numbers = true)]
+    raan: f64,
+    /// UTC date as YYYY-MM-DD[THH:MM:SSZ], defaulting to now
+    #[arg(long, value_parser = time::parse_utc)]
+    date: Option<f64>,
+    /// Sweep dates as START:END in UTC, such as 2026-01-01:2027-01-01
+    #[arg(long, value_parser = porkchop::parse_range)]
+    range: Option<(f64, f64)>,
+    /// Days between dates in a sweep
+    #[arg(long, default_value_t = 1.0)]
+    step: f64,
+    /// Circular altitude in km; drifts the node with J2 and finds eclipse seasons
+    #[arg(long, value_parser = orbit::parse_length)]
+    altitude: Option<f64>,
+}
+
+#[derive(Args)]
+struct SatArgs {
+    /// Satellite or constellation; lists the catalog if omitted
+    #[arg(value_enum)]
+    satellite: Option<satellites::Satellite>,
+    /// Take the orbit from this satellite's element set in a file; - for stdin
+    #[arg(long, requires = "satellite")]
+    tle: Option<PathBuf>,
+    /// Take the orbit from a current element set downloaded from CelesTrak
+    #[cfg(feature = "fetch")]
+    #[arg(long, requires = "satellite", conflicts_with = "tle")]
+    fetch: bool,
+}
+
+#[cfg(feature = "fetch")]
+#[derive(Args)]
+#[command(group(ArgGroup::new("query").required(true).args(["catnr", "group"])))]
+struct FetchArgs {
+    /// NORAD catalog number
+    #[arg(long, value_parser = fetch::parse_catalog)]
+    catnr: Option<fetch::Query>,
+    /// CelesTrak group, such as stations, starlink or gps-ops
+    #[arg(long, value_parser = fetch::parse_group)]
+    group: Option<fetch::Query>,
+    /// Hours a cached download stays fresh, instead of the group's own expiry
+    #[arg(long)]
+    max_age: Option<f64>,
+    /// Write to a file instead of stdout
+    #[arg(short, long)]
+    out: Option<PathBuf>,
+}
+
+#[cfg(feature = "fetch")]
+#[derive(Subcommand)]
+enum CacheAction {
+    /// Cached downloads with their age and number of element sets
+    List,
+    /// Remove one cached download, or all of them
+    Clear {
+        /// NORAD catalog number
+        #[arg(long, value_parser = fetch::parse_catalog, conflicts_with = "group")]
+        catnr: Option<fetch::Query>,
+        /// CelesTrak group
+        #[arg(long, value_parser = fetch::parse_group)]
+        group: Option<fetch::Query>,
+    },
+}
+
+#[derive(Subcommand)]
+enum DetermineKind {
+    /// Gibbs' method, for three positions well apart along the orbit
+    Gibbs(GibbsArgs),
+    /// Herrick-Gibbs method, for three closely spaced positions at known times
+    HerrickGibbs(HerrickGibbsArgs),
+}
+
+#[derive(Args)]
+struct GibbsArgs {
+    /// First Earth-centred inertial position in km
+    #[arg(
+        long,
+        num_args = 3,
+        value_names = ["X", "Y", "Z"],
+        allow_negative_numbers = true,
+        required = true
+    )]
+    r1: Vec<f64>,
+    /// Second position in km, where the velocity is found
+    #[arg(
+        long,
+        num_args = 3,
+        value_names = ["X", "Y", "Z"],
+        allow_negative_numbers = true,
+        required = true
+    )]
+    r2: Vec<f64>,
+    /// Third position in km
+    #[arg(
+        long,
+        num_args = 3,
+        value_names = ["X", "Y", "Z"],
+        allow_negative_numbers = true,
+        required = true
+    )]
+    r3: Vec<f64>,
+}
+
+#[derive(Args)]
+struct HerrickGibbsArgs {
+    #[command(flatten)]
+    positions: GibbsArgs,
+    /// Times of the three positions in s from any common epoch
+    #[arg(
+        long,
+        num_args = 3,
+        value_names = ["T1", "T2", "T3"],
+        allow_negative_numbers = true,
+        required = true
+    )]
+    times: Vec<f64>,
+}
+
+#[derive(Subcommand)]
+enum RendezvousKind {
+    /// Clohessy-Wiltshire coast and two-burn rendezvous with a target on a circular orbit
+    Cw(CwArgs),
+}
+
+#[derive(Args)]
+struct CwArgs {
+    /// Circular altitude of the target in km
+    #[arg(long, value_parser = orbit::parse_length)]
+    altitude: f64,
+    /// Chaser position relative to the target in m: radial, along-track, cross-track
+    #[arg(
+        long,
+        num_args = 3,
+        value_names = ["X", "Y", "Z"],
+        allow_negative_numbers = true,
+        required = true
+    )]
+    rel_pos: Vec<f64>,
+    /// Chaser velocity relative to the target in m/s
+    #[arg(
+        long,
+        num_args = 3,
+        value_names = ["VX", "VY", "VZ"],
+        allow_negative_numbers = true,
+        default_values_t = [0.0, 0.0, 0.0]
+    )]
+    rel_vel: Vec<f64>,
+    /// Coast and transfer time, in s or with a min, hr, day or sday suffix
+    #[arg(long, value_parser = orbit::parse_time_of_flight)]
+    tof: f64,
+    /// Central body
+    #[arg(long, value_enum, default_value = "earth")]
+    body: Named,
+}
+
+#[derive(Subcommand)]
+enum TimeKind {
+    /// Show a time on the UTC, TAI and TT scales, as Julian dates and as GMST
+    Convert {
+        /// ISO 8601 time such as 2025-06-01T12:00:00Z, or JD2460828.0 or MJD60827.5
+        time: String,
+        /// Time scale of the input
+        #[arg(long, value_enum, default_value = "utc")]
+        scale: time::Scale,
+    },
+}
+
+#[derive(Args)]
+struct TransferArgs {
+    /// Altitude of the starting circular orbit in km
+    #[arg(long, value_parser = orbit::parse_length)]
+    from: f64,
+    /// Altitude of the final circular orbit in km
+    #[arg(long, value_parser = orbit::parse_length)]
+    to: f64,
+    /// Central body
+    #[arg(long, value_enum, default_value = "earth")]
+    body: Named,
+}
+
+#[derive(Args)]
+struct BudgetArgs {
+    /// Plan file listing the maneuvers, in TOML or, with a .json extension, JSON
+    #[arg(long, required_unless_present = "maneuvers")]
+    plan: Option<PathBuf>,
+    /// Maneuver as hohmann:FROM:TO, plane-change:ALT:DEG, circularize:PERI:APO or
+    /// deorbit:ALT:PERIGEE in km and degrees; repeat in flight order, after any in the plan
+    #[arg(long = "maneuver", value_parser = maneuver::parse)]
+    maneuvers: Vec<maneuver::Maneuver>,
+    /// Margin in percent on top of the maneuvers, overriding the plan's
+    #[arg(long)]
+    margin: Option<f64>,
+    /// Central body
+    #[arg(long, value_enum, default_value = "earth")]
+    body: Named,
+    /// Specific impulse in s, with --dry, to size the propellant for the total
+    #[arg(long, requires = "dry")]
+    isp: Option<f64>,
+    /// Dry mass of the stage in kg
+    #[arg(long, requires = "isp")]
+    dry: Option<f64>,
+}
+
+#[derive(Args)]
+struct PhaseArgs {
+    /// Circular altitude in km of the chaser, or the planet to depart from
+    #[arg(long, value_parser = phase::parse_endpoint)]
+    from: phase::Endpoint,
+    /// Circular altitude in km of the target, or the planet to arrive at
+    #[arg(long, value_parser = phase::parse_endpoint)]
+    to: phase::Endpoint,
+    /// Central body for altitudes
+    #[arg(long, value_enum, default_value = "earth")]
+    body: Named,
+    /// Current longitude of the chaser in degrees, for the time to the window
+    #[arg(long, requires = "to_longitude", allow_negative_numbers = true)]
+    from_longitude: Option<f64>,
+    /// Current longitude of the target in degrees
+    #[arg(long, requires = "from_longitude", allow_negative_numbers = true)]
+    to_longitude: Option<f64>,
+    /// UTC date for the planets' positions as YYYY-MM-DD[THH:MM:SSZ], defaulting to now
+    #[arg(long, value_parser = time::parse_utc)]
+    date: Option<f64>,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("first").required(true).args(["period1", "orbit1"])))]
+#[command(group(ArgGroup::new("second").required(true).args(["period2", "orbit2"])))]
+struct SynodicArgs {
+    /// Period of the first orbit, in s or with a min, hr, day or sday suffix
+    #[arg(long, value_parser = orbit::parse_period)]
+    period1: Option<f64>,
+    /// Period of the second orbit
+    #[arg(long, value_parser = orbit::parse_period)]
+    period2: Option<f64>,
+    /// First orbit as a circular altitude in km around --body, or a planet
+    #[arg(long, value_parser = phase::parse_endpoint)]
+    orbit1: Option<phase::Endpoint>,
+    /// Second orbit as a circular altitude in km around --body, or a planet
+    #[arg(long, value_parser = phase::parse_endpoint)]
+    orbit2: Option<phase::Endpoint>,
+    /// Central body for altitudes
+    #[arg(long, value_enum, default_value = "earth")]
+    body: Named,
+}
+
+#[derive(Args)]
+struct LambertArgs {
+    /// Departure position in km
+    #[arg(
+        long,
+        num_args = 3,
+        value_names = ["X", "Y", "Z"],
+        allow_negative_numbers = true,
+        required = true
+    )]
+    r1: Vec<f64>,
+    /// Arrival position in km
+    #[arg(
+        long,
+        num_args = 3,
+        value_names = ["X", "Y", "Z"],
+        allow_negative_numbers = true,
+        required = true
+    )]
+    r2: Vec<f64>,
+    /// Time of flight, in s or with a min, hr, day or sday suffix
+    #[arg(long, value_parser = orbit::parse_time_of_flight)]
+    tof: f64,
+    /// Full revolutions before arrival
+    #[arg(long, default_value_t = 0)]
+    revs: u32,
+    /// Direction of motion around the body
+    #[arg(long, value_enum, default_value = "prograde")]
+    direction: lambert::Direction,
+    /// Central body
+    #[arg(long, value_enum, default_value = "earth")]
+    body: Named,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("arrival").required(true).args(["arrive", "tof"])))]
+struct InterplanetaryArgs {
+    /// Departure planet
+    #[arg(long, value_enum)]
+    from: Named,
+    /// Arrival planet
+    #[arg(long, value_enum)]
+    to: Named,
+    /// UTC departure date as YYYY-MM-DD[THH:MM:SSZ]
+    #[arg(long, value_parser = time::parse_utc)]
+    depart: f64,
+    /// UTC arrival date
+    #[arg(long, value_parser = time::parse_utc)]
+    arrive: Option<f64>,
+    /// Time of flight instead of the arrival date, such as 259day
+    #[arg(long, value_parser = orbit::parse_period)]
+    tof: Option<f64>,
+    /// Circular parking orbit altitude at departure in km
+    #[arg(long, value_parser = orbit::parse_length, default_value_t = 200.0)]
+    parking: f64,
+    /// Periapsis altitude of the target orbit in km
+    #[arg(long, value_parser = orbit::parse_length, default_value_t = 400.0)]
+    capture: f64,
+    /// Apoapsis altitude of the target orbit in km, if it is not circular
+    #[arg(long, value_parser = orbit::parse_length)]
+    capture_apoapsis: Option<f64>,
+}
+
+#[derive(Args)]
+struct WhereArgs {
+    /// Planet or the Sun
+    #[arg(value_enum)]
+    body: Named,
+    /// UTC date as YYYY-MM-DD[THH:MM:SSZ], defaulting to now
+    #[arg(long, value_parser = time::parse_utc)]
+    at: Option<f64>,
+}
+
+#[derive(Args)]
+struct PorkchopArgs {
+    /// Departure planet
+    #[arg(long, value_enum)]
+    from: Named,
+    /// Arrival planet
+    #[arg(long, value_enum)]
+    to: Named,
+    /// Departure dates as START:END in UTC, such as 2026-09-01:2027-01-01
+    #[arg(long, value_parser = porkchop::parse_range)]
+    depart: (f64, f64),
+    /// Arrival dates as START:END in UTC
+    #[arg(long, value_parser = porkchop::parse_range)]
+    arrive: (f64, f64),
+    /// Days between grid dates
+    #[arg(long, default_value_t = 5.0)]
+    step: f64,
+    /// Output format; csv writes one row per date pair with a header row
+    #[arg(long, value_enum, default_value = "text")]
+    format: OutputFormat,
+    /// Write to a file instead of stdout
+    #[arg(short, long)]
+    out: Option<PathBuf>,
+    /// Also shade the launch energy over the grid into an SVG file
+    #[arg(long)]
+    svg: Option<PathBuf>,
+    /// Highest C3 in km^2/s^2 shaded in the SVG
+    #[arg(long, default_value_t = 50.0)]
+    max_c3: f64,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("target").required(true).multiple(true).args(["wet", "delta_v"])))]
+struct RocketArgs {
+    /// Specific impulse in s
+    #[arg(long)]
+    isp: f64,
+    /// Dry (burnout) mass in kg
+    #[arg(long)]
+    dry: f64,
+    /// Wet (initial) mass in kg, for the delta-v available
+    #[arg(long)]
+    wet: Option<f64>,
+    /// Target delta-v in m/s, for the propellant it needs
+    #[arg(long)]
+    delta_v: Option<f64>,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("field").required(true).args(["mass", "mu", "gravity"])))]
+struct CustomArgs {
+    /// Mass in kg, or with an Mearth, Mjup or Msun suffix
+    #[arg(long, value_parser = orbit::parse_mass)]
+    mass: Option<f64>,
+    /// Gravitational parameter G * M in m^3/s^2, instead of the mass
+    #[arg(long)]
+    mu: Option<f64>,
+    /// Surface gravity in m/s^2, instead of the mass
+    #[arg(long)]
+    gravity: Option<f64>,
+    /// Radius in m
+    #[arg(long)]
+    radius: f64,
+    /// Sidereal rotation period in s, negative for retrograde rotation
+    #[arg(long, allow_negative_numbers = true)]
+    rotation_period: Option<f64>,
+    #[command(flatten)]
+    orbit: BodyArgs,
+}
+
+#[derive(Args)]
+struct ConfigBodyArgs {
+    /// Name of the body's table in the file
+    name: String,
+    /// Body definitions to read instead of the default file
+    #[arg(long)]
+    config: Option<PathBuf>,
+    #[command(flatten)]
+    orbit: BodyArgs,
+}
+
+#[derive(Args)]
+struct TleArgs {
+    /// File of element sets or CCSDS OMMs, with or without title lines; - or none for stdin
+    file: Option<PathBuf>,
+    /// Output format; omm writes each set as a CCSDS Orbit Mean-Elements Message
+    #[arg(long, value_enum, default_value = "text")]
+    format: TleFormat,
+    /// Write the omm messages to a file instead of stdout
+    #[arg(short, long)]
+    out: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum TleFormat {
+    Text,
+    Omm,
+}
+
+/// Where a command takes its element sets from. The command defines the
+/// "source" group these belong to, and whether it is required.
+#[derive(Args)]
+struct TleSource {
+    /// File of element sets or CCSDS OMMs; - for stdin
+    #[arg(long, group = "source")]
+    tle: Option<PathBuf>,
+    /// Download the element set of this NORAD catalog number from CelesTrak
+    #[cfg(feature = "fetch")]
+    #[arg(long, value_parser = fetch::parse_catalog, group = "source")]
+    catnr: Option<fetch::Query>,
+    /// Download the element sets of this CelesTrak group, such as stations
+    #[cfg(feature = "fetch")]
+    #[arg(long, value_parser = fetch::parse_group, group = "source")]
+    group: Option<fetch::Query>,
+}
+
+#[derive(Args)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+#[command(group(ArgGroup::new("source").required(true)))]
+struct PropagateArgs {
+    #[command(subcommand)]
+    method: Option<PropagateMethod>,
+    #[command(flatten)]
+    source: TleSource,
+    /// UTC time as YYYY-MM-DDTHH:MM:SSZ, defaulting to each set's epoch; the
+    /// start of the samples for czml and oem
+    #[arg(long, value_parser = time::parse_utc)]
+    at: Option<f64>,
+    /// Output format; czml samples each set from --at for an animation in CesiumJS, oem
+    /// writes the samples as a CCSDS Orbit Ephemeris Message in TEME
+    #[arg(long, value_enum, default_value = "text")]
+    format: PropagateFormat,
+    /// Time span of the czml or oem samples such as 90min, 6h or 2d, defaulting to one orbital
+    /// period
+    #[arg(long, value_parser = orbit::parse_period)]
+    duration: Option<f64>,
+    /// Time between czml or oem samples
+    #[arg(long, value_parser = orbit::parse_period, default_value = "60s")]
+    step: f64,
+    /// Write to a file instead of stdout
+    #[arg(short, long)]
+    out: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum PropagateMethod {
+    /// Advance Keplerian elements analytically on the two-body orbit
+    Kepler(KeplerArgs),
+    /// Integrate Keplerian elements numerically, optionally with J2 and drag
+    Numeric(NumericArgs),
+}
+
+#[derive(Args)]
+struct NumericArgs {
+    #[command(flatten)]
+    orbit: KeplerArgs,
+    /// Integrator
+    #[arg(long, value_enum, default_value = "rkf45")]
+    method: propagator::Method,
+    /// Include the central body's J2 oblateness, taking the inertial z axis as its pole
+    #[arg(long)]
+    j2: bool,
+    /// Ballistic coefficient m / (Cd * A) in kg/m^2, to include drag (Earth only)
+    #[arg(long)]
+    bc: Option<f64>,
+    /// Integration step for rk4, or the first trial step for rkf45
+    #[arg(long, value_parser = orbit::parse_period, default_value = "10s")]
+    integration_step: f64,
+    /// Largest local position error per rkf45 step in m
+    #[arg(long, default_value_t = 1e-3)]
+    tolerance: f64,
+}
+
+#[derive(Args)]
+struct KeplerArgs {
+    /// Semi-major axis in km (negative if hyperbolic), eccentricity and angles in degrees
+    #[arg(
+        long,
+        num_args = 6,
+        value_names = ["A", "E", "I", "RAAN", "ARGP", "NU"],
+        allow_negative_numbers = true,
+        required = true
+    )]
+    elements: Vec<f64>,
+    /// UTC time of the elements as YYYY-MM-DDTHH:MM:SSZ, to label the samples
+    #[arg(long, value_parser = time::parse_utc)]
+    epoch: Option<f64>,
+    /// Time span such as 90min, 6h or 2d, defaulting to one orbital period
+    #[arg(long, value_parser = orbit::parse_period)]
+    duration: Option<f64>,
+    /// Time between samples
+    #[arg(long, value_parser = orbit::parse_period, default_value = "60s")]
+    step: f64,
+    /// Central body
+    #[arg(long, value_enum, default_value = "earth")]
+    body: Named,
+    /// Output format; csv writes one row per sample with a header row
+    #[arg(long, value_enum, default_value = "text")]
+    format: OutputFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum PropagateFormat {
+    Text,
+    Czml,
+    Oem,
+}
+
+#[derive(Args)]
+struct FrameArgs {
+    /// Frame of the input position
+    #[arg(long, value_enum)]
+    from: frames::Frame,
+    /// Frame to convert to
+    #[arg(long, value_enum)]
+    to: frames::Frame,
+    /// UTC epoch as YYYY-MM-DDTHH:MM:SSZ, needed unless both frames are Earth-fixed
+    #[arg(long, value_parser = time::parse_utc)]
+    epoch: Option<f64>,
+    /// Position in km, or latitude and longitude in degrees and altitude in km for geodetic
+    #[arg(
+        num_args = 3,
+        value_names = ["X", "Y", "Z"],
+        allow_negative_numbers = true,
+        required = true
+    )]
+    position: Vec<f64>,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("source").required(true)))]
+struct PassesArgs {
+    #[command(flatten)]
+    source: TleSource,
+    /// Ground station as LAT,LON[,ALT] in degrees and m above WGS-84
+    #[arg(long, value_parser = passes::parse_station, allow_hyphen_values = true)]
+    station: frames::Geodetic,
+    /// UTC start time as YYYY-MM-DDTHH:MM:SSZ, defaulting to now
+    #[arg(long, value_parser = time::parse_utc)]
+    start: Option<f64>,
+    /// Number of days to search
+    #[arg(long, default_value_t = 1.0)]
+    days: f64,
+    /// Minimum elevation in degrees for a pass
+    #[arg(long, default_value_t = 0.0)]
+    min_elevation: f64,
+    /// Output format; csv writes one row per pass with a header row
+    #[arg(long, value_enum, default_value = "text")]
+    format: OutputFormat,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("source").required(true)))]
+struct GroundtrackArgs {
+    #[command(flatten)]
+    source: TleSource,
+    /// Semi-major axis in km, eccentricity and angles in degrees, at the start time
+    #[arg(
+        long,
+        num_args = 6,
+        value_names = ["A", "E", "I", "RAAN", "ARGP", "NU"],
+        allow_negative_numbers = true,
+        requires = "start",
+        group = "source"
+    )]
+    elements: Option<Vec<f64>>,
+    /// UTC start time as YYYY-MM-DDTHH:MM:SSZ, defaulting to each set's epoch
+    #[arg(long, value_parser = time::parse_utc)]
+    start: Option<f64>,
+    /// Time span such as 90min, 6h or 2d, defaulting to one orbital period
+    #[arg(long, value_parser = orbit::parse_period)]
+    duration: Option<f64>,
+    /// Time between points
+    #[arg(long, value_parser = orbit::parse_period, default_value = "60s")]
+    step: f64,
+    /// Also give the visibility footprint above this minimum elevation in degrees
+    #[arg(long, value_name = "ELEVATION")]
+    footprint: Option<f64>,
+    /// Output format; geojson and kml write a line feature per track, plus
+    /// a polygon per point with --footprint
+    #[arg(long, value_enum, default_value = "text")]
+    format: TrackFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum TrackFormat {
+    Text,
+    Csv,
+    Geojson,
+    Kml,
+}
+
+#[derive(Args)]
+#[command(args_conflicts_with_subcommands = true)]
+struct BodyArgs {
+    #[command(subcommand)]
+    action: Option<BodyAction>,
+    /// Circular altitude in km, or with a unit such as 250mi, instead of the presets
+    #[arg(value_parser = orbit::parse_length, conflicts_with_all = ["periapsis", "sma"])]
+    altitude: Option<f64>,
+    /// Circular altitude in km, as an alternative to the positional argument
+    #[arg(
+        long = "altitude",
+        value_parser = orbit::parse_length,
+        value_name = "ALTITUDE",
+        conflicts_with_all = ["altitude", "periapsis", "sma"]
+    )]
+    at: Option<f64>,
+    /// Periapsis altitude in km of an elliptical orbit
+    #[arg(long, value_parser = orbit::parse_length, requires = "apoapsis", conflicts_with = "sma")]
+    periapsis: Option<f64>,
+    /// Apoapsis altitude in km of an elliptical orbit
+    #[arg(long, value_parser = orbit::parse_length, requires = "periapsis")]
+    apoapsis: Option<f64>,
+    /// Semi-major axis in km from the body's centre, for an elliptical orbit
+    #[arg(long, value_parser = orbit::parse_length, requires = "ecc")]
+    sma: Option<f64>,
+    /// Eccentricity, from 0 up to but excluding 1
+    #[arg(long, requires = "sma")]
+    ecc: Option<f64>,
+    /// Also report partial derivatives of period and velocity with respect to altitude
+    #[arg(long)]
+    partials: bool,
+    /// Table of circular altitudes as MIN:MAX:STEP in km instead of the presets
+    #[arg(
+        long,
+        value_parser = sweep::parse_sweep,
+        conflicts_with_all = ["altitude", "at", "periapsis", "sma", "partials", "escape", "inclination"]
+    )]
+    sweep: Option<sweep::Sweep>,
+    /// Solve for the circular altitude with this period, in s or with a min, hr, day or sday suffix
+    #[arg(
+        long,
+        value_parser = orbit::parse_period,
+        conflicts_with_all = ["altitude", "at", "periapsis", "sma", "sweep", "partials", "escape", "inclination"]
+    )]
+    period: Option<f64>,
+    /// Inclination in degrees; adds secular J2 drift rates of the node and periapsis
+    #[arg(long)]
+    inclination: Option<f64>,
+    /// Also report escape velocities, and with --vinf the burn to depart from each orbit
+    #[arg(long)]
+    escape: bool,
+    /// Hyperbolic excess velocity in km/s for the departure burn
+    #[arg(long, requires = "escape")]
+    vinf: Option<f64>,
+    /// Treat the body as a sphere of its equatorial radius
+    #[arg(long)]
+    spherical: bool,
+    /// Output format; table lines the orbits up in columns, text writes a paragraph per orbit and csv one row per orbit with a header row
+    #[arg(long, value_enum, default_value = "table")]
+    format: ReportFormat,
+}
+
+#[derive(Subcommand)]
+enum BodyAction {
+    /// Speed, energy and angular momentum at any radius along an orbit
+    Visviva(VisvivaArgs),
+    /// Drag decay rate and remaining lifetime of a low Earth orbit
+    Decay(DecayArgs),
+    /// Circular Earth orbits whose ground track repeats, including J2 drift
+    Repeat(RepeatArgs),
+}
+
+#[derive(Args)]
+struct RepeatArgs {
+    /// Nodal revolutions in one repeat cycle
+    #[arg(long)]
+    revs: u32,
+    /// Nodal days in one repeat cycle
+    #[arg(long, default_value_t = 1)]
+    days: u32,
+    /// Inclinations in degrees to solve for, separated by commas
+    #[arg(long, value_delimiter = ',', default_value = "0,28.5,45,51.6,63.4,90")]
+    inc: Vec<f64>,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("drag").required(true).args(["bc", "bstar", "mass"])))]
+struct DecayArgs {
+    /// Circular altitude in km, or the perigee altitude with --apoapsis
+    #[arg(long, value_parser = orbit::parse_length)]
+    altitude: f64,
+    /// Apogee altitude in km of an elliptical orbit
+    #[arg(long, value_parser = orbit::parse_length)]
+    apoapsis: Option<f64>,
+    /// Ballistic coefficient m / (Cd * A) in kg/m^2
+    #[arg(long)]
+    bc: Option<f64>,
+    /// B* drag term of a two-line element set in 1/earth radii
+    #[arg(long)]
+    bstar: Option<f64>,
+    /// Spacecraft mass in kg, with --area and --cd for the ballistic coefficient
+    #[arg(long, requires = "area")]
+    mass: Option<f64>,
+    /// Cross-sectional area in m^2
+    #[arg(long, requires = "mass")]
+    area: Option<f64>,
+    /// Drag coefficient with --mass, defaulting to 2.2
+    #[arg(long, requires = "mass", conflicts_with_all = ["bc", "bstar"])]
+    cd: Option<f64>,
+}
+
+#[derive(Args)]
+struct VisvivaArgs {
+    /// Semi-major axis in km from the body's centre
+    #[arg(long, value_parser = orbit::parse_length)]
+    sma: f64,
+    /// Distance in km from the body's centre at which to evaluate the orbit
+    #[arg(long, value_parser = orbit::parse_length)]
+    at_radius: f64,
+    /// Eccentricity; if omitted the radius is taken to be periapsis or apoapsis
+    #[arg(long)]
+    ecc: Option<f64>,
+    /// Output format; csv writes a header row and one value row
+    #[arg(long, value_enum, default_value = "text")]
+    format: OutputFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    Text,
+    Csv,
+}
+
+/// Output format of the body reports, which can also be laid out as tables.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ReportFormat {
+    Table,
+    Text,
+    Csv,
+}
+
+#[derive(Args)]
+#[group(required = true, multiple = false)]
+struct ElementsArgs {
+    /// Position in km and velocity in km/s
+    #[arg(
+        long,
+        num_args = 6,
+        value_names = ["X", "Y", "Z", "VX", "VY", "VZ"],
+        allow_negative_numbers = true
+    )]
+    from_state: Option<Vec<f64>>,
+    /// Semi-major axis in km (negative if hyperbolic), eccentricity and angles in degrees
+    #[arg(
+        long,
+        num_args = 6,
+        value_names = ["A", "E", "I", "RAAN", "ARGP", "NU"],
+        allow_negative_numbers = true
+    )]
+    from_elements: Option<Vec<f64>>,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("anomaly").required(true).args(["mean", "eccentric", "true_anomaly"])))]
+struct AnomalyArgs {
+    /// Mean anomaly in degrees
+    #[arg(long, allow_negative_numbers = true)]
+    mean: Option<f64>,
+    /// Eccentric anomaly in degrees, or the hyperbolic anomaly if the orbit is open
+    #[arg(long, allow_negative_numbers = true)]
+    eccentric: Option<f64>,
+    /// True anomaly in degrees
+    #[arg(long = "true", value_name = "TRUE", allow_negative_numbers = true)]
+    true_anomaly: Option<f64>,
+    /// Eccentricity, non-negative and not 1; above 1 for hyperbolic orbits
+    #[arg(long)]
+    ecc: f64,
+}
+
+#[derive(Args)]
+struct PhasingArgs {
+    /// Circular altitude of the constellation in km
+    #[arg(long, value_parser = orbit::parse_length)]
+    altitude: f64,
+    /// Total number of satellites (T)
+    #[arg(long)]
+    satellites: u32,
+    /// Number of equally spaced planes (P)
+    #[arg(long, default_value_t = 1)]
+    planes: u32,
+    /// Walker phasing factor (F), from 0 to P - 1
+    #[arg(long, default_value_t = 0)]
+    phasing: u32,
+    /// Drift orbit altitude relative to the constellation in km; negative is below and drifts ahead
+    #[arg(long, value_parser = orbit::parse_length, default_value_t = -10.0, allow_negative_numbers = true)]
+    drift_offset: f64,
+}
+
+#[derive(Args)]
+struct GltfArgs {
+    /// Circular orbit as ALT[:INC[:RAAN]] in km and degrees; repeat for more orbits
+    #[arg(long = "orbit", value_parser = sample::parse_circular, required = true)]
+    orbits: Vec<sample::Circular>,
+    /// Also draw each orbit's ground track over one period
+    #[arg(long)]
+    ground_tracks: bool,
+    /// Number of points per orbit
+    #[arg(
+        long,
+        default_value_t = 256,
+        value_parser = RangedU64ValueParser::<usize>::new().range(2..)
+    )]
+    points: usize,
+    /// Scene units per metre (the default makes one unit 1000 km)
+    #[arg(long, default_value_t = 1e-6)]
+    scale: f64,
+    /// GLB file to write
+    #[arg(long)]
+    out: PathBuf,
+}
+
+#[derive(Args)]
+struct RadiationArgs {
+    /// Circular altitude in km
+    #[arg(long, value_parser = orbit::parse_length)]
+    altitude: f64,
+    /// Inclination in degrees
+    #[arg(long, default_value_t = 0.0)]
+    inclination: f64,
+    /// Right ascension of the ascending node in degrees, from Greenwich at the start
+    #[arg(long, default_value_t = 0.0, allow_negative_numbers = true)]
+    raan: f64,
+    /// Number of orbits to follow
+    #[arg(long, default_value_t = 15)]
+    orbits: u32,
+}
+
+#[derive(Args)]
+struct ThermalArgs {
+    /// Circular altitude in km
+    #[arg(long, value_parser = orbit::parse_length)]
+    altitude: f64,
+    /// Solar beta angle in degrees
+    #[arg(long, default_value_t = 0.0, allow_negative_numbers = true)]
+    beta: f64,
+    /// Date (YYYY-MM-DD) for the Earth-Sun distance; 1 AU if omitted
+    #[arg(long, value_parser = time::parse_date)]
+    date: Option<f64>,
+    /// Earth Bond albedo
+    #[arg(long, default_value_t = 0.3)]
+    albedo: f64,
+}
+
+#[derive(Args)]
+struct PowerArgs {
+    /// Circular altitude in km
+    #[arg(long, value_parser = orbit::parse_length)]
+    altitude: f64,
+    /// Solar beta angle in degrees
+    #[arg(long, default_value_t = 0.0, allow_negative_numbers = true)]
+    beta: f64,
+    /// Date (YYYY-MM-DD) for the Earth-Sun distance; 1 AU if omitted
+    #[arg(long, value_parser = time::parse_date)]
+    date: Option<f64>,
+    /// Array area in m^2
+    #[arg(long)]
+    area: f64,
+    /// Cell efficiency, including packing and degradation (0-1)
+    #[arg(long, default_value_t = 0.28)]
+    efficiency: f64,
+    #[arg(long, value_enum, default_value = "sun-tracking")]
+    pointing: power::Pointing,
+}
+
+#[derive(Args)]
+struct PlotArgs {
+    #[arg(value_enum)]
+    curve: plot::Curve,
+    /// Lowest altitude in km
+    #[arg(long, value_parser = orbit::parse_length, default_value_t = 200.0)]
+    from: f64,
+    /// Highest altitude in km
+    #[arg(long, value_parser = orbit::parse_length, default_value_t = 40_000.0)]
+    to: f64,
+    /// Number of altitudes sampled
+    #[arg(long, default_value_t = 200)]
+    points: usize,
+    /// Minimum elevation in degrees for coverage
+    #[arg(long, default_value_t = 0.0)]
+    min_elevation: f64,
+    /// Ballistic coefficient in kg/m^2 for decay
+    #[arg(long, default_value_t = 100.0)]
+    bc: f64,
+    /// SVG file to write
+    #[arg(long)]
+    out: PathBuf,
+}
+
+#[derive(Args)]
+struct SampleArgs {
+    /// Altitude in km
+    #[arg(long, value_parser = orbit::parse_length)]
+    altitude: f64,
+    /// Inclination in degrees
+    #[arg(long, default_value_t = 0.0)]
+    inclination: f64,
+    /// Right ascension of the ascending node in degrees
+    #[arg(long, default_value_t = 0.0)]
+    raan: f64,
+    /// Number of points over one period
+    #[arg(
+        long,
+        default_value_t = 256,
+        value_parser = RangedU64ValueParser::<usize>::new().range(1..)
+    )]
+    points: usize,
+    #[arg(long, value_enum, default_value = "eci")]
+    frame: sample::Frame,
+    /// Multiplier applied to positions in metres (e.g. 1e-3 for km)
+    #[arg(long, default_value_t = 1.0)]
+    scale: f64,
+    /// Up axis of the exported coordinates
+    #[arg(long, value_enum, default_value = "z")]
+    up: sample::Up,
+    #[arg(long, value_enum, default_value = "json")]
+    format: sample::Format,
+    /// Write to a file instead of stdout
+    #[arg(long)]
+    out: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct HabArgs {
+    /// Stellar mass in solar masses (suffixes: Msun, kg)
+    #[arg(long, value_parser = habitable::parse_mass, default_value = "1")]
+    star_mass: f64,
+    /// Luminosity in solar luminosities (suffixes: Lsun, W); estimated from the mass if omitted
+    #[arg(long, value_parser = habitable::parse_luminosity)]
+    luminosity: Option<f64>,
+    /// Effective temperature in K; estimated from mass and luminosity if omitted
+    #[arg(long)]
+    teff: Option<f64>,
+    /// Orbital distance to evaluate in AU (suffixes: AU, km, m)
+    #[arg(long, value_parser = habitable::parse_distance)]
+    distance: Option<f64>,
+    /// Bond albedo used for the equilibrium temperature
+    #[arg(long, default_value_t = 0.3)]
+    albedo: f64,
+}
+
+#[derive(Args)]
+struct DispersionArgs {
+    /// Burnout altitude in km
+    #[arg(long, value_parser = orbit::parse_length)]
+    altitude: f64,
+    /// Inertial burnout velocity in m/s
+    #[arg(long)]
+    velocity: f64,
+    /// Flight-path angle above the local horizontal in degrees
+    #[arg(long, default_value_t = 0.0, allow_negative_numbers = true)]
+    fpa: f64,
+    /// Burnout latitude in degrees
+    #[arg(long, default_value_t = 0.0, allow_negative_numbers = true)]
+    latitude: f64,
+    /// Inertial flight azimuth in degrees from north
+    #[arg(long, default_value_t = 90.0)]
+    azimuth: f64,
+    /// One-sigma altitude error in km
+    #[arg(long, value_parser = orbit::parse_length, default_value_t = 0.0)]
+    sigma_altitude: f64,
+    /// One-sigma velocity error in m/s
+    #[arg(long, default_value_t = 0.0)]
+    sigma_velocity: f64,
+    /// One-sigma flight-path angle error in degrees
+    #[arg(long, default_value_t = 0.0)]
+    sigma_fpa: f64,
+    /// One-sigma azimuth error in degrees
+    #[arg(long, default_value_t = 0.0)]
+    sigma_azimuth: f64,
+    /// Number of Monte Carlo samples
+    #[arg(long, default_value_t = 10_000)]
+    samples: usize,
+    /// Seed for reproducing the Monte Carlo run
+    #[arg(long)]
+    seed: Option<u64>,
+}
+
+/// Unit system chosen with --units, set once at startup.
+static UNITS: OnceLock<units::System> = OnceLock::new();
+
+/// Number style chosen with --precision and --notation, set once at startup.
+static STYLE: OnceLock<format::Style> = OnceLock::new();
+
+/// Report units for quantities around `body`. CSV columns keep their own
+/// units and decimals so scripts do not depend on --units or --precision.
+fn report_units(body: &Body) -> units::Units {
+    units::Units::new(UNITS.get().copied().unwrap_or_default(), body)
+        .with_style(report_style())
+        .with_theme(report_theme())
+}
+
+fn report_style() -> format::Style {
+    STYLE.get().copied().unwrap_or_default()
+}
+
+/// Colours chosen with --color, set once at startup.
+static THEME: OnceLock<theme::Theme> = OnceLock::new();
+
+fn report_theme() -> theme::Theme {
+    THEME.get().copied().unwrap_or_default()
+}
+
+/// A section heading and the rule under it.
+fn print_heading(heading: &str) {
+    println!("{}", report_theme().heading(heading));
+    println!("{SEPARATOR}");
+}
+
+/// Localized report lines for the body commands.
+trait Report {
+    fn get_label(&self) -> String;
+    fn get_period_string(&self) -> String;
+    fn get_velocity_string(&self) -> String;
+    fn get_sensitivity_string(&self) -> String;
+    /// dPeriod/dAltitude and dVelocity/dAltitude at the lowest and highest
+    /// altitude, per report length unit.
+    fn get_sensitivities(&self) -> ((String, String), (String, String));
+    fn get_escape_string(&self, v_infinity: Option<MetersPerSecond>) -> String;
+    fn get_j2_string(&self, inclination: f64) -> Option<String>;
+    fn get_polar_altitude_string(&self) -> String;
+}
+
+impl Report for Orbit {
+    fn get_label(&self) -> String {
+        let units = report_units(&self.body);
+        let (brief, unit) = (|km| units.brief(km), units.length_symbol());
+        let plane = self
+            .inclination
+            .map(|inclination| format!(", i = {} deg", units.style.brief(inclination, 4)))
+            .unwrap_or_default();
+        match &self.altitude {
+            Altitude::Single { value } => {
+                format!("{} ({} {unit}{plane}) ", self.name, brief(*value))
+            }
+            Altitude::Range { max, min } => format!(
+                "{} ({}-{} {unit}{plane}) ",
+                self.name,
+                brief(*min),
+                brief(*max)
+            ),
+            Altitude::Elliptical {
+                periapsis,
+                apoapsis,
+            } => format!(
+                "{} ({} x {} {unit}, e = {}{plane}) ",
+                self.name,
+                brief(*periapsis),
+                brief(*apoapsis),
+                units.style.number(self.eccentricity(), 4)
+            ),
+        }
+    }
+
+    fn get_period_string(&self) -> String {
+        let (min, max) = self.periods();
+        let units = report_units(&self.body);
+        let number = |value: f64, decimals| units.style.number(value, decimals);
+        let (min_seconds, max_seconds) = (number(min.0, 0), number(max.0, 0));
+        let (min_minutes, max_minutes) = (number(min.minutes(), 2), number(max.minutes(), 2));
+        let (min_days, max_days) = (number(min.days(), 2), number(max.days(), 2));
+        let canonical = match (units.time_units(min), units.time_units(max)) {
+            (Some(low), Some(high)) => match &self.altitude {
+                Altitude::Range { .. } => format!("\n{}-{} TU", number(low, 4), number(high, 4)),
+                _ => format!("\n{} TU", number(low, 4)),
+            },
+            _ => String::new(),
+        };
+
+        let periods = match &self.altitude {
+            Altitude::Single { .. } | Altitude::Elliptical { .. } => format!(
+                "{min_seconds} {}
+{min_minutes} {}
+{min_days} {}",
+                t!("seconds"),
+                t!("minutes"),
+                t!("days")
+            ),
+            Altitude::Range { .. } => format!(
+                "{min_seconds}-{max_seconds} {}
+{min_minutes}-{max_minutes} {} 
+{min_days}-{max_days} {}",
+                t!("seconds"),
+                t!("minutes"),
+                t!("days")
+            ),
+        };
+        periods + &canonical
+    }
+
+    fn get_velocity_string(&self) -> String {
+        let units = report_units(&self.body);
+        let (min, max) = self.velocities();
+        match &self.altitude {
+            Altitude::Single { .. } => units.speed(min),
+            Altitude::Range { .. } => format!("{}-{}", units.speed_number(min), units.speed(max)),
+            Altitude::Elliptical { .. } => format!(
+                "{} ({})\n{} ({})",
+                units.speed(min),
+                t!("at-periapsis"),
+                units.speed(max),
+                t!("at-apoapsis")
+            ),
+        }
+    }
+
+    fn get_sensitivity_string(&self) -> String {
+        let units = report_units(&self.body);
+        let per_length = format!("{}/{}", units.speed_symbol(), units.length_symbol());
+        let period_per_length = format!("s/{}", units.length_symbol());
+        let ((low_period, high_period), (low_velocity, high_velocity)) = self.get_sensitivities();
+
+        match &self.altitude {
+            Altitude::Single { .. } => format!(
+                "{}: {low_period} {period_per_length}\n{}: {low_velocity} {per_length}",
+                t!("period-per-altitude"),
+                t!("velocity-per-altitude"),
+            ),
+            Altitude::Range { .. } => format!(
+                "{}: {low_period}-{high_period} {period_per_length}\n{}: {low_velocity} .. {high_velocity} {per_length}",
+                t!("period-per-altitude"),
+                t!("velocity-per-altitude"),
+            ),
+            Altitude::Elliptical { .. } => format!(
+                "{}: {low_period} {period_per_length}\n{}: {low_velocity} {per_length} ({})",
+                t!("period-per-altitude"),
+                t!("velocity-per-altitude"),
+                t!("at-periapsis")
+            ),
+        }
+    }
+
+    fn get_sensitivities(&self) -> ((String, String), (String, String)) {
+        let units = report_units(&self.body);
+        // per length unit, from rates per km
+        let length = units.length_scale().km();
+        let speed = units.speed_scale().0;
+        // altitude in km, step of one metre
+        let step = 1e-3;
+        let (min, max) = self.altitude.bounds();
+        // raise the altitude, or both apsides of an ellipse together
+        let shifted = |altitude: f64, h: f64| Orbit {
+            name: String::new(),
+            altitude: match self.altitude {
+                Altitude::Elliptical { .. } => Altitude::Elliptical {
+                    periapsis: min + h,
+                    apoapsis: max + h,
+                },
+                _ => Altitude::Single {
+                    value: altitude + h,
+                },
+            },
+            inclination: self.inclination,
+            body: self.body.clone(),
+        };
+        let d_period = |altitude: f64| {
+            let rate =
+                sensitivity::central_difference(|h| shifted(altitude, h).periods().0 .0, 0.0, step);
+            units.style.number(rate * length, 6)
+        };
+        let d_velocity = |altitude: f64| {
+            let rate = sensitivity::central_difference(
+                |h| shifted(altitude, h).velocities().0 .0,
+                0.0,
+                step,
+            );
+            units.style.number(rate * length / speed, 6)
+        };
+        (
+            (d_period(min), d_period(max)),
+            (d_velocity(min), d_velocity(max)),
+        )
+    }
+
+    fn get_escape_string(&self, v_infinity: Option<MetersPerSecond>) -> String {
+        let units = report_units(&self.body);
+        let speeds = |(low, high): (MetersPerSecond, MetersPerSecond)| match &self.altitude {
+            Altitude::Single { .. } => units.speed(low),
+            Altitude::Range { .. } => format!("{}-{}", units.speed_number(low), units.speed(high)),
+            Altitude::Elliptical { .. } => format!(
+                "{} ({})\n{} ({})",
+                units.speed(low),
+                t!("at-periapsis"),
+                units.speed(high),
+                t!("at-apoapsis")
+            ),
+        };
+        let escape = format!(
+            "{}: {}",
+            t!("escape-velocity"),
+            speeds(self.escape_velocities())
+        );
+        let Some(v_infinity) = v_infinity else {
+            return escape;
+        };
+        format!(
+            "{escape}\n{}: {}",
+            t!("departure-burn"),
+            speeds(self.departure_burns(v_infinity))
+        )
+    }
+
+    fn get_polar_altitude_string(&self) -> String {
+        let units = report_units(&self.body);
+        let (min, max) = self.altitude.bounds();
+        let (polar_min, polar_max) = self.polar_altitudes();
+        // altitudes in km
+        let range = |low: f64, high: f64| match &self.altitude {
+            Altitude::Single { .. } => units.length(Meters::from_km(low)),
+            Altitude::Range { .. } => format!(
+                "{}-{}",
+                units.length_number(Meters::from_km(low)),
+                units.length(Meters::from_km(high))
+            ),
+            Altitude::Elliptical { .. } => format!(
+                "{} ({}), {} ({})",
+                units.length(Meters::from_km(low)),
+                t!("at-periapsis"),
+                units.length(Meters::from_km(high)),
+                t!("at-apoapsis")
+            ),
+        };
+        format!(
+            "{}: {}\n{}: {}",
+            t!("over-equator"),
+            range(min, max),
+            t!("over-poles"),
+            range(polar_min, polar_max)
+        )
+    }
+
+    fn get_j2_string(&self, inclination: f64) -> Option<String> {
+        let (low, high) = self.j2_rates(inclination)?;
+        let rate = |rate: f64| report_style().number(rate, 4);
+        Some(match &self.altitude {
+            Altitude::Range { .. } => format!(
+                // rates are often negative, so not joined with a hyphen
+                "{}: {} .. {} deg/d\n{}: {} .. {} deg/d",
+                t!("nodal-regression"),
+                rate(low.nodal_regression),
+                rate(high.nodal_regression),
+                t!("apsidal-rotation"),
+                rate(low.apsidal_rotation),
+                rate(high.apsidal_rotation)
+            ),
+            _ => format!(
+                "{}: {} deg/d\n{}: {} deg/d",
+                t!("nodal-regression"),
+                rate(low.nodal_regression),
+                t!("apsidal-rotation"),
+                rate(low.apsidal_rotation)
+            ),
+        })
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+    i18n::init(cli.lang.unwrap_or_else(Lang::from_env));
+    UNITS.get_or_init(|| cli.units);
+    STYLE.get_or_init(|| format::Style {
+        notation: cli.notation,
+        precision: cli.precision,
+    });
+    THEME.get_or_init(|| theme::Theme::new(cli.color.detect()));
+    match &cli.command {
+        Some(Commands::Earth(args)) => report_named(Named::Earth, args),
+        Some(Commands::Sun(args)) => report_named(Named::Sun, args),
+        Some(Commands::Mercury(args)) => report_named(Named::Mercury, args),
+        Some(Commands::Venus(args)) => report_named(Named::Venus, args),
+        Some(Commands::Moon(args)) => report_named(Named::Moon, args),
+        Some(Commands::Mars(args)) => report_named(Named::Mars, args),
+        Some(Commands::Jupiter(args)) => report_named(Named::Jupiter, args),
+        Some(Commands::Saturn(args)) => report_named(Named::Saturn, args),
+        Some(Commands::Uranus(args)) => report_named(Named::Uranus, args),
+        Some(Commands::Neptune(args)) => report_named(Named::Neptune, args),
+        Some(Commands::Pluto(args)) => report_named(Named::Pluto, args),
+        Some(Commands::Custom(args)) => custom(args),
+        Some(Commands::Body(args)) => config_body(args),
+        Some(Commands::Validate) => validate(),
+        Some(Commands::Quiz {
+            topic,
+            count,
+            seed,
+            answers,
+        }) => quiz(*topic, *count, *seed, *answers),
+        Some(Commands::Sample(args)) => sample(args),
+        Some(Commands::Hab(args)) => hab(args),
+        Some(Commands::Dispersion(args)) => dispersion(args),
+        Some(Commands::Compliance { altitude, bc }) => compliance(*altitude, *bc),
+        Some(Commands::Makeup {
+            altitude,
+            bc,
+            solar,
+        }) => makeup(*altitude, *bc, *solar),
+        Some(Commands::Radiation(args)) => radiation(args),
+        Some(Commands::Thermal(args)) => thermal(args),
+        Some(Commands::Power(args)) => power(args),
+        Some(Commands::Plot(args)) => plot(args),
+        Some(Commands::Diagram { orbits, out }) => diagram(orbits, out),
+        Some(Commands::Gltf(args)) => gltf(args),
+        Some(Commands::Phasing(args)) => phasing(args),
+        Some(Commands::Elements(args)) => elements(args),
+        Some(Commands::Anomaly(args)) => anomaly(args),
+        Some(Commands::Transfer { kind }) => match kind {
+            TransferKind::Hohmann(args) => hohmann(args),
+            TransferKind::Bielliptic { transfer, apogee } => bielliptic(transfer, *apogee),
+        },
+        Some(Commands::Maneuver { kind }) => match kind {
+            ManeuverKind::PlaneChange(args) => plane_change(args),
+        },
+        Some(Commands::Phase(args)) => phase(args),
+        Some(Commands::Synodic(args)) => synodic(args),
+        Some(Commands::Lambert(args)) => lambert(args),
+        Some(Commands::Porkchop(args)) => porkchop(args),
+        Some(Commands::Where(args)) => where_is(args),
+        Some(Commands::Interplanetary(args)) => interplanetary(args),
+        Some(Commands::Soi { body }) => soi(*body),
+        Some(Commands::Lagrange { system }) => lagrange(*system),
+        Some(Commands::Coverage(args)) => coverage(args),
+        Some(Commands::Eclipse(args)) => eclipse(args),
+        Some(Commands::Beta(args)) => beta(args),
+        Some(Commands::Sat(args)) => sat(args),
+        #[cfg(feature = "fetch")]
+        Some(Commands::Fetch(args)) => fetch_tles(args),
+        #[cfg(feature = "fetch")]
+        Some(Commands::Cache { action }) => cache(action),
+        Some(Commands::Determine { kind }) => match kind {
+            DetermineKind::Gibbs(args) => determine(args, None),
+            DetermineKind::HerrickGibbs(args) => determine(&args.positions, Some(&args.times)),
+        },
+        Some(Commands::Rendezvous { kind }) => match kind {
+            RendezvousKind::Cw(args) => clohessy_wiltshire(args),
+        },
+        Some(Commands::Budget(args)) => budget(args),
+        Some(Commands::Rocket(args)) => rocket(args),
+        Some(Commands::Tle(args)) => tle(args),
+        Some(Commands::Propagate(args)) => propagate(args),
+        Some(Commands::Groundtrack(args)) => ground_track(args),
+        Some(Commands::Passes(args)) => passes(args),
+        Some(Commands::Frame(args)) => frame(args),
+        Some(Commands::Time { kind }) => match kind {
+            TimeKind::Convert { time, scale } => convert_time(time, *scale),
+        },
+        Some(Commands::Animate { orbit, frames, out }) => animate(orbit, *frames, out),
+        None => {}
+    }
+}
+
+fn presets(named: Named, body: &Rc<Body>) -> Vec<Orbit> {
+    named
+        .presets()
+        .into_iter()
+        .map(|preset| Orbit {
+            name: match preset.name {
+                PresetName::Low => t!("preset-low-orbit"),
+                PresetName::Synchronous => t!("preset-synchronous"),
+                PresetName::Named(name) => name.to_string(),
+            },
+            altitude: preset.altitude,
+            inclination: preset.inclination,
+            body: body.clone(),
+        })
+        .collect()
+}
+
+fn report_named(named: Named, args: &BodyArgs) {
+    match (named, &args.action) {
+        (Named::Earth, Some(BodyAction::Decay(decay))) => return decay_lifetime(decay),
+        (Named::Earth, Some(BodyAction::Repeat(repeat))) => return repeat_ground_track(repeat),
+        _ => {}
+    }
+    report(
+        &t!(named.name()),
+        named.body(),
+        |body| presets(named, body),
+        args,
+    );
+}
+
+fn custom(args: &CustomArgs) {
+    let valid = |value: Option<f64>| value.is_none_or(|value| value > 0.0);
+    if !(args.radius > 0.0
+        && valid(args.mass)
+        && valid(args.mu)
+        && valid(args.gravity)
+        && args.rotation_period != Some(0.0))
+    {
+        eprintln!("{}", t!("error-custom-body"));
+        std::process::exit(1);
+    }
+    let body = match (args.mass, args.mu, args.gravity) {
+        (Some(mass), ..) => Body::from_mass(Kilograms(mass), Meters(args.radius)),
+        (_, Some(mu), _) => Body::from_mu(GravitationalParameter(mu), Meters(args.radius)),
+        (.., Some(gravity)) => Body::from_surface_gravity(gravity, Meters(args.radius)),
+        _ => unreachable!("clap requires a mass, mu or gravity"),
+    };
+    let body = Body {
+        rotation_period: args.rotation_period.map(Seconds),
+        ..body
+    };
+    report(&t!("custom-body"), body, synchronous_preset, &args.orbit);
+}
+
+fn config_body(args: &ConfigBodyArgs) {
+    let Some(path) = args.config.clone().or_else(config::default_path) else {
+        eprintln!("{}", t!("error-no-config"));
+        std::process::exit(1);
+    };
+    let fail = |error: String| -> ! {
+        eprintln!(
+            "{}",
+            t!("error-config", path = path.display(), error = error)
+        );
+        std::process::exit(1)
+    };
+    let bodies = config::load(&path).unwrap_or_else(|err| fail(err));
+    let Some(definition) = bodies.get(&args.name) else {
+        let names: Vec<&str> = bodies.keys().map(String::as_str).collect();
+        fail(t!(
+            "error-unknown-body",
+            name = args.name,
+            names = names.join(", ")
+        ))
+    };
+    let body = definition
+        .body()
+        .unwrap_or_else(|err| fail(format!("{}: {err}", args.name)));
+    let presets = definition
+        .presets()
+        .unwrap_or_else(|err| fail(format!("{}: {err}", args.name)));
+
+    report(
+        &args.name,
+        body,
+        |body| {
+            presets
+                .into_iter()
+                .map(|(name, altitude, inclination)| Orbit {
+                    name,
+                    altitude,
+                    inclination,
+                    body: body.clone(),
+                })
+                .chain(synchronous_preset(body))
+                .collect()
+        },
+        &args.orbit,
+    );
+}
+
+/// The synchronous orbit of a body without built-in presets, if it rotates.
+fn synchronous_preset(body: &Rc<Body>) -> Vec<Orbit> {
+    body.synchronous_altitude()
+        .map(|altitude| Orbit {
+            name: t!("preset-synchronous"),
+            altitude: Altitude::Single {
+                value: (altitude * 100.0).round() / 100.0,
+            },
+            inclination: None,
+            body: body.clone(),
+        })
+        .into_iter()
+        .collect()
+}
+
+/// Report on `body`, using `presets` when the arguments name no orbit.
+fn report(name: &str, body: Body, presets: impl FnOnce(&Rc<Body>) -> Vec<Orbit>, args: &BodyArgs) {
+    let body = Rc::new(if args.spherical {
+        body.spherical()
+    } else {
+        body
+    });
+    let oblate = body.flattening > 0.0;
+
+    if let Some(BodyAction::Decay(_)) = &args.action {
+        eprintln!("{}", t!("error-decay-earth"));
+        std::process::exit(1);
+    }
+    if let Some(BodyAction::Repeat(_)) = &args.action {
+        eprintln!("{}", t!("error-repeat-earth"));
+        std::process::exit(1);
+    }
+
+    if let Some(BodyAction::Visviva(visviva)) = &args.action {
+        let result = match visviva.format {
+            OutputFormat::Text => {
+                print_vis_viva(name, &body, visviva);
+                Ok(())
+            }
+            OutputFormat::Csv => write_vis_viva_csv(&body, visviva),
+        };
+        if let Err(err) = result {
+            eprintln!("{}", t!("error-write", error = err));
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(sweep) = args.sweep {
+        let result = match args.format {
+            ReportFormat::Table | ReportFormat::Text => {
+                print_sweep(name, &body, &sweep);
+                Ok(())
+            }
+            ReportFormat::Csv => write_sweep_csv(&body, &sweep),
+        };
+        if let Err(err) = result {
+            eprintln!("{}", t!("error-write", error = err));
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(period) = args.period {
+        let result = match args.format {
+            ReportFormat::Table | ReportFormat::Text => {
+                print_period_solution(name, &body, period);
+                Ok(())
+            }
+            ReportFormat::Csv => write_period_solution_csv(&body, period),
+        };
+        if let Err(err) = result {
+            eprintln!("{}", t!("error-write", error = err));
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let mut orbits = vec![];
+
+    let user = match (
+        args.altitude.or(args.at),
+        args.periapsis,
+        args.apoapsis,
+        args.sma,
+        args.ecc,
+    ) {
+        (Some(value), ..) => Some(Altitude::Single { value }),
+        (_, Some(periapsis), Some(apoapsis), ..) if periapsis <= apoapsis => {
+            Some(Altitude::Elliptical {
+                periapsis,
+                apoapsis,
+            })
+        }
+        (_, Some(_), Some(_), ..) => {
+            eprintln!("{}", t!("error-apsides"));
+            std::process::exit(1);
+        }
+        (.., Some(sma), Some(ecc)) if sma > 0.0 && (0.0..1.0).contains(&ecc) => {
+            Some(Altitude::from_elements(&body, sma, ecc))
+        }
+        (.., Some(_), Some(_)) => {
+            eprintln!("{}", t!("error-elements"));
+            std::process::exit(1);
+        }
+        _ => None,
+    };
+    match user {
+        Some(altitude) => orbits.push(Orbit {
+            name: t!("user-defined"),
+            altitude,
+            inclination: args.inclination,
+            body: body.clone(),
+        }),
+        None => orbits.extend(presets(&body)),
+    }
+    if orbits.is_empty() {
+        eprintln!("{}", t!("error-no-orbit"));
+        std::process::exit(1);
+    }
+    let v_infinity = args.vinf.map(MetersPerSecond::from_km_per_s);
+
+    if args.format == ReportFormat::Csv {
+        if let Err(err) = write_orbits_csv(&orbits, args.inclination, args.escape, v_infinity) {
+            eprintln!("{}", t!("error-write", error = err));
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    println!();
+    print_heading(&t!("constants"));
+    let style = report_style();
+    let constant = |value: f64| {
+        let sign = if value.is_sign_negative() { "" } else { "+" };
+        format!("{sign}{}", style.exact(value))
+    };
+    println!("{}: {}", t!("pi"), style.brief(PI, 15));
+    println!(
+        "{}: {} N*m^2*kg^-2",
+        t!("gravitational-constant"),
+        constant(GRAVITATIONAL_CONSTANT)
+    );
+    println!(
+        "{}: {} kg",
+        t!("body-mass", body = name),
+        constant(body.mass().0)
+    );
+    println!(
+        "{}: {} m^3*s^-2",
+        t!("body-mu", body = name),
+        constant(body.mu.0)
+    );
+    println!(
+        "{}: {} m",
+        t!("body-radius", body = name),
+        constant(body.radius.0)
+    );
+    if oblate {
+        println!(
+            "{}: {} m",
+            t!("body-polar-radius", body = name),
+            constant(body.polar_radius().0.round())
+        );
+        println!(
+            "{}: 1/{}",
+            t!("body-flattening", body = name),
+            style.number(1.0 / body.flattening, 3)
+        );
+    }
+    if let Some(period) = body.rotation_period {
+        println!(
+            "{}: {} h",
+            t!("body-rotation", body = name),
+            style.number(period.hours(), 4)
+        );
+    }
+    println!("{SEPARATOR}");
+    if args.format == ReportFormat::Table {
+        print_orbit_tables(name, &body, &orbits, args, v_infinity);
+        return;
+    }
+    println!();
+    println!();
+    print_heading(&t!("orbital-periods"));
+    println!();
+
+    orbits
+        .iter()
+        .for_each(|o| println!("{}\n{}\n", o.get_label(), o.get_period_string()));
+
+    println!("{SEPARATOR}");
+    println!();
+    println!();
+    print_heading(&t!("orbital-velocities"));
+    println!();
+
+    orbits
+        .iter()
+        .for_each(|o| println!("{}\n{}\n", o.get_label(), o.get_velocity_string()));
+
+    println!("{SEPARATOR}");
+    println!();
+
+    if oblate {
+        println!();
+        print_heading(&t!("ellipsoid-altitudes", body = name));
+        println!();
+
+        orbits
+            .iter()
+            .for_each(|o| println!("{}\n{}\n", o.get_label(), o.get_polar_altitude_string()));
+
+        println!("{SEPARATOR}");
+        println!();
+    }
+
+    if args.partials {
+        println!();
+        print_heading(&t!("sensitivities"));
+        println!();
+
+        orbits
+            .iter()
+            .for_each(|o| println!("{}\n{}\n", o.get_label(), o.get_sensitivity_string()));
+
+        println!("{SEPARATOR}");
+        println!();
+    }
+
+    if let Some(inclination) = args.inclination {
+        println!();
+        print_heading(&t!("j2", inclination = style.brief(inclination, 4)));
+        println!();
+
+        orbits
+            .iter()
+            .for_each(|o| match o.get_j2_string(inclination) {
+                Some(rates) => println!("{}\n{rates}\n", o.get_label()),
+                None => println!("{}\n", t!("j2-unknown", body = name)),
+            });
+
+        println!("{SEPARATOR}");
+        println!();
+    }
+
+    if args.escape {
+        println!();
+        print_heading(&t!("escape"));
+        print_surface_escape(&body, v_infinity);
+        println!();
+
+        orbits
+            .iter()
+            .for_each(|o| println!("{}\n{}\n", o.get_label(), o.get_escape_string(v_infinity)));
+
+        println!("{SEPARATOR}");
+        println!();
+    }
+}
+
+/// Escape velocity at the surface, at the equator and poles of an oblate
+/// body, and C3 for `v_infinity`.
+fn print_surface_escape(body: &Body, v_infinity: Option<MetersPerSecond>) {
+    let units = report_units(body);
+    if body.flattening > 0.0 {
+        println!(
+            "{}: {} ({})",
+            t!("surface-escape-velocity"),
+            units.speed(calculate_escape_velocity(body.mu, body.radius)),
+            t!("at-equator")
+        );
+        println!(
+            "{}: {} ({})",
+            t!("surface-escape-velocity"),
+            units.speed(calculate_escape_velocity(body.mu, body.polar_radius())),
+            t!("at-poles")
+        );
+    } else {
+        println!(
+            "{}: {}",
+            t!("surface-escape-velocity"),
+            units.speed(calculate_escape_velocity(body.mu, body.radius))
+        );
+    }
+    if let Some(v_infinity) = v_infinity {
+        println!("C3: {}", units.energy_to(v_infinity.0.powi(2), 1e3));
+    }
+}
+
+/// Lowest and highest value of `orbit` in one cell: a single orbit has one,
+/// a range joins them with a hyphen and an ellipse with `apsides`, such as
+/// " x " between the periapsis and apoapsis altitudes.
+fn span(orbit: &Orbit, low: String, high: String, apsides: &str) -> String {
+    match orbit.altitude {
+        Altitude::Single { .. } => low,
+        Altitude::Range { .. } => format!("{low}-{high}"),
+        Altitude::Elliptical { .. } => format!("{low}{apsides}{high}"),
+    }
+}
+
+/// The orbits of a body report in tables, one row per orbit. Units go in the
+/// headings, so the cells hold numbers only.
+fn print_orbit_tables(
+    name: &str,
+    body: &Body,
+    orbits: &[Orbit],
+    args: &BodyArgs,
+    v_infinity: Option<MetersPerSecond>,
+) {
+    let units = report_units(body);
+    let (length, speed) = (units.length_symbol(), units.speed_symbol());
+    let theme = units.theme;
+    let number = |value: f64, decimals| units.style.number(value, decimals);
+    let speeds = |orbit: &Orbit, (low, high): (MetersPerSecond, MetersPerSecond)| {
+        span(
+            orbit,
+            units.speed_number(low),
+            units.speed_number(high),
+            " / ",
+        )
+    };
+    let lengths = |orbit: &Orbit, (low, high): (f64, f64)| {
+        span(
+            orbit,
+            units.length_number(Meters::from_km(low)),
+            units.length_number(Meters::from_km(high)),
+            " x ",
+        )
+    };
+    let section = |heading: String, table: Table| {
+        println!();
+        print_heading(&heading);
+        print!("{}", table.render());
+        println!("{SEPARATOR}");
+    };
+
+    let elliptical = orbits
+        .iter()
+        .any(|o| matches!(o.altitude, Altitude::Elliptical { .. }));
+    let inclined = orbits.iter().any(|o| o.inclination.is_some());
+    let canonical = units.system == units::System::Canonical;
+    let mut table = Table::new()
+        .with_theme(theme)
+        .column(t!("table-orbit"), Align::Left)
+        .column(t!("table-altitude", unit = length), Align::Right);
+    if elliptical {
+        table = table.column("e", Align::Right);
+    }
+    if inclined {
+        table = table.column("i (deg)", Align::Right);
+    }
+    let mut table = table
+        .column(
+            t!("table-period", unit = if canonical { "TU" } else { "min" }),
+            Align::Right,
+        )
+        .column(t!("table-velocity", unit = speed), Align::Right);
+    for o in orbits {
+        let (low, high) = o.altitude.bounds();
+        let altitude = span(o, units.brief(low), units.brief(high), " x ");
+        let mut row = vec![
+            o.name.clone(),
+            if low < 0.0 {
+                theme.warning(&altitude)
+            } else {
+                altitude
+            },
+        ];
+        if elliptical {
+            row.push(match o.altitude {
+                Altitude::Elliptical { .. } => number(o.eccentricity(), 4),
+                _ => String::new(),
+            });
+        }
+        if inclined {
+            row.push(
+                o.inclination
+                    .map(|i| units.style.brief(i, 4))
+                    .unwrap_or_default(),
+            );
+        }
+        let (min, max) = o.periods();
+        let period = |period: Seconds| match units.time_units(period) {
+            Some(turns) => number(turns, 4),
+            None => number(period.minutes(), 2),
+        };
+        row.push(match o.altitude {
+            Altitude::Range { .. } => format!("{}-{}", period(min), period(max)),
+            _ => period(min),
+        });
+        row.push(speeds(o, o.velocities()));
+        table.row(row);
+    }
+    section(t!("orbits"), table);
+
+    if body.flattening > 0.0 {
+        let mut table = Table::new()
+            .with_theme(theme)
+            .column(t!("table-orbit"), Align::Left)
+            .column(format!("{} ({length})", t!("over-equator")), Align::Right)
+            .column(format!("{} ({length})", t!("over-poles")), Align::Right);
+        for o in orbits {
+            table.row(vec![
+                o.name.clone(),
+                lengths(o, o.altitude.bounds()),
+                lengths(o, o.polar_altitudes()),
+            ]);
+        }
+        section(t!("ellipsoid-altitudes", body = name), table);
+    }
+
+    if args.partials {
+        let mut table = Table::new()
+            .with_theme(theme)
+            .column(t!("table-orbit"), Align::Left)
+            .column(
+                format!("{} (s/{length})", t!("period-per-altitude")),
+                Align::Right,
+            )
+            .column(
+                format!("{} ({speed}/{length})", t!("velocity-per-altitude")),
+                Align::Right,
+            );
+        for o in orbits {
+            let ((low_period, high_period), (low_velocity, high_velocity)) = o.get_sensitivities();
+            let (period, velocity) = match o.altitude {
+                Altitude::Single { .. } => (low_period, low_velocity),
+                // velocity rates are negative, so not joined with a hyphen
+                Altitude::Range { .. } => (
+                    format!("{low_period}-{high_period}"),
+                    format!("{low_velocity} .. {high_velocity}"),
+                ),
+                Altitude::Elliptical { .. } => (
+                    low_period,
+                    format!("{low_velocity} ({})", t!("at-periapsis")),
+                ),
+            };
+            table.row(vec![o.name.clone(), period, velocity]);
+        }
+        section(t!("sensitivities"), table);
+    }
+
+    if let Some(inclination) = args.inclination {
+        let heading = t!("j2", inclination = units.style.brief(inclination, 4));
+        let mut table = Table::new()
+            .with_theme(theme)
+            .column(t!("table-orbit"), Align::Left)
+            .column(format!("{} (deg/d)", t!("nodal-regression")), Align::Right)
+            .column(format!("{} (deg/d)", t!("apsidal-rotation")), Align::Right);
+        for o in orbits {
+            let Some((low, high)) = o.j2_rates(inclination) else {
+                break;
+            };
+            let rates = |low: f64, high: f64| match o.altitude {
+                Altitude::Range { .. } => format!("{} .. {}", number(low, 4), number(high, 4)),
+                _ => number(low, 4),
+            };
+            table.row(vec![
+                o.name.clone(),
+                rates(low.nodal_regression, high.nodal_regression),
+                rates(low.apsidal_rotation, high.apsidal_rotation),
+            ]);
+        }
+        if table.is_empty() {
+            println!();
+            print_heading(&heading);
+            println!("{}", t!("j2-unknown", body = name));
+            println!("{SEPARATOR}");
+        } else {
+            section(heading, table);
+        }
+    }
+
+    if args.escape {
+        println!();
+        print_heading(&t!("escape"));
+        print_surface_escape(body, v_infinity);
+        println!();
+        let mut table = Table::new()
+            .with_theme(theme)
+            .column(t!("table-orbit"), Align::Left)
+            .column(format!("{} ({speed})", t!("escape-velocity")), Align::Right);
+        if v_infinity.is_some() {
+            table = table.column(format!("{} ({speed})", t!("departure-burn")), Align::Right);
+        }
+        for o in orbits {
+            let mut row = vec![o.name.clone(), speeds(o, o.escape_velocities())];
+            if let Some(v_infinity) = v_infinity {
+                row.push(speeds(o, o.departure_burns(v_infinity)));
+            }
+            table.row(row);
+        }
+        print!("{}", table.render());
+        println!("{SEPARATOR}");
+    }
+    println!();
+}
+
+// Headers stay in English so scripts and spreadsheets do not depend on --lang.
+const ORBIT_CSV_HEADER: [&str; 9] = [
+    "orbit",
+    "low_altitude_km",
+    "high_altitude_km",
+    "eccentricity",
+    "inclination_deg",
+    "low_period_s",
+    "high_period_s",
+    "low_velocity_m_s",
+    "high_velocity_m_s",
+];
+
+/// One row per orbit. Ranges give both ends; single orbits repeat the value
+/// and ellipses give the periapsis then the apoapsis. `inclination` (deg)
+/// adds J2 rate columns, left empty when the body's J2 is unknown, `escape`
+/// adds escape velocity columns, and `v_infinity` C3 and departure
+/// burn columns.
+fn write_orbits_csv(
+    orbits: &[Orbit],
+    inclination: Option<f64>,
+    escape: bool,
+    v_infinity: Option<MetersPerSecond>,
+) -> io::Result<()> {
+    let mut writer = io::stdout().lock();
+    let mut header = ORBIT_CSV_HEADER.to_vec();
+    if inclination.is_some() {
+        header.extend([
+            "low_nodal_regression_deg_d",
+            "high_nodal_regression_deg_d",
+            "low_apsidal_rotation_deg_d",
+            "high_apsidal_rotation_deg_d",
+        ]);
+    }
+    if escape {
+        header.extend(["low_escape_velocity_m_s", "high_escape_velocity_m_s"]);
+    }
+    if v_infinity.is_some() {
+        header.extend([
+            "c3_km2_s2",
+            "low_departure_burn_m_s",
+            "high_departure_burn_m_s",
+        ]);
+    }
+    csv::write_record(&mut writer, &header)?;
+    for orbit in orbits {
+        let (low, high) = orbit.altitude.bounds();
+        let (low_period, high_period) = orbit.periods();
+        let (low_velocity, high_velocity) = orbit.velocities();
+        let mut record = vec![
+            orbit.name.clone(),
+            low.to_string(),
+            high.to_string(),
+            format!("{:.6}", orbit.eccentricity()),
+            orbit
+                .inclination
+                .map(|inclination| inclination.to_string())
+                .unwrap_or_default(),
+            format!("{:.3}", low_period.0),
+            format!("{:.3}", high_period.0),
+            format!("{:.3}", low_velocity.0),
+            format!("{:.3}", high_velocity.0),
+        ];
+        if let Some(inclination) = inclination {
+            match orbit.j2_rates(inclination) {
+                Some((low, high)) => record.extend([
+                    format!("{:.6}", low.nodal_regression),
+                    format!("{:.6}", high.nodal_regression),
+                    format!("{:.6}", low.apsidal_rotation),
+                    format!("{:.6}", high.apsidal_rotation),
+                ]),
+                None => record.extend(vec![String::new(); 4]),
+            }
+        }
+        if escape {
+            let (low, high) = orbit.escape_velocities();
+            record.extend([format!("{:.3}", low.0), format!("{:.3}", high.0)]);
+        }
+        if let Some(v_infinity) = v_infinity {
+            let (low, high) = orbit.departure_burns(v_infinity);
+            record.extend([
+                format!("{:.3}", v_infinity.km_per_s().powi(2)),
+                format!("{:.3}", low.0),
+                format!("{:.3}", high.0),
+            ]);
+        }
+        csv::write_record(&mut writer, &record)?;
+    }
+    writer.flush()
+}
+
+fn print_period_solution(name: &str, body: &Body, period: f64) {
+    let axis = calculate_semi_major_axis(body.mu, Seconds(period));
+    let units = report_units(body);
+
+    println!();
+    print_heading(&t!(
+        "period-solution",
+        body = name,
+        period = units.style.number(period, 3)
+    ));
+    println!("{}: {}", t!("semi-major-axis"), units.length(axis));
+    println!(
+        "{}: {}",
+        t!("period-altitude"),
+        units.length(axis - body.radius)
+    );
+    println!(
+        "{}: {}",
+        t!("circular-velocity"),
+        units.speed(calculate_circular_orbital_velocity(body.mu, axis))
+    );
+    if axis < body.radius {
+        let warning = t!("period-below-surface", body = name);
+        println!("{}", report_theme().warning(&warning));
+    }
+    println!("{SEPARATOR}");
+    println!();
+}
+
+fn write_period_solution_csv(body: &Body, period: f64) -> io::Result<()> {
+    let axis = calculate_semi_major_axis(body.mu, Seconds(period));
+    let mut writer = io::stdout().lock();
+    csv::write_record(
+        &mut writer,
+        &[
+            "period_s",
+            "semi_major_axis_km",
+            "altitude_km",
+            "velocity_m_s",
+        ],
+    )?;
+    csv::write_record(
+        &mut writer,
+        &[
+            format!("{period:.3}"),
+            format!("{:.3}", axis.km()),
+            format!("{:.3}", (axis - body.radius).km()),
+            format!(
+                "{:.3}",
+                calculate_circular_orbital_velocity(body.mu, axis).0
+            ),
+        ],
+    )?;
+    writer.flush()
+}
+
+fn vis_viva_point(body: &Body, args: &VisvivaArgs) -> VisVivaPoint {
+    calculate_vis_viva_point(
+        body.mu,
+        Meters::from_km(args.sma),
+        Meters::from_km(args.at_radius),
+        args.ecc,
+    )
+    .unwrap_or_else(|| {
+        eprintln!("{}", t!("error-visviva"));
+        std::process::exit(1)
+    })
+}
+
+fn print_vis_viva(name: &str, body: &Body, args: &VisvivaArgs) {
+    let point = vis_viva_point(body, args);
+    let units = report_units(body);
+
+    println!();
+    print_heading(&t!(
+        "visviva",
+        body = name,
+        radius = units.length_number(Meters::from_km(args.at_radius)),
+        sma = units.length_number(Meters::from_km(args.sma)),
+        unit = units.length_symbol()
+    ));
+    let number = |value: f64, decimals| units.style.number(value, decimals);
+    println!("{}: {}", t!("eccentricity"), number(point.eccentricity, 6));
+    if args.ecc.is_none() {
+        println!("{}", t!("visviva-apsis"));
+    }
+    println!(
+        "{}: {} / {}",
+        t!("visviva-apsides"),
+        units.length(point.semi_major_axis * (1.0 - point.eccentricity)),
+        units.length(point.semi_major_axis * (1.0 + point.eccentricity))
+    );
+    println!("{}: {}", t!("velocity"), units.speed(point.velocity));
+    println!(
+        "{}: {}",
+        t!("circular-velocity"),
+        units.speed(calculate_circular_orbital_velocity(body.mu, point.radius))
+    );
+    println!(
+        "{}: ±{} deg",
+        t!("flight-path-angle"),
+        number(point.flight_path_angle, 4)
+    );
+    println!(
+        "{}: {}",
+        t!("specific-energy"),
+        units.energy_to(point.energy, 1.0)
+    );
+    println!(
+        "{}: {}",
+        t!("specific-angular-momentum"),
+        units.angular_momentum_to(point.angular_momentum, 1e3)
+    );
+    println!(
+        "{}: {} {}",
+        t!("orbital-period"),
+        number(
+            calculate_orbital_period(body.mu, point.semi_major_axis).0,
+            3
+        ),
+        t!("seconds")
+    );
+    if point.semi_major_axis * (1.0 - point.eccentricity) < body.radius {
+        let warning = t!("visviva-below-surface", body = name);
+        println!("{}", report_theme().warning(&warning));
+    }
+    println!("{SEPARATOR}");
+    println!();
+}
+
+fn write_vis_viva_csv(body: &Body, args: &VisvivaArgs) -> io::Result<()> {
+    let point = vis_viva_point(body, args);
+    let mut writer = io::stdout().lock();
+    csv::write_record(
+        &mut writer,
+        &[
+            "semi_major_axis_km",
+            "radius_km",
+            "eccentricity",
+            "velocity_m_s",
+            "flight_path_angle_deg",
+            "specific_energy_km2_s2",
+            "angular_momentum_km2_s",
+        ],
+    )?;
+    csv::write_record(
+        &mut writer,
+        &[
+            format!("{:.3}", point.semi_major_axis.km()),
+            format!("{:.3}", point.radius.km()),
+            format!("{:.6}", point.eccentricity),
+            format!("{:.3}", point.velocity.0),
+            format!("{:.4}", point.flight_path_angle),
+            format!("{:.6}", point.energy / 1e6),
+            format!("{:.3}", point.angular_momentum / 1e6),
+        ],
+    )?;
+    writer.flush()
+}
+
+fn repeat_ground_track(args: &RepeatArgs) {
+    if args.revs == 0 || args.days == 0 {
+        eprintln!("{}", t!("error-repeat"));
+        std::process::exit(1);
+    }
+    let body = Body::earth();
+    let units = report_units(&body);
+    let common = repeat::gcd(args.revs, args.days);
+    let row = |label: String, orbit: Option<repeat::Repeat>| match orbit {
+        Some(orbit) => println!(
+            "{label:>18}  {:>14}  {:>14}  {:>14}",
+            units.length_number(orbit.semi_major_axis - body.radius),
+            units.style.number(orbit.nodal_period.minutes(), 3),
+            units.style.number(orbit.nodal_day.hours(), 3)
+        ),
+        None => {
+            let below = format!("{:>14}", t!("repeat-below-surface"));
+            println!("{label:>18}  {}", report_theme().warning(&below));
+        }
+    };
+
+    println!();
+    print_heading(&t!("repeat", revs = args.revs, days = args.days));
+    println!(
+        "{:>18}  {:>14}  {:>14}  {:>14}",
+        t!("repeat-inclination"),
+        t!("sweep-altitude", unit = units.length_symbol()),
+        t!("repeat-nodal-period"),
+        t!("repeat-nodal-day")
+    );
+    for &inclination in &args.inc {
+        row(
+            units.style.brief(inclination, 4),
+            repeat::solve(&body, args.revs, args.days, inclination),
+        );
+    }
+    match repeat::sun_synchronous(&body, args.revs, args.days) {
+        Some(orbit) => row(
+            format!(
+                "{} {}",
+                t!("repeat-sso"),
+                units.style.number(orbit.inclination, 2)
+            ),
+            Some(orbit),
+        ),
+        None => row(t!("repeat-sso"), None),
+    }
+    println!("{SEPARATOR}");
+    // neighbouring tracks of the shortest cycle
+    let spacing = 360.0 * common as f64 / args.revs as f64;
+    println!(
+        "{}: {} deg ({})",
+        t!("repeat-spacing"),
+        units.style.number(spacing, 3),
+        units.length(spacing.to_radians() * body.radius)
+    );
+    if common > 1 {
+        println!(
+            "{}",
+            t!(
+                "repeat-shorter",
+                revs = args.revs / common,
+                days = args.days / common
+            )
+        );
+    }
+    println!();
+}
+
+fn print_sweep(name: &str, body: &Body, sweep: &sweep::Sweep) {
+    let units = report_units(body);
+    println!();
+    print_heading(&t!("sweep", body = name));
+    println!(
+        "{:>14}  {:>14}  {:>14}",
+        t!("sweep-altitude", unit = units.length_symbol()),
+        t!("sweep-period"),
+        t!("sweep-velocity", unit = units.speed_symbol())
+    );
+    for altitude in sweep.altitudes() {
+        let radius = body.radius + Meters::from_km(altitude);
+        let brief = format!("{:>14}", units.brief(altitude));
+        println!(
+            "{}  {:>14}  {:>14}",
+            if altitude < 0.0 {
+                units.theme.warning(&brief)
+            } else {
+                brief
+            },
+            units
+                .style
+                .number(calculate_orbital_period(body.mu, radius).minutes(), 2),
+            units.speed_number(calculate_circular_orbital_velocity(body.mu, radius))
+        );
+    }
+    println!("{SEPARATOR}");
+    println!();
+}
+
+fn write_sweep_csv(body: &Body, sweep: &sweep::Sweep) -> io::Result<()> {
+    let mut writer = io::stdout().lock();
+    csv::write_record(&mut writer, &["altitude_km", "period_s", "velocity_m_s"])?;
+    for altitude in sweep.altitudes() {
+        let radius = body.radius + Meters::from_km(altitude);
+        csv::write_record(
+            &mut writer,
+            &[
+                altitude.to_string(),
+                format!("{:.3}", calculate_orbital_period(body.mu, radius).0),
+                format!(
+                    "{:.3}",
+                    calculate_circular_orbital_velocity(body.mu, radius).0
+                ),
+            ],
+        )?;
+    }
+    writer.flush()
+}
+
+fn validate() {
+    let results = validate::run();
+
+    println!();
+    print_heading(&t!("validation"));
+    println!();
+
+    let style = report_style();
+    results.iter().for_each(|r| {
+        let status = if r.passed() {
+            t!("validation-pass")
+        } else {
+            t!("validation-fail")
+        };
+        println!(
+            "{} [{status}]\n{}\n{}\n{}\n",
+            r.case.name,
+            r.case.source,
+            t!(
+                "validation-values",
+                expected = style.number(r.case.expected, 6),
+                computed = style.number(r.computed, 6),
+                unit = r.case.unit
+            ),
+            t!(
+                "validation-error",
+                error = style.scientific(r.relative_error(), 3),
+                tolerance = style.scientific(r.case.tolerance, 0)
+            ),
+        );
+    });
+
+    let failed = results.iter().filter(|r| !r.passed()).count();
+
+    println!("{SEPARATOR}");
+    println!(
+        "{}",
+        t!(
+            "validation-summary",
+            passed = results.len() - failed,
+            failed = failed
+        )
+    );
+    println!();
+
+    if failed > 0 {
+        std::process::exit(1);
+    }
+}
+
+fn quiz(topic: Topic, count: usize, seed: Option<u64>, answers: bool) {
+    let seed = seed.unwrap_or_else(Rng::clock_seed);
+    let body = Body::earth();
+    let units = report_units(&body);
+    let (brief, unit) = (|km| units.brief(km), units.length_symbol());
+    let problems = quiz::generate(topic, count, &mut Rng::new(seed));
+
+    println!();
+    print_heading(&t!("quiz", seed = seed));
+    println!(
+        "{}",
+        t!(
+            "quiz-given",
+            mu = units.style.scientific(body.mu.0, 9),
+            radius = brief(body.radius.km()),
+            unit = unit
+        )
+    );
+    println!();
+
+    problems.iter().enumerate().for_each(|(i, p)| {
+        let question = match p {
+            quiz::Problem::Period { altitude } => {
+                t!("quiz-period", altitude = brief(*altitude), unit = unit)
+            }
+            quiz::Problem::Hohmann { from, to } => t!(
+                "quiz-hohmann",
+                from = brief(*from),
+                to = brief(*to),
+                unit = unit
+            ),
+            quiz::Problem::Escape { altitude } => {
+                t!("quiz-escape", altitude = brief(*altitude), unit = unit)
+            }
+        };
+        println!("{}. {question}\n", i + 1);
+    });
+
+    println!("{SEPARATOR}");
+    println!();
+
+    if !answers {
+        println!("{}", t!("quiz-answers-hint"));
+        println!();
+        return;
+    }
+
+    print_heading(&t!("quiz-answer-key"));
+    println!();
+
+    problems.iter().enumerate().for_each(|(i, p)| {
+        let answer = match p.solve(&body) {
+            quiz::Answer::Period { period } => {
+                format!(
+                    "{} {}",
+                    units.style.number(period.minutes(), 2),
+                    t!("minutes")
+                )
+            }
+            quiz::Answer::Hohmann(transfer) => format!(
+                "{} + {} = {}, {} hr",
+                units.speed_number_to(transfer.first_burn, MetersPerSecond(1.0)),
+                units.speed_number_to(transfer.second_burn, MetersPerSecond(1.0)),
+                units.speed_to(transfer.total(), MetersPerSecond(1.0)),
+                units.style.number(transfer.transfer_time.hours(), 2)
+            ),
+            quiz::Answer::Escape { velocity } => units.speed_to(velocity, MetersPerSecond(1.0)),
+        };
+        println!("{}. {answer}", i + 1);
+    });
+
+    println!();
+    println!("{SEPARATOR}");
+    println!();
+}
+
+/// Buffered writer for `--out`, or stdout when no path is given.
+fn create_output(out: &Option<PathBuf>) -> Box<dyn Write> {
+    match out {
+        Some(path) => match File::create(path) {
+            Ok(file) => Box::new(BufWriter::new(file)),
+            Err(err) => {
+                eprintln!(
+                    "{}",
+                    t!("error-create-file", path = path.display(), error = err)
+                );
+                std::process::exit(1)
+            }
+        },
+        None => Box::new(BufWriter::new(io::stdout().lock())),
+    }
+}
+
+fn sample(args: &SampleArgs) {
+    let trajectory = sample::sample_circular(
+        &Body::earth(),
+        args.altitude,
+        args.inclination,
+        args.raan,
+        args.points,
+        args.frame,
+    );
+
+    let mut writer = create_output(&args.out);
+    let result = match args.format {
+        sample::Format::Json => trajectory.write_json(&mut writer, args.frame, args.scale, args.up),
+        sample::Format::Binary => trajectory.write_binary(&mut writer, args.scale, args.up),
+    };
+    if let Err(err) = result.and_then(|_| writer.flush()) {
+        eprintln!("{}", t!("error-write", error = err));
+        std::process::exit(1);
+    }
+}
+
+fn hab(args: &HabArgs) {
+    let luminosity = args
+        .luminosity
+        .unwrap_or_else(|| habitable::Star::main_sequence_luminosity(args.star_mass));
+    let star = habitable::Star {
+        mass: args.star_mass,
+        luminosity,
+        temperature: args.teff.unwrap_or_else(|| {
+            habitable::Star::main_sequence_temperature(args.star_mass, luminosity)
+        }),
+    };
+    let style = report_style();
+    let estimated = |given: bool| {
+        if given {
+            String::new()
+        } else {
+            format!(" ({})", t!("main-sequence-estimate"))
+        }
+    };
+    let period = |distance: f64| {
+        let days = star.period(distance).days();
+        format!(
+            "{} {} ({} {})",
+            style.number(days, 1),
+            t!("days"),
+            style.number(days / 365.25, 2),
+            t!("years")
+        )
+    };
+
+    println!();
+    print_heading(&t!("star"));
+    println!("{}: {} Msun", t!("star-mass"), style.brief(star.mass, 4));
+    println!(
+        "{}: {} Lsun{}",
+        t!("luminosity"),
+        style.number(star.luminosity, 3),
+        estimated(args.luminosity.is_some())
+    );
+    println!(
+        "{}: {} K{}",
+        t!("effective-temperature"),
+        style.number(star.temperature, 0),
+        estimated(args.teff.is_some())
+    );
+    let (min, max) = habitable::VALID_TEMPERATURES;
+    if !(min..=max).contains(&star.temperature) {
+        let warning = t!(
+            "hab-temperature-warning",
+            min = style.brief(min, 0),
+            max = style.brief(max, 0)
+        );
+        println!("{}", report_theme().warning(&warning));
+    }
+    println!("{SEPARATOR}");
+    println!();
+    println!();
+    print_heading(&t!("habitable-zone"));
+    println!();
+
+    habitable::LIMITS.iter().for_each(|limit| {
+        let (edge, name) = match limit {
+            habitable::Limit::RecentVenus => ("hab-optimistic-inner", "hab-recent-venus"),
+            habitable::Limit::RunawayGreenhouse => {
+                ("hab-conservative-inner", "hab-runaway-greenhouse")
+            }
+            habitable::Limit::MaximumGreenhouse => {
+                ("hab-conservative-outer", "hab-maximum-greenhouse")
+            }
+            habitable::Limit::EarlyMars => ("hab-optimistic-outer", "hab-early-mars"),
+        };
+        let distance = limit.distance(star.luminosity, star.temperature);
+        println!(
+            "{} ({}) \n{} AU, {} S_earth\n{}\n",
+            t!(edge),
+            t!(name),
+            style.number(distance, 3),
+            style.number(limit.effective_flux(star.temperature), 3),
+            period(distance)
+        );
+    });
+
+    println!("{SEPARATOR}");
+    println!();
+
+    if let Some(distance) = args.distance {
+        let zone = match star.zone(distance) {
+            habitable::Zone::TooHot => t!("hab-too-hot"),
+            habitable::Zone::Optimistic => t!("hab-zone-optimistic"),
+            habitable::Zone::Conservative => t!("hab-zone-conservative"),
+            habitable::Zone::TooCold => t!("hab-too-cold"),
+        };
+
+        println!();
+        print_heading(&t!("hab-at-distance", distance = style.brief(distance, 6)));
+        println!(
+            "{}: {} S_earth ({} W/m^2)",
+            t!("stellar-flux"),
+            style.number(star.flux(distance), 3),
+            style.number(star.flux(distance) * habitable::SOLAR_CONSTANT, 1)
+        );
+        println!(
+            "{}: {} K ({})",
+            t!("equilibrium-temperature"),
+            style.number(star.equilibrium_temperature(distance, args.albedo), 1),
+            t!("bond-albedo", albedo = style.brief(args.albedo, 3))
+        );
+        println!("{}: {}", t!("orbital-period"), period(distance));
+        println!("{}: {zone}", t!("zone"));
+        println!("{SEPARATOR}");
+        println!();
+    }
+}
+
+fn dispersion(args: &DispersionArgs) {
+    let body = Body::earth();
+    let burnout = dispersion::Burnout {
+        altitude: args.altitude,
+        velocity: args.velocity,
+        flight_path_angle: args.fpa,
+        latitude: args.latitude,
+        azimuth: args.azimuth,
+    };
+    let sigmas = dispersion::Sigmas {
+        altitude: args.sigma_altitude,
+        velocity: args.sigma_velocity,
+        flight_path_angle: args.sigma_fpa,
+        azimuth: args.sigma_azimuth,
+    };
+    let seed = args.seed.unwrap_or_else(Rng::clock_seed);
+
+    let nominal = dispersion::insertion(&body, &burnout);
+    let linear = dispersion::linearized(&body, &burnout, &sigmas);
+    let units = report_units(&body);
+    // altitudes in km, to about 10 m
+    let altitude = |km: f64| units.length_number_to(Meters::from_km(km), Meters(10.0));
+    let unit = units.theme.unit(units.length_symbol());
+
+    println!();
+    print_heading(&t!("nominal-orbit"));
+    println!(
+        "{}: {} {unit}",
+        t!("perigee-altitude"),
+        altitude(nominal.perigee)
+    );
+    println!(
+        "{}: {} {unit}",
+        t!("apogee-altitude"),
+        altitude(nominal.apogee)
+    );
+    println!(
+        "{}: {} deg",
+        t!("inclination"),
+        units.style.number(nominal.inclination, 3)
+    );
+    println!("{SEPARATOR}");
+    println!();
+
+    if !nominal.apogee.is_finite() {
+        println!("{}", t!("dispersion-escape"));
+        println!();
+        return;
+    }
+
+    println!();
+    print_heading(&t!("dispersion-linearized"));
+    println!(
+        "{}: {} {unit}",
+        t!("perigee-altitude"),
+        altitude(linear.perigee)
+    );
+    println!(
+        "{}: {} {unit}",
+        t!("apogee-altitude"),
+        altitude(linear.apogee)
+    );
+    println!(
+        "{}: {} deg",
+        t!("inclination"),
+        units.style.number(linear.inclination, 4)
+    );
+    println!("{SEPARATOR}");
+    println!();
+
+    if args.samples < 2 {
+        return;
+    }
+    let mc = dispersion::monte_carlo(&body, &burnout, &sigmas, args.samples, &mut Rng::new(seed));
+    let line =
+        |name: String, s: &dispersion::Statistics, value: &dyn Fn(f64) -> String, unit: &str| {
+            println!(
+                "{name}: {}",
+                t!(
+                    "dispersion-statistics",
+                    mean = value(s.mean),
+                    sigma = value(s.sigma),
+                    min = value(s.min),
+                    max = value(s.max),
+                    unit = unit
+                )
+            )
+        };
+
+    println!();
+    print_heading(&t!(
+        "dispersion-monte-carlo",
+        samples = args.samples,
+        seed = seed
+    ));
+    line(t!("perigee-altitude"), &mc.perigee, &altitude, &unit);
+    line(t!("apogee-altitude"), &mc.apogee, &altitude, &unit);
+    line(
+        t!("inclination"),
+        &mc.inclination,
+        &|degrees| units.style.number(degrees, 4),
+        "deg",
+    );
+    if mc.escaped > 0 {
+        println!("{}", t!("dispersion-escaped", count = mc.escaped));
+    }
+    println!("{SEPARATOR}");
+    println!();
+}
+
+fn compliance(altitude: f64, bc: f64) {
+    if altitude <= decay::REENTRY_ALTITUDE {
+        eprintln!(
+            "{}",
+            t!("error-decay-reentry", reentry = decay::REENTRY_ALTITUDE)
+        );
+        std::process::exit(1);
+    }
+    let body = Body::earth();
+    let units = report_units(&body);
+    let orbit = decay::Elements::from_altitudes(&body, altitude, altitude);
+    let lifetime = decay::lifetime(&body, orbit, bc, decay::MAX_LIFETIME_YEARS).map(decay::years);
+
+    println!();
+    print_heading(&t!(
+        "compliance",
+        altitude = units.brief(altitude),
+        unit = units.length_symbol(),
+        bc = units.style.brief(bc, 2)
+    ));
+    match lifetime {
+        Some(years) => println!(
+            "{}: {} {}",
+            t!("natural-lifetime"),
+            units.style.number(years, 1),
+            t!("years")
+        ),
+        None => println!(
+            "{}: > {} {}",
+            t!("natural-lifetime"),
+            units.style.brief(decay::MAX_LIFETIME_YEARS, 0),
+            t!("years")
+        ),
+    }
+    println!("{SEPARATOR}");
+    println!();
+
+    decay::DisposalRule::ALL.iter().for_each(|&rule| {
+        let compliant = rule.complies(lifetime);
+        let status = if compliant {
+            t!("validation-pass")
+        } else {
+            t!("validation-fail")
+        };
+        let name = match rule {
+            decay::DisposalRule::TwentyFiveYear => t!("rule-25-year"),
+            decay::DisposalRule::FiveYear => t!("rule-5-year"),
+        };
+        println!("{name} [{status}]");
+
+        if !compliant {
+            let perigee = decay::perigee_for_lifetime(&body, altitude, bc, rule.limit());
+            let burn = maneuver::Maneuver::Deorbit { altitude, perigee }.delta_v(&body);
+            println!(
+                "{}",
+                t!(
+                    "compliance-lower-perigee",
+                    perigee = units.length_to(Meters::from_km(perigee), Meters(100.0)),
+                    delta_v = units.speed_to(burn, MetersPerSecond(0.1))
+                )
+            );
+        }
+        println!();
+    });
+
+    println!("{SEPARATOR}");
+    println!();
+}
+
+fn decay_lifetime(args: &DecayArgs) {
+    let body = Body::earth();
+    let cd = args.cd.unwrap_or(decay::DRAG_COEFFICIENT);
+    let bc = match (args.bc, args.bstar, args.mass.zip(args.area)) {
+        (Some(bc), ..) => bc,
+        (_, Some(bstar), _) => decay::ballistic_coefficient_from_bstar(bstar),
+        (.., Some((mass, area))) => decay::ballistic_coefficient(mass, area, cd),
+        _ => unreachable!("clap requires a ballistic coefficient, B* or mass"),
+    };
+    let apogee = args.apoapsis.unwrap_or(args.altitude);
+    if !(bc > 0.0 && bc.is_finite() && cd > 0.0 && args.altitude <= apogee) {
+        eprintln!("{}", t!("error-decay"));
+        std::process::exit(1);
+    }
+    if args.altitude <= decay::REENTRY_ALTITUDE {
+        eprintln!(
+            "{}",
+            t!("error-decay-reentry", reentry = decay::REENTRY_ALTITUDE)
+        );
+        std::process::exit(1);
+    }
+    let elements = decay::Elements::from_altitudes(&body, args.altitude, apogee);
+    let (da, _) = decay::rates(&body, &elements, bc);
+    let period = calculate_orbital_period(body.mu, elements.semi_major_axis);
+    // dP/da = 3 P / (2 a)
+    let period_rate = 1.5 * period.0 / elements.semi_major_axis.0 * da;
+    let lifetime = decay::lifetime(&body, elements, bc, decay::MAX_LIFETIME_YEARS);
+    let units = report_units(&body);
+
+    println!();
+    print_heading(&t!(
+        "decay",
+        perigee = units.brief(args.altitude),
+        apogee = units.brief(apogee),
+        unit = units.length_symbol(),
+        bc = units.style.number(bc, 2)
+    ));
+    println!(
+        "{}: {} kg/m^3",
+        t!("decay-density"),
+        units
+            .style
+            .scientific(atmosphere::density(args.altitude), 3)
+    );
+    println!(
+        "{}: {}/{}",
+        t!("decay-rate"),
+        units.length_to(Meters(da * 86_400.0), Meters(0.1)),
+        t!("day")
+    );
+    println!(
+        "{}: {} s/{}",
+        t!("decay-period-rate"),
+        units.style.number(period_rate * 86_400.0, 4),
+        t!("day")
+    );
+    match lifetime {
+        Some(seconds) => println!(
+            "{}: {} {} ({} {})",
+            t!("natural-lifetime"),
+            units.style.number(decay::years(seconds), 2),
+            t!("years"),
+            units.style.number(seconds.days(), 0),
+            t!("days")
+        ),
+        None => println!(
+            "{}: > {} {}",
+            t!("natural-lifetime"),
+            units.style.brief(decay::MAX_LIFETIME_YEARS, 0),
+            t!("years")
+        ),
+    }
+    println!("{SEPARATOR}");
+    println!(
+        "{}",
+        t!(
+            "decay-note",
+            reentry = units.brief(decay::REENTRY_ALTITUDE),
+            unit = units.length_symbol()
+        )
+    );
+    println!();
+}
+
+fn makeup(altitude: f64, bc: f64, solar: atmosphere::Activity) {
+    if altitude <= decay::REENTRY_ALTITUDE {
+        eprintln!(
+            "{}",
+            t!("error-decay-reentry", reentry = decay::REENTRY_ALTITUDE)
+        );
+        std::process::exit(1);
+    }
+    let body = Body::earth();
+    let density = atmosphere::density(altitude) * solar.factor(altitude);
+    let makeup = decay::makeup(&body, altitude, bc, density);
+    let units = report_units(&body);
+
+    println!();
+    print_heading(&t!(
+        "makeup",
+        altitude = units.brief(altitude),
+        unit = units.length_symbol(),
+        bc = units.style.brief(bc, 2)
+    ));
+    println!(
+        "{}: {} kg/m^3",
+        t!("makeup-density"),
+        units.style.scientific(density, 3)
+    );
+    println!(
+        "{}: {}/{}",
+        t!("makeup-decay"),
+        units.length_to(Meters::from_km(makeup.decay_per_day), Meters(1.0)),
+        t!("day")
+    );
+    println!(
+        "{}: {}",
+        t!("makeup-delta-v"),
+        units.speed_to(makeup.delta_v_per_year, MetersPerSecond(0.01))
+    );
+    println!("{SEPARATOR}");
+    println!();
+}
+
+fn radiation(args: &RadiationArgs) {
+    let exposure = radiation::exposure(
+        &Body::earth(),
+        args.altitude,
+        args.inclination,
+        args.raan,
+        args.orbits,
+    );
+    let units = report_units(&Body::earth());
+    let style = units.style;
+    let percent = |fraction: f64, decimals| style.number(100.0 * fraction, decimals);
+
+    println!();
+    print_heading(&t!(
+        "radiation",
+        altitude = units.brief(args.altitude),
+        unit = units.length_symbol(),
+        inclination = style.brief(args.inclination, 4),
+        orbits = args.orbits
+    ));
+    println!(
+        "{}: {} %",
+        t!("radiation-saa-fraction"),
+        percent(exposure.saa_fraction, 2)
+    );
+    println!(
+        "{}: {}",
+        t!("radiation-saa-per-day"),
+        style.number(exposure.crossings_per_day(), 1)
+    );
+    println!(
+        "{}: {} %",
+        t!(
+            "radiation-inner-belt",
+            low = style.brief(radiation::INNER_BELT.0, 1),
+            high = style.brief(radiation::INNER_BELT.1, 1)
+        ),
+        percent(exposure.inner_fraction, 1)
+    );
+    println!(
+        "{}: {} %",
+        t!(
+            "radiation-outer-belt",
+            low = style.brief(radiation::OUTER_BELT.0, 1),
+            high = style.brief(radiation::OUTER_BELT.1, 1)
+        ),
+        percent(exposure.outer_fraction, 1)
+    );
+    println!("{SEPARATOR}");
+    println!();
+
+    if exposure.crossings.is_empty() {
+        println!("{}", t!("radiation-no-saa"));
+        println!();
+        return;
+    }
+    print_heading(&t!("radiation-crossings"));
+    println!(
+        "{:>5}  {:>10}  {:>10}  {:>10}  {:>10}",
+        t!("radiation-orbit"),
+        t!("radiation-entry"),
+        t!("radiation-exit"),
+        t!("radiation-duration"),
+        t!("radiation-min-field")
+    );
+    for crossing in &exposure.crossings {
+        println!(
+            "{:>5}  {:>10}  {:>10}  {:>10}  {:>10}",
+            crossing.orbit(exposure.period),
+            style.number(crossing.entry / 60.0, 1),
+            style.number(crossing.exit / 60.0, 1),
+            style.number(crossing.duration() / 60.0, 1),
+            style.number(crossing.min_field * 1e6, 2)
+        );
+    }
+    println!("{SEPARATOR}");
+    println!();
+}
+
+fn thermal(args: &ThermalArgs) {
+    let body = Body::earth();
+    let radius = body.radius + Meters::from_km(args.altitude);
+    let solar_flux = args
+        .date
+        .map_or(thermal::SOLAR_CONSTANT, thermal::solar_flux);
+    let environment = thermal::environment(body.radius, radius, args.beta, solar_flux, args.albedo);
+    let period = calculate_orbital_period(body.mu, radius).minutes();
+    let eclipse = 1.0 - environment.sunlit_fraction;
+
+    println!();
+    let units = report_units(&body);
+    let number = |value: f64| units.style.number(value, 1);
+    print_heading(&t!(
+        "thermal",
+        altitude = units.brief(args.altitude),
+        unit = units.length_symbol(),
+        beta = units.style.brief(args.beta, 4)
+    ));
+    println!(
+        "{}: {}% ({} {})",
+        t!("sunlit-fraction"),
+        number(environment.sunlit_fraction * 100.0),
+        number(environment.sunlit_fraction * period),
+        t!("minutes")
+    );
+    println!(
+        "{}: {}% ({} {})",
+        t!("eclipse-fraction"),
+        number(eclipse * 100.0),
+        number(eclipse * period),
+        t!("minutes")
+    );
+    println!(
+        "{}: {} deg",
+        t!("critical-beta"),
+        number(eclipse::critical_beta(body.radius, radius))
+    );
+    println!("{SEPARATOR}");
+    println!();
+    println!();
+    print_heading(&t!("orbit-averaged-flux"));
+    println!("{}: {} W/m^2", t!("solar-flux"), number(solar_flux));
+    println!(
+        "{}: {} W/m^2",
+        t!("average-solar"),
+        number(environment.average_solar)
+    );
+    println!(
+        "{}: {} W/m^2",
+        t!("average-albedo"),
+        number(environment.average_albedo)
+    );
+    println!(
+        "{}: {} W/m^2",
+        t!("earth-infrared"),
+        number(environment.earth_infrared)
+    );
+    println!("{SEPARATOR}");
+    println!();
+}
+
+fn power(args: &PowerArgs) {
+    let body = Body::earth();
+    let radius = body.radius + Meters::from_km(args.altitude);
+    let solar_flux = args
+        .date
+        .map_or(thermal::SOLAR_CONSTANT, thermal::solar_flux);
+    let lit = power::illumination(body.radius, radius, args.beta, args.pointing);
+    let period = calculate_orbital_period(body.mu, radius);
+
+    let peak = solar_flux * args.area * args.efficiency * lit.peak; // W
+    let average = solar_flux * args.area * args.efficiency * lit.average; // W
+    let sunlit_average = if lit.sunlit > 0.0 {
+        average / lit.sunlit
+    } else {
+        0.0
+    };
+
+    println!();
+    let units = report_units(&body);
+    let number = |value: f64, decimals| units.style.number(value, decimals);
+    print_heading(&t!(
+        "power",
+        altitude = units.brief(args.altitude),
+        unit = units.length_symbol(),
+        beta = units.style.brief(args.beta, 4)
+    ));
+    println!("{}: {} W/m^2", t!("solar-flux"), number(solar_flux, 1));
+    println!(
+        "{}: {}% ({} {})",
+        t!("sunlit-fraction"),
+        number(lit.sunlit * 100.0, 1),
+        number(lit.sunlit * period.minutes(), 1),
+        t!("minutes")
+    );
+    println!("{SEPARATOR}");
+    println!();
+    println!();
+    print_heading(&t!("array-output"));
+    println!("{}: {} W", t!("peak-power"), number(peak, 2));
+    println!(
+        "{}: {} W",
+        t!("sunlit-average-power"),
+        number(sunlit_average, 2)
+    );
+    println!("{}: {} W", t!("orbit-average-power"), number(average, 2));
+    println!(
+        "{}: {} Wh",
+        t!("energy-per-orbit"),
+        number(average * period.hours(), 2)
+    );
+    println!("{SEPARATOR}");
+    println!();
+}
+
+fn plot(args: &PlotArgs) {
+    let options = plot::Options {
+        min_elevation: args.min_elevation,
+        ballistic_coefficient: args.bc,
+    };
+    let data = plot::series(
+        args.curve,
+        &Body::earth(),
+        args.from,
+        args.to,
+        args.points,
+        &options,
+    );
+    if data.len() < 2 {
+        eprintln!("{}", t!("plot-no-data"));
+        std::process::exit(1);
+    }
+
+    let (title, y) = match args.curve {
+        plot::Curve::Period => (t!("orbital-period"), t!("plot-period-axis")),
+        plot::Curve::Velocity => (t!("circular-velocity"), t!("plot-velocity-axis")),
+        plot::Curve::Coverage => (
+            t!("plot-coverage-title", elevation = args.min_elevation),
+            t!("plot-coverage-axis"),
+        ),
+        plot::Curve::Decay => (t!("plot-decay-title", bc = args.bc), t!("plot-decay-axis")),
+    };
+    let labels = plot::Labels {
+        title,
+        x: t!("plot-altitude-axis"),
+        y,
+    };
+    let log_y = matches!(args.curve, plot::Curve::Decay);
+
+    if let Err(err) = plot::render_svg(&args.out, &labels, &data, log_y) {
+        eprintln!("{}", t!("error-write", error = err));
+        std::process::exit(1);
+    }
+}
+
+// Standard geostationary transfer orbit apsides, km
+const GTO_PERIGEE: f64 = 250.0;
+const GTO_APOGEE: f64 = 35786.0;
+
+fn diagram(names: &[String], out: &Path) {
+    let earth = Rc::new(Body::earth());
+    let presets = presets(Named::Earth, &earth);
+    let radius = |altitude: f64| (earth.radius + Meters::from_km(altitude)).0;
+
+    let items: Vec<diagram::Item> = names
+        .iter()
+        .map(|name| {
+            if name.eq_ignore_ascii_case("gto") {
+                return diagram::Item {
+                    label: "GTO".to_string(),
+                    shape: diagram::Shape::Transfer {
+                        periapsis: radius(GTO_PERIGEE),
+                        apoapsis: radius(GTO_APOGEE),
+                    },
+                };
+            }
+            if let Some(orbit) = presets.iter().find(|o| o.name.eq_ignore_ascii_case(name)) {
+                return match orbit.altitude {
+                    Altitude::Single { value } => diagram::Item {
+                        label: format!("{} ({value} km)", orbit.name),
+                        shape: diagram::Shape::Circle {
+                            radius: radius(value),
+                        },
+                    },
+                    Altitude::Range { max, min } => diagram::Item {
+                        label: format!("{} ({min}-{max} km)", orbit.name),
+                        shape: diagram::Shape::Band {
+                            inner: radius(min),
+                            outer: radius(max),
+                        },
+                    },
+                    Altitude::Elliptical {
+                        periapsis,
+                        apoapsis,
+                    } => diagram::Item {
+                        label: orbit.name.clone(),
+                        shape: diagram::Shape::Transfer {
+                            periapsis: radius(periapsis),
+                            apoapsis: radius(apoapsis),
+                        },
+                    },
+                };
+            }
+            match orbit::parse_length(name) {
+                Ok(altitude) => diagram::Item {
+                    label: format!("{altitude} km"),
+                    shape: diagram::Shape::Circle {
+                        radius: radius(altitude),
+                    },
+                },
+                Err(_) => {
+                    eprintln!("{}", t!("diagram-unknown-orbit", name = name));
+                    std::process::exit(1)
+                }
+            }
+        })
+        .collect();
+
+    let labels = diagram::Labels {
+        title: t!("diagram"),
+        body: t!("earth"),
+        periapsis: t!("perigee"),
+        apoapsis: t!("apogee"),
+    };
+    if let Err(err) = diagram::render_svg(out, earth.radius.0, &items, &labels) {
+        eprintln!("{}", t!("error-write", error = err));
+        std::process::exit(1);
+    }
+}
+
+fn gltf(args: &GltfArgs) {
+    let earth = Body::earth();
+    let mut paths = vec![];
+    for (i, orbit) in args.orbits.iter().enumerate() {
+        let color = gltf::PALETTE[i % gltf::PALETTE.len()];
+        let trajectory = sample::sample_circular(
+            &earth,
+            orbit.altitude,
+            orbit.inclination,
+            orbit.raan,
+            args.points,
+            sample::Frame::Eci,
+        );
+        let name = format!("{} km {}°", orbit.altitude, orbit.inclination);
+        if args.ground_tracks {
+            // just clear of the surface so the sphere does not hide it
+            let track = trajectory.ground_track(earth.radius * 1.002, EARTH_ROTATION_RATE);
+            paths.push(gltf::Path {
+                name: t!("ground-track", orbit = name),
+                points: track.export_positions(args.scale, sample::Up::Y),
+                closed: false,
+                color,
+            });
+        }
+        paths.push(gltf::Path {
+            name,
+            points: trajectory.export_positions(args.scale, sample::Up::Y),
+            closed: true,
+            color,
+        });
+    }
+
+    let result = File::create(&args.out).and_then(|file| {
+        let mut writer = BufWriter::new(file);
+        gltf::write_glb(
+            &mut writer,
+            &t!("earth"),
+            earth.radius.0 * args.scale,
+            &paths,
+        )?;
+        writer.flush()
+    });
+    if let Err(err) = result {
+        eprintln!("{}", t!("error-write", error = err));
+        std::process::exit(1);
+    }
+}
+
+fn animate(orbit: &sample::Circular, frames: usize, out: &Path) {
+    let earth = Body::earth();
+    let trajectory = sample::sample_circular(
+        &earth,
+        orbit.altitude,
+        orbit.inclination,
+        orbit.raan,
+        frames,
+        sample::Frame::Eci,
+    );
+    let track = trajectory.ground_track(earth.radius, EARTH_ROTATION_RATE);
+
+    let result = std::fs::create_dir_all(out)
+        .map_err(|err| err.into())
+        .and_then(|_| {
+            trajectory
+                .times
+                .iter()
+                .enumerate()
+                .try_for_each(|(index, time)| {
+                    let labels = animate::Labels {
+                        title: t!(
+                            "animate-title",
+                            altitude = orbit.altitude,
+                            inclination = orbit.inclination,
+                            minutes = format!("{:.1}", time / 60.0)
+                        ),
+                        longitude: t!("longitude"),
+                        latitude: t!("latitude"),
+                    };
+                    let path = animate::frame_path(out, index);
+                    animate::render_frame(
+                        &path,
+                        earth.radius.0,
+                        &trajectory,
+                        &track,
+                        index,
+                        &labels,
+                    )
+                })
+        });
+    match result {
+        Ok(()) => println!(
+            "{}",
+            t!(
+                "animate-written",
+                count = trajectory.times.len(),
+                dir = out.display()
+            )
+        ),
+        Err(err) => {
+            eprintln!("{}", t!("error-write", error = err));
+            std::process::exit(1);
+        }
+    }
+}
+
+fn phasing(args: &PhasingArgs) {
+    let walker = constellation::Walker {
+        total: args.satellites,
+        planes: args.planes,
+        phasing: args.phasing,
+    };
+    if !walker.is_valid() || args.drift_offset == 0.0 {
+        eprintln!(
+            "{}",
+            t!(
+                "phasing-invalid",
+                total = walker.total,
+                planes = walker.planes,
+                phasing = walker.phasing
+            )
+        );
+        std::process::exit(1);
+    }
+
+    let body = Body::earth();
+    let radius = body.radius + Meters::from_km(args.altitude);
+    let offset = Meters::from_km(args.drift_offset);
+    let drift = constellation::drift_rate(body.mu, radius, offset);
+    // raise or lower into the drift orbit, then return once on station
+    let delta_v = 2.0 * calculate_hohmann_transfer(body.mu, radius, radius + offset).total();
+    let units = report_units(&body);
+
+    println!();
+    print_heading(&t!(
+        "phasing",
+        total = walker.total,
+        planes = walker.planes,
+        phasing = walker.phasing,
+        altitude = units.brief(args.altitude),
+        unit = units.length_symbol()
+    ));
+    let number = |value: f64, decimals| units.style.number(value, decimals);
+    println!("{}: {}", t!("phasing-per-plane"), walker.per_plane());
+    println!(
+        "{}: {} deg",
+        t!("phasing-in-plane"),
+        number(walker.in_plane_spacing(), 3)
+    );
+    println!(
+        "{}: {} deg",
+        t!("phasing-raan"),
+        number(walker.plane_spacing(), 3)
+    );
+    println!(
+        "{}: {} deg",
+        t!("phasing-offset"),
+        number(walker.phase_offset(), 3)
+    );
+    println!("{SEPARATOR}");
+    println!();
+
+    print_heading(&t!(
+        "phasing-drift",
+        offset = format!(
+            "{}{}",
+            if args.drift_offset > 0.0 { "+" } else { "" },
+            units.brief(args.drift_offset)
+        ),
+        unit = units.length_symbol()
+    ));
+    println!(
+        "{}: {}{} deg/d",
+        t!("phasing-drift-rate"),
+        if drift.is_sign_negative() { "" } else { "+" },
+        number(drift, 4)
+    );
+    println!(
+        "{}: {} {}",
+        t!("phasing-slot-time"),
+        number(walker.in_plane_spacing() / drift.abs(), 1),
+        t!("days")
+    );
+    println!(
+        "{}: {}",
+        t!("phasing-delta-v"),
+        units.speed_to(delta_v, MetersPerSecond(0.01))
+    );
+    println!("{SEPARATOR}");
+    println!(
+        "{:>6}  {:>12}  {:>12}",
+        t!("phasing-slot"),
+        t!("phasing-target"),
+        t!("phasing-days")
+    );
+    for slot in 1..walker.per_plane() {
+        let target = slot as f64 * walker.in_plane_spacing();
+        println!(
+            "{slot:>6}  {:>12}  {:>12}",
+            number(target, 3),
+            number(target / drift.abs(), 1)
+        );
+    }
+    println!("{SEPARATOR}");
+    println!();
+}
+
+fn elements(args: &ElementsArgs) {
+    let body = Body::earth();
+    let (state, elements) = match (&args.from_state, &args.from_elements) {
+        (Some(values), _) => {
+            let state = StateVector {
+                position: [values[0], values[1], values[2]].map(|v| v * 1000.0),
+                velocity: [values[3], values[4], values[5]].map(|v| v * 1000.0),
+            };
+            (state, KeplerianElements::from_state(body.mu.0, &state))
+        }
+        (_, Some(values)) => {
+            let elements = KeplerianElements {
+                semi_major_axis: values[0] * 1000.0,
+                eccentricity: values[1],
+                inclination: values[2],
+                raan: values[3],
+                argument_of_periapsis: values[4],
+                true_anomaly: values[5],
+            };
+            let open = elements.eccentricity >= 1.0;
+            if elements.eccentricity < 0.0
+                || elements.eccentricity == 1.0
+                || (elements.semi_major_axis < 0.0) != open
+            {
+                eprintln!("{}", t!("error-keplerian"));
+                std::process::exit(1);
+            }
+            (elements.to_state(body.mu.0), elements)
+        }
+        _ => unreachable!("clap requires one input"),
+    };
+    print_elements(&body, &state, &elements);
+}
+
+fn coverage(args: &CoverageArgs) {
+    let body = args.body.body().spherical();
+    let sensor = match (args.half_angle, args.swath, args.min_elevation) {
+        (Some(half_angle), _, _) => coverage::Sensor::HalfAngle(half_angle),
+        (_, Some(swath), _) => coverage::Sensor::Swath(swath),
+        (_, _, Some(elevation)) => coverage::Sensor::MinElevation(elevation),
+        _ => unreachable!("clap requires a sensor"),
+    };
+    let angle = coverage::central_angle(&body, args.altitude, sensor);
+    let surface = coverage::surface_area(&body);
+    let area = coverage::footprint_area(&body, angle);
+    let period = calculate_orbital_period(body.mu, body.radius + Meters::from_km(args.altitude));
+    let units = report_units(&body);
+
+    println!();
+    print_heading(&t!(
+        "coverage",
+        altitude = units.brief(args.altitude),
+        unit = units.length_symbol(),
+        inclination = units.style.brief(args.inc, 4)
+    ));
+    let number = |value: f64, decimals| units.style.number(value, decimals);
+    println!(
+        "{}: {} deg",
+        t!("coverage-central-angle"),
+        number(angle.to_degrees(), 3)
+    );
+    println!(
+        "{}: {}",
+        t!("coverage-radius"),
+        units.length_to(angle * body.radius, Meters(100.0))
+    );
+    println!(
+        "{}: {} ({}%)",
+        t!("coverage-area"),
+        units.area_to(area, 1e6),
+        number(area / surface * 100.0, 3)
+    );
+    println!(
+        "{}: {}%",
+        t!("coverage-per-orbit"),
+        number(coverage::fraction_per_orbit(angle) * 100.0, 2)
+    );
+    println!(
+        "{}: {}",
+        t!("coverage-orbits-per-day"),
+        number(Seconds::from_days(1.0) / period, 3)
+    );
+    let latitude = units.style.brief(args.lat, 4);
+    match coverage::revisit(&body, args.altitude, args.inc, angle, args.lat) {
+        Some(revisit) => println!(
+            "{}: {} {} ({} {})",
+            t!("coverage-revisit", latitude = latitude),
+            number(revisit.hours(), 2),
+            t!("hours"),
+            number(revisit.days(), 2),
+            t!("days")
+        ),
+        None => println!(
+            "{}: {}",
+            t!("coverage-revisit", latitude = latitude),
+            t!("coverage-never")
+        ),
+    }
+    println!("{SEPARATOR}");
+    println!("{}", t!("coverage-note"));
+    println!();
+}
+
+fn eclipse(args: &EclipseArgs) {
+    let body = args.body.body().spherical();
+    let radius = body.radius + Meters::from_km(args.altitude);
+    let period = calculate_orbital_period(body.mu, radius).minutes();
+    let eclipse = eclipse::eclipse_fraction(body.radius, radius, args.beta);
+    let longest = eclipse::eclipse_fraction(body.radius, radius, 0.0);
+    let units = report_units(&body);
+
+    println!();
+    print_heading(&t!(
+        "eclipse",
+        body = t!(args.body.name()),
+        altitude = units.brief(args.altitude),
+        unit = units.length_symbol(),
+        beta = units.style.brief(args.beta, 4)
+    ));
+    let number = |value: f64| units.style.number(value, 1);
+    println!(
+        "{}: {} {}",
+        t!("orbital-period"),
+        number(period),
+        t!("minutes")
+    );
+    println!(
+        "{}: {}% ({} {})",
+        t!("sunlit-fraction"),
+        number((1.0 - eclipse) * 100.0),
+        number((1.0 - eclipse) * period),
+        t!("minutes")
+    );
+    println!(
+        "{}: {}% ({} {})",
+        t!("eclipse-fraction"),
+        number(eclipse * 100.0),
+        number(eclipse * period),
+        t!("minutes")
+    );
+    println!(
+        "{}: {} {}",
+        t!("eclipse-longest"),
+        number(longest * period),
+        t!("minutes")
+    );
+    println!(
+        "{}: {} deg",
+        t!("critical-beta"),
+        number(eclipse::critical_beta(body.radius, radius))
+    );
+    println!("{SEPARATOR}");
+    println!("{}", t!("eclipse-note"));
+    println!();
+}
+
+fn beta(args: &BetaArgs) {
+    let body = Body::earth();
+    let style = report_style();
+    // (orbit radius, nodal drift in deg/day) when the altitude is known
+    let orbit = args.altitude.map(|altitude| {
+        let radius = body.radius + Meters::from_km(altitude);
+        let j2 = body.j2.expect("the Earth has a J2");
+        let rates = calculate_j2_rates(body.mu, body.radius, j2, radius, 0.0, args.inc);
+        (radius, rates.nodal_regression)
+    });
+
+    let Some(range) = args.range else {
+        let days = args.date.unwrap_or_else(time::now);
+        let angle = beta::beta_angle(args.inc, args.raan, days);
+        println!();
+        print_heading(&t!(
+            "beta",
+            inclination = style.brief(args.inc, 4),
+            raan = style.brief(args.raan, 4),
+            date = time::format_utc(days)
+        ));
+        println!("{}: {} deg", t!("beta-angle"), style.number(angle, 2));
+        if let Some((radius, _)) = orbit {
+            let period = calculate_orbital_period(body.mu, radius).minutes();
+            let eclipse = eclipse::eclipse_fraction(body.radius, radius, angle);
+            println!(
+                "{}: {}% ({} {})",
+                t!("eclipse-fraction"),
+                style.number(eclipse * 100.0, 1),
+                style.number(eclipse * period, 1),
+                t!("minutes")
+            );
+            println!(
+                "{}: {} deg",
+                t!("critical-beta"),
+                style.number(eclipse::critical_beta(body.radius, radius), 1)
+            );
+        }
+        println!("{SEPARATOR}");
+        println!();
+        return;
+    };
+
+    if args.step <= 0.0 {
+        eprintln!("{}", t!("error-beta"));
+        std::process::exit(1)
+    }
+    let drift = orbit.map_or(0.0, |(_, drift)| drift);
+    let samples = beta::sweep(args.inc, args.raan, drift, range, args.step);
+    let by_beta = |a: &&beta::Sample, b: &&beta::Sample| a.beta.total_cmp(&b.beta);
+    let lowest = samples.iter().min_by(by_beta).expect("a sweep has samples");
+    let highest = samples.iter().max_by(by_beta).expect("a sweep has samples");
+
+    println!();
+    print_heading(&t!(
+        "beta-sweep",
+        inclination = style.brief(args.inc, 4),
+        raan = style.brief(args.raan, 4),
+        start = time::format_utc(range.0),
+        end = time::format_utc(range.1)
+    ));
+    println!(
+        "{}: {} deg ({})",
+        t!("beta-minimum"),
+        style.number(lowest.beta, 2),
+        time::format_utc(lowest.days)
+    );
+    println!(
+        "{}: {} deg ({})",
+        t!("beta-maximum"),
+        style.number(highest.beta, 2),
+        time::format_utc(highest.days)
+    );
+    if let Some((radius, drift)) = orbit {
+        let critical = eclipse::critical_beta(body.radius, radius);
+        println!(
+            "{}: {} deg/day",
+            t!("beta-nodal-drift"),
+            style.number(drift, 4)
+        );
+        println!("{}: {} deg", t!("critical-beta"), style.number(critical, 1));
+        println!("{SEPARATOR}");
+        println!("{}", t!("beta-seasons"));
+        let seasons = beta::seasons(&samples, critical);
+        if seasons.is_empty() {
+            println!("  {}", t!("beta-no-seasons"));
+        }
+        for (first, last) in seasons {
+            println!(
+                "  {} - {} ({} {})",
+                time::format_utc(first),
+                time::format_utc(last),
+                style.number(last - first + args.step, 0),
+                t!("days")
+            );
+        }
+    }
+    println!("{SEPARATOR}");
+    println!();
+}
+
+fn sat(args: &SatArgs) {
+    let earth = Rc::new(Body::earth());
+    let Some(satellite) = args.satellite else {
+        println!();
+        print_heading(&t!("sat-catalog"));
+        for satellite in satellites::ALL {
+            let orbit = satellite.orbit(&earth);
+            let catalog = satellite
+                .catalog_number()
+                .map(|number| format!(", NORAD {number}"))
+                .unwrap_or_default();
+            println!("{}{catalog}", orbit.get_label().trim_end());
+        }
+        println!("{SEPARATOR}");
+        println!();
+        return;
+    };
+
+    #[cfg(feature = "fetch")]
+    let fetched = args.fetch.then(|| {
+        let query = match (satellite.catalog_number(), satellite.group()) {
+            (Some(number), _) => fetch::Query::Catalog(number.to_string()),
+            (_, Some(group)) => fetch::Query::Group(group.to_string()),
+            _ => unreachable!("every satellite has a number or a group"),
+        };
+        download(&query)
+    });
+    #[cfg(not(feature = "fetch"))]
+    let fetched = None;
+    let sets = fetched.or_else(|| args.tle.as_ref().map(|_| read_tles(&args.tle)));
+
+    let (orbit, source) = match sets {
+        Some(sets) => {
+            // constellations have no number of their own, so take the first
+            let set = match satellite.catalog_number() {
+                Some(number) => sets.iter().find(|set| set.catalog_number == number),
+                None => sets.first(),
+            };
+            let Some(set) = set else {
+                eprintln!("{}", t!("error-sat-tle", name = satellite.name()));
+                std::process::exit(1)
+            };
+            let source = t!(
+                "sat-source-tle",
+                catalog = &set.catalog_number,
+                year = set.epoch_year,
+                day = report_style().number(set.epoch_day, 4)
+            );
+            (satellites::from_tle(satellite.name(), set, &earth), source)
+        }
+        None => (satellite.orbit(&earth), t!("sat-source-nominal")),
+    };
+
+    println!();
+    print_heading(&t!("sat", name = satellite.name()));
+    println!("{}", orbit.get_label().trim_end());
+    if let Some(number) = satellite.catalog_number() {
+        println!("NORAD {number}");
+    }
+    println!();
+    println!("{}", orbit.get_period_string());
+    println!();
+    println!("{}", orbit.get_velocity_string());
+    println!("{SEPARATOR}");
+    println!("{source}");
+    println!();
 }
 
-fn calculate_circular_orbital_velocity(mass: f64, semi_major_axis: f64) -> f64 {
-    // mass: kg
-    // semi_major_axis: m
-    ((GRAVITATIONAL_CONSTANT * mass) / semi_major_axis).sqrt() // m/s
+/// Element sets for `query` as text, warning when they come from an expired
+/// copy and exiting with a message when they cannot be had.
+#[cfg(feature = "fetch")]
+fn fetch_text(query: &fetch::Query, max_age: Option<std::time::Duration>) -> String {
+    let download = fetch::fetch(query, max_age).unwrap_or_else(|err| {
+        eprintln!("{}", t!("error-fetch", error = err));
+        std::process::exit(1)
+    });
+    if let Some(age) = download.stale {
+        eprintln!(
+            "{}",
+            t!(
+                "warning-stale-tle",
+                hours = format!("{:.1}", age.as_secs_f64() / 3600.0)
+            )
+        );
+    }
+    download.text
+}
+
+/// Parsed element sets for `query`.
+#[cfg(feature = "fetch")]
+fn download(query: &fetch::Query) -> Vec<tle::Tle> {
+    tle::parse(&fetch_text(query, None)).unwrap_or_else(|err| {
+        eprintln!("{}", t!("error-fetch", error = err));
+        std::process::exit(1)
+    })
+}
+
+#[cfg(feature = "fetch")]
+fn fetch_tles(args: &FetchArgs) {
+    let query = args.catnr.as_ref().or(args.group.as_ref());
+    let query = query.expect("clap requires a catalog number or group");
+    let max_age = args
+        .max_age
+        .map(|hours| std::time::Duration::from_secs_f64(hours.max(0.0) * 3600.0));
+    let text = fetch_text(query, max_age);
+    let mut output = create_output(&args.out);
+    if let Err(err) = output
+        .write_all(text.as_bytes())
+        .and_then(|_| output.flush())
+    {
+        eprintln!("{}", t!("error-write", error = err));
+        std::process::exit(1);
+    }
+}
+
+#[cfg(feature = "fetch")]
+fn cache(action: &CacheAction) {
+    let Some(dir) = fetch::cache_dir() else {
+        eprintln!("{}", t!("error-no-cache"));
+        std::process::exit(1);
+    };
+    match action {
+        CacheAction::List => {
+            let entries = fetch::entries(&dir);
+            println!();
+            print_heading(&t!("cache", path = dir.display()));
+            if entries.is_empty() {
+                println!("{}", t!("cache-empty"));
+            }
+            for entry in entries {
+                let query = match &entry.query {
+                    fetch::Query::Catalog(number) => format!("NORAD {number}"),
+                    fetch::Query::Group(group) => group.clone(),
+                };
+                let state = if entry.expired {
+                    t!("cache-expired")
+                } else {
+                    t!("cache-fresh")
+                };
+                println!(
+                    "{query}: {} ({} h, {state})",
+                    t!("cache-sets", count = entry.sets),
+                    report_style().number(entry.age.as_secs_f64() / 3600.0, 1)
+                );
+            }
+            println!("{SEPARATOR}");
+            println!();
+        }
+        CacheAction::Clear { catnr, group } => {
+            match fetch::clear(&dir, catnr.as_ref().or(group.as_ref())) {
+                Ok(removed) => println!("{}", t!("cache-cleared", count = removed)),
+                Err(err) => {
+                    eprintln!("{}", t!("error-write", error = err));
+                    std::process::exit(1);
+                }
+            }
+        }
+    }
+}
+
+fn determine(args: &GibbsArgs, times: Option<&[f64]>) {
+    let body = Body::earth();
+    let km = |values: &[f64]| [values[0], values[1], values[2]].map(|v| v * 1000.0);
+    let positions = [km(&args.r1), km(&args.r2), km(&args.r3)];
+    let velocity = match times {
+        Some(times) => {
+            determine::herrick_gibbs(body.mu.0, positions, [times[0], times[1], times[2]])
+        }
+        None => determine::gibbs(body.mu.0, positions),
+    }
+    .unwrap_or_else(|err| {
+        eprintln!("{}", t!("error-determine", error = err));
+        std::process::exit(1)
+    });
+    let state = StateVector {
+        position: positions[1],
+        velocity,
+    };
+    print_elements(
+        &body,
+        &state,
+        &KeplerianElements::from_state(body.mu.0, &state),
+    );
+}
+
+fn print_elements(body: &Body, state: &StateVector, elements: &KeplerianElements) {
+    let units = report_units(body);
+    println!();
+    print_heading(&t!("elements"));
+    println!(
+        "{}: {}",
+        t!("semi-major-axis"),
+        units.length(Meters(elements.semi_major_axis))
+    );
+    let number = |value: f64, decimals| units.style.number(value, decimals);
+    println!(
+        "{}: {}",
+        t!("eccentricity"),
+        number(elements.eccentricity, 6)
+    );
+    println!(
+        "{}: {} deg",
+        t!("inclination"),
+        number(elements.inclination, 4)
+    );
+    println!("{}: {} deg", t!("raan"), number(elements.raan, 4));
+    println!(
+        "{}: {} deg",
+        t!("argument-of-periapsis"),
+        number(elements.argument_of_periapsis, 4)
+    );
+    println!(
+        "{}: {} deg",
+        t!("true-anomaly"),
+        number(elements.true_anomaly, 4)
+    );
+    println!("{SEPARATOR}");
+    println!(
+        "{}: {}",
+        t!("periapsis-altitude"),
+        units.length(Meters(elements.periapsis()) - body.radius)
+    );
+    if let Some(apoapsis) = elements.apoapsis() {
+        println!(
+            "{}: {}",
+            t!("apoapsis-altitude"),
+            units.length(Meters(apoapsis) - body.radius)
+        );
+        let period = calculate_orbital_period(body.mu, Meters(elements.semi_major_axis));
+        println!(
+            "{}: {} {}",
+            t!("orbital-period"),
+            number(period.minutes(), 3),
+            t!("minutes")
+        );
+    }
+    println!("{SEPARATOR}");
+    println!();
+
+    // finer than the report's usual decimals, to pass on to other tools
+    let join = |values: [f64; 3], scale: f64| values.map(|v| number(v / scale, 6)).join(", ");
+    let lengths = join(state.position, units.length_scale().0);
+    let speeds = join(state.velocity, units.speed_scale().0);
+    print_heading(&t!("state-vector"));
+    println!("{}: {lengths} {}", t!("position"), units.length_symbol());
+    println!("{}: {speeds} {}", t!("velocity"), units.speed_symbol());
+    println!("{SEPARATOR}");
+    println!();
+}
+
+fn anomaly(args: &AnomalyArgs) {
+    let e = args.ecc;
+    let result = match (args.mean, args.eccentric, args.true_anomaly) {
+        (Some(mean), ..) => anomaly::Anomalies::from_mean(mean.to_radians(), e),
+        (_, Some(eccentric), _) => anomaly::Anomalies::from_eccentric(eccentric.to_radians(), e),
+        (.., Some(true_anomaly)) => anomaly::Anomalies::from_true(true_anomaly.to_radians(), e),
+        _ => unreachable!("clap requires one anomaly"),
+    };
+    let anomalies = result.unwrap_or_else(|err| {
+        eprintln!("{}", t!("error-anomaly", error = err));
+        std::process::exit(1)
+    });
+    let hyperbolic = e > 1.0;
+    // hyperbolic mean and eccentric anomalies are not angles, so give radians too
+    let style = report_style();
+    let line = |label: String, value: f64| {
+        println!(
+            "{label}: {} deg ({} rad)",
+            style.number(value.to_degrees(), 6),
+            style.number(value, 8)
+        )
+    };
+
+    println!();
+    print_heading(&t!(
+        if hyperbolic {
+            "anomaly-hyperbolic"
+        } else {
+            "anomaly-elliptic"
+        },
+        eccentricity = style.brief(e, 6)
+    ));
+    line(t!("mean-anomaly"), anomalies.mean);
+    line(
+        t!(if hyperbolic {
+            "hyperbolic-anomaly"
+        } else {
+            "eccentric-anomaly"
+        }),
+        anomalies.eccentric,
+    );
+    line(t!("true-anomaly"), anomalies.true_anomaly);
+    if hyperbolic {
+        println!(
+            "{}: {} deg",
+            t!("asymptote-anomaly"),
+            style.number((-1.0 / e).acos().to_degrees(), 6)
+        );
+    }
+    println!("{SEPARATOR}");
+    println!();
+}
+
+fn hohmann(args: &TransferArgs) {
+    let body = args.body.body();
+    let units = report_units(&body);
+    if args.from < 0.0 || args.to < 0.0 {
+        eprintln!("{}", t!("error-transfer-altitude"));
+        std::process::exit(1);
+    }
+    let transfer = calculate_hohmann_transfer(
+        body.mu,
+        body.radius + Meters::from_km(args.from),
+        body.radius + Meters::from_km(args.to),
+    );
+
+    println!();
+    print_heading(&t!(
+        "transfer-hohmann",
+        body = t!(args.body.name()),
+        from = units.brief(args.from),
+        to = units.brief(args.to),
+        unit = units.length_symbol()
+    ));
+    println!(
+        "{}: {}",
+        t!("transfer-first-burn"),
+        units.speed(transfer.first_burn)
+    );
+    println!(
+        "{}: {}",
+        t!("transfer-second-burn"),
+        units.speed(transfer.second_burn)
+    );
+    println!(
+        "{}: {}",
+        t!("transfer-total"),
+        units.speed(transfer.total())
+    );
+    println!(
+        "{}: {} {} ({} {})",
+        t!("transfer-time"),
+        units.style.number(transfer.transfer_time.hours(), 2),
+        t!("hours"),
+        units.style.number(transfer.transfer_time.minutes(), 1),
+        t!("minutes")
+    );
+    println!("{SEPARATOR}");
+    println!();
+}
+
+fn bielliptic(args: &TransferArgs, apogee: f64) {
+    let body = args.body.body();
+    let units = report_units(&body);
+    if args.from < 0.0 || args.to < 0.0 {
+        eprintln!("{}", t!("error-transfer-altitude"));
+        std::process::exit(1);
+    }
+    if apogee < args.from.max(args.to) {
+        eprintln!("{}", t!("error-bielliptic-apogee"));
+        std::process::exit(1);
+    }
+    let from = body.radius + Meters::from_km(args.from);
+    let to = body.radius + Meters::from_km(args.to);
+    let transfer =
+        calculate_bielliptic_transfer(body.mu, from, to, body.radius + Meters::from_km(apogee));
+    let hohmann = calculate_hohmann_transfer(body.mu, from, to);
+    let ratio = from.max(to) / from.min(to);
+
+    println!();
+    print_heading(&t!(
+        "transfer-bielliptic",
+        body = t!(args.body.name()),
+        from = units.brief(args.from),
+        to = units.brief(args.to),
+        apogee = units.brief(apogee),
+        unit = units.length_symbol()
+    ));
+    println!(
+        "{}: {}",
+        t!("transfer-first-burn"),
+        units.speed(transfer.first_burn)
+    );
+    println!(
+        "{}: {}",
+        t!("transfer-second-burn"),
+        units.speed(transfer.second_burn)
+    );
+    println!(
+        "{}: {}",
+        t!("transfer-third-burn"),
+        units.speed(transfer.third_burn)
+    );
+    println!(
+        "{}: {}",
+        t!("transfer-total"),
+        units.speed(transfer.total())
+    );
+    println!(
+        "{}: {} {} ({} {})",
+        t!("transfer-time"),
+        units.style.number(transfer.transfer_time.hours(), 2),
+        t!("hours"),
+        units.style.number(transfer.transfer_time.days(), 2),
+        t!("days")
+    );
+    println!("{SEPARATOR}");
+    println!(
+        "{}: {}",
+        t!("transfer-hohmann-total"),
+        units.speed(hohmann.total())
+    );
+    println!("{}: {}", t!("transfer-ratio"), units.style.number(ratio, 2));
+    let saving = hohmann.total() - transfer.total();
+    if saving > MetersPerSecond(0.0) {
+        println!(
+            "{}",
+            t!("transfer-bielliptic-wins", saving = units.speed(saving))
+        );
+    } else {
+        println!(
+            "{}",
+            t!("transfer-hohmann-wins", saving = units.speed(-saving))
+        );
+    }
+    let note = match calculate_bielliptic_advantage(from, to) {
+        BiellipticAdvantage::Never => "transfer-ratio-hohmann",
+        BiellipticAdvantage::Depends => "transfer-ratio-depends",
+        BiellipticAdvantage::Always => "transfer-ratio-bielliptic",
+    };
+    println!("{}", t!(note));
+    println!("{SEPARATOR}");
+    println!();
+}
+
+fn plane_change(args: &PlaneChangeArgs) {
+    let body = args.body.body();
+    let units = report_units(&body);
+    if args.altitude < 0.0 || args.from.is_some_and(|from| from < 0.0) {
+        eprintln!("{}", t!("error-transfer-altitude"));
+        std::process::exit(1);
+    }
+    let body_name = t!(args.body.name());
+    let Some(from) = args.from else {
+        let velocity = calculate_circular_orbital_velocity(
+            body.mu,
+            body.radius + Meters::from_km(args.altitude),
+        );
+        println!();
+        print_heading(&t!(
+            "plane-change",
+            body = body_name,
+            angle = units.style.brief(args.delta_inc, 4),
+            altitude = units.brief(args.altitude),
+            unit = units.length_symbol()
+        ));
+        println!("{}: {}", t!("circular-velocity"), units.speed(velocity));
+        println!(
+            "{}: {}",
+            t!("plane-change-delta-v"),
+            units.speed(calculate_plane_change(velocity, args.delta_inc))
+        );
+        println!("{SEPARATOR}");
+        println!();
+        return;
+    };
+
+    let separate = maneuver::Maneuver::Hohmann {
+        from,
+        to: args.altitude,
+    }
+    .delta_v(&body)
+        + maneuver::Maneuver::PlaneChange {
+            altitude: args.altitude,
+            inclination: args.delta_inc,
+        }
+        .delta_v(&body);
+    let at_second = maneuver::combined_transfer(&body, from, args.altitude, args.delta_inc, 0.0);
+    let optimal = maneuver::optimal_combined_transfer(&body, from, args.altitude, args.delta_inc);
+    let split = |transfer: &maneuver::CombinedTransfer| {
+        println!(
+            "{}: {} ({} deg)",
+            t!("transfer-first-burn"),
+            units.speed(transfer.first_burn),
+            units.style.number(transfer.first_angle, 2)
+        );
+        println!(
+            "{}: {} ({} deg)",
+            t!("transfer-second-burn"),
+            units.speed(transfer.second_burn),
+            units.style.number(transfer.second_angle, 2)
+        );
+        println!(
+            "{}: {}",
+            t!("transfer-total"),
+            units.speed(transfer.total())
+        );
+    };
+
+    println!();
+    print_heading(&t!(
+        "plane-change-transfer",
+        body = body_name,
+        angle = units.style.brief(args.delta_inc, 4),
+        from = units.brief(from),
+        to = units.brief(args.altitude),
+        unit = units.length_symbol()
+    ));
+    println!("{}: {}", t!("plane-change-separate"), units.speed(separate));
+    println!("{SEPARATOR}");
+    println!("{}", t!("plane-change-at-second"));
+    split(&at_second);
+    println!("{SEPARATOR}");
+    println!("{}", t!("plane-change-optimal"));
+    split(&optimal);
+    println!(
+        "{}",
+        t!(
+            "plane-change-saving",
+            saving = units.speed(separate - optimal.total())
+        )
+    );
+    println!("{SEPARATOR}");
+    println!();
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+fn phase(args: &PhaseArgs) {
+    use phase::Endpoint;
+
+    if args.from == args.to {
+        eprintln!("{}", t!("error-phase-same"));
+        std::process::exit(1);
+    }
+    let (mu, from, to, heading) = match (args.from, args.to) {
+        (Endpoint::Altitude(from), Endpoint::Altitude(to)) => {
+            let body = args.body.body();
+            (
+                body.mu,
+                body.radius + Meters::from_km(from),
+                body.radius + Meters::from_km(to),
+                t!(
+                    "phase",
+                    body = t!(args.body.name()),
+                    from = report_units(&body).brief(from),
+                    to = report_units(&body).brief(to),
+                    unit = report_units(&body).length_symbol()
+                ),
+            )
+        }
+        (Endpoint::Planet(from), Endpoint::Planet(to)) => {
+            let orbit = |named: Named| named.mean_orbit().expect("parsed as a planet");
+            (
+                GravitationalParameter(habitable::SUN_MU),
+                orbit(from).semi_major_axis,
+                orbit(to).semi_major_axis,
+                t!("phase-planets", from = t!(from.name()), to = t!(to.name())),
+            )
+        }
+        _ => {
+            eprintln!("{}", t!("error-phase-mixed"));
+            std::process::exit(1);
+        }
+    };
+    let window = phase::hohmann_window(mu, from, to);
+    // planets use days throughout, orbits around a body hours
+    let planets = matches!(args.from, Endpoint::Planet(_));
+    let style = report_style();
+    let duration = |time: Seconds| {
+        if planets {
+            format!("{} {}", style.number(time.days(), 1), t!("days"))
+        } else {
+            format!("{} {}", style.number(time.hours(), 3), t!("hours"))
+        }
+    };
+
+    println!();
+    print_heading(&heading);
+    println!(
+        "{}: {}",
+        t!("transfer-time"),
+        duration(window.transfer_time)
+    );
+    println!(
+        "{}: {} deg",
+        t!("phase-angle"),
+        style.number(window.phase_angle, 3)
+    );
+    match window.synodic_period {
+        Some(synodic) => println!("{}: {}", t!("synodic-period"), duration(synodic)),
+        None => {
+            println!("{}", t!("phase-same-orbit"));
+            println!("{SEPARATOR}");
+            println!();
+            return;
+        }
+    }
 
-    #[test]
-    fn test_orbital_period() {
-        assert_eq!(calculate_orbital_period(1.0, 1.0), 769089.0705748867)
+    let current = match (args.from, args.to) {
+        (Endpoint::Planet(from), Endpoint::Planet(to)) => {
+            let date = args.date.unwrap_or_else(time::now);
+            let longitude = |named: Named| named.mean_orbit().unwrap().longitude_at(date);
+            println!("{SEPARATOR}");
+            println!("{}: {}", t!("phase-date"), time::format_utc(date));
+            println!(
+                "{}: {} deg",
+                t!("phase-longitude", body = t!(from.name())),
+                style.number(longitude(from), 3)
+            );
+            println!(
+                "{}: {} deg",
+                t!("phase-longitude", body = t!(to.name())),
+                style.number(longitude(to), 3)
+            );
+            Some((date, phase::phase(longitude(from), longitude(to))))
+        }
+        _ => args
+            .from_longitude
+            .zip(args.to_longitude)
+            .map(|(from, to)| (0.0, phase::phase(from, to))),
+    };
+    if let Some((date, current)) = current {
+        let wait = phase::wait_time(mu, from, to, current).expect("orbits differ");
+        println!("{}: {} deg", t!("phase-current"), style.number(current, 3));
+        if planets {
+            println!(
+                "{}: {} ({})",
+                t!("phase-next-window"),
+                time::format_utc(date + wait.days()),
+                t!("phase-in", time = duration(wait))
+            );
+        } else {
+            println!(
+                "{}: {}",
+                t!("phase-next-window"),
+                t!("phase-in", time = duration(wait))
+            );
+        }
     }
+    println!("{SEPARATOR}");
+    println!("{}", t!("phase-note"));
+    println!();
+}
 
-    #[test]
-    fn test_orbital_velocity() {
-        assert_eq!(
-            calculate_circular_orbital_velocity(1.0, 1.0),
-            8.169638914909275e-6
+fn synodic(args: &SynodicArgs) {
+    let body = args.body.body();
+    let period = |endpoint: phase::Endpoint| match endpoint {
+        phase::Endpoint::Altitude(altitude) => {
+            calculate_orbital_period(body.mu, body.radius + Meters::from_km(altitude))
+        }
+        phase::Endpoint::Planet(named) => calculate_orbital_period(
+            GravitationalParameter(habitable::SUN_MU),
+            named
+                .mean_orbit()
+                .expect("parsed as a planet")
+                .semi_major_axis,
+        ),
+    };
+    let first = args
+        .period1
+        .map(Seconds)
+        .or(args.orbit1.map(period))
+        .expect("clap requires a first orbit");
+    let second = args
+        .period2
+        .map(Seconds)
+        .or(args.orbit2.map(period))
+        .expect("clap requires a second orbit");
+    let style = report_style();
+    let line = |time: Seconds| {
+        format!(
+            "{} {} ({} {})",
+            style.number(time.0, 1),
+            t!("seconds"),
+            style.number(time.days(), 4),
+            t!("days")
         )
+    };
+
+    println!();
+    print_heading(&t!("synodic"));
+    println!("{}: {}", t!("synodic-first"), line(first));
+    println!("{}: {}", t!("synodic-second"), line(second));
+    match calculate_synodic_period(first, second) {
+        Some(synodic) => {
+            println!("{}: {}", t!("synodic-period"), line(synodic));
+            println!(
+                "{}: {} / {}",
+                t!("synodic-revolutions"),
+                style.number(synodic / first, 4),
+                style.number(synodic / second, 4)
+            );
+        }
+        None => println!("{}", t!("phase-same-orbit")),
     }
+    println!("{SEPARATOR}");
+    println!();
 }
 
-#[derive(Parser)]
-#[command(author, version, about, long_about = None, arg_required_else_help = true)]
-struct Cli {
-    /// Celestial body to calculate orbits for
-    #[command(subcommand)]
-    command: Option<Commands>,
+fn lambert(args: &LambertArgs) {
+    let body = args.body.body();
+    let km = |values: &[f64]| [values[0], values[1], values[2]].map(|v| v * 1000.0);
+    let (r1, r2) = (km(&args.r1), km(&args.r2));
+    let solutions = lambert::solve(body.mu.0, r1, r2, args.tof, args.revs, args.direction)
+        .unwrap_or_else(|err| {
+            eprintln!("{}", t!("error-lambert", error = err));
+            std::process::exit(1)
+        });
+    let units = report_units(&body);
+    let velocity = |values: [f64; 3]| units.velocity_to(values, MetersPerSecond(1e-3));
+    let speed = |values: [f64; 3]| {
+        let speed = values.iter().map(|v| v * v).sum::<f64>().sqrt();
+        units.speed_to(MetersPerSecond(speed), MetersPerSecond(1e-3))
+    };
+
+    println!();
+    print_heading(&t!("lambert", revolutions = args.revs));
+    println!(
+        "{}: {} {}",
+        t!("lambert-time-of-flight"),
+        units.style.number(args.tof, 1),
+        t!("seconds")
+    );
+    for solution in &solutions {
+        let elements = KeplerianElements::from_state(
+            body.mu.0,
+            &StateVector {
+                position: r1,
+                velocity: solution.departure,
+            },
+        );
+        println!("{SEPARATOR}");
+        println!(
+            "{}: {} ({})",
+            t!("lambert-departure"),
+            velocity(solution.departure),
+            speed(solution.departure)
+        );
+        println!(
+            "{}: {} ({})",
+            t!("lambert-arrival"),
+            velocity(solution.arrival),
+            speed(solution.arrival)
+        );
+        println!(
+            "{}: {}",
+            t!("semi-major-axis"),
+            units.length_to(Meters(elements.semi_major_axis), Meters(1.0))
+        );
+        println!(
+            "{}: {}",
+            t!("eccentricity"),
+            units.style.number(elements.eccentricity, 6)
+        );
+        println!(
+            "{}: {} deg",
+            t!("inclination"),
+            units.style.number(elements.inclination, 4)
+        );
+        println!(
+            "{}: {}",
+            t!("periapsis-altitude"),
+            units.length_to(Meters(elements.periapsis()) - body.radius, Meters(1.0))
+        );
+    }
+    println!("{SEPARATOR}");
+    println!();
 }
 
-#[derive(Subcommand)]
-enum Commands {
-    /// Calculate common satellite orbits for Earth
-    Earth { altitude: Option<f64> },
+fn where_is(args: &WhereArgs) {
+    let days = args.at.unwrap_or_else(time::now);
+    let (Some(position), Some(earth)) = (
+        ephemeris::position(args.body, days),
+        ephemeris::position(Named::Earth, days),
+    ) else {
+        eprintln!("{}", t!("error-where"));
+        std::process::exit(1)
+    };
+    let au = habitable::ASTRONOMICAL_UNIT;
+    let units = report_units(&Body::earth());
+    let number = |value: f64, decimals| units.style.number(value, decimals);
+    let join = |values: [f64; 3]| values.map(|v| number(v / au, 6)).join(", ");
+
+    println!();
+    print_heading(&t!(
+        "where",
+        body = t!(args.body.name()),
+        date = time::format_utc(days)
+    ));
+    if args.body != Named::Sun {
+        let (longitude, latitude, distance) = ephemeris::spherical(position);
+        println!("{}", t!("where-heliocentric"));
+        println!("{}: {} AU", t!("position"), join(position));
+        println!(
+            "{}: {} deg",
+            t!("where-ecliptic-longitude"),
+            number(longitude, 4)
+        );
+        println!(
+            "{}: {} deg",
+            t!("where-ecliptic-latitude"),
+            number(latitude, 4)
+        );
+        println!(
+            "{}: {} AU",
+            t!("where-sun-distance"),
+            number(distance / au, 6)
+        );
+        println!("{SEPARATOR}");
+    }
+    if args.body != Named::Earth {
+        let geocentric = ephemeris::equatorial([0, 1, 2].map(|i| position[i] - earth[i]));
+        let (right_ascension, declination, distance) = ephemeris::spherical(geocentric);
+        let seconds = (right_ascension / 15.0 * 3600.0 * 10.0).round() / 10.0;
+        println!("{}", t!("where-geocentric"));
+        println!("{}: {} AU", t!("position"), join(geocentric));
+        println!(
+            "{}: {:02}h {:02}m {:04.1}s ({} deg)",
+            t!("where-right-ascension"),
+            (seconds / 3600.0).floor() as i64 % 24,
+            (seconds / 60.0).floor() as i64 % 60,
+            seconds % 60.0,
+            number(right_ascension, 4)
+        );
+        println!(
+            "{}: {} deg",
+            t!("where-declination"),
+            number(declination, 4)
+        );
+        println!(
+            "{}: {} AU ({})",
+            t!("where-earth-distance"),
+            number(distance / au, 6),
+            units.length_to(Meters(distance), Meters(1e3))
+        );
+        println!(
+            "{}: {} {}",
+            t!("where-light-time"),
+            number(ephemeris::light_time(distance).minutes(), 2),
+            t!("minutes")
+        );
+        println!("{SEPARATOR}");
+    }
+    println!("{}", t!("where-note"));
+    println!();
 }
 
-#[derive(Debug, Clone)]
-struct Body {
-    mass: f64,   // kg
-    radius: f64, // m
+fn clohessy_wiltshire(args: &CwArgs) {
+    let body = args.body.body();
+    let n = rendezvous::mean_motion(body.mu, body.radius + Meters::from_km(args.altitude));
+    let start = StateVector {
+        position: [args.rel_pos[0], args.rel_pos[1], args.rel_pos[2]],
+        velocity: [args.rel_vel[0], args.rel_vel[1], args.rel_vel[2]],
+    };
+    let coast = rendezvous::propagate(n, &start, args.tof);
+    let units = report_units(&body);
+    let position = |values: [f64; 3]| units.position_to(values, Meters(1e-3));
+    let velocity = |values: [f64; 3]| units.velocity_to(values, MetersPerSecond(1e-4));
+    let length = |values: [f64; 3]| values.iter().map(|v| v * v).sum::<f64>().sqrt();
+    let range = |values: [f64; 3]| units.length_to(Meters(length(values)), Meters(1e-3));
+    let speed =
+        |values: [f64; 3]| units.speed_to(MetersPerSecond(length(values)), MetersPerSecond(1e-4));
+
+    println!();
+    print_heading(&t!(
+        "rendezvous",
+        altitude = units.brief(args.altitude),
+        unit = units.length_symbol()
+    ));
+    println!(
+        "{}: {} {}",
+        t!("orbital-period"),
+        units.style.number(2.0 * std::f64::consts::PI / n / 60.0, 3),
+        t!("minutes")
+    );
+    println!(
+        "{}: {} {}",
+        t!("lambert-time-of-flight"),
+        units.style.number(args.tof, 1),
+        t!("seconds")
+    );
+    println!("{SEPARATOR}");
+    println!("{}", t!("rendezvous-coast"));
+    println!("{}: {}", t!("position"), position(coast.position));
+    println!("{}: {}", t!("velocity"), velocity(coast.velocity));
+    println!("{}: {}", t!("rendezvous-range"), range(coast.position));
+    println!("{SEPARATOR}");
+    println!("{}", t!("rendezvous-transfer"));
+    match rendezvous::rendezvous(n, &start, args.tof) {
+        Ok(burns) => {
+            println!(
+                "{}: {} ({})",
+                t!("rendezvous-first-burn"),
+                velocity(burns.first),
+                speed(burns.first)
+            );
+            println!(
+                "{}: {} ({})",
+                t!("rendezvous-second-burn"),
+                velocity(burns.second),
+                speed(burns.second)
+            );
+            println!(
+                "{}: {}",
+                t!("transfer-total"),
+                units.speed_to(MetersPerSecond(burns.total()), MetersPerSecond(1e-4))
+            );
+        }
+        Err(_) => println!("{}", t!("rendezvous-singular")),
+    }
+    println!("{SEPARATOR}");
+    println!("{}", t!("rendezvous-note"));
+    println!();
 }
 
-enum Altitude {
-    // all always in km
-    Single { value: f64 },
-    Range { max: f64, min: f64 },
+fn lagrange(system: lagrange::System) {
+    let (primary, secondary) = (system.primary(), system.secondary());
+    let distance = secondary
+        .orbital_distance()
+        .expect("secondaries orbit a primary");
+    let mu = system.mass_ratio();
+    let units = report_units(&primary.body());
+    let length = |fraction: f64| units.length_to(distance * fraction, Meters(1e3));
+    let (primary_name, secondary_name) = (t!(primary.name()), t!(secondary.name()));
+
+    println!();
+    print_heading(&t!(
+        "lagrange",
+        primary = primary_name.clone(),
+        secondary = secondary_name.clone()
+    ));
+    println!("{}: {}", t!("lagrange-separation"), length(1.0));
+    println!(
+        "{}: {}",
+        t!("lagrange-mass-ratio"),
+        units.style.scientific(mu, 6)
+    );
+    for (index, [x, y]) in lagrange::points(mu).into_iter().enumerate() {
+        println!("{SEPARATOR}");
+        println!("L{}", index + 1);
+        println!(
+            "{}: {}, {}",
+            t!("position"),
+            units.length_number_to(distance * x, Meters(1e3)),
+            length(y)
+        );
+        println!(
+            "{}: {}",
+            t!("lagrange-from", body = primary_name.clone()),
+            length((x + mu).hypot(y))
+        );
+        println!(
+            "{}: {}",
+            t!("lagrange-from", body = secondary_name.clone()),
+            length((x - 1.0 + mu).hypot(y))
+        );
+    }
+    println!("{SEPARATOR}");
+    println!("{}", t!("lagrange-note", primary = primary_name));
+    println!();
 }
 
-struct Orbit {
-    name: String,
-    altitude: Altitude,
-    body: Rc<Body>,
+fn soi(named: Named) {
+    let (Some(parent), Some(distance), Some(soi), Some(hill)) = (
+        named.parent(),
+        named.orbital_distance(),
+        named.sphere_of_influence(),
+        named.hill_radius(),
+    ) else {
+        eprintln!("{}", t!("error-soi"));
+        std::process::exit(1)
+    };
+    let radius = named.body().radius;
+    let body = t!(named.name());
+    let units = report_units(&named.body());
+    let length = |length: Meters| units.length_to(length, Meters(1e3));
+
+    println!();
+    print_heading(&t!("soi", body = body.clone()));
+    println!("{}: {}", t!("soi-parent"), t!(parent.name()));
+    println!("{}: {}", t!("soi-distance"), length(distance));
+    println!(
+        "{}: {} ({} {})",
+        t!("interplanetary-soi"),
+        length(soi),
+        units.style.number(soi / radius, 1),
+        t!("soi-radii")
+    );
+    println!(
+        "{}: {} ({} {})",
+        t!("soi-hill"),
+        length(hill),
+        units.style.number(hill / radius, 1),
+        t!("soi-radii")
+    );
+    println!("{SEPARATOR}");
+    println!("{}", t!("soi-note", body = body));
+    println!();
 }
 
-impl Orbit {
-    fn get_period_string(&self) -> String {
-        match &self.altitude {
-            Altitude::Single { value } => {
-                let axis = self.body.radius + value * 1000.0;
-                let period_in_seconds = calculate_orbital_period(self.body.mass, axis).ceil();
-                let period_in_minutes = &period_in_seconds / 60.0;
-                let period_in_days = &period_in_minutes / (60.0 * 24.0);
+fn interplanetary(args: &InterplanetaryArgs) {
+    let arrive = args
+        .arrive
+        .or(args.tof.map(|tof| args.depart + tof / 86_400.0))
+        .expect("clap requires an arrival");
+    let apoapsis = args.capture_apoapsis.unwrap_or(args.capture);
+    let transfer = (args.capture_apoapsis.is_none() || apoapsis >= args.capture)
+        .then(|| {
+            interplanetary::transfer(
+                args.from,
+                args.to,
+                args.depart,
+                arrive,
+                args.parking,
+                (args.capture, apoapsis),
+            )
+        })
+        .flatten()
+        .unwrap_or_else(|| {
+            eprintln!("{}", t!("error-interplanetary"));
+            std::process::exit(1)
+        });
+    let arc = transfer.arc;
+    let from = t!(args.from.name());
+    let to = t!(args.to.name());
+    let (departure, arrival) = (
+        report_units(&args.from.body()),
+        report_units(&args.to.body()),
+    );
 
-                format!(
-                    "{period_in_seconds} seconds
-{period_in_minutes:.2} minutes
-{period_in_days:.2} days"
-                )
-            }
-            Altitude::Range { max, min } => {
-                let max_axis = self.body.radius + max * 1000.0;
-                let min_axis = self.body.radius + min * 1000.0;
+    println!();
+    print_heading(&t!("interplanetary", from = from.clone(), to = to.clone()));
+    println!(
+        "{}: {}",
+        t!("porkchop-depart"),
+        time::format_utc(arc.depart)
+    );
+    println!(
+        "{}: {}",
+        t!("porkchop-arrive"),
+        time::format_utc(arc.arrive)
+    );
+    println!(
+        "{}: {} {}",
+        t!("porkchop-time-of-flight"),
+        departure.style.number(arc.arrive - arc.depart, 1),
+        t!("days")
+    );
+    println!("{SEPARATOR}");
+    println!("{}", t!("interplanetary-departure", body = from.clone()));
+    println!(
+        "{}: {}",
+        t!("porkchop-c3"),
+        departure.energy_to(arc.c3() * 1e6, 1e3)
+    );
+    println!(
+        "{}: {}",
+        t!("interplanetary-v-inf"),
+        departure.speed_to(MetersPerSecond(arc.departure_v_inf), MetersPerSecond(1.0))
+    );
+    println!(
+        "{}: {}",
+        t!("interplanetary-soi"),
+        departure.length_to(transfer.departure_soi, Meters(1e3))
+    );
+    println!(
+        "{}: {}",
+        t!(
+            "interplanetary-injection",
+            altitude = departure.brief(args.parking),
+            unit = departure.length_symbol()
+        ),
+        departure.speed_to(transfer.injection, MetersPerSecond(0.1))
+    );
+    println!("{SEPARATOR}");
+    println!("{}", t!("interplanetary-arrival", body = to));
+    println!(
+        "{}: {}",
+        t!("interplanetary-v-inf"),
+        arrival.speed_to(MetersPerSecond(arc.arrival_v_inf), MetersPerSecond(1.0))
+    );
+    println!(
+        "{}: {}",
+        t!("interplanetary-soi"),
+        arrival.length_to(transfer.arrival_soi, Meters(1e3))
+    );
+    println!(
+        "{}: {}",
+        t!(
+            "interplanetary-capture",
+            periapsis = arrival.brief(args.capture),
+            apoapsis = arrival.brief(apoapsis),
+            unit = arrival.length_symbol()
+        ),
+        arrival.speed_to(transfer.capture, MetersPerSecond(0.1))
+    );
+    println!("{SEPARATOR}");
+    println!(
+        "{}: {}",
+        t!("transfer-total"),
+        departure.speed_to(transfer.total(), MetersPerSecond(0.1))
+    );
+    println!("{SEPARATOR}");
+    println!();
+}
 
-                let max_period_in_seconds =
-                    calculate_orbital_period(self.body.mass, max_axis).ceil();
-                let max_period_in_minutes = &max_period_in_seconds / 60.0;
-                let max_period_in_days = &max_period_in_minutes / (60.0 * 24.0);
+fn porkchop(args: &PorkchopArgs) {
+    if args.from.mean_orbit().is_none()
+        || args.to.mean_orbit().is_none()
+        || args.from == args.to
+        || args.step <= 0.0
+    {
+        eprintln!("{}", t!("error-porkchop"));
+        std::process::exit(1);
+    }
+    let departures = porkchop::dates(args.depart.0, args.depart.1, args.step);
+    let arrivals = porkchop::dates(args.arrive.0, args.arrive.1, args.step);
+    let cells = porkchop::grid(args.from, args.to, &departures, &arrivals);
+    if cells.is_empty() {
+        eprintln!("{}", t!("error-porkchop-empty"));
+        std::process::exit(1);
+    }
 
-                let min_period_in_seconds =
-                    calculate_orbital_period(self.body.mass, min_axis).ceil();
-                let min_period_in_minutes = &min_period_in_seconds / 60.0;
-                let min_period_in_days = &min_period_in_minutes / (60.0 * 24.0);
+    let result = match args.format {
+        OutputFormat::Text => {
+            print_porkchop(args, &cells);
+            Ok(())
+        }
+        OutputFormat::Csv => write_porkchop_csv(&args.out, &cells),
+    };
+    if let Err(err) = result {
+        eprintln!("{}", t!("error-write", error = err));
+        std::process::exit(1);
+    }
 
-                format!(
-                    "{min_period_in_seconds}-{max_period_in_seconds} seconds
-{min_period_in_minutes:.2}-{max_period_in_minutes:.2} minutes 
-{min_period_in_days:.2}-{max_period_in_days:.2} days"
-                )
-            }
+    if let Some(path) = &args.svg {
+        let labels = porkchop::Labels {
+            title: t!(
+                "porkchop-title",
+                from = t!(args.from.name()),
+                to = t!(args.to.name())
+            ),
+            x: t!(
+                "porkchop-depart-axis",
+                date = time::format_utc(args.depart.0)
+            ),
+            y: t!(
+                "porkchop-arrive-axis",
+                date = time::format_utc(args.arrive.0)
+            ),
+        };
+        let step = (args.step, args.step);
+        if let Err(err) = porkchop::render_svg(path, &labels, &cells, step, args.max_c3) {
+            eprintln!("{}", t!("error-write", error = err));
+            std::process::exit(1);
         }
     }
+}
 
-    fn get_velocity_string(&self) -> String {
-        match &self.altitude {
-            Altitude::Single { value } => {
-                let axis = self.body.radius + value * 1000.0;
+fn print_porkchop(args: &PorkchopArgs, cells: &[porkchop::Cell]) {
+    let units = report_units(&args.to.body());
+    let by = |key: fn(&porkchop::Cell) -> f64| {
+        *cells
+            .iter()
+            .min_by(|a, b| key(a).total_cmp(&key(b)))
+            .expect("the grid is not empty")
+    };
+    let print_cell = |cell: porkchop::Cell| {
+        println!(
+            "{}: {}",
+            t!("porkchop-depart"),
+            time::format_utc(cell.depart)
+        );
+        println!(
+            "{}: {}",
+            t!("porkchop-arrive"),
+            time::format_utc(cell.arrive)
+        );
+        println!(
+            "{}: {} {}",
+            t!("porkchop-time-of-flight"),
+            units.style.number(cell.arrive - cell.depart, 1),
+            t!("days")
+        );
+        println!(
+            "{}: {}",
+            t!("porkchop-c3"),
+            units.energy_to(cell.c3() * 1e6, 1e3)
+        );
+        println!(
+            "{}: {}",
+            t!("porkchop-arrival-v-inf"),
+            units.speed_to(MetersPerSecond(cell.arrival_v_inf), MetersPerSecond(1.0))
+        );
+    };
 
-                let velocity =
-                    (calculate_circular_orbital_velocity(self.body.mass, axis) * 60.0 * 60.0)
-                        / 1000.0;
-                format!("{velocity:.2} km/hr")
-            }
-            Altitude::Range { max, min } => {
-                let min_axis = self.body.radius + min * 1000.0;
-                let max_axis = self.body.radius + max * 1000.0;
+    println!();
+    print_heading(&t!(
+        "porkchop-title",
+        from = t!(args.from.name()),
+        to = t!(args.to.name())
+    ));
+    println!("{}: {}", t!("porkchop-cells"), cells.len());
+    println!("{SEPARATOR}");
+    println!("{}", t!("porkchop-lowest-c3"));
+    print_cell(by(|cell| cell.c3()));
+    println!("{SEPARATOR}");
+    println!("{}", t!("porkchop-lowest-total"));
+    print_cell(by(|cell| cell.departure_v_inf + cell.arrival_v_inf));
+    println!("{SEPARATOR}");
+    println!();
+}
+
+fn write_porkchop_csv(out: &Option<PathBuf>, cells: &[porkchop::Cell]) -> io::Result<()> {
+    let mut writer = create_output(out);
+    csv::write_record(
+        &mut writer,
+        &[
+            "depart_utc",
+            "arrive_utc",
+            "time_of_flight_days",
+            "c3_km2_s2",
+            "departure_v_inf_km_s",
+            "arrival_v_inf_km_s",
+        ],
+    )?;
+    for cell in cells {
+        csv::write_record(
+            &mut writer,
+            &[
+                time::format_utc(cell.depart),
+                time::format_utc(cell.arrive),
+                format!("{:.3}", cell.arrive - cell.depart),
+                format!("{:.6}", cell.c3()),
+                format!("{:.6}", cell.departure_v_inf / 1000.0),
+                format!("{:.6}", cell.arrival_v_inf / 1000.0),
+            ],
+        )?;
+    }
+    writer.flush()
+}
+
+const DEFAULT_MARGIN: f64 = 10.0; // percent
+
+fn budget(args: &BudgetArgs) {
+    let body = args.body.body();
+    let units = report_units(&body);
+    let plan = args.plan.as_ref().map(|path| {
+        maneuver::load_plan(path).unwrap_or_else(|err| {
+            eprintln!("{}", t!("error-config", path = path.display(), error = err));
+            std::process::exit(1)
+        })
+    });
+    let margin = args
+        .margin
+        .or(plan.as_ref().and_then(|plan| plan.margin))
+        .unwrap_or(DEFAULT_MARGIN);
+    if margin < 0.0 {
+        eprintln!("{}", t!("error-budget-margin"));
+        std::process::exit(1);
+    }
+    let items: Vec<(String, MetersPerSecond)> = plan
+        .into_iter()
+        .flat_map(|plan| plan.maneuvers)
+        .map(|step| (step.name, step.maneuver))
+        .chain(args.maneuvers.iter().map(|&maneuver| (None, maneuver)))
+        .map(|(name, maneuver)| {
+            let description = maneuver_label(&maneuver, &units);
+            let label = match name {
+                Some(name) => format!("{name}: {description}"),
+                None => description,
+            };
+            (label, maneuver.delta_v(&body))
+        })
+        .collect();
+    let subtotal: MetersPerSecond = items.iter().map(|&(_, delta_v)| delta_v).sum();
+    let reserve = subtotal * margin / 100.0;
+    let width = items
+        .iter()
+        .map(|(label, _)| label.chars().count())
+        .chain([t!("budget-maneuver").chars().count()])
+        .max()
+        .unwrap_or(0);
+
+    println!();
+    print_heading(&t!("budget", body = t!(args.body.name())));
+    println!(
+        "{:>3}  {:<width$}  {:>14}",
+        "#",
+        t!("budget-maneuver"),
+        t!("budget-delta-v", unit = units.speed_symbol())
+    );
+    for (i, (label, delta_v)) in items.iter().enumerate() {
+        println!(
+            "{:>3}  {label:<width$}  {:>14}",
+            i + 1,
+            units.speed_number(*delta_v)
+        );
+    }
+    println!("{SEPARATOR}");
+    println!("{}: {}", t!("budget-subtotal"), units.speed(subtotal));
+    println!(
+        "{}: {}",
+        t!("budget-margin", margin = units.style.brief(margin, 2)),
+        units.speed(reserve)
+    );
+    println!(
+        "{}: {}",
+        t!("budget-total"),
+        units.speed(subtotal + reserve)
+    );
+    if let (Some(isp), Some(dry)) = (args.isp, args.dry) {
+        if isp <= 0.0 || dry <= 0.0 {
+            eprintln!("{}", t!("error-rocket"));
+            std::process::exit(1);
+        }
+        let propellant = rocket::propellant(Seconds(isp), Kilograms(dry), subtotal + reserve).0;
+        println!("{SEPARATOR}");
+        let (isp, dry_label) = (units.style.brief(isp, 2), units.style.brief(dry, 2));
+        println!(
+            "{}: {} kg",
+            t!("budget-propellant", isp = isp, dry = dry_label),
+            units.style.number(propellant, 2)
+        );
+        println!(
+            "{}: {} kg",
+            t!("rocket-wet-mass"),
+            units.style.number(dry + propellant, 2)
+        );
+    }
+    println!("{SEPARATOR}");
+    println!();
+}
+
+fn rocket(args: &RocketArgs) {
+    let valid = args.isp > 0.0
+        && args.dry > 0.0
+        && args.wet.is_none_or(|wet| wet >= args.dry)
+        && args.delta_v.is_none_or(|delta_v| delta_v >= 0.0);
+    if !valid {
+        eprintln!("{}", t!("error-rocket"));
+        std::process::exit(1);
+    }
 
-                let min_velocity =
-                    calculate_circular_orbital_velocity(self.body.mass, min_axis) * 60.0 * 60.0
-                        / 1000.0;
-                let max_velocity =
-                    calculate_circular_orbital_velocity(self.body.mass, max_axis) * 60.0 * 60.0
-                        / 1000.0;
+    let units = report_units(&Body::earth());
+    let speed = |speed: MetersPerSecond| units.speed_to(speed, MetersPerSecond(0.01));
+    let number = |value: f64, decimals| units.style.number(value, decimals);
 
-                format!("{min_velocity:.2}-{max_velocity:.2} km/s")
+    println!();
+    print_heading(&t!(
+        "rocket",
+        isp = units.style.brief(args.isp, 2),
+        dry = units.style.brief(args.dry, 2)
+    ));
+    println!(
+        "{}: {}",
+        t!("rocket-exhaust-velocity"),
+        speed(rocket::exhaust_velocity(Seconds(args.isp)))
+    );
+    if let Some(wet) = args.wet {
+        println!("{SEPARATOR}");
+        println!("{}: {} kg", t!("rocket-wet-mass"), number(wet, 2));
+        println!(
+            "{}: {} kg",
+            t!("rocket-propellant"),
+            number(wet - args.dry, 2)
+        );
+        println!("{}: {}", t!("rocket-mass-ratio"), number(wet / args.dry, 4));
+        println!(
+            "{}: {}",
+            t!("rocket-delta-v"),
+            speed(rocket::delta_v(
+                Seconds(args.isp),
+                Kilograms(wet),
+                Kilograms(args.dry)
+            ))
+        );
+    }
+    if let Some(delta_v) = args.delta_v {
+        let propellant = rocket::propellant(
+            Seconds(args.isp),
+            Kilograms(args.dry),
+            MetersPerSecond(delta_v),
+        )
+        .0;
+        println!("{SEPARATOR}");
+        println!(
+            "{}",
+            t!("rocket-target", delta_v = speed(MetersPerSecond(delta_v)))
+        );
+        println!("{}: {} kg", t!("rocket-propellant"), number(propellant, 2));
+        println!(
+            "{}: {} kg",
+            t!("rocket-wet-mass"),
+            number(args.dry + propellant, 2)
+        );
+        println!(
+            "{}: {}",
+            t!("rocket-mass-ratio"),
+            number(1.0 + propellant / args.dry, 4)
+        );
+        if let Some(wet) = args.wet {
+            let spare = wet - args.dry - propellant;
+            if spare >= 0.0 {
+                println!("{}", t!("rocket-enough", spare = number(spare, 2)));
+            } else {
+                println!("{}", t!("rocket-short", short = number(-spare, 2)));
             }
         }
     }
+    println!("{SEPARATOR}");
+    println!();
 }
 
-fn main() {
-    let cli = Cli::parse();
-    match &cli.command {
-        Some(Commands::Earth { altitude }) => earth(altitude.clone()),
-        _ => {}
+fn maneuver_label(maneuver: &maneuver::Maneuver, units: &units::Units) -> String {
+    let (brief, unit) = (|km| units.brief(km), units.length_symbol());
+    match *maneuver {
+        maneuver::Maneuver::Hohmann { from, to } => t!(
+            "maneuver-hohmann",
+            from = brief(from),
+            to = brief(to),
+            unit = unit
+        ),
+        maneuver::Maneuver::PlaneChange {
+            altitude,
+            inclination,
+        } => t!(
+            "maneuver-plane-change",
+            altitude = brief(altitude),
+            unit = unit,
+            inclination = units.style.brief(inclination, 4)
+        ),
+        maneuver::Maneuver::Circularize {
+            periapsis,
+            apoapsis,
+        } => t!(
+            "maneuver-circularize",
+            periapsis = brief(periapsis),
+            apoapsis = brief(apoapsis),
+            unit = unit
+        ),
+        maneuver::Maneuver::Deorbit { altitude, perigee } => t!(
+            "maneuver-deorbit",
+            altitude = brief(altitude),
+            perigee = brief(perigee),
+            unit = unit
+        ),
     }
 }
 
-fn earth(altitude: Option<f64>) {
-    let earth = Rc::new(Body {
-        mass: 5.9722e24,
-        radius: 6.3781e6,
+/// Every element set in `file`, or in standard input for none or "-".
+fn read_tles(file: &Option<PathBuf>) -> Vec<tle::Tle> {
+    let (source, text) = match file {
+        Some(path) if path.as_os_str() != "-" => {
+            (path.display().to_string(), std::fs::read_to_string(path))
+        }
+        _ => ("stdin".to_string(), io::read_to_string(io::stdin())),
+    };
+    let text = text.unwrap_or_else(|err| {
+        eprintln!("{}", t!("error-read", source = &source, error = err));
+        std::process::exit(1)
     });
+    ccsds::parse_sets(&text).unwrap_or_else(|err| {
+        eprintln!("{}", t!("error-read", source = source, error = err));
+        std::process::exit(1)
+    })
+}
 
-    let mut orbits = vec![];
+impl TleSource {
+    /// The element sets downloaded for --catnr or --group, or else read from
+    /// --tle.
+    fn read(&self) -> Vec<tle::Tle> {
+        #[cfg(feature = "fetch")]
+        if let Some(query) = self.catnr.as_ref().or(self.group.as_ref()) {
+            return download(query);
+        }
+        read_tles(&self.tle)
+    }
+}
 
-    match &altitude {
-        Some(value) => orbits.push(Orbit {
-            name: "User Defined".to_string(),
-            altitude: Altitude::Single {
-                value: value.clone(),
-            },
-            body: earth.clone(),
-        }),
+fn tle(args: &TleArgs) {
+    let earth = Body::earth();
+    let sets = read_tles(&args.file);
+    if args.format == TleFormat::Omm {
+        let mut writer = create_output(&args.out);
+        let created = time::now();
+        let written = sets.iter().enumerate().try_for_each(|(i, set)| {
+            if i > 0 {
+                writeln!(writer)?;
+            }
+            ccsds::write_omm(&mut writer, set, created)
+        });
+        if let Err(err) = written.and_then(|_| writer.flush()) {
+            eprintln!("{}", t!("error-write", error = err));
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let units = report_units(&earth);
+    let altitude = |km| units.length_to(Meters::from_km(km), Meters(100.0));
+    let number = |value: f64, decimals| units.style.number(value, decimals);
+    for set in sets {
+        let (perigee, apogee) = set.apsis_altitudes(&earth);
+
+        println!();
+        print_heading(&t!(
+            "tle",
+            name = set.name.as_deref().unwrap_or(&set.international_designator),
+            catalog = &set.catalog_number
+        ));
+        println!(
+            "{}",
+            t!(
+                "tle-epoch",
+                year = set.epoch_year,
+                day = number(set.epoch_day, 8)
+            )
+        );
+        println!(
+            "{}: {} rev/d",
+            t!("mean-motion"),
+            number(set.mean_motion, 8)
+        );
+        println!(
+            "{}: {} {}",
+            t!("orbital-period"),
+            number(set.period() / 60.0, 3),
+            t!("minutes")
+        );
+        println!("{}: {} deg", t!("inclination"), number(set.inclination, 4));
+        println!("{}: {}", t!("eccentricity"), number(set.eccentricity, 7));
+        println!("{}: {} deg", t!("raan"), number(set.raan, 4));
+        println!(
+            "{}: {} deg",
+            t!("argument-of-periapsis"),
+            number(set.argument_of_perigee, 4)
+        );
+        println!(
+            "{}: {} deg",
+            t!("mean-anomaly"),
+            number(set.mean_anomaly, 4)
+        );
+        println!("{}: {}", t!("perigee-altitude"), altitude(perigee));
+        println!("{}: {}", t!("apogee-altitude"), altitude(apogee));
+        println!("{}: {}", t!("bstar"), units.style.exact(set.bstar));
+        println!("{SEPARATOR}");
+        println!();
+    }
+}
+
+fn propagate(args: &PropagateArgs) {
+    if let Some(PropagateMethod::Kepler(kepler)) = &args.method {
+        return propagate_kepler(kepler);
+    }
+    if let Some(PropagateMethod::Numeric(numeric)) = &args.method {
+        return propagate_numeric(numeric);
+    }
+    match args.format {
+        PropagateFormat::Czml => return write_propagated_czml(args),
+        PropagateFormat::Oem => return write_propagated_oem(args),
+        PropagateFormat::Text => {}
+    }
+    for set in args.source.read() {
+        let epoch = propagate::epoch(&set);
+        let days = args.at.unwrap_or(epoch);
+        let state = propagate::sgp4(&set, days).unwrap_or_else(|err| {
+            eprintln!("{}", t!("error-propagate", error = err));
+            std::process::exit(1)
+        });
+        let point = frames::geodetic(frames::teme_to_ecef(state.position, days));
+        let units = report_units(&Body::earth());
+
+        println!();
+        print_heading(&t!(
+            "tle",
+            name = set.name.as_deref().unwrap_or(&set.international_designator),
+            catalog = &set.catalog_number
+        ));
+        println!(
+            "{}",
+            t!(
+                "since-epoch",
+                days = format!(
+                    "{}{}",
+                    if days < epoch { "" } else { "+" },
+                    units.style.number(days - epoch, 6)
+                )
+            )
+        );
+        println!(
+            "{}: {}",
+            t!("position-teme"),
+            units.position_to(state.position, Meters(1e-3))
+        );
+        println!(
+            "{}: {}",
+            t!("velocity-teme"),
+            units.velocity_to(state.velocity, MetersPerSecond(1e-3))
+        );
+        println!("{SEPARATOR}");
+        println!(
+            "{}: {}",
+            t!("latitude"),
+            units.style.number(point.latitude, 4)
+        );
+        println!(
+            "{}: {}",
+            t!("longitude"),
+            units.style.number(point.longitude, 4)
+        );
+        println!(
+            "{}: {}",
+            t!("geodetic-altitude"),
+            units.length_to(Meters(point.altitude), Meters(1.0))
+        );
+        println!("{SEPARATOR}");
+        println!();
+    }
+}
+
+/// Sample times and TEME states of an element set for `propagate --format
+/// czml` and `oem`.
+fn propagated_samples(args: &PropagateArgs, set: &tle::Tle) -> (Vec<f64>, Vec<StateVector>) {
+    let start = args.at.unwrap_or_else(|| propagate::epoch(set));
+    let duration = args.duration.unwrap_or_else(|| set.period());
+    let times = groundtrack::times(start, duration, args.step);
+    let states = times
+        .iter()
+        .map(|&days| {
+            propagate::sgp4(set, days).unwrap_or_else(|err| {
+                eprintln!("{}", t!("error-propagate", error = err));
+                std::process::exit(1)
+            })
+        })
+        .collect();
+    (times, states)
+}
+
+fn write_propagated_czml(args: &PropagateArgs) {
+    let objects: Vec<export::Ephemeris> = args
+        .source
+        .read()
+        .iter()
+        .map(|set| {
+            let (times, states) = propagated_samples(args, set);
+            let positions = times
+                .iter()
+                .zip(states)
+                .map(|(&days, state)| frames::teme_to_ecef(state.position, days))
+                .collect();
+            export::Ephemeris {
+                name: set
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| set.international_designator.clone()),
+                times,
+                positions,
+            }
+        })
+        .collect();
+
+    let mut writer = create_output(&args.out);
+    let name = match &args.out {
+        Some(path) => path
+            .file_stem()
+            .map_or(String::new(), |stem| stem.to_string_lossy().into_owned()),
+        None => t!("czml-document"),
+    };
+    if let Err(err) = export::write_czml(&mut writer, &name, &objects).and_then(|_| writer.flush())
+    {
+        eprintln!("{}", t!("error-write", error = err));
+        std::process::exit(1);
+    }
+}
+
+fn write_propagated_oem(args: &PropagateArgs) {
+    let segments: Vec<ccsds::Segment> = args
+        .source
+        .read()
+        .iter()
+        .map(|set| {
+            let (times, states) = propagated_samples(args, set);
+            ccsds::segment(set, times, states)
+        })
+        .collect();
+
+    let mut writer = create_output(&args.out);
+    if let Err(err) =
+        ccsds::write_oem(&mut writer, &segments, time::now()).and_then(|_| writer.flush())
+    {
+        eprintln!("{}", t!("error-write", error = err));
+        std::process::exit(1);
+    }
+}
+
+/// Elements and sample times in s after their epoch for `propagate kepler`
+/// and `propagate numeric`.
+fn ephemeris_request(args: &KeplerArgs, body: &Body) -> (KeplerianElements, Vec<f64>) {
+    let values = &args.elements;
+    let elements = KeplerianElements {
+        semi_major_axis: values[0] * 1000.0,
+        eccentricity: values[1],
+        inclination: values[2],
+        raan: values[3],
+        argument_of_periapsis: values[4],
+        true_anomaly: values[5],
+    };
+    let open = elements.eccentricity >= 1.0;
+    if elements.eccentricity < 0.0
+        || elements.eccentricity == 1.0
+        || (elements.semi_major_axis < 0.0) != open
+    {
+        eprintln!("{}", t!("error-keplerian"));
+        std::process::exit(1);
+    }
+    let duration = match args.duration {
+        Some(duration) => duration,
+        None if !open => calculate_orbital_period(body.mu, Meters(elements.semi_major_axis)).0,
         None => {
-            orbits.push(Orbit {
-                name: "VLEO".to_string(),
-                altitude: Altitude::Range {
-                    max: 450.0,
-                    min: 100.0,
-                },
-                body: earth.clone(),
-            });
-            orbits.push(Orbit {
-                name: "LEO".to_string(),
-                altitude: Altitude::Range {
-                    max: 2000.0,
-                    min: 450.0,
-                },
-                body: earth.clone(),
-            });
-            orbits.push(Orbit {
-                name: "MEO".to_string(),
-                altitude: Altitude::Range {
-                    min: 2000.0,
-                    max: 36000.0,
-                },
-                body: earth.clone(),
-            });
-            orbits.push(Orbit {
-                name: "GEO".to_string(),
-                altitude: Altitude::Single { value: 35786.0 },
-                body: earth.clone(),
+            eprintln!("{}", t!("error-kepler-duration"));
+            std::process::exit(1);
+        }
+    };
+    let times = groundtrack::times(0.0, duration, args.step)
+        .into_iter()
+        .map(|days| days * 86_400.0)
+        .collect();
+    (elements, times)
+}
+
+fn propagate_kepler(args: &KeplerArgs) {
+    let body = args.body.body();
+    let (elements, times) = ephemeris_request(args, &body);
+    let samples: Vec<(f64, StateVector)> = times
+        .into_iter()
+        .map(|elapsed| {
+            let state = propagate::kepler(body.mu.0, &elements, elapsed).unwrap_or_else(|err| {
+                eprintln!("{}", t!("error-propagate", error = err));
+                std::process::exit(1)
             });
+            (elapsed, state)
+        })
+        .collect();
+    write_ephemeris(
+        args,
+        &t!("kepler-ephemeris", body = t!(args.body.name())),
+        &samples,
+    );
+}
+
+fn propagate_numeric(args: &NumericArgs) {
+    let orbit = &args.orbit;
+    if args.bc.is_some_and(|bc| bc <= 0.0) || args.tolerance <= 0.0 {
+        eprintln!("{}", t!("error-numeric"));
+        std::process::exit(1);
+    }
+    if args.bc.is_some() && orbit.body != Named::Earth {
+        eprintln!("{}", t!("error-drag-earth"));
+        std::process::exit(1);
+    }
+    let body = orbit.body.body();
+    let (elements, times) = ephemeris_request(orbit, &body);
+    let initial = elements.to_state(body.mu.0);
+    let integrator = propagator::Propagator {
+        model: propagator::Model {
+            body,
+            j2: args.j2,
+            ballistic_coefficient: args.bc,
+        },
+        method: args.method,
+        step: args.integration_step,
+        tolerance: args.tolerance,
+    };
+    let states = integrator.run(&initial, &times).unwrap_or_else(|err| {
+        eprintln!("{}", t!("error-propagate", error = err));
+        std::process::exit(1)
+    });
+    let method = match args.method {
+        propagator::Method::Rk4 => "RK4",
+        propagator::Method::Rkf45 => "RKF45",
+    };
+    write_ephemeris(
+        orbit,
+        &t!(
+            "numeric-ephemeris",
+            body = t!(orbit.body.name()),
+            method = method
+        ),
+        &times.into_iter().zip(states).collect::<Vec<_>>(),
+    );
+}
+
+fn write_ephemeris(args: &KeplerArgs, heading: &str, samples: &[(f64, StateVector)]) {
+    let result = match args.format {
+        OutputFormat::Text => {
+            print_ephemeris(
+                heading,
+                args.epoch,
+                samples,
+                &report_units(&args.body.body()),
+            );
+            Ok(())
         }
+        OutputFormat::Csv => write_ephemeris_csv(args.epoch, samples),
+    };
+    if let Err(err) = result {
+        eprintln!("{}", t!("error-write", error = err));
+        std::process::exit(1);
+    }
+}
+
+/// Sample time as UTC when the epoch is known, else elapsed seconds.
+fn sample_time(epoch: Option<f64>, elapsed: f64) -> String {
+    match epoch {
+        Some(epoch) => time::format_utc(epoch + elapsed / 86_400.0),
+        None => report_style().number(elapsed, 1),
     }
+}
 
+fn print_ephemeris(
+    heading: &str,
+    epoch: Option<f64>,
+    samples: &[(f64, StateVector)],
+    units: &units::Units,
+) {
     println!();
-    println!("Constants");
-    println!("{SEPARATOR}");
-    println!("Pi: {PI}");
-    println!("Gravitational Constant: {GRAVITATIONAL_CONSTANT:+e} N*m^2*kg^-2");
-    println!("Earth Mass: {:+e} kg", earth.mass);
-    println!("Earth Radius: {:+e} m", earth.radius);
+    print_heading(heading);
+    let time_heading = if epoch.is_some() {
+        t!("groundtrack-time")
+    } else {
+        t!("kepler-elapsed")
+    };
+    let (length, speed) = (units.length_symbol(), units.speed_symbol());
+    println!(
+        "{time_heading:<20}  {:>14}  {:>14}  {:>14}  {:>10}  {:>10}  {:>10}",
+        format!("x ({length})"),
+        format!("y ({length})"),
+        format!("z ({length})"),
+        format!("vx ({speed})"),
+        format!("vy ({speed})"),
+        format!("vz ({speed})")
+    );
+    for (elapsed, state) in samples {
+        // adding 0 prints a negative zero as 0
+        let [x, y, z] = state
+            .position
+            .map(|v| units.length_number_to(Meters(v + 0.0), Meters(1.0)));
+        let [vx, vy, vz] = state
+            .velocity
+            .map(|v| units.speed_number_to(MetersPerSecond(v + 0.0), MetersPerSecond(1e-3)));
+        println!(
+            "{:<20}  {x:>14}  {y:>14}  {z:>14}  {vx:>10}  {vy:>10}  {vz:>10}",
+            sample_time(epoch, *elapsed)
+        );
+    }
     println!("{SEPARATOR}");
     println!();
+}
+
+fn write_ephemeris_csv(epoch: Option<f64>, samples: &[(f64, StateVector)]) -> io::Result<()> {
+    let mut writer = io::stdout().lock();
+    let mut header = vec!["elapsed_s"];
+    if epoch.is_some() {
+        header.push("time_utc");
+    }
+    header.extend(["x_km", "y_km", "z_km", "vx_km_s", "vy_km_s", "vz_km_s"]);
+    csv::write_record(&mut writer, &header)?;
+    for (elapsed, state) in samples {
+        let mut record = vec![format!("{elapsed:.3}")];
+        if let Some(epoch) = epoch {
+            record.push(time::format_utc(epoch + elapsed / 86_400.0));
+        }
+        record.extend(state.position.map(|v| format!("{:.6}", v / 1000.0 + 0.0)));
+        record.extend(state.velocity.map(|v| format!("{:.9}", v / 1000.0 + 0.0)));
+        csv::write_record(&mut writer, &record)?;
+    }
+    writer.flush()
+}
+
+fn ground_track(args: &GroundtrackArgs) {
+    let tracks: Vec<(String, Vec<groundtrack::TrackPoint>)> = match &args.elements {
+        Some(values) => {
+            let elements = KeplerianElements {
+                semi_major_axis: values[0] * 1000.0,
+                eccentricity: values[1],
+                inclination: values[2],
+                raan: values[3],
+                argument_of_periapsis: values[4],
+                true_anomaly: values[5],
+            };
+            let start = args.start.expect("clap requires --start with --elements");
+            let duration = args.duration.unwrap_or_else(|| {
+                calculate_orbital_period(Body::earth().mu, Meters(elements.semi_major_axis)).0
+            });
+            let times = groundtrack::times(start, duration, args.step);
+            let points =
+                groundtrack::from_elements(&elements, start, &times).unwrap_or_else(|err| {
+                    eprintln!("{}", t!("error-groundtrack", error = err));
+                    std::process::exit(1)
+                });
+            vec![(t!("groundtrack-elements"), points)]
+        }
+        None => args
+            .source
+            .read()
+            .iter()
+            .map(|set| {
+                let start = args.start.unwrap_or_else(|| propagate::epoch(set));
+                let duration = args.duration.unwrap_or_else(|| set.period());
+                let times = groundtrack::times(start, duration, args.step);
+                let points = groundtrack::from_tle(set, &times).unwrap_or_else(|err| {
+                    eprintln!("{}", t!("error-propagate", error = err));
+                    std::process::exit(1)
+                });
+                let name = set
+                    .name
+                    .clone()
+                    .unwrap_or_else(|| set.international_designator.clone());
+                (name, points)
+            })
+            .collect(),
+    };
+
+    let earth = Body::earth();
+    let features = || {
+        let mut features = vec![];
+        for (name, points) in &tracks {
+            features.push(groundtrack::feature(name, points));
+            if let Some(elevation) = args.footprint {
+                let footprint = t!("groundtrack-footprint", name = name);
+                features.extend(
+                    points
+                        .iter()
+                        .map(|point| groundtrack::footprint(&earth, point, elevation, &footprint)),
+                );
+            }
+        }
+        features
+    };
+    let written = match args.format {
+        TrackFormat::Text => {
+            for (name, points) in &tracks {
+                print_ground_track(
+                    name,
+                    points,
+                    footprint_radii(points, args.footprint).as_deref(),
+                );
+            }
+            Ok(())
+        }
+        TrackFormat::Csv => write_ground_track_csv(&tracks, args.footprint),
+        TrackFormat::Geojson => export::write_geojson(&mut io::stdout().lock(), &features()),
+        TrackFormat::Kml => export::write_kml(
+            &mut io::stdout().lock(),
+            &t!("groundtrack-document"),
+            &features(),
+        ),
+    };
+    if let Err(err) = written {
+        eprintln!("{}", t!("error-write", error = err));
+        std::process::exit(1);
+    }
+}
+
+fn print_ground_track(name: &str, points: &[groundtrack::TrackPoint], footprints: Option<&[f64]>) {
+    let units = report_units(&Body::earth());
+    let unit = units.length_symbol();
+
     println!();
-    println!("Orbital Periods");
+    print_heading(&t!("groundtrack", name = name));
+    let footprint_heading = footprints.map_or(String::new(), |_| {
+        format!("  {:>14}", t!("groundtrack-footprint-radius", unit = unit))
+    });
+    println!(
+        "{:<20}  {:>16}  {:>16}  {:>14}{footprint_heading}",
+        t!("groundtrack-time"),
+        t!("latitude"),
+        t!("longitude"),
+        t!("groundtrack-altitude", unit = unit)
+    );
+    for (i, point) in points.iter().enumerate() {
+        let footprint = footprints.map_or(String::new(), |radii| {
+            let radius = units.length_number_to(Meters::from_km(radii[i]), Meters(100.0));
+            format!("  {radius:>14}")
+        });
+        println!(
+            "{:<20}  {:>16}  {:>16}  {:>14}{footprint}",
+            time::format_utc(point.time),
+            units.style.number(point.position.latitude, 4),
+            units.style.number(point.position.longitude, 4),
+            units.length_number_to(Meters(point.position.altitude), Meters(1.0))
+        );
+    }
     println!("{SEPARATOR}");
     println!();
+}
+
+/// Visibility radius in km at each point, when a minimum elevation is given.
+fn footprint_radii(points: &[groundtrack::TrackPoint], elevation: Option<f64>) -> Option<Vec<f64>> {
+    let earth = Body::earth();
+    let elevation = elevation?;
+    Some(
+        points
+            .iter()
+            .map(|point| {
+                plot::footprint_radius(&earth, point.position.altitude / 1000.0, elevation)
+            })
+            .collect(),
+    )
+}
+
+fn write_ground_track_csv(
+    tracks: &[(String, Vec<groundtrack::TrackPoint>)],
+    elevation: Option<f64>,
+) -> io::Result<()> {
+    let mut writer = io::stdout().lock();
+    let mut header = vec![
+        "name",
+        "time_utc",
+        "latitude_deg",
+        "longitude_deg",
+        "altitude_km",
+    ];
+    if elevation.is_some() {
+        header.push("footprint_radius_km");
+    }
+    csv::write_record(&mut writer, &header)?;
+    for (name, points) in tracks {
+        let radii = footprint_radii(points, elevation);
+        for (i, point) in points.iter().enumerate() {
+            let mut record = vec![
+                name.clone(),
+                time::format_utc(point.time),
+                format!("{:.6}", point.position.latitude),
+                format!("{:.6}", point.position.longitude),
+                format!("{:.3}", point.position.altitude / 1000.0),
+            ];
+            if let Some(radii) = &radii {
+                record.push(format!("{:.1}", radii[i]));
+            }
+            csv::write_record(&mut writer, &record)?;
+        }
+    }
+    writer.flush()
+}
+
+fn passes(args: &PassesArgs) {
+    let start = args.start.unwrap_or_else(time::now);
+    let end = start + args.days;
+    let sets: Vec<(String, Vec<passes::Pass>)> = args
+        .source
+        .read()
+        .iter()
+        .map(|set| {
+            let found = passes::find(set, &args.station, start, end, args.min_elevation)
+                .unwrap_or_else(|err| {
+                    eprintln!("{}", t!("error-propagate", error = err));
+                    std::process::exit(1)
+                });
+            let name = set
+                .name
+                .clone()
+                .unwrap_or_else(|| set.international_designator.clone());
+            (name, found)
+        })
+        .collect();
 
-    orbits.iter().for_each(|o| match &o.altitude {
-        Altitude::Single { value } => {
-            println!("{} ({value} km) \n{}\n", o.name, o.get_period_string());
+    match args.format {
+        OutputFormat::Text => {
+            for (name, found) in &sets {
+                print_passes(args, start, name, found);
+            }
         }
-        Altitude::Range { max, min } => {
-            println!("{} ({min}-{max} km) \n{}\n", o.name, o.get_period_string());
+        OutputFormat::Csv => {
+            if let Err(err) = write_passes_csv(&sets) {
+                eprintln!("{}", t!("error-write", error = err));
+                std::process::exit(1);
+            }
         }
-    });
+    }
+}
 
+fn print_passes(args: &PassesArgs, start: f64, name: &str, found: &[passes::Pass]) {
+    let style = report_style();
+    println!();
+    print_heading(&t!(
+        "passes",
+        name = name,
+        latitude = style.number(args.station.latitude, 4),
+        longitude = style.number(args.station.longitude, 4),
+        start = time::format_utc(start),
+        days = style.brief(args.days, 4)
+    ));
+    if found.is_empty() {
+        println!(
+            "{}",
+            t!(
+                "passes-none",
+                elevation = style.brief(args.min_elevation, 2)
+            )
+        );
+    } else {
+        let mut table = Table::new()
+            .with_theme(report_theme())
+            .column(t!("passes-aos"), Align::Left)
+            .column(t!("passes-culmination"), Align::Left)
+            .column(t!("passes-los"), Align::Left)
+            .column(t!("passes-duration"), Align::Right)
+            .column(t!("passes-max-elevation"), Align::Right)
+            .column(t!("passes-azimuth"), Align::Right);
+        for pass in found {
+            table.row(vec![
+                time::format_utc(pass.aos),
+                time::format_utc(pass.culmination),
+                time::format_utc(pass.los),
+                style.number(pass.duration() / 60.0, 1),
+                style.number(pass.max_elevation, 1),
+                // padded so the arrows line up
+                format!(
+                    "{:>3} > {:<3}",
+                    style.number(pass.aos_azimuth, 0),
+                    style.number(pass.los_azimuth, 0)
+                ),
+            ]);
+        }
+        print!("{}", table.render());
+    }
     println!("{SEPARATOR}");
     println!();
+}
+
+fn write_passes_csv(sets: &[(String, Vec<passes::Pass>)]) -> io::Result<()> {
+    let mut writer = io::stdout().lock();
+    csv::write_record(
+        &mut writer,
+        &[
+            "name",
+            "aos_utc",
+            "culmination_utc",
+            "los_utc",
+            "duration_s",
+            "max_elevation_deg",
+            "aos_azimuth_deg",
+            "los_azimuth_deg",
+        ],
+    )?;
+    for (name, found) in sets {
+        for pass in found {
+            csv::write_record(
+                &mut writer,
+                &[
+                    name.clone(),
+                    time::format_utc(pass.aos),
+                    time::format_utc(pass.culmination),
+                    time::format_utc(pass.los),
+                    format!("{:.1}", pass.duration()),
+                    format!("{:.2}", pass.max_elevation),
+                    format!("{:.1}", pass.aos_azimuth),
+                    format!("{:.1}", pass.los_azimuth),
+                ],
+            )?;
+        }
+    }
+    writer.flush()
+}
+
+fn convert_time(input: &str, scale: time::Scale) {
+    let days = time::parse_epoch(input).unwrap_or_else(|err| {
+        eprintln!("{}", t!("error-time", error = err));
+        std::process::exit(1)
+    });
+    // through TAI, which has room for a leap second given in UTC
+    let tai = match (scale, time::parse_utc_to_tai(input)) {
+        (time::Scale::Utc, Ok(tai)) => tai,
+        _ => time::convert(days, scale, time::Scale::Tai),
+    };
+    let utc = time::convert(tai, time::Scale::Tai, time::Scale::Utc);
+    let tt = tai + time::TT_MINUS_TAI / 86_400.0;
+
     println!();
-    println!("Orbital Velocities");
+    print_heading(&t!("time-convert", time = input.trim()));
+    println!("UTC: {}Z", time::format_iso_from_tai(tai, 3));
+    println!("TAI: {}", time::format_iso(tai, 3));
+    println!("TT: {}", time::format_iso(tt, 3));
+    println!(
+        "{}: {} s",
+        t!("tai-minus-utc"),
+        time::tai_minus_utc_at_tai(tai)
+    );
+    println!("{SEPARATOR}");
+    let style = report_style();
+    println!(
+        "{} (UTC): {}",
+        t!("julian-date"),
+        style.number(utc + time::J2000, 6)
+    );
+    println!(
+        "{} (UTC): {}",
+        t!("modified-julian-date"),
+        style.number(utc + time::J2000_MJD, 6)
+    );
+    println!(
+        "{} (TT): {}",
+        t!("julian-date"),
+        style.number(tt + time::J2000, 6)
+    );
+    println!(
+        "{}: {} {}",
+        t!("julian-centuries"),
+        style.number(tt / 36_525.0, 6),
+        t!("julian-centuries-unit")
+    );
+    println!(
+        "{}: {} deg",
+        t!("gmst"),
+        style.number(time::gmst(utc).to_degrees(), 6)
+    );
     println!("{SEPARATOR}");
     println!();
+}
 
-    orbits.iter().for_each(|o| match &o.altitude {
-        Altitude::Single { value } => {
-            println!("{} ({value} km) \n{}\n", o.name, o.get_velocity_string());
-        }
-        Altitude::Range { max, min } => {
+fn frame(args: &FrameArgs) {
+    // geodetic positions carry angles in the first two places
+    let to_metres = |values: [f64; 3], frame: frames::Frame| match frame {
+        frames::Frame::Geodetic => [values[0], values[1], values[2] * 1000.0],
+        _ => values.map(|v| v * 1000.0),
+    };
+    let input = [args.position[0], args.position[1], args.position[2]];
+    let converted = frames::convert(to_metres(input, args.from), args.from, args.to, args.epoch)
+        .unwrap_or_else(|err| {
+            eprintln!("{}", t!("error-frame", error = err));
+            std::process::exit(1)
+        });
+    let name = |frame: frames::Frame| match frame {
+        frames::Frame::Eci => t!("frame-eci"),
+        frames::Frame::Teme => t!("frame-teme"),
+        frames::Frame::Ecef => t!("frame-ecef"),
+        frames::Frame::Geodetic => t!("frame-geodetic"),
+    };
+    let units = report_units(&Body::earth());
+    let print = |label: &str, values: [f64; 3], frame: frames::Frame| match frame {
+        frames::Frame::Geodetic => {
+            println!("{label}:");
+            println!("  {}: {}", t!("latitude"), units.style.number(values[0], 6));
+            println!(
+                "  {}: {}",
+                t!("longitude"),
+                units.style.number(values[1], 6)
+            );
             println!(
-                "{} ({min}-{max} km) \n{}\n",
-                o.name,
-                o.get_velocity_string()
+                "  {}: {}",
+                t!("geodetic-altitude"),
+                units.length_to(Meters(values[2]), Meters(1e-3))
             );
         }
-    });
+        _ => println!("{label}: {}", units.position_to(values, Meters(1e-3))),
+    };
 
+    println!();
+    print_heading(&t!("frame", from = name(args.from), to = name(args.to)));
+    if let Some(epoch) = args.epoch {
+        println!("{}: {}", t!("frame-epoch"), time::format_utc(epoch));
+    }
+    print(&t!("frame-input"), to_metres(input, args.from), args.from);
+    print(&t!("frame-output"), converted, args.to);
     println!("{SEPARATOR}");
-    println!()
+    println!();
 }
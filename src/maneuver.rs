@@ -0,0 +1,348 @@
+use std::{fs, path::Path};
+
+use serde::Deserialize;
+
+use crate::{
+    calculate_circular_orbital_velocity, calculate_combined_burn, calculate_hohmann_transfer,
+    calculate_plane_change, calculate_vis_viva_velocity,
+    dimension::{Meters, MetersPerSecond},
+    Body,
+};
+
+// Impulsive maneuvers between orbits around one body, costed as ideal
+// instantaneous burns for delta-v budgets. Altitudes are in km and angles in
+// degrees, as on the command line. A plan file lists maneuvers in flight
+// order, in TOML or JSON:
+//
+//     margin = 10              # percent, optional
+//
+//     [[maneuver]]
+//     name = "Transfer to GEO" # optional
+//     kind = "hohmann"
+//     from = 400
+//     to = 35786
+//
+//     [[maneuver]]
+//     kind = "plane-change"
+//     altitude = 35786
+//     inclination = 28.5
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(tag = "kind", rename_all = "kebab-case")]
+pub enum Maneuver {
+    /// Two-burn transfer between circular orbits
+    Hohmann { from: f64, to: f64 },
+    /// Inclination change of a circular orbit, with no change in speed
+    PlaneChange { altitude: f64, inclination: f64 },
+    /// Burn at apoapsis that raises periapsis to make the orbit circular
+    Circularize { periapsis: f64, apoapsis: f64 },
+    /// Burn from a circular orbit that lowers perigee to `perigee`
+    Deorbit { altitude: f64, perigee: f64 },
+}
+
+impl Maneuver {
+    pub fn validate(&self) -> Result<(), String> {
+        let valid = match *self {
+            Maneuver::Hohmann { from, to } => from >= 0.0 && to >= 0.0,
+            Maneuver::PlaneChange {
+                altitude,
+                inclination,
+            } => altitude >= 0.0 && (0.0..=180.0).contains(&inclination.abs()),
+            Maneuver::Circularize {
+                periapsis,
+                apoapsis,
+            } => periapsis >= 0.0 && periapsis <= apoapsis,
+            // a perigee below the surface is the usual target
+            Maneuver::Deorbit { altitude, perigee } => altitude >= 0.0 && perigee <= altitude,
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(format!("invalid maneuver {self:?}"))
+        }
+    }
+
+    /// Total delta-v around `body`.
+    pub fn delta_v(&self, body: &Body) -> MetersPerSecond {
+        let radius = |altitude: f64| body.radius + Meters::from_km(altitude);
+        match *self {
+            Maneuver::Hohmann { from, to } => {
+                calculate_hohmann_transfer(body.mu, radius(from), radius(to)).total()
+            }
+            Maneuver::PlaneChange {
+                altitude,
+                inclination,
+            } => calculate_plane_change(
+                calculate_circular_orbital_velocity(body.mu, radius(altitude)),
+                inclination,
+            ),
+            Maneuver::Circularize {
+                periapsis,
+                apoapsis,
+            } => {
+                calculate_hohmann_transfer(body.mu, radius(periapsis), radius(apoapsis)).second_burn
+            }
+            Maneuver::Deorbit { altitude, perigee } => {
+                calculate_hohmann_transfer(body.mu, radius(altitude), radius(perigee)).first_burn
+            }
+        }
+    }
+}
+
+/// Hohmann transfer between circular orbits that also turns the orbit plane,
+/// with part of the turn at each burn.
+#[derive(Debug, Clone, Copy)]
+pub struct CombinedTransfer {
+    pub first_angle: f64,  // deg, turned at the first burn
+    pub second_angle: f64, // deg
+    pub first_burn: MetersPerSecond,
+    pub second_burn: MetersPerSecond,
+}
+
+impl CombinedTransfer {
+    /// Total delta-v.
+    pub fn total(&self) -> MetersPerSecond {
+        self.first_burn + self.second_burn
+    }
+}
+
+/// Transfer from `from` to `to` km turning the plane by `inclination` (deg),
+/// `first_angle` (deg) of it at the first burn and the rest at the second.
+pub fn combined_transfer(
+    body: &Body,
+    from: f64,
+    to: f64,
+    inclination: f64,
+    first_angle: f64,
+) -> CombinedTransfer {
+    let (r1, r2) = (
+        body.radius + Meters::from_km(from),
+        body.radius + Meters::from_km(to),
+    );
+    let axis = (r1 + r2) / 2.0;
+    let second_angle = inclination - first_angle;
+    CombinedTransfer {
+        first_angle,
+        second_angle,
+        first_burn: calculate_combined_burn(
+            calculate_circular_orbital_velocity(body.mu, r1),
+            calculate_vis_viva_velocity(body.mu, r1, axis),
+            first_angle,
+        ),
+        second_burn: calculate_combined_burn(
+            calculate_vis_viva_velocity(body.mu, r2, axis),
+            calculate_circular_orbital_velocity(body.mu, r2),
+            second_angle,
+        ),
+    }
+}
+
+/// The split of the plane change between the two burns that needs the least
+/// total delta-v. Most of the turn belongs at the slower burn; for LEO to GEO
+/// only a couple of degrees go with the first.
+pub fn optimal_combined_transfer(
+    body: &Body,
+    from: f64,
+    to: f64,
+    inclination: f64,
+) -> CombinedTransfer {
+    // the total is convex in the split, so a golden-section search converges
+    let total = |angle: f64| combined_transfer(body, from, to, inclination, angle).total();
+    let ratio = (5f64.sqrt() - 1.0) / 2.0;
+    let (mut low, mut high) = (0.0_f64.min(inclination), 0.0_f64.max(inclination));
+    for _ in 0..100 {
+        let left = high - ratio * (high - low);
+        let right = low + ratio * (high - low);
+        if total(left) < total(right) {
+            high = right;
+        } else {
+            low = left;
+        }
+    }
+    combined_transfer(body, from, to, inclination, (low + high) / 2.0)
+}
+
+/// Parse `hohmann:FROM:TO`, `plane-change:ALT:DEG`, `circularize:PERI:APO`
+/// or `deorbit:ALT:PERIGEE`. Altitudes may carry a length unit.
+pub fn parse(input: &str) -> Result<Maneuver, String> {
+    let mut parts = input.split(':');
+    let kind = parts.next().unwrap_or("").trim().to_ascii_lowercase();
+    let parts: Vec<&str> = parts.collect();
+    let length = crate::parse_length;
+    let maneuver = match (kind.as_str(), &parts[..]) {
+        ("hohmann", &[from, to]) => Maneuver::Hohmann {
+            from: length(from)?,
+            to: length(to)?,
+        },
+        ("plane-change" | "plane", &[altitude, inclination]) => Maneuver::PlaneChange {
+            altitude: length(altitude)?,
+            inclination: inclination
+                .trim()
+                .parse::<f64>()
+                .map_err(|err| format!("{inclination:?}: {err}"))?,
+        },
+        ("circularize", &[periapsis, apoapsis]) => Maneuver::Circularize {
+            periapsis: length(periapsis)?,
+            apoapsis: length(apoapsis)?,
+        },
+        ("deorbit", &[altitude, perigee]) => Maneuver::Deorbit {
+            altitude: length(altitude)?,
+            perigee: length(perigee)?,
+        },
+        _ => {
+            return Err(
+                "expected hohmann:FROM:TO, plane-change:ALT:DEG, circularize:PERI:APO or deorbit:ALT:PERIGEE"
+                    .to_string(),
+            )
+        }
+    };
+    maneuver.validate()?;
+    Ok(maneuver)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Step {
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub maneuver: Maneuver,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Plan {
+    /// Margin in percent on top of the summed maneuvers.
+    pub margin: Option<f64>,
+    #[serde(rename = "maneuver", default)]
+    pub maneuvers: Vec<Step>,
+}
+
+/// Read a plan, as JSON for a .json file and TOML otherwise.
+pub fn load_plan(path: &Path) -> Result<Plan, String> {
+    let source = fs::read_to_string(path).map_err(|err| err.to_string())?;
+    let plan: Plan = if path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("json"))
+    {
+        serde_json::from_str(&source).map_err(|err| err.to_string())?
+    } else {
+        toml::from_str(&source).map_err(|err| err.to_string())?
+    };
+    plan.maneuvers
+        .iter()
+        .try_for_each(|step| step.maneuver.validate())?;
+    Ok(plan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_maneuvers() {
+        assert_eq!(
+            parse("hohmann:400:35786"),
+            Ok(Maneuver::Hohmann {
+                from: 400.0,
+                to: 35786.0
+            })
+        );
+        assert!(matches!(
+            parse("plane:500:10"),
+            Ok(Maneuver::PlaneChange { .. })
+        ));
+        assert_eq!(
+            parse("deorbit:250mi:50km"),
+            Ok(Maneuver::Deorbit {
+                altitude: 402.336,
+                perigee: 50.0
+            })
+        );
+        assert!(parse("plane:500:10km").is_err());
+        assert!(parse("circularize:800:400").is_err());
+        assert!(parse("deorbit:400").is_err());
+        assert!(parse("dock:1:2").is_err());
+    }
+
+    #[test]
+    fn test_parse_plan() {
+        let plan: Plan = toml::from_str(
+            r#"
+            margin = 5
+            [[maneuver]]
+            name = "Raise"
+            kind = "hohmann"
+            from = 200
+            to = 800
+            [[maneuver]]
+            kind = "deorbit"
+            altitude = 800
+            perigee = 50
+            "#,
+        )
+        .unwrap();
+        assert_eq!(plan.margin, Some(5.0));
+        assert_eq!(plan.maneuvers.len(), 2);
+        assert_eq!(plan.maneuvers[0].name.as_deref(), Some("Raise"));
+
+        let json: Plan = serde_json::from_str(
+            r#"{"maneuver": [{"kind": "circularize", "periapsis": 250, "apoapsis": 35786}]}"#,
+        )
+        .unwrap();
+        assert!(json.margin.is_none());
+        assert!(matches!(
+            json.maneuvers[0].maneuver,
+            Maneuver::Circularize { .. }
+        ));
+    }
+
+    #[test]
+    fn test_combined_gto_to_geo() {
+        // Cape Canaveral to GEO: turning all 28.5 deg at apogee costs far
+        // less than turning at GEO speed afterwards, and moving about two
+        // degrees to the perigee burn saves a little more
+        let earth = Body::earth();
+        let separate = Maneuver::Hohmann {
+            from: 200.0,
+            to: 35786.0,
+        }
+        .delta_v(&earth)
+            + Maneuver::PlaneChange {
+                altitude: 35786.0,
+                inclination: 28.5,
+            }
+            .delta_v(&earth);
+        let at_apogee = combined_transfer(&earth, 200.0, 35786.0, 28.5, 0.0);
+        let optimal = optimal_combined_transfer(&earth, 200.0, 35786.0, 28.5);
+        assert!((at_apogee.second_burn.0 - 1_830.0).abs() < 10.0);
+        assert!(at_apogee.total() < separate - MetersPerSecond(1_000.0));
+        assert!(optimal.total() < at_apogee.total());
+        assert!((1.0..4.0).contains(&optimal.first_angle));
+        assert!((optimal.first_angle + optimal.second_angle - 28.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_circularize_reverses_deorbit() {
+        // lowering perigee from a circle is the apoapsis burn of a Hohmann
+        // transfer run backwards, and so costs the same as circularizing
+        let earth = Body::earth();
+        let circularize = Maneuver::Circularize {
+            periapsis: 250.0,
+            apoapsis: 35786.0,
+        }
+        .delta_v(&earth);
+        let deorbit = Maneuver::Deorbit {
+            altitude: 35786.0,
+            perigee: 250.0,
+        }
+        .delta_v(&earth);
+        assert!((circularize.0 - 1_472.0).abs() < 2.0);
+        assert!((circularize - deorbit).abs() < MetersPerSecond(1e-9));
+
+        let hohmann = Maneuver::Hohmann {
+            from: 250.0,
+            to: 35786.0,
+        }
+        .delta_v(&earth);
+        assert!(((hohmann - circularize).0 - 2_440.0).abs() < 5.0);
+    }
+}
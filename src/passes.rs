@@ -0,0 +1,213 @@
+use crate::{
+    frames::{self, Geodetic},
+    propagate,
+    tle::Tle,
+};
+
+// Visibility of a satellite from a ground site. Positions go TEME -> ECEF by
+// sidereal time, then into the site's topocentric south-east-zenith (SEZ)
+// frame, whose z axis is the ellipsoid normal. A pass starts (acquisition of
+// signal, AOS) when the elevation rises through the minimum and ends (loss
+// of signal, LOS) when it falls back; the crossings are found by scanning at
+// a fixed step and bisecting, so passes shorter than the step can be missed.
+
+const SCAN_STEP: f64 = 30.0 / 86_400.0; // days
+const TOLERANCE: f64 = 0.01 / 86_400.0; // days
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Look {
+    pub azimuth: f64,   // deg, clockwise from north
+    pub elevation: f64, // deg
+    pub range: f64,     // m
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pass {
+    // days since J2000 UTC, clipped to the search window
+    pub aos: f64,
+    pub los: f64,
+    pub culmination: f64,
+    pub aos_azimuth: f64,   // deg
+    pub los_azimuth: f64,   // deg
+    pub max_elevation: f64, // deg
+}
+
+impl Pass {
+    /// Duration in s.
+    pub fn duration(&self) -> f64 {
+        (self.los - self.aos) * 86_400.0
+    }
+}
+
+/// Parse a site as LAT,LON\[,ALT\] in degrees and m above the ellipsoid.
+pub fn parse_station(input: &str) -> Result<Geodetic, String> {
+    let invalid = || format!("invalid station '{input}', expected LAT,LON[,ALT]");
+    let parts = input
+        .split(',')
+        .map(|part| part.trim().parse::<f64>().map_err(|_| invalid()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let (latitude, longitude, altitude) = match parts[..] {
+        [latitude, longitude] => (latitude, longitude, 0.0),
+        [latitude, longitude, altitude] => (latitude, longitude, altitude),
+        _ => return Err(invalid()),
+    };
+    if !(-90.0..=90.0).contains(&latitude) || !(-180.0..=360.0).contains(&longitude) {
+        return Err(invalid());
+    }
+    Ok(Geodetic {
+        latitude,
+        longitude,
+        altitude,
+    })
+}
+
+/// Azimuth, elevation and range of an Earth-fixed position seen from `station`.
+pub fn look_angles(station: &Geodetic, position: [f64; 3]) -> Look {
+    let site = frames::ecef(station);
+    let [x, y, z] = [0, 1, 2].map(|i| position[i] - site[i]);
+    let (sin_lat, cos_lat) = station.latitude.to_radians().sin_cos();
+    let (sin_lon, cos_lon) = station.longitude.to_radians().sin_cos();
+    let south = sin_lat * cos_lon * x + sin_lat * sin_lon * y - cos_lat * z;
+    let east = -sin_lon * x + cos_lon * y;
+    let zenith = cos_lat * cos_lon * x + cos_lat * sin_lon * y + sin_lat * z;
+    let range = (x * x + y * y + z * z).sqrt();
+    Look {
+        azimuth: east.atan2(-south).to_degrees().rem_euclid(360.0),
+        elevation: (zenith / range).asin().to_degrees(),
+        range,
+    }
+}
+
+/// Look angles of an element set's satellite at `days` since J2000 UTC.
+pub fn look(tle: &Tle, station: &Geodetic, days: f64) -> Result<Look, String> {
+    let state = propagate::sgp4(tle, days)?;
+    Ok(look_angles(
+        station,
+        frames::teme_to_ecef(state.position, days),
+    ))
+}
+
+/// Passes above `min_elevation` (deg) between `start` and `end` (days since
+/// J2000 UTC). A pass under way at either end is cut at the window.
+pub fn find(
+    tle: &Tle,
+    station: &Geodetic,
+    start: f64,
+    end: f64,
+    min_elevation: f64,
+) -> Result<Vec<Pass>, String> {
+    let above = |days: f64| -> Result<f64, String> {
+        Ok(look(tle, station, days)?.elevation - min_elevation)
+    };
+    // time in [low, high] where the sign of `above` changes
+    let crossing = |mut low: f64, mut high: f64, rising: bool| -> Result<f64, String> {
+        while high - low > TOLERANCE {
+            let middle = (low + high) / 2.0;
+            if (above(middle)? >= 0.0) == rising {
+                high = middle;
+            } else {
+                low = middle;
+            }
+        }
+        Ok((low + high) / 2.0)
+    };
+
+    let mut passes = vec![];
+    let mut aos = (above(start)? >= 0.0).then_some(start);
+    let mut previous = start;
+    while previous < end {
+        let next = (previous + SCAN_STEP).min(end);
+        let visible = above(next)? >= 0.0;
+        match aos {
+            None if visible => aos = Some(crossing(previous, next, true)?),
+            Some(rise) if !visible => {
+                passes.push(pass(tle, station, rise, crossing(previous, next, false)?)?);
+                aos = None;
+            }
+            _ => {}
+        }
+        previous = next;
+    }
+    if let Some(rise) = aos {
+        passes.push(pass(tle, station, rise, end)?);
+    }
+    Ok(passes)
+}
+
+/// Details of the pass between `aos` and `los`, with the culmination found
+/// by golden-section search (elevation has a single peak over a LEO pass).
+fn pass(tle: &Tle, station: &Geodetic, aos: f64, los: f64) -> Result<Pass, String> {
+    let elevation = |days: f64| -> Result<f64, String> { Ok(look(tle, station, days)?.elevation) };
+    let ratio = (5f64.sqrt() - 1.0) / 2.0;
+    let (mut low, mut high) = (aos, los);
+    while high - low > TOLERANCE {
+        let a = high - ratio * (high - low);
+        let b = low + ratio * (high - low);
+        if elevation(a)? < elevation(b)? {
+            low = a;
+        } else {
+            high = b;
+        }
+    }
+    let culmination = (low + high) / 2.0;
+    Ok(Pass {
+        aos,
+        los,
+        culmination,
+        aos_azimuth: look(tle, station, aos)?.azimuth,
+        los_azimuth: look(tle, station, los)?.azimuth,
+        max_elevation: elevation(culmination)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tle;
+
+    #[test]
+    fn test_look_angles() {
+        let station = parse_station("52.0,4.4,0").unwrap();
+        let up = frames::ecef(&Geodetic {
+            altitude: 400_000.0,
+            ..station
+        });
+        let look = look_angles(&station, up);
+        assert!((look.elevation - 90.0).abs() < 1e-6);
+        assert!((look.range - 400_000.0).abs() < 1e-3);
+
+        // a point due east on the local horizontal plane
+        let site = frames::ecef(&station);
+        let (sin_lon, cos_lon) = 4.4f64.to_radians().sin_cos();
+        let east = [site[0] - sin_lon * 1e5, site[1] + cos_lon * 1e5, site[2]];
+        let look = look_angles(&station, east);
+        assert!((look.azimuth - 90.0).abs() < 1e-6);
+        assert!(look.elevation.abs() < 1e-6);
+
+        assert!(parse_station("52.0").is_err());
+        assert!(parse_station("95,0,0").is_err());
+    }
+
+    #[test]
+    fn test_find_iss_passes() {
+        let sets = tle::parse(
+            "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927
+2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537",
+        )
+        .unwrap();
+        let iss = &sets[0];
+        let station = parse_station("52.0,4.4,0").unwrap();
+        let start = propagate::epoch(iss);
+        let passes = find(iss, &station, start, start + 1.0, 10.0).unwrap();
+        // a site at 52 deg N sees the ISS a few times a day
+        assert!(!passes.is_empty() && passes.len() < 8);
+        for pass in &passes {
+            assert!(pass.aos < pass.culmination && pass.culmination < pass.los);
+            assert!(pass.max_elevation >= 10.0 && pass.duration() < 15.0 * 60.0);
+            if pass.aos > start {
+                let at_aos = look(iss, &station, pass.aos).unwrap().elevation;
+                assert!((at_aos - 10.0).abs() < 0.01);
+            }
+        }
+    }
+}
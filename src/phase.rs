@@ -0,0 +1,148 @@
+use clap::ValueEnum;
+
+use crate::{
+    bodies::Named,
+    calculate_orbital_period, calculate_synodic_period,
+    dimension::{GravitationalParameter, Meters, Seconds},
+};
+
+// Phasing for Hohmann transfers between coplanar circular orbits around one
+// body, from co-orbital rendezvous to interplanetary launch windows. The
+// phase angle is the target's angular lead over the chaser, in the direction
+// of motion; a transfer must start when it equals the angle the target will
+// sweep less than half a revolution during the coast.
+
+/// One end of a transfer: a circular altitude in km around a central body,
+/// or a planet on its mean orbit around the Sun.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Endpoint {
+    Altitude(f64),
+    Planet(Named),
+}
+
+/// Parse an altitude in km, optionally with a length unit, or the name of a
+/// planet.
+pub fn parse_endpoint(input: &str) -> Result<Endpoint, String> {
+    if let Ok(altitude) = crate::parse_length(input) {
+        return if altitude >= 0.0 {
+            Ok(Endpoint::Altitude(altitude))
+        } else {
+            Err("altitude must not be negative".to_string())
+        };
+    }
+    match Named::from_str(input.trim(), true) {
+        Ok(named) if named.mean_orbit().is_some() => Ok(Endpoint::Planet(named)),
+        _ => Err(format!(
+            "'{input}' is neither an altitude in km nor a planet"
+        )),
+    }
+}
+
+/// Departure geometry of a Hohmann transfer from radius `from` to `to`.
+#[derive(Debug, Clone, Copy)]
+pub struct Window {
+    pub transfer_time: Seconds,
+    pub phase_angle: f64,                // deg, from -180 to 180
+    pub synodic_period: Option<Seconds>, // between windows
+}
+
+pub fn hohmann_window(mu: GravitationalParameter, from: Meters, to: Meters) -> Window {
+    let transfer_time = calculate_orbital_period(mu, (from + to) / 2.0) / 2.0;
+    // the fraction of a turn the target sweeps during the coast
+    let target_turns = transfer_time / calculate_orbital_period(mu, to);
+    Window {
+        transfer_time,
+        phase_angle: normalize(180.0 - 360.0 * target_turns),
+        synodic_period: calculate_synodic_period(
+            calculate_orbital_period(mu, from),
+            calculate_orbital_period(mu, to),
+        ),
+    }
+}
+
+/// Time until the target leads the chaser by the window's phase angle,
+/// starting from a lead of `phase` (deg), or `None` for equal orbits.
+pub fn wait_time(
+    mu: GravitationalParameter,
+    from: Meters,
+    to: Meters,
+    phase: f64,
+) -> Option<Seconds> {
+    let window = hohmann_window(mu, from, to);
+    let synodic = window.synodic_period?;
+    // deg/s, negative when the chaser is the faster
+    let relative =
+        360.0 / calculate_orbital_period(mu, to).0 - 360.0 / calculate_orbital_period(mu, from).0;
+    Some(Seconds(
+        ((window.phase_angle - phase) / relative).rem_euclid(synodic.0),
+    ))
+}
+
+/// An angle in degrees from -180 up to 180.
+pub fn normalize(angle: f64) -> f64 {
+    let angle = (angle + 180.0).rem_euclid(360.0) - 180.0;
+    if angle == -180.0 {
+        180.0
+    } else {
+        angle
+    }
+}
+
+/// Lead in degrees of `target` over `chaser`, given as longitudes in degrees.
+pub fn phase(chaser: f64, target: f64) -> f64 {
+    normalize(target - chaser)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{bodies::Named, Body};
+
+    #[test]
+    fn test_earth_to_mars_window() {
+        // 259 days of coast with Mars 44 deg ahead at departure, and
+        // windows every 2.14 years
+        let earth = Named::Earth.mean_orbit().unwrap().semi_major_axis;
+        let mars = Named::Mars.mean_orbit().unwrap().semi_major_axis;
+        let sun = Named::Sun.body().mu;
+        let window = hohmann_window(sun, earth, mars);
+        assert!((window.transfer_time.days() - 259.0).abs() < 1.0);
+        assert!((window.phase_angle - 44.3).abs() < 0.5);
+        assert!((window.synodic_period.unwrap().days() - 779.9).abs() < 1.0);
+
+        // inward transfers need the target behind
+        let venus = Named::Venus.mean_orbit().unwrap().semi_major_axis;
+        assert!(hohmann_window(sun, earth, venus).phase_angle < 0.0);
+    }
+
+    #[test]
+    fn test_wait_time_reaches_phase_angle() {
+        let earth = Body::earth();
+        let (from, to) = (
+            earth.radius + Meters::from_km(400.0),
+            earth.radius + Meters::from_km(35_786.0),
+        );
+        let window = hohmann_window(earth.mu, from, to);
+        let wait = wait_time(earth.mu, from, to, 90.0).unwrap();
+        let swept = |radius: Meters| 360.0 * (wait / calculate_orbital_period(earth.mu, radius));
+        let lead = 90.0 + swept(to) - swept(from);
+        assert!((normalize(lead) - window.phase_angle).abs() < 1e-6);
+        assert!(wait < window.synodic_period.unwrap());
+        assert!(wait_time(earth.mu, from, from, 10.0).is_none());
+    }
+
+    #[test]
+    fn test_parse_endpoint() {
+        assert_eq!(parse_endpoint("400"), Ok(Endpoint::Altitude(400.0)));
+        assert_eq!(parse_endpoint("Mars"), Ok(Endpoint::Planet(Named::Mars)));
+        assert!(parse_endpoint("moon").is_err());
+        assert!(parse_endpoint("-5").is_err());
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize(190.0), -170.0);
+        assert_eq!(normalize(-180.0), 180.0);
+        assert_eq!(phase(350.0, 10.0), 20.0);
+    }
+}
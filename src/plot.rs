@@ -0,0 +1,154 @@
+use std::path::Path;
+
+use clap::ValueEnum;
+use plotters::prelude::*;
+
+use crate::{
+    calculate_circular_orbital_velocity, calculate_orbital_period,
+    decay::{self, Elements},
+    dimension::Meters,
+    Body,
+};
+
+// Parametric curves against altitude, rendered to SVG with plotters.
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Curve {
+    /// Orbital period in minutes
+    #[value(name = "period-vs-altitude")]
+    Period,
+    /// Circular velocity in km/s
+    #[value(name = "velocity-vs-altitude")]
+    Velocity,
+    /// Ground footprint radius in km for a minimum elevation
+    #[value(name = "coverage-vs-altitude")]
+    Coverage,
+    /// Drag lifetime in years for a ballistic coefficient
+    #[value(name = "decay-vs-altitude")]
+    Decay,
+}
+
+pub struct Options {
+    pub min_elevation: f64,         // deg, for coverage
+    pub ballistic_coefficient: f64, // kg/m^2, for decay
+}
+
+/// Ground distance from the sub-satellite point to the edge of visibility,
+/// in km, for a circular altitude in km.
+pub fn footprint_radius(body: &Body, altitude: f64, min_elevation: f64) -> f64 {
+    let elevation = min_elevation.to_radians();
+    let r = body.radius + Meters::from_km(altitude);
+    // Earth central angle between the sub-satellite point and the edge
+    let central_angle = (body.radius * elevation.cos() / r).acos() - elevation;
+    (body.radius * central_angle).km()
+}
+
+pub fn series(
+    curve: Curve,
+    body: &Body,
+    from: f64,
+    to: f64,
+    points: usize,
+    options: &Options,
+) -> Vec<(f64, f64)> {
+    (0..points)
+        .map(|k| from + (to - from) * k as f64 / (points - 1).max(1) as f64)
+        .filter_map(|altitude| {
+            let radius = body.radius + Meters::from_km(altitude);
+            let value = match curve {
+                Curve::Period => calculate_orbital_period(body.mu, radius).minutes(),
+                Curve::Velocity => calculate_circular_orbital_velocity(body.mu, radius).km_per_s(),
+                Curve::Coverage => footprint_radius(body, altitude, options.min_elevation),
+                Curve::Decay => decay::years(decay::lifetime(
+                    body,
+                    Elements::from_altitudes(body, altitude, altitude),
+                    options.ballistic_coefficient,
+                    decay::MAX_LIFETIME_YEARS,
+                )?),
+            };
+            // points beyond the model's range (e.g. no decay within the cap)
+            // are left out of the curve
+            value.is_finite().then_some((altitude, value))
+        })
+        .collect()
+}
+
+pub struct Labels {
+    pub title: String,
+    pub x: String,
+    pub y: String,
+}
+
+pub fn render_svg(
+    path: &Path,
+    labels: &Labels,
+    data: &[(f64, f64)],
+    log_y: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (x_min, x_max) = bounds(data.iter().map(|p| p.0));
+    let (y_min, y_max) = bounds(data.iter().map(|p| p.1));
+
+    let root = SVGBackend::new(path, (800, 560)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let mut builder = ChartBuilder::on(&root);
+    builder
+        .caption(&labels.title, ("sans-serif", 24))
+        .margin(16)
+        .x_label_area_size(48)
+        .y_label_area_size(72);
+
+    if log_y {
+        let mut chart =
+            builder.build_cartesian_2d(x_min..x_max, (y_min.max(1e-3)..y_max).log_scale())?;
+        chart
+            .configure_mesh()
+            .x_desc(&labels.x)
+            .y_desc(&labels.y)
+            .draw()?;
+        chart.draw_series(LineSeries::new(data.iter().copied(), BLUE.stroke_width(2)))?;
+    } else {
+        let mut chart = builder.build_cartesian_2d(x_min..x_max, 0.0..y_max * 1.05)?;
+        chart
+            .configure_mesh()
+            .x_desc(&labels.x)
+            .y_desc(&labels.y)
+            .draw()?;
+        chart.draw_series(LineSeries::new(data.iter().copied(), BLUE.stroke_width(2)))?;
+    }
+
+    root.present()?;
+    Ok(())
+}
+
+fn bounds(values: impl Iterator<Item = f64>) -> (f64, f64) {
+    values.fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), v| {
+        (min.min(v), max.max(v))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_footprint_at_geo() {
+        // GEO sees out to about 81.3 degrees of Earth central angle
+        let body = Body::earth();
+        let radius = footprint_radius(&body, 35_786.0, 0.0);
+        let central_angle = (Meters::from_km(radius) / body.radius).to_degrees();
+        assert!((central_angle - 81.3).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_series_spans_range() {
+        let options = Options {
+            min_elevation: 0.0,
+            ballistic_coefficient: 100.0,
+        };
+        let data = series(Curve::Period, &Body::earth(), 200.0, 40_000.0, 50, &options);
+        assert_eq!(data.len(), 50);
+        assert_eq!(data[0].0, 200.0);
+        assert_eq!(data[49].0, 40_000.0);
+        assert!(data.windows(2).all(|w| w[1].1 > w[0].1));
+    }
+}
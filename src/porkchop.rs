@@ -0,0 +1,190 @@
+use plotters::prelude::*;
+use std::path::Path;
+
+use crate::{bodies::Named, ephemeris, habitable, lambert, time};
+
+// Porkchop grids: for every pair of departure and arrival dates, the
+// heliocentric Lambert arc between the two planets on their mean-element
+// ephemerides, and the hyperbolic excess speeds it needs at each end. The
+// launch energy C3 is the square of the departure excess speed. Arcs are
+// prograde with no full revolutions, as for ordinary planetary transfers.
+
+/// One departure and arrival date pair.
+#[derive(Debug, Clone, Copy)]
+pub struct Cell {
+    pub depart: f64,          // days since J2000
+    pub arrive: f64,          // days since J2000
+    pub departure_v_inf: f64, // m/s
+    pub arrival_v_inf: f64,   // m/s
+}
+
+impl Cell {
+    /// Launch energy in km^2/s^2.
+    pub fn c3(&self) -> f64 {
+        (self.departure_v_inf / 1000.0).powi(2)
+    }
+}
+
+fn difference(a: [f64; 3], b: [f64; 3]) -> f64 {
+    (0..3).map(|i| (a[i] - b[i]).powi(2)).sum::<f64>().sqrt()
+}
+
+/// The transfer from `from` leaving at `depart` to `to` arriving at
+/// `arrive`, or `None` if it arrives first, both are the same planet or
+/// either body has no ephemeris.
+pub fn cell(from: Named, to: Named, depart: f64, arrive: f64) -> Option<Cell> {
+    if arrive <= depart || from == to {
+        return None;
+    }
+    let (start, end) = (
+        ephemeris::state(from, depart)?,
+        ephemeris::state(to, arrive)?,
+    );
+    let solutions = lambert::solve(
+        habitable::SUN_MU,
+        start.position,
+        end.position,
+        (arrive - depart) * 86_400.0,
+        0,
+        lambert::Direction::Prograde,
+    )
+    .ok()?;
+    let arc = solutions.first()?;
+    Some(Cell {
+        depart,
+        arrive,
+        departure_v_inf: difference(arc.departure, start.velocity),
+        arrival_v_inf: difference(arc.arrival, end.velocity),
+    })
+}
+
+/// Every cell of the grid, by departure and then arrival, leaving out pairs
+/// with no transfer.
+pub fn grid(from: Named, to: Named, departures: &[f64], arrivals: &[f64]) -> Vec<Cell> {
+    departures
+        .iter()
+        .flat_map(|&depart| {
+            arrivals
+                .iter()
+                .filter_map(move |&arrive| cell(from, to, depart, arrive))
+        })
+        .collect()
+}
+
+/// Days from `start` to `end` inclusive, `step` days apart.
+pub fn dates(start: f64, end: f64, step: f64) -> Vec<f64> {
+    let count = ((end - start) / step + 1e-9).floor().max(0.0) as usize;
+    (0..=count).map(|k| start + k as f64 * step).collect()
+}
+
+/// Parse a date range `START:END`, each end a UTC date or time as accepted
+/// by `time::parse_utc`.
+pub fn parse_range(input: &str) -> Result<(f64, f64), String> {
+    // times hold colons too, so try each split until both ends parse
+    input
+        .match_indices(':')
+        .find_map(|(index, _)| {
+            let start = time::parse_utc(&input[..index]).ok()?;
+            let end = time::parse_utc(&input[index + 1..]).ok()?;
+            Some((start, end))
+        })
+        .filter(|(start, end)| start <= end)
+        .ok_or_else(|| {
+            format!("invalid range '{input}', expected START:END such as 2026-01-01:2027-01-01")
+        })
+}
+
+pub struct Labels {
+    pub title: String,
+    pub x: String,
+    pub y: String,
+}
+
+/// Shade each cell by C3 from blue (low) to red, up to `max_c3` km^2/s^2,
+/// and leave costlier cells blank. Axes are days since the first date.
+pub fn render_svg(
+    path: &Path,
+    labels: &Labels,
+    cells: &[Cell],
+    step: (f64, f64),
+    max_c3: f64,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let first = (
+        cells.iter().map(|c| c.depart).fold(f64::INFINITY, f64::min),
+        cells.iter().map(|c| c.arrive).fold(f64::INFINITY, f64::min),
+    );
+    let last = (
+        cells
+            .iter()
+            .map(|c| c.depart)
+            .fold(f64::NEG_INFINITY, f64::max),
+        cells
+            .iter()
+            .map(|c| c.arrive)
+            .fold(f64::NEG_INFINITY, f64::max),
+    );
+
+    let root = SVGBackend::new(path, (800, 640)).into_drawing_area();
+    root.fill(&WHITE)?;
+    let mut chart = ChartBuilder::on(&root)
+        .caption(&labels.title, ("sans-serif", 24))
+        .margin(16)
+        .x_label_area_size(48)
+        .y_label_area_size(72)
+        .build_cartesian_2d(
+            0.0..last.0 - first.0 + step.0,
+            0.0..last.1 - first.1 + step.1,
+        )?;
+    chart
+        .configure_mesh()
+        .x_desc(&labels.x)
+        .y_desc(&labels.y)
+        .draw()?;
+    chart.draw_series(cells.iter().filter(|c| c.c3() <= max_c3).map(|c| {
+        let (x, y) = (c.depart - first.0, c.arrive - first.1);
+        let shade = (c.c3() / max_c3).clamp(0.0, 1.0);
+        Rectangle::new(
+            [(x, y), (x + step.0, y + step.1)],
+            HSLColor((1.0 - shade) * 0.66, 0.8, 0.5).filled(),
+        )
+    }))?;
+
+    root.present()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mars_2026_window() {
+        // the late-2026 Earth-Mars opportunity needs a C3 of about 9 to 10
+        // km^2/s^2 for a departure in November and a landing in autumn 2027
+        let depart = time::parse_utc("2026-11-10").unwrap();
+        let arrive = time::parse_utc("2027-09-01").unwrap();
+        let transfer = cell(Named::Earth, Named::Mars, depart, arrive).unwrap();
+        assert!((8.0..12.0).contains(&transfer.c3()));
+        assert!((2_000.0..4_000.0).contains(&transfer.arrival_v_inf));
+
+        let cells = grid(
+            Named::Earth,
+            Named::Mars,
+            &dates(depart, depart + 20.0, 10.0),
+            &dates(depart - 10.0, arrive, 100.0),
+        );
+        // 3 departures by 4 arrivals, less the arrival before every departure
+        assert_eq!(cells.len(), 3 * 3);
+        assert!(cell(Named::Earth, Named::Moon, depart, arrive).is_none());
+    }
+
+    #[test]
+    fn test_parse_range() {
+        let (start, end) = parse_range("2026-01-01:2027-01-01").unwrap();
+        assert_eq!(end - start, 365.0);
+        let (start, end) = parse_range("2026-01-01T06:00:2026-01-02T18:30").unwrap();
+        assert!((end - start - 1.520_833).abs() < 1e-5);
+        assert!(parse_range("2027-01-01:2026-01-01").is_err());
+        assert!(parse_range("2026-01-01").is_err());
+    }
+}
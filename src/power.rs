@@ -0,0 +1,87 @@
+use std::f64::consts::PI;
+
+use clap::ValueEnum;
+
+use crate::dimension::Meters;
+
+// Solar array output around a circular orbit. Positions are sampled by the
+// in-plane angle u measured from orbit noon (the point closest to the Sun).
+// In the orbit frame the Sun direction is (cos beta, 0, sin beta), the
+// satellite direction is (cos u, sin u, 0), and the cylindrical shadow test
+// matches the eclipse module.
+
+const SAMPLES: usize = 3600;
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Pointing {
+    /// Array normal follows the Sun
+    SunTracking,
+    /// Body-mounted on the zenith face of a nadir-pointing spacecraft
+    Zenith,
+    /// Body-mounted facing the orbit normal on the Sun side
+    OrbitNormal,
+}
+
+pub struct Illumination {
+    pub average: f64, // orbit-averaged cosine of incidence, zero in eclipse
+    pub peak: f64,    // best cosine of incidence over the orbit
+    pub sunlit: f64,  // fraction of the orbit in sunlight
+}
+
+pub fn illumination(
+    body_radius: Meters,
+    orbit_radius: Meters,
+    beta: f64, // deg
+    pointing: Pointing,
+) -> Illumination {
+    let (sin_beta, cos_beta) = beta.to_radians().sin_cos();
+    let shadow = body_radius / orbit_radius;
+
+    let (mut total, mut peak, mut sunlit) = (0.0, 0.0_f64, 0);
+    for k in 0..SAMPLES {
+        let u = 2.0 * PI * k as f64 / SAMPLES as f64;
+        let along_sun = cos_beta * u.cos();
+        let in_shadow = along_sun < 0.0 && (1.0 - along_sun.powi(2)).sqrt() < shadow;
+        if in_shadow {
+            continue;
+        }
+        sunlit += 1;
+        let incidence = match pointing {
+            Pointing::SunTracking => 1.0,
+            Pointing::Zenith => along_sun.max(0.0),
+            Pointing::OrbitNormal => sin_beta.abs(),
+        };
+        total += incidence;
+        peak = peak.max(incidence);
+    }
+
+    Illumination {
+        average: total / SAMPLES as f64,
+        peak,
+        sunlit: sunlit as f64 / SAMPLES as f64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{eclipse, EARTH_RADIUS};
+
+    #[test]
+    fn test_sunlit_fraction_matches_eclipse_model() {
+        let (body, r) = (Meters(EARTH_RADIUS), Meters(EARTH_RADIUS + 550_000.0));
+        for beta in [0.0, 20.0, 45.0, 70.0] {
+            let sampled = illumination(body, r, beta, Pointing::SunTracking).sunlit;
+            let analytic = 1.0 - eclipse::eclipse_fraction(body, r, beta);
+            assert!((sampled - analytic).abs() < 1e-3, "beta {beta}");
+        }
+    }
+
+    #[test]
+    fn test_zenith_panel_averages_one_over_pi_at_high_altitude() {
+        // far from the body the shadow vanishes and cos+ averages 1 / pi
+        let body = Meters(EARTH_RADIUS);
+        let lit = illumination(body, 1e3 * body, 0.0, Pointing::Zenith);
+        assert!((lit.average - 1.0 / PI).abs() < 1e-3);
+    }
+}
@@ -0,0 +1,154 @@
+use std::f64::consts::PI;
+
+use crate::{
+    anomaly::Anomalies,
+    elements::{KeplerianElements, StateVector},
+    time,
+    tle::Tle,
+};
+
+// SGP4/SDP4 propagation of two-line element sets, through the sgp4 crate
+// with its recommended WGS-84 constants and IAU sidereal time. SGP4 works in
+// the True Equator, Mean Equinox (TEME) frame of the element set; see
+// `frames` for Earth-fixed and geodetic positions.
+//
+// Classical elements propagate analytically on the unperturbed two-body
+// conic: the mean anomaly advances at the mean motion and Kepler's equation
+// gives the true anomaly, with every other element fixed. The state is in
+// whatever inertial frame the elements are.
+
+/// Days since J2000 of the element set's epoch.
+pub fn epoch(tle: &Tle) -> f64 {
+    time::civil_days(tle.epoch_year as i64, 1, 1) as f64 - 0.5 + tle.epoch_day - 1.0
+}
+
+/// TEME state in m and m/s at `days` since J2000 UTC.
+pub fn sgp4(tle: &Tle, days: f64) -> Result<StateVector, String> {
+    let radians = PI / 180.0;
+    let orbit = sgp4::Orbit::from_kozai_elements(
+        &sgp4::WGS84,
+        tle.inclination * radians,
+        tle.raan * radians,
+        tle.eccentricity,
+        tle.argument_of_perigee * radians,
+        tle.mean_anomaly * radians,
+        tle.mean_motion * 2.0 * PI / 1440.0, // rad/min
+    )
+    .map_err(|err| err.to_string())?;
+    let constants = sgp4::Constants::new(
+        sgp4::WGS84,
+        sgp4::iau_epoch_to_sidereal_time,
+        epoch(tle) / 365.25,
+        tle.bstar,
+        orbit,
+    )
+    .map_err(|err| err.to_string())?;
+    let prediction = constants
+        .propagate(sgp4::MinutesSinceEpoch((days - epoch(tle)) * 1440.0))
+        .map_err(|err| err.to_string())?;
+
+    Ok(StateVector {
+        position: prediction.position.map(|v| v * 1000.0),
+        velocity: prediction.velocity.map(|v| v * 1000.0),
+    })
+}
+
+/// Two-body state `elapsed` s after the epoch of `elements`, on an ellipse
+/// or a hyperbola (negative semi-major axis).
+pub fn kepler(mu: f64, elements: &KeplerianElements, elapsed: f64) -> Result<StateVector, String> {
+    let (a, e) = (elements.semi_major_axis, elements.eccentricity);
+    if !(e >= 0.0 && e != 1.0 && a != 0.0 && (a < 0.0) == (e > 1.0)) {
+        return Err(
+            "elements need a non-negative eccentricity other than 1, with a positive semi-major axis for closed orbits and a negative one for hyperbolic orbits"
+                .to_string(),
+        );
+    }
+    let mean_motion = (mu / a.abs().powi(3)).sqrt(); // rad/s
+    let mean = Anomalies::from_true(elements.true_anomaly.to_radians(), e)?.mean;
+    let true_anomaly = Anomalies::from_mean(mean + mean_motion * elapsed, e)?.true_anomaly;
+    Ok(KeplerianElements {
+        true_anomaly: true_anomaly.to_degrees(),
+        ..*elements
+    }
+    .to_state(mu))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        calculate_orbital_period,
+        dimension::{GravitationalParameter, Meters},
+        tle, EARTH_MU,
+    };
+
+    #[test]
+    fn test_sgp4_reference_case() {
+        // Vallado et al. (2006), "Revisiting Spacetrack Report #3", test
+        // case 00005 at epoch and 360 minutes later. The published values
+        // use WGS-72 constants, which move positions by tens of metres.
+        let sets = tle::parse(
+            "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753
+2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667",
+        )
+        .unwrap();
+        let set = &sets[0];
+        let at_epoch = sgp4(set, epoch(set)).unwrap();
+        let expected = [7_022.465_29, -1_400.082_97, 0.039_951_6];
+        for (computed, expected) in at_epoch.position.iter().zip(expected) {
+            assert!((computed / 1000.0 - expected).abs() < 0.05);
+        }
+        let later = sgp4(set, epoch(set) + 0.25).unwrap();
+        let expected = [-7_154.031_20, -3_783.176_82, -3_536.194_12];
+        for (computed, expected) in later.position.iter().zip(expected) {
+            assert!((computed / 1000.0 - expected).abs() < 0.05);
+        }
+    }
+
+    #[test]
+    fn test_kepler_conserves_energy_and_closes() {
+        let elements = KeplerianElements {
+            semi_major_axis: 26_600_000.0,
+            eccentricity: 0.74,
+            inclination: 63.4,
+            raan: 40.0,
+            argument_of_periapsis: 270.0,
+            true_anomaly: 10.0,
+        };
+        let energy = |state: StateVector| {
+            let [x, y, z] = state.position;
+            let [vx, vy, vz] = state.velocity;
+            (vx * vx + vy * vy + vz * vz) / 2.0 - EARTH_MU / (x * x + y * y + z * z).sqrt()
+        };
+        let start = kepler(EARTH_MU, &elements, 0.0).unwrap();
+        let later = kepler(EARTH_MU, &elements, 20_000.0).unwrap();
+        assert!((energy(later) / energy(start) - 1.0).abs() < 1e-9);
+        // one period later the satellite is back where it started
+        let period = calculate_orbital_period(
+            GravitationalParameter(EARTH_MU),
+            Meters(elements.semi_major_axis),
+        );
+        let back = kepler(EARTH_MU, &elements, period.0).unwrap();
+        for i in 0..3 {
+            assert!((back.position[i] - start.position[i]).abs() < 1e-3);
+        }
+
+        let hyperbola = KeplerianElements {
+            semi_major_axis: -20_000_000.0,
+            eccentricity: 1.5,
+            true_anomaly: -60.0,
+            ..elements
+        };
+        let state = kepler(EARTH_MU, &hyperbola, 3_600.0).unwrap();
+        assert!((energy(state) - EARTH_MU / 40_000_000.0).abs() < 1e-3);
+        assert!(kepler(
+            EARTH_MU,
+            &KeplerianElements {
+                eccentricity: 1.5,
+                ..elements
+            },
+            0.0
+        )
+        .is_err());
+    }
+}
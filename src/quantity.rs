@@ -0,0 +1,53 @@
+// Parsing of numbers with optional unit suffixes, e.g. "1.1Msun" or "2e30kg".
+// Each caller lists the suffixes it accepts with their factor to the unit the
+// calculation works in; a bare number is already in that unit.
+
+pub fn parse(input: &str, units: &[(&str, f64)]) -> Result<f64, String> {
+    let input = input.trim();
+    // the longest leading part that is a number, so "2e30kg" splits at "kg"
+    let (value, suffix) = (1..=input.len())
+        .rev()
+        .filter(|&i| input.is_char_boundary(i))
+        .find_map(|i| {
+            let value = input[..i].trim().parse::<f64>().ok()?;
+            Some((value, input[i..].trim()))
+        })
+        .ok_or_else(|| format!("invalid number '{input}'"))?;
+
+    if suffix.is_empty() {
+        return Ok(value);
+    }
+
+    units
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(suffix))
+        .map(|(_, factor)| value * factor)
+        .ok_or_else(|| {
+            let names: Vec<&str> = units.iter().map(|(name, _)| *name).collect();
+            format!(
+                "unknown unit '{suffix}', expected one of: {}",
+                names.join(", ")
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UNITS: &[(&str, f64)] = &[("km", 1.0), ("m", 1e-3)];
+
+    #[test]
+    fn test_bare_number_keeps_default_unit() {
+        assert_eq!(parse("400", UNITS), Ok(400.0));
+        assert_eq!(parse("1.5e3", UNITS), Ok(1500.0));
+    }
+
+    #[test]
+    fn test_suffixes() {
+        assert_eq!(parse("400km", UNITS), Ok(400.0));
+        assert_eq!(parse("2e5 m", UNITS), Ok(200.0));
+        assert!(parse("400mi", UNITS).is_err());
+        assert!(parse("fast", UNITS).is_err());
+    }
+}
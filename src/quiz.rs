@@ -0,0 +1,102 @@
+use clap::ValueEnum;
+
+use crate::{
+    calculate_escape_velocity, calculate_hohmann_transfer, calculate_orbital_period,
+    dimension::{Meters, MetersPerSecond, Seconds},
+    random::Rng,
+    Body, HohmannTransfer,
+};
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Topic {
+    Period,
+    Hohmann,
+    Escape,
+}
+
+pub enum Problem {
+    // altitudes in km
+    Period { altitude: f64 },
+    Hohmann { from: f64, to: f64 },
+    Escape { altitude: f64 },
+}
+
+pub enum Answer {
+    Period { period: Seconds },
+    Hohmann(HohmannTransfer),
+    Escape { velocity: MetersPerSecond },
+}
+
+// Altitudes are rounded to 10 km so the problems read like textbook ones.
+fn altitude(rng: &mut Rng, min: f64, max: f64) -> f64 {
+    (rng.range(min, max) / 10.0).round() * 10.0
+}
+
+pub fn generate(topic: Topic, count: usize, rng: &mut Rng) -> Vec<Problem> {
+    (0..count)
+        .map(|_| match topic {
+            Topic::Period => Problem::Period {
+                altitude: altitude(rng, 200.0, 36_000.0),
+            },
+            Topic::Hohmann => {
+                let from = altitude(rng, 200.0, 2_000.0);
+                Problem::Hohmann {
+                    from,
+                    to: altitude(rng, from + 1_000.0, 40_000.0),
+                }
+            }
+            Topic::Escape => Problem::Escape {
+                altitude: altitude(rng, 0.0, 2_000.0),
+            },
+        })
+        .collect()
+}
+
+impl Problem {
+    pub fn solve(&self, body: &Body) -> Answer {
+        let radius = |altitude: f64| body.radius + Meters::from_km(altitude);
+        match self {
+            Problem::Period { altitude } => Answer::Period {
+                period: calculate_orbital_period(body.mu, radius(*altitude)),
+            },
+            Problem::Hohmann { from, to } => Answer::Hohmann(calculate_hohmann_transfer(
+                body.mu,
+                radius(*from),
+                radius(*to),
+            )),
+            Problem::Escape { altitude } => Answer::Escape {
+                velocity: calculate_escape_velocity(body.mu, radius(*altitude)),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seed_reproduces_problems() {
+        let altitudes = |seed| {
+            generate(Topic::Period, 5, &mut Rng::new(seed))
+                .iter()
+                .map(|p| match p {
+                    Problem::Period { altitude } => *altitude,
+                    _ => unreachable!(),
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(altitudes(42), altitudes(42));
+        assert_ne!(altitudes(42), altitudes(43));
+    }
+
+    #[test]
+    fn test_hohmann_problems_raise_the_orbit() {
+        for problem in generate(Topic::Hohmann, 50, &mut Rng::new(1)) {
+            match problem {
+                Problem::Hohmann { from, to } => assert!(to > from),
+                _ => unreachable!(),
+            }
+        }
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{
+    calculate_orbital_period,
+    dimension::{Meters, Seconds},
+    geomagnetic::Dipole,
+    sample, Body, EARTH_ROTATION_RATE,
+};
+
+// Trapped-radiation exposure of a circular Earth orbit. The orbit is sampled
+// every `STEP` seconds in the Earth-fixed frame, and each sample is checked
+// against the eccentric dipole in `geomagnetic`: inside the South Atlantic
+// Anomaly or not, and trapped on an L-shell in the inner or outer Van Allen
+// belt or not. Consecutive samples inside the anomaly make up one crossing.
+// This is a screening tool for comparing orbits, not a dose model.
+
+/// Rough L range of the inner Van Allen belt.
+pub const INNER_BELT: (f64, f64) = (1.2, 2.5);
+/// Rough L range of the outer Van Allen belt.
+pub const OUTER_BELT: (f64, f64) = (3.0, 7.0);
+const STEP: f64 = 10.0; // s
+
+/// One pass through the South Atlantic Anomaly.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Crossing {
+    pub entry: f64,     // s
+    pub exit: f64,      // s
+    pub min_field: f64, // T
+}
+
+impl Crossing {
+    pub fn duration(&self) -> f64 {
+        self.exit - self.entry
+    }
+
+    /// Revolution the crossing starts in, counting from 1.
+    pub fn orbit(&self, period: Seconds) -> u32 {
+        (self.entry / period.0).floor() as u32 + 1
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Exposure {
+    pub period: Seconds,
+    pub span: Seconds,
+    /// Fraction of the time spent inside the anomaly
+    pub saa_fraction: f64,
+    /// Fraction of the samples trapped in the inner belt
+    pub inner_fraction: f64,
+    /// Fraction of the samples trapped in the outer belt
+    pub outer_fraction: f64,
+    pub crossings: Vec<Crossing>,
+}
+
+impl Exposure {
+    pub fn crossings_per_day(&self) -> f64 {
+        self.crossings.len() as f64 * 86_400.0 / self.span.0
+    }
+}
+
+/// Exposure of a circular orbit around `body` at `altitude` km over
+/// `orbits` revolutions, starting at the ascending node with the node
+/// `raan` degrees east of Greenwich.
+pub fn exposure(body: &Body, altitude: f64, inclination: f64, raan: f64, orbits: u32) -> Exposure {
+    let dipole = Dipole::igrf_2020();
+    let radius = body.radius + Meters::from_km(altitude);
+    let period = calculate_orbital_period(body.mu, radius);
+    let points = (orbits as f64 * period.0 / STEP).ceil() as usize;
+    let trajectory = sample::sample_circular_over(
+        body,
+        altitude,
+        inclination,
+        raan,
+        points,
+        sample::Frame::Eci,
+        orbits as f64,
+    );
+    // ground_track keeps the radius, so this is the body-fixed position
+    let fixed = trajectory.ground_track(radius, EARTH_ROTATION_RATE);
+
+    let mut crossings: Vec<Crossing> = vec![];
+    let mut inside = false;
+    let (mut inner, mut outer) = (0usize, 0usize);
+    for (time, &position) in fixed.times.iter().zip(&fixed.positions) {
+        if dipole.trapped(position) {
+            let l = dipole.l_shell(position);
+            inner += usize::from((INNER_BELT.0..INNER_BELT.1).contains(&l));
+            outer += usize::from((OUTER_BELT.0..OUTER_BELT.1).contains(&l));
+        }
+
+        if dipole.in_saa(position) {
+            let field = dipole.field(position);
+            if !inside {
+                crossings.push(Crossing {
+                    entry: *time,
+                    exit: *time,
+                    min_field: field,
+                });
+            }
+            let crossing = crossings.last_mut().unwrap();
+            crossing.exit = *time;
+            crossing.min_field = crossing.min_field.min(field);
+        }
+        inside = dipole.in_saa(position);
+    }
+
+    let samples = fixed.times.len() as f64;
+    let span = orbits as f64 * period.0;
+    let saa_time = crossings
+        .iter()
+        .fold(0.0, |total, crossing| total + crossing.duration());
+    Exposure {
+        period,
+        span: Seconds(span),
+        saa_fraction: saa_time / span,
+        inner_fraction: inner as f64 / samples,
+        outer_fraction: outer as f64 / samples,
+        crossings,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_equatorial_orbit_stays_off_the_belts() {
+        // the anomaly reaches the equator over Brazil, so an equatorial
+        // orbit meets it about once a revolution
+        let exposure = exposure(&Body::earth(), 500.0, 0.0, 0.0, 15);
+        let revolutions = 86_400.0 / exposure.period.0;
+        assert!((exposure.crossings_per_day() - revolutions).abs() < 1.5);
+        assert_eq!(exposure.inner_fraction, 0.0);
+        assert_eq!(exposure.outer_fraction, 0.0);
+    }
+
+    #[test]
+    fn test_iss_orbit_crosses_the_anomaly() {
+        let exposure = exposure(&Body::earth(), 420.0, 51.6, 0.0, 15);
+        // a few passes a day, each a few minutes long
+        let per_day = exposure.crossings_per_day();
+        assert!((2.0..10.0).contains(&per_day));
+        assert!(exposure.saa_fraction > 0.0 && exposure.saa_fraction < 0.2);
+        for crossing in &exposure.crossings {
+            assert!(crossing.duration() < 1_200.0);
+            assert!(crossing.min_field < 30e-6);
+            assert!((1..=15).contains(&crossing.orbit(exposure.period)));
+        }
+        assert!(exposure.inner_fraction > 0.0);
+    }
+
+    #[test]
+    fn test_polar_orbit_reaches_the_outer_belt() {
+        let exposure = exposure(&Body::earth(), 800.0, 98.0, 0.0, 15);
+        assert!(exposure.outer_fraction > 0.0);
+    }
+}
@@ -0,0 +1,197 @@
+use crate::{
+    dimension::{GravitationalParameter, Meters},
+    elements::StateVector,
+};
+
+// Clohessy-Wiltshire (Hill) equations for a chaser close to a target on a
+// circular orbit, in the target's local frame: x radial (outward), y along
+// track in the direction of motion and z along the orbit normal. The
+// linearised motion has a closed-form state transition matrix in the mean
+// motion n, which gives the relative state after a coast, and inverting its
+// position-from-velocity block gives the velocity that reaches the target in
+// a given time. Positions are in m, velocities in m/s and times in s.
+
+/// Blocks of the state transition matrix after `t` s at mean motion `n`
+/// rad/s: position from position, position from velocity, velocity from
+/// position and velocity from velocity.
+type Blocks = [[[f64; 3]; 3]; 4];
+
+fn transition(n: f64, t: f64) -> Blocks {
+    let (s, c) = (n * t).sin_cos();
+    let nt = n * t;
+    [
+        [
+            [4.0 - 3.0 * c, 0.0, 0.0],
+            [6.0 * (s - nt), 1.0, 0.0],
+            [0.0, 0.0, c],
+        ],
+        [
+            [s / n, 2.0 * (1.0 - c) / n, 0.0],
+            [-2.0 * (1.0 - c) / n, (4.0 * s - 3.0 * nt) / n, 0.0],
+            [0.0, 0.0, s / n],
+        ],
+        [
+            [3.0 * n * s, 0.0, 0.0],
+            [-6.0 * n * (1.0 - c), 0.0, 0.0],
+            [0.0, 0.0, -n * s],
+        ],
+        [
+            [c, 2.0 * s, 0.0],
+            [-2.0 * s, 4.0 * c - 3.0, 0.0],
+            [0.0, 0.0, c],
+        ],
+    ]
+}
+
+fn apply(matrix: [[f64; 3]; 3], vector: [f64; 3]) -> [f64; 3] {
+    matrix.map(|row| row[0] * vector[0] + row[1] * vector[1] + row[2] * vector[2])
+}
+
+fn add(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn norm(a: [f64; 3]) -> f64 {
+    (a[0] * a[0] + a[1] * a[1] + a[2] * a[2]).sqrt()
+}
+
+/// Mean motion in rad/s of a circular orbit of `radius` around a body with
+/// gravitational parameter `mu`.
+pub fn mean_motion(mu: GravitationalParameter, radius: Meters) -> f64 {
+    (mu.0 / radius.0.powi(3)).sqrt()
+}
+
+/// Relative state `t` s after `state` at mean motion `n` rad/s.
+pub fn propagate(n: f64, state: &StateVector, t: f64) -> StateVector {
+    let [rr, rv, vr, vv] = transition(n, t);
+    StateVector {
+        position: add(apply(rr, state.position), apply(rv, state.velocity)),
+        velocity: add(apply(vr, state.position), apply(vv, state.velocity)),
+    }
+}
+
+/// The two burns of a rendezvous: one to start the transfer and one to stop
+/// at the target.
+#[derive(Debug, Clone, Copy)]
+pub struct Rendezvous {
+    pub first: [f64; 3],  // m/s
+    pub second: [f64; 3], // m/s
+}
+
+impl Rendezvous {
+    /// Total delta-v in m/s.
+    pub fn total(&self) -> f64 {
+        norm(self.first) + norm(self.second)
+    }
+}
+
+/// Burns that bring a chaser at `state` to rest at the target `tof` s later,
+/// at mean motion `n` rad/s. Transfers lasting a whole number of orbits (and
+/// some others in the orbit plane) cannot be steered and fail.
+pub fn rendezvous(n: f64, state: &StateVector, tof: f64) -> Result<Rendezvous, String> {
+    let [rr, rv, ..] = transition(n, tof);
+    let inverse = invert(rv)
+        .ok_or("no transfer reaches the target in this time; try another time of flight")?;
+    let target = apply(rr, state.position).map(|v| -v);
+    let departure = apply(inverse, target);
+    let arrival = propagate(
+        n,
+        &StateVector {
+            position: state.position,
+            velocity: departure,
+        },
+        tof,
+    );
+    Ok(Rendezvous {
+        first: [0, 1, 2].map(|i| departure[i] - state.velocity[i]),
+        second: arrival.velocity.map(|v| -v),
+    })
+}
+
+/// Inverse of a 3x3 matrix, or `None` if it is singular.
+fn invert(m: [[f64; 3]; 3]) -> Option<[[f64; 3]; 3]> {
+    let cofactor = |r: usize, c: usize| {
+        let (r1, r2) = ((r + 1) % 3, (r + 2) % 3);
+        let (c1, c2) = ((c + 1) % 3, (c + 2) % 3);
+        m[r1][c1] * m[r2][c2] - m[r1][c2] * m[r2][c1]
+    };
+    let determinant = (0..3).map(|c| m[0][c] * cofactor(0, c)).sum::<f64>();
+    let scale = m.iter().flatten().fold(0.0_f64, |max, v| max.max(v.abs()));
+    if determinant.abs() <= 1e-12 * scale.powi(3) {
+        return None;
+    }
+    Some([0, 1, 2].map(|r| [0, 1, 2].map(|c| cofactor(c, r) / determinant)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calculate_orbital_period, EARTH_MU, EARTH_RADIUS};
+
+    fn earth_mean_motion(altitude: f64) -> f64 {
+        mean_motion(
+            GravitationalParameter(EARTH_MU),
+            Meters(EARTH_RADIUS + altitude * 1000.0),
+        )
+    }
+
+    #[test]
+    fn test_mean_motion() {
+        // a geostationary orbit turns once a sidereal day
+        let n = mean_motion(GravitationalParameter(EARTH_MU), Meters(42_164_170.0));
+        assert!((2.0 * std::f64::consts::PI / n - 86_164.09).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_drift_free_ellipse_closes() {
+        // an along-track speed of -2 n x0 relative to a radial offset x0
+        // cancels the drift, so the chaser circles back after one orbit
+        let n = earth_mean_motion(400.0);
+        let start = StateVector {
+            position: [100.0, 0.0, 50.0],
+            velocity: [0.0, -2.0 * n * 100.0, 0.0],
+        };
+        let period = calculate_orbital_period(
+            GravitationalParameter(EARTH_MU),
+            Meters(EARTH_RADIUS + 400e3),
+        )
+        .0;
+        let later = propagate(n, &start, period);
+        for i in 0..3 {
+            assert!((later.position[i] - start.position[i]).abs() < 1e-6);
+            assert!((later.velocity[i] - start.velocity[i]).abs() < 1e-9);
+        }
+        // while a radial offset alone drifts along track by 12 pi x0 per orbit
+        let offset = StateVector {
+            position: [100.0, 0.0, 0.0],
+            velocity: [0.0; 3],
+        };
+        let drift = propagate(n, &offset, period).position[1];
+        assert!((drift + 12.0 * std::f64::consts::PI * 100.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_rendezvous_reaches_target() {
+        let n = earth_mean_motion(400.0);
+        let start = StateVector {
+            position: [-200.0, -1_000.0, 30.0],
+            velocity: [0.1, 0.0, -0.05],
+        };
+        let burns = rendezvous(n, &start, 2_000.0).unwrap();
+        let transfer = StateVector {
+            position: start.position,
+            velocity: add(start.velocity, burns.first),
+        };
+        let end = propagate(n, &transfer, 2_000.0);
+        for i in 0..3 {
+            assert!(end.position[i].abs() < 1e-6);
+            assert!((end.velocity[i] + burns.second[i]).abs() < 1e-9);
+        }
+        let period = calculate_orbital_period(
+            GravitationalParameter(EARTH_MU),
+            Meters(EARTH_RADIUS + 400e3),
+        )
+        .0;
+        assert!(rendezvous(n, &start, period).is_err());
+    }
+}
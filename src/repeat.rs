@@ -0,0 +1,154 @@
+use std::f64::consts::PI;
+
+use crate::{
+    dimension::{Meters, Seconds},
+    Body,
+};
+
+// Repeat ground tracks for circular orbits. A track repeats when the
+// satellite makes a whole number of nodal revolutions while the body turns a
+// whole number of times under the orbit plane. With J2 the node regresses,
+// which lengthens or shortens the body's turn relative to it, and the mean
+// anomaly and argument of periapsis both drift, which shortens the nodal
+// period. The semi-major axis satisfying
+//
+//     revolutions / days = (n + dM + dw) / (w_body - dW)
+//
+// is found by fixed-point iteration from the two-body answer; the J2 terms
+// are small enough that it converges in a handful of steps.
+
+/// Mean solar motion in rad/s, the node rate of a sun-synchronous Earth orbit.
+const SUN_SYNCHRONOUS_RATE: f64 = 2.0 * PI / (365.242_19 * 86_400.0);
+
+const TOLERANCE: f64 = 1e-6; // m
+const MAX_ITERATIONS: usize = 100;
+
+/// A circular orbit whose ground track repeats.
+#[derive(Debug, Clone, Copy)]
+pub struct Repeat {
+    pub semi_major_axis: Meters,
+    pub inclination: f64,      // deg
+    pub nodal_period: Seconds, // from one ascending node to the next
+    pub nodal_day: Seconds,    // for the body to turn once under the node
+}
+
+/// Secular rates in rad/s of the node and of the argument of latitude of a
+/// circular orbit with `semi_major_axis` (m) and `inclination` (deg).
+fn rates(body: &Body, semi_major_axis: f64, inclination: f64) -> (f64, f64) {
+    let mean_motion = (body.mu.0 / semi_major_axis.powi(3)).sqrt();
+    let factor =
+        1.5 * mean_motion * body.j2.unwrap_or(0.0) * (body.radius.0 / semi_major_axis).powi(2);
+    let cos_i = inclination.to_radians().cos();
+    let node = -factor * cos_i;
+    // mean anomaly drifts by factor * (1 - 1.5 sin^2 i) and the argument of
+    // periapsis by factor * (2 - 2.5 sin^2 i)
+    let sin_squared = 1.0 - cos_i * cos_i;
+    let latitude = mean_motion + factor * (3.0 - 4.0 * sin_squared);
+    (node, latitude)
+}
+
+fn rotation_rate(body: &Body) -> Option<f64> {
+    body.rotation_period.map(|period| 2.0 * PI / period.0)
+}
+
+fn repeat(body: &Body, semi_major_axis: f64, inclination: f64) -> Repeat {
+    let (node, latitude) = rates(body, semi_major_axis, inclination);
+    let rotation = rotation_rate(body).expect("checked by the callers");
+    Repeat {
+        semi_major_axis: Meters(semi_major_axis),
+        inclination,
+        nodal_period: Seconds(2.0 * PI / latitude),
+        nodal_day: Seconds(2.0 * PI / (rotation - node)),
+    }
+}
+
+/// The circular orbit at `inclination` (deg) making `revolutions` in `days`
+/// nodal days, or `None` if the body does not rotate or the orbit would lie
+/// below the surface.
+pub fn solve(body: &Body, revolutions: u32, days: u32, inclination: f64) -> Option<Repeat> {
+    let rotation = rotation_rate(body)?;
+    let ratio = revolutions as f64 / days as f64;
+    let mu = body.mu.0;
+    let mut semi_major_axis = (mu / (ratio * rotation).powi(2)).cbrt();
+    for _ in 0..MAX_ITERATIONS {
+        let (node, latitude) = rates(body, semi_major_axis, inclination);
+        let mean_motion = (mu / semi_major_axis.powi(3)).sqrt();
+        // the rate the orbit must make, less the J2 drift it gets for free
+        let needed = ratio * (rotation - node) - (latitude - mean_motion);
+        let next = (mu / needed.powi(2)).cbrt();
+        let step = next - semi_major_axis;
+        semi_major_axis = next;
+        if step.abs() < TOLERANCE {
+            break;
+        }
+    }
+    (semi_major_axis > body.radius.0).then(|| repeat(body, semi_major_axis, inclination))
+}
+
+/// The sun-synchronous Earth orbit making `revolutions` in `days` nodal
+/// days, whose node follows the mean Sun, or `None` if no inclination turns
+/// the node fast enough or the orbit would lie below the surface.
+pub fn sun_synchronous(body: &Body, revolutions: u32, days: u32) -> Option<Repeat> {
+    let mut inclination = 98.0;
+    let mut orbit = solve(body, revolutions, days, inclination)?;
+    for _ in 0..MAX_ITERATIONS {
+        let (node, _) = rates(body, orbit.semi_major_axis.0, 0.0);
+        // the node rate at zero inclination, scaled by cos i
+        let cos_i = SUN_SYNCHRONOUS_RATE / node;
+        if !(-1.0..=1.0).contains(&cos_i) {
+            return None;
+        }
+        inclination = cos_i.acos().to_degrees();
+        let next = solve(body, revolutions, days, inclination)?;
+        let step = next.semi_major_axis - orbit.semi_major_axis;
+        orbit = next;
+        if step.abs() < Meters(TOLERANCE) {
+            break;
+        }
+    }
+    Some(orbit)
+}
+
+/// Greatest common divisor, to tell whether a repeat cycle could be shorter.
+pub fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repeat_closes() {
+        let earth = Body::earth();
+        let orbit = solve(&earth, 15, 1, 51.6).unwrap();
+        assert!((15.0 * orbit.nodal_period - orbit.nodal_day).abs() < Seconds(1e-6));
+        // J2 speeds the orbit along and turns the node against the Earth, so
+        // the same repeat sits a little lower than in two-body motion
+        let two_body = Body {
+            j2: None,
+            ..Body::earth()
+        };
+        let kepler = solve(&two_body, 15, 1, 51.6).unwrap();
+        assert!(kepler.semi_major_axis > orbit.semi_major_axis);
+        assert!(((kepler.semi_major_axis - earth.radius).km() - 554.0).abs() < 1.0);
+        // too many revolutions to fit above the surface
+        assert!(solve(&earth, 18, 1, 51.6).is_none());
+    }
+
+    #[test]
+    fn test_landsat() {
+        // Landsat 8 repeats 233 orbits in 16 days at a nominal 705 km, a
+        // semi-major axis of 7,077.7 km, and 98.2 deg
+        let earth = Body::earth();
+        let orbit = sun_synchronous(&earth, 233, 16).unwrap();
+        assert!((orbit.semi_major_axis.km() - 7_077.7).abs() < 1.0);
+        assert!((orbit.inclination - 98.2).abs() < 0.1);
+        assert_eq!(gcd(233, 16), 1);
+        assert_eq!(gcd(30, 2), 2);
+    }
+}
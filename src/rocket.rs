@@ -0,0 +1,52 @@
+// Tsiolkovsky rocket equation for a single stage: delta-v = Isp * g0 *
+// ln(wet / dry), with the specific impulse in seconds and masses in kg.
+
+use crate::dimension::{Kilograms, MetersPerSecond, Seconds};
+
+/// Standard gravity in m/s^2, which converts specific impulse in s to an
+/// effective exhaust velocity.
+pub const STANDARD_GRAVITY: f64 = 9.806_65;
+
+/// Effective exhaust velocity.
+pub fn exhaust_velocity(isp: Seconds) -> MetersPerSecond {
+    MetersPerSecond(isp.0 * STANDARD_GRAVITY)
+}
+
+/// Delta-v from burning a stage down from `wet` to `dry`.
+pub fn delta_v(isp: Seconds, wet: Kilograms, dry: Kilograms) -> MetersPerSecond {
+    exhaust_velocity(isp) * (wet / dry).ln()
+}
+
+/// Propellant that gives `dry` a delta-v of `delta_v`.
+pub fn propellant(isp: Seconds, dry: Kilograms, delta_v: MetersPerSecond) -> Kilograms {
+    dry * (delta_v / exhaust_velocity(isp)).exp_m1()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delta_v() {
+        // a mass ratio of e gives exactly one exhaust velocity
+        let dv = delta_v(
+            Seconds(300.0),
+            Kilograms(std::f64::consts::E * 1000.0),
+            Kilograms(1000.0),
+        );
+        assert!((dv.0 - 300.0 * STANDARD_GRAVITY).abs() < 1e-9);
+        let dv = delta_v(Seconds(320.0), Kilograms(1200.0), Kilograms(800.0));
+        assert!((dv.0 - 1_272.4).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_propellant_inverts_delta_v() {
+        let (isp, dry) = (Seconds(320.0), Kilograms(800.0));
+        let propellant = propellant(isp, dry, MetersPerSecond(1_500.0));
+        assert!((delta_v(isp, dry + propellant, dry).0 - 1_500.0).abs() < 1e-9);
+        assert_eq!(
+            super::propellant(isp, dry, MetersPerSecond(0.0)),
+            Kilograms(0.0)
+        );
+    }
+}
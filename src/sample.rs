@@ -0,0 +1,276 @@
+use std::io::{self, Write};
+
+use clap::ValueEnum;
+
+use crate::{
+    calculate_orbital_period,
+    dimension::{Meters, Seconds},
+    Body,
+};
+
+// Position samples for visualization. Orbits are circular, so points evenly
+// spaced in time are also evenly spaced in anomaly.
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Frame {
+    /// Body-centred inertial, equator in the x-y plane
+    Eci,
+    /// Orbit plane in x-y, x towards the ascending node
+    Perifocal,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Up {
+    /// Right-handed, z up (the frame's own convention)
+    Z,
+    /// Right-handed, y up (Bevy, Godot, three.js)
+    Y,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Format {
+    Json,
+    /// Little-endian f32 x, y, z triples with no header
+    Binary,
+}
+
+/// A circular orbit given on the command line as `ALT[:INC[:RAAN]]`, with
+/// the altitude in km or with a length unit.
+#[derive(Debug, Clone, Copy)]
+pub struct Circular {
+    pub altitude: f64,    // km
+    pub inclination: f64, // deg
+    pub raan: f64,        // deg
+}
+
+pub fn parse_circular(input: &str) -> Result<Circular, String> {
+    let mut parts = input.split(':');
+    let altitude = crate::parse_length(parts.next().unwrap_or(""))?;
+    let values = parts
+        .map(|part| {
+            part.trim()
+                .parse::<f64>()
+                .map_err(|err| format!("{part:?}: {err}"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    match values[..] {
+        [] => Ok(Circular {
+            altitude,
+            inclination: 0.0,
+            raan: 0.0,
+        }),
+        [inclination] => Ok(Circular {
+            altitude,
+            inclination,
+            raan: 0.0,
+        }),
+        [inclination, raan] => Ok(Circular {
+            altitude,
+            inclination,
+            raan,
+        }),
+        _ => Err("expected ALT[:INC[:RAAN]]".to_string()),
+    }
+}
+
+/// Geocentric latitude and longitude in degrees of a position vector.
+pub fn lat_lon([x, y, z]: [f64; 3]) -> (f64, f64) {
+    (z.atan2(x.hypot(y)).to_degrees(), y.atan2(x).to_degrees())
+}
+
+pub struct Trajectory {
+    pub period: Seconds,
+    pub times: Vec<f64>,          // s
+    pub positions: Vec<[f64; 3]>, // m
+}
+
+pub fn sample_circular(
+    body: &Body,
+    altitude: f64,    // km
+    inclination: f64, // deg
+    raan: f64,        // deg
+    points: usize,
+    frame: Frame,
+) -> Trajectory {
+    sample_circular_over(body, altitude, inclination, raan, points, frame, 1.0)
+}
+
+/// Like `sample_circular`, but spreading the points over several periods.
+pub fn sample_circular_over(
+    body: &Body,
+    altitude: f64,    // km
+    inclination: f64, // deg
+    raan: f64,        // deg
+    points: usize,
+    frame: Frame,
+    periods: f64,
+) -> Trajectory {
+    let radius = body.radius + Meters::from_km(altitude);
+    let period = calculate_orbital_period(body.mu, radius);
+    let radius = radius.0;
+    let (sin_i, cos_i) = inclination.to_radians().sin_cos();
+    let (sin_raan, cos_raan) = raan.to_radians().sin_cos();
+
+    let times: Vec<f64> = (0..points)
+        .map(|k| periods * period.0 * k as f64 / points as f64)
+        .collect();
+    let positions = times
+        .iter()
+        .map(|t| {
+            // argument of latitude, measured from the ascending node
+            let (sin_u, cos_u) = (2.0 * std::f64::consts::PI * t / period.0).sin_cos();
+            match frame {
+                Frame::Perifocal => [radius * cos_u, radius * sin_u, 0.0],
+                Frame::Eci => [
+                    radius * (cos_raan * cos_u - sin_raan * sin_u * cos_i),
+                    radius * (sin_raan * cos_u + cos_raan * sin_u * cos_i),
+                    radius * sin_u * sin_i,
+                ],
+            }
+        })
+        .collect();
+
+    Trajectory {
+        period,
+        times,
+        positions,
+    }
+}
+
+impl Trajectory {
+    /// Positions scaled and converted to the requested up axis.
+    pub fn export_positions(&self, scale: f64, up: Up) -> Vec<[f64; 3]> {
+        self.positions
+            .iter()
+            .map(|[x, y, z]| match up {
+                Up::Z => [x * scale, y * scale, z * scale],
+                Up::Y => [x * scale, z * scale, -y * scale],
+            })
+            .collect()
+    }
+
+    /// Sub-satellite points on a body spinning at `rotation_rate` (rad/s)
+    /// about z, with the body-fixed and inertial frames aligned at t = 0.
+    /// Points sit `radius` from the centre; lift them slightly above the
+    /// surface so they remain visible on a rendered sphere.
+    pub fn ground_track(&self, radius: Meters, rotation_rate: f64) -> Trajectory {
+        let radius = radius.0;
+        let positions = self
+            .times
+            .iter()
+            .zip(&self.positions)
+            .map(|(t, [x, y, z])| {
+                let (sin_theta, cos_theta) = (rotation_rate * t).sin_cos();
+                let fixed = [
+                    cos_theta * x + sin_theta * y,
+                    -sin_theta * x + cos_theta * y,
+                    *z,
+                ];
+                let norm = (fixed[0].powi(2) + fixed[1].powi(2) + fixed[2].powi(2)).sqrt();
+                fixed.map(|v| v * radius / norm)
+            })
+            .collect();
+        Trajectory {
+            period: self.period,
+            times: self.times.clone(),
+            positions,
+        }
+    }
+
+    pub fn write_json(
+        &self,
+        out: &mut impl Write,
+        frame: Frame,
+        scale: f64,
+        up: Up,
+    ) -> io::Result<()> {
+        let name =
+            |value: Option<clap::builder::PossibleValue>| value.unwrap().get_name().to_string();
+        writeln!(out, "{{")?;
+        writeln!(out, "  \"frame\": \"{}\",", name(frame.to_possible_value()))?;
+        writeln!(out, "  \"up\": \"{}\",", name(up.to_possible_value()))?;
+        writeln!(out, "  \"scale\": {scale},")?;
+        writeln!(out, "  \"period\": {},", self.period.0)?;
+        let times: Vec<String> = self.times.iter().map(|t| t.to_string()).collect();
+        writeln!(out, "  \"times\": [{}],", times.join(", "))?;
+        let positions: Vec<String> = self
+            .export_positions(scale, up)
+            .iter()
+            .map(|[x, y, z]| format!("[{x}, {y}, {z}]"))
+            .collect();
+        writeln!(out, "  \"positions\": [{}]", positions.join(", "))?;
+        writeln!(out, "}}")
+    }
+
+    pub fn write_binary(&self, out: &mut impl Write, scale: f64, up: Up) -> io::Result<()> {
+        for position in self.export_positions(scale, up) {
+            for value in position {
+                out.write_all(&(value as f32).to_le_bytes())?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_samples_lie_on_the_circle() {
+        let body = Body::earth();
+        let trajectory = sample_circular(&body, 400.0, 51.6, 30.0, 64, Frame::Eci);
+        let radius = (body.radius + Meters(400_000.0)).0;
+        for [x, y, z] in &trajectory.positions {
+            let r = (x * x + y * y + z * z).sqrt();
+            assert!((r - radius).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_max_latitude_is_inclination() {
+        let body = Body::earth();
+        let trajectory = sample_circular(&body, 400.0, 51.6, 0.0, 4, Frame::Eci);
+        // a quarter period past the ascending node is the northernmost point
+        let [x, y, z] = trajectory.positions[1];
+        let latitude = z.atan2((x * x + y * y).sqrt()).to_degrees();
+        assert!((latitude - 51.6).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_ground_track_drifts_west() {
+        let body = Body::earth();
+        let trajectory = sample_circular(&body, 400.0, 0.0, 0.0, 4, Frame::Eci);
+        let track = trajectory.ground_track(body.radius, 7.292_115e-5);
+        // back at the ascending node after one period, short of it in longitude
+        let radius = body.radius.0;
+        let [x, y, _] = track.positions[0];
+        assert!((x - radius).abs() < 1e-6 && y.abs() < 1e-6);
+        let [x, y, z] = track.positions[2];
+        let r = (x * x + y * y + z * z).sqrt();
+        assert!((r - radius).abs() < 1e-6);
+        let longitude = y.atan2(x).to_degrees();
+        let expected = 180.0 - (7.292_115e-5 * trajectory.period.0 / 2.0).to_degrees();
+        assert!((longitude - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parse_circular() {
+        let orbit = parse_circular("400:51.6").unwrap();
+        assert_eq!(
+            (orbit.altitude, orbit.inclination, orbit.raan),
+            (400.0, 51.6, 0.0)
+        );
+        assert!(parse_circular("400:1:2:3").is_err());
+        assert!(parse_circular("low").is_err());
+    }
+
+    #[test]
+    fn test_binary_is_three_floats_per_point() {
+        let body = Body::earth();
+        let trajectory = sample_circular(&body, 400.0, 0.0, 0.0, 10, Frame::Perifocal);
+        let mut out = vec![];
+        trajectory.write_binary(&mut out, 1.0, Up::Y).unwrap();
+        assert_eq!(out.len(), 10 * 3 * 4);
+    }
+}
@@ -0,0 +1,153 @@
+use std::rc::Rc;
+
+use clap::ValueEnum;
+
+use crate::{tle::Tle, Altitude, Body, Orbit};
+
+// Nominal orbits of a few well-known Earth satellites, for quick realistic
+// examples. Crewed stations and Hubble drift by kilometres between reboosts
+// and with solar activity, so the figures are typical rather than current;
+// an element set for the same object gives the orbit at its epoch instead.
+// Constellations are given by one shell or plane, without a catalog number.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Satellite {
+    Iss,
+    Tiangong,
+    Hubble,
+    #[value(name = "landsat-9")]
+    Landsat9,
+    Starlink,
+    Gps,
+}
+
+pub const ALL: [Satellite; 6] = [
+    Satellite::Iss,
+    Satellite::Tiangong,
+    Satellite::Hubble,
+    Satellite::Landsat9,
+    Satellite::Starlink,
+    Satellite::Gps,
+];
+
+impl Satellite {
+    /// Display name.
+    pub fn name(self) -> &'static str {
+        match self {
+            Satellite::Iss => "ISS",
+            Satellite::Tiangong => "Tiangong",
+            Satellite::Hubble => "Hubble",
+            Satellite::Landsat9 => "Landsat 9",
+            Satellite::Starlink => "Starlink",
+            Satellite::Gps => "GPS",
+        }
+    }
+
+    /// NORAD catalog number, for satellites rather than constellations.
+    pub fn catalog_number(self) -> Option<&'static str> {
+        match self {
+            Satellite::Iss => Some("25544"),
+            Satellite::Tiangong => Some("48274"),
+            Satellite::Hubble => Some("20580"),
+            Satellite::Landsat9 => Some("49260"),
+            Satellite::Starlink | Satellite::Gps => None,
+        }
+    }
+
+    /// CelesTrak group with the element sets of a constellation.
+    pub fn group(self) -> Option<&'static str> {
+        match self {
+            Satellite::Starlink => Some("starlink"),
+            Satellite::Gps => Some("gps-ops"),
+            _ => None,
+        }
+    }
+
+    /// Nominal perigee and apogee altitudes in km and inclination in deg.
+    fn elements(self) -> (f64, f64, f64) {
+        match self {
+            Satellite::Iss => (415.0, 422.0, 51.64),
+            Satellite::Tiangong => (380.0, 390.0, 41.47),
+            Satellite::Hubble => (505.0, 512.0, 28.47),
+            Satellite::Landsat9 => (705.0, 705.0, 98.2),
+            // the first shell
+            Satellite::Starlink => (550.0, 550.0, 53.0),
+            // semi-major axis 26,560 km, half a sidereal day
+            Satellite::Gps => (20_180.0, 20_180.0, 55.0),
+        }
+    }
+
+    /// The nominal orbit around `earth`.
+    pub fn orbit(self, earth: &Rc<Body>) -> Orbit {
+        let (perigee, apogee, inclination) = self.elements();
+        Orbit {
+            name: self.name().to_string(),
+            altitude: if perigee == apogee {
+                Altitude::Single { value: perigee }
+            } else {
+                Altitude::Elliptical {
+                    periapsis: perigee,
+                    apoapsis: apogee,
+                }
+            },
+            inclination: Some(inclination),
+            body: earth.clone(),
+        }
+    }
+}
+
+/// The orbit an element set describes around `earth`, with apsides to the
+/// nearest 10 m.
+pub fn from_tle(name: &str, set: &Tle, earth: &Rc<Body>) -> Orbit {
+    let rounded = |altitude: f64| (altitude * 100.0).round() / 100.0;
+    let (perigee, apogee) = set.apsis_altitudes(earth);
+    Orbit {
+        name: name.to_string(),
+        altitude: Altitude::Elliptical {
+            periapsis: rounded(perigee),
+            apoapsis: rounded(apogee),
+        },
+        inclination: Some(set.inclination),
+        body: earth.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tle;
+
+    #[test]
+    fn test_nominal_orbits() {
+        let earth = Rc::new(Body::earth());
+        // the ISS goes round in about 92.9 minutes and GPS in half a
+        // sidereal day
+        let (iss, _) = Satellite::Iss.orbit(&earth).periods();
+        assert!((iss.minutes() - 92.9).abs() < 0.2);
+        let (gps, _) = Satellite::Gps.orbit(&earth).periods();
+        assert!((gps.0 - 43_082.0).abs() < 60.0);
+        for satellite in ALL {
+            let (low, high) = satellite.orbit(&earth).altitude.bounds();
+            assert!(0.0 < low && low <= high);
+        }
+    }
+
+    #[test]
+    fn test_element_set_overrides() {
+        let set = &tle::parse(
+            "1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927
+2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537",
+        )
+        .unwrap()[0];
+        assert_eq!(
+            Some(set.catalog_number.as_str()),
+            Satellite::Iss.catalog_number()
+        );
+        let earth = Rc::new(Body::earth());
+        let orbit = from_tle("ISS", set, &earth);
+        // the station flew lower in 2008
+        let (perigee, apogee) = orbit.altitude.bounds();
+        assert!((330.0..360.0).contains(&perigee) && apogee - perigee < 20.0);
+        assert_eq!(orbit.inclination, Some(51.6416));
+    }
+}
@@ -0,0 +1,39 @@
+// Partial derivatives for trade studies. Outputs with a cheap closed form
+// could be differentiated analytically, but central differencing works for
+// every calculator in the crate and is accurate to O(step^2).
+
+/// Derivative of `f` at `x` by central differencing with the given step.
+pub fn central_difference(f: impl Fn(f64) -> f64, x: f64, step: f64) -> f64 {
+    (f(x + step) - f(x - step)) / (2.0 * step)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        calculate_circular_orbital_velocity, calculate_orbital_period,
+        dimension::{GravitationalParameter, Meters},
+        EARTH_MU, EARTH_RADIUS,
+    };
+
+    #[test]
+    fn test_period_partial_matches_analytic() {
+        // dT/da = 3T / 2a
+        let a = EARTH_RADIUS + 400_000.0;
+        let period = |a| calculate_orbital_period(GravitationalParameter(EARTH_MU), Meters(a)).0;
+        let numeric = central_difference(period, a, 1.0);
+        let analytic = 1.5 * period(a) / a;
+        assert!((numeric - analytic).abs() / analytic < 1e-8);
+    }
+
+    #[test]
+    fn test_velocity_partial_matches_analytic() {
+        // dv/da = -v / 2a
+        let a = EARTH_RADIUS + 400_000.0;
+        let velocity =
+            |a| calculate_circular_orbital_velocity(GravitationalParameter(EARTH_MU), Meters(a)).0;
+        let numeric = central_difference(velocity, a, 1.0);
+        let analytic = -0.5 * velocity(a) / a;
+        assert!((numeric - analytic).abs() / analytic.abs() < 1e-6);
+    }
+}
@@ -0,0 +1,59 @@
+// Evenly spaced altitudes for tables. Each value is computed from its index
+// rather than by repeated addition, so a long sweep neither drifts nor loses
+// its end point to rounding, and is then rounded to the millimetre so that
+// printing 0.1 km steps does not show binary artifacts such as 0.30000000000000004.
+
+/// Altitudes in km from `min` to `max` inclusive in steps of `step`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sweep {
+    pub min: f64,
+    pub max: f64,
+    pub step: f64,
+}
+
+impl Sweep {
+    pub fn altitudes(&self) -> impl Iterator<Item = f64> {
+        let Sweep { min, max, step } = *self;
+        // tolerate a max that is a step multiple up to rounding
+        let count = ((max - min) / step + 1e-9).floor() as usize + 1;
+        (0..count).map(move |i| ((min + i as f64 * step) * 1e6).round() / 1e6)
+    }
+}
+
+/// Parse `MIN:MAX:STEP` in km, each optionally with a length unit.
+pub fn parse_sweep(input: &str) -> Result<Sweep, String> {
+    let values = input
+        .split(':')
+        .map(crate::parse_length)
+        .collect::<Result<Vec<_>, _>>()?;
+    match values[..] {
+        [min, max, step] if step > 0.0 && min <= max => Ok(Sweep { min, max, step }),
+        [_, _, _] => Err("expected MIN <= MAX and a positive STEP".to_string()),
+        _ => Err("expected MIN:MAX:STEP".to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_altitudes_include_end() {
+        let sweep = parse_sweep("200:2000:100").unwrap();
+        let altitudes: Vec<f64> = sweep.altitudes().collect();
+        assert_eq!(altitudes.len(), 19);
+        assert_eq!(altitudes[0], 200.0);
+        assert_eq!(altitudes[18], 2000.0);
+
+        let sweep = parse_sweep("0:1:0.1").unwrap();
+        assert_eq!(sweep.altitudes().count(), 11);
+        assert_eq!(sweep.altitudes().nth(3), Some(0.3));
+    }
+
+    #[test]
+    fn test_parse_rejects_bad_ranges() {
+        assert!(parse_sweep("2000:200:100").is_err());
+        assert!(parse_sweep("200:2000:0").is_err());
+        assert!(parse_sweep("200:2000").is_err());
+    }
+}
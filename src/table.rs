@@ -0,0 +1,143 @@
+// Plain-text tables for terminal reports. Every column is as wide as its
+// widest cell, columns are two spaces apart, and a rule of dashes under the
+// headings marks where the rows start, so a long list of orbits can be read
+// down a column. Widths count characters rather than bytes, so headings such
+// as "Höhe (km)" in other languages line up too, and escape sequences count
+// for nothing, so cells can be coloured. Cells hold one line each and
+// trailing spaces are trimmed.
+
+use crate::theme::{self, Theme};
+
+/// Where the cells of a column sit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    /// Names and labels
+    Left,
+    /// Numbers, so their decimal points line up
+    Right,
+}
+
+/// Headings and rows of formatted cells.
+#[derive(Debug, Clone, Default)]
+pub struct Table {
+    columns: Vec<(String, Align)>,
+    rows: Vec<Vec<String>>,
+    theme: Theme,
+}
+
+impl Table {
+    pub fn new() -> Self {
+        Table::default()
+    }
+
+    /// The same table, writing its headings in `theme`.
+    pub fn with_theme(self, theme: Theme) -> Self {
+        Table { theme, ..self }
+    }
+
+    /// Add a column after the others.
+    pub fn column(mut self, heading: impl Into<String>, align: Align) -> Self {
+        self.columns.push((heading.into(), align));
+        self
+    }
+
+    /// Add a row, one cell per column. Missing cells are left empty and
+    /// extra ones dropped.
+    pub fn row(&mut self, cells: Vec<String>) {
+        self.rows.push(cells);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// The table as lines ending in a line break.
+    pub fn render(&self) -> String {
+        let widths: Vec<usize> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(column, (heading, _))| {
+                self.rows
+                    .iter()
+                    .map(|row| theme::width(cell(row, column)))
+                    .fold(theme::width(heading), usize::max)
+            })
+            .collect();
+        let line = |cells: Vec<String>| {
+            let padded: Vec<String> = cells
+                .iter()
+                .zip(&self.columns)
+                .zip(&widths)
+                .map(|((text, (_, align)), &width)| {
+                    let fill = " ".repeat(width - theme::width(text));
+                    match align {
+                        Align::Left => format!("{text}{fill}"),
+                        Align::Right => format!("{fill}{text}"),
+                    }
+                })
+                .collect();
+            format!("{}\n", padded.join("  ").trim_end())
+        };
+
+        let mut out = line(
+            self.columns
+                .iter()
+                .map(|(heading, _)| self.theme.heading(heading))
+                .collect(),
+        );
+        let rules: Vec<String> = widths.iter().map(|&width| "-".repeat(width)).collect();
+        out += &format!("{}\n", rules.join("  "));
+        for row in &self.rows {
+            out += &line(
+                (0..self.columns.len())
+                    .map(|column| cell(row, column).to_string())
+                    .collect(),
+            );
+        }
+        out
+    }
+}
+
+/// Cell `column` of `row`, empty when the row is short.
+fn cell(row: &[String], column: usize) -> &str {
+    row.get(column).map_or("", String::as_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let mut table = Table::new()
+            .column("Orbit", Align::Left)
+            .column("Höhe (km)", Align::Right);
+        assert!(table.is_empty());
+        table.row(vec!["LEO".into(), "450-2000".into()]);
+        table.row(vec!["GEO".into(), "35786.07".into()]);
+        table.row(vec!["Graveyard".into()]);
+        assert_eq!(
+            table.render(),
+            "Orbit      Höhe (km)
+---------  ---------
+LEO         450-2000
+GEO         35786.07
+Graveyard
+"
+        );
+
+        let mut table = Table::new()
+            .column("Orbit", Align::Left)
+            .column("Altitude (km)", Align::Right)
+            .with_theme(Theme::new(true));
+        table.row(vec!["Low".into(), Theme::new(true).warning("-50")]);
+        assert_eq!(
+            table.render(),
+            "\x1b[1mOrbit\x1b[0m  \x1b[1mAltitude (km)\x1b[0m
+-----  -------------
+Low              \x1b[1;33m-50\x1b[0m
+"
+        );
+    }
+}
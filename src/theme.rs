@@ -0,0 +1,123 @@
+use std::{env, io::IsTerminal};
+
+use clap::ValueEnum;
+
+// Colours in terminal reports. A theme turns headings, unit symbols and
+// warnings into ANSI escape sequences, or leaves them alone when colour is
+// off, so reports call it for every styled piece instead of writing escape
+// codes themselves. With --color auto, the default, colour is on only when
+// standard output is a terminal, TERM is not "dumb" and NO_COLOR is unset or
+// empty (https://no-color.org); always and never override all three. Only
+// the SGR codes for bold and the eight basic colours are used, which every
+// terminal emulator in use understands. CSV output is never coloured.
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ColorChoice {
+    /// Colour on a terminal, unless NO_COLOR is set
+    #[default]
+    Auto,
+    /// Colour even when writing to a file or pipe
+    Always,
+    /// No colour
+    Never,
+}
+
+impl ColorChoice {
+    /// Whether to colour standard output, from the environment for `auto`.
+    pub fn detect(self) -> bool {
+        let no_color = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        let dumb = env::var_os("TERM").is_some_and(|term| term == "dumb");
+        self.enabled(std::io::stdout().is_terminal() && !dumb, no_color)
+    }
+
+    /// Whether to colour output going to a terminal or not, with NO_COLOR
+    /// set or not.
+    pub fn enabled(self, terminal: bool, no_color: bool) -> bool {
+        match self {
+            ColorChoice::Auto => terminal && !no_color,
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+}
+
+const BOLD: &str = "1";
+const YELLOW: &str = "1;33";
+const CYAN: &str = "36";
+
+/// How styled pieces of a report are written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Theme {
+    pub color: bool,
+}
+
+impl Theme {
+    pub fn new(color: bool) -> Self {
+        Theme { color }
+    }
+
+    /// Section headings and table headings.
+    pub fn heading(&self, text: &str) -> String {
+        self.paint(BOLD, text)
+    }
+
+    /// Unit symbols after a number, such as "km/s".
+    pub fn unit(&self, text: &str) -> String {
+        self.paint(CYAN, text)
+    }
+
+    /// Values and lines that need attention, such as an orbit below the
+    /// surface.
+    pub fn warning(&self, text: &str) -> String {
+        self.paint(YELLOW, text)
+    }
+
+    fn paint(&self, code: &str, text: &str) -> String {
+        if self.color && !text.is_empty() {
+            format!("\x1b[{code}m{text}\x1b[0m")
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+/// Characters of `text` that take up space on a terminal, without escape
+/// sequences, for lining up coloured text.
+pub fn width(text: &str) -> usize {
+    let mut width = 0;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // an SGR sequence runs up to its final 'm'
+            chars.by_ref().find(|&c| c == 'm');
+        } else {
+            width += 1;
+        }
+    }
+    width
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_choice() {
+        assert!(ColorChoice::Auto.enabled(true, false));
+        assert!(!ColorChoice::Auto.enabled(false, false));
+        assert!(!ColorChoice::Auto.enabled(true, true));
+        assert!(ColorChoice::Always.enabled(false, true));
+        assert!(!ColorChoice::Never.enabled(true, false));
+    }
+
+    #[test]
+    fn test_paint() {
+        let plain = Theme::default();
+        assert_eq!(plain.warning("below the surface"), "below the surface");
+        let color = Theme::new(true);
+        assert_eq!(color.unit("km"), "\x1b[36mkm\x1b[0m");
+        assert_eq!(color.heading(""), "");
+        assert_eq!(width(&color.heading("Höhe")), 4);
+        assert_eq!(width("7.6686 km/s"), 11);
+    }
+}
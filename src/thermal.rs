@@ -0,0 +1,64 @@
+use std::f64::consts::PI;
+
+use crate::{dimension::Meters, eclipse};
+
+// First-order orbital heating environment for circular orbits. Solar flux
+// scales with the Earth-Sun distance on the given date. Albedo and Earth
+// infrared are for a nadir-facing plate, using the (R / r)^2 view factor of
+// the Earth's disc. Albedo is averaged over the sunlit part of the ground
+// track, where the sub-satellite solar zenith cosine averages cos(beta) / pi
+// over a full revolution.
+
+pub const SOLAR_CONSTANT: f64 = 1361.0; // W*m^-2 at 1 AU
+pub const EARTH_IR: f64 = 237.0; // W*m^-2, mean outgoing longwave at the top of the atmosphere
+
+pub struct Environment {
+    pub sunlit_fraction: f64,
+    pub average_solar: f64,  // W*m^-2, sun-facing surface
+    pub average_albedo: f64, // W*m^-2, nadir-facing surface
+    pub earth_infrared: f64, // W*m^-2, nadir-facing surface
+}
+
+/// Earth-Sun distance in AU (Astronomical Almanac low-precision formula).
+pub fn sun_distance(days_since_j2000: f64) -> f64 {
+    let g = (357.529 + 0.985_600_28 * days_since_j2000).to_radians();
+    1.000_14 - 0.016_71 * g.cos() - 0.000_14 * (2.0 * g).cos()
+}
+
+/// Solar flux in W/m^2 at the Earth on the given date.
+pub fn solar_flux(days_since_j2000: f64) -> f64 {
+    SOLAR_CONSTANT / sun_distance(days_since_j2000).powi(2)
+}
+
+pub fn environment(
+    body_radius: Meters,
+    orbit_radius: Meters,
+    beta: f64,       // deg
+    solar_flux: f64, // W*m^-2
+    albedo: f64,
+) -> Environment {
+    let sunlit_fraction = 1.0 - eclipse::eclipse_fraction(body_radius, orbit_radius, beta);
+    let view_factor = (body_radius / orbit_radius).powi(2);
+
+    Environment {
+        sunlit_fraction,
+        average_solar: solar_flux * sunlit_fraction,
+        average_albedo: solar_flux * albedo * view_factor * beta.to_radians().cos() / PI,
+        earth_infrared: EARTH_IR * view_factor,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::time::parse_date;
+
+    #[test]
+    fn test_perihelion_and_aphelion_flux() {
+        // early January is near perihelion, early July near aphelion
+        let january = solar_flux(parse_date("2026-01-03").unwrap());
+        let july = solar_flux(parse_date("2026-07-04").unwrap());
+        assert!((january - 1407.0).abs() < 3.0);
+        assert!((july - 1316.0).abs() < 3.0);
+    }
+}
@@ -0,0 +1,391 @@
+use std::{
+    f64::consts::PI,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+// Epochs are f64 days since J2000 (2000-01-01 12:00), on the UTC time scale
+// unless a function says otherwise. UT1 - UTC stays below 0.9 s, which moves
+// Greenwich sidereal time by under 4 arcseconds, so UTC stands in for UT1.
+//
+// TAI runs ahead of UTC by a whole number of leap seconds since 1972, and TT
+// = TAI + 32.184 s. Days on those scales are counted from J2000 on the same
+// scale, so J2000 TT (the astronomical epoch) is 11:58:55.816 UTC.
+
+/// Julian date of J2000.
+pub const J2000: f64 = 2_451_545.0;
+/// Modified Julian date of J2000.
+pub const J2000_MJD: f64 = 51_544.5;
+/// TT - TAI in s.
+pub const TT_MINUS_TAI: f64 = 32.184;
+
+/// UTC dates from which TAI - UTC took each value in s (IERS Bulletin C).
+/// Update when a new leap second is announced.
+const LEAP_SECONDS: &[(i64, i64, f64)] = &[
+    (1972, 1, 10.0),
+    (1972, 7, 11.0),
+    (1973, 1, 12.0),
+    (1974, 1, 13.0),
+    (1975, 1, 14.0),
+    (1976, 1, 15.0),
+    (1977, 1, 16.0),
+    (1978, 1, 17.0),
+    (1979, 1, 18.0),
+    (1980, 1, 19.0),
+    (1981, 7, 20.0),
+    (1982, 7, 21.0),
+    (1983, 7, 22.0),
+    (1985, 7, 23.0),
+    (1988, 1, 24.0),
+    (1990, 1, 25.0),
+    (1991, 1, 26.0),
+    (1992, 7, 27.0),
+    (1993, 7, 28.0),
+    (1994, 7, 29.0),
+    (1996, 1, 30.0),
+    (1997, 7, 31.0),
+    (1999, 1, 32.0),
+    (2006, 1, 33.0),
+    (2009, 1, 34.0),
+    (2012, 7, 35.0),
+    (2015, 7, 36.0),
+    (2017, 1, 37.0),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Scale {
+    Utc,
+    Tai,
+    Tt,
+}
+
+/// Days from 2000-01-01 to the given proleptic Gregorian date (H. Hinnant's
+/// days-from-civil algorithm).
+pub fn civil_days(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468 - 10_957
+}
+
+/// Proleptic Gregorian (year, month, day) of a count of days since
+/// 2000-01-01, the inverse of `civil_days`.
+pub fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 10_957 + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// Format days since J2000 as YYYY-MM-DDTHH:MM:SS with `decimals` digits of
+/// seconds, without a time zone.
+pub fn format_iso(days: f64, decimals: usize) -> String {
+    let scale = 10i64.pow(decimals as u32);
+    let units = ((days + 0.5) * 86_400.0 * scale as f64).round() as i64;
+    let seconds = units.div_euclid(scale);
+    let (year, month, day) = civil_from_days(seconds.div_euclid(86_400));
+    let clock = seconds.rem_euclid(86_400);
+    let fraction = match decimals {
+        0 => String::new(),
+        _ => format!(".{:0decimals$}", units.rem_euclid(scale)),
+    };
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}{fraction}",
+        clock / 3600,
+        clock / 60 % 60,
+        clock % 60
+    )
+}
+
+/// Format days since J2000 UTC as YYYY-MM-DDTHH:MM:SSZ, rounded to the
+/// second.
+pub fn format_utc(days: f64) -> String {
+    format!("{}Z", format_iso(days, 0))
+}
+
+/// TAI - UTC in s at `days` since J2000 UTC. Before 1972 UTC ran at a
+/// different rate from TAI; those dates get the 1972 value of 10 s.
+pub fn tai_minus_utc(days: f64) -> f64 {
+    LEAP_SECONDS
+        .iter()
+        .rev()
+        .find(|(year, month, _)| days >= civil_days(*year, *month, 1) as f64 - 0.5)
+        .map_or(LEAP_SECONDS[0].2, |&(_, _, offset)| offset)
+}
+
+/// Convert days since J2000 from one time scale to another.
+pub fn convert(days: f64, from: Scale, to: Scale) -> f64 {
+    // offset from UTC in s
+    let ahead = |scale: Scale, utc: f64| match scale {
+        Scale::Utc => 0.0,
+        Scale::Tai => tai_minus_utc(utc),
+        Scale::Tt => tai_minus_utc(utc) + TT_MINUS_TAI,
+    };
+    // the offset depends on UTC, so find it from a first guess; the one
+    // refinement settles except at the leap second itself
+    let guess = days - ahead(from, days) / 86_400.0;
+    let utc = days - ahead(from, guess) / 86_400.0;
+    utc + ahead(to, utc) / 86_400.0
+}
+
+/// Parse a time as ISO 8601 (see `parse_utc`), or as a Julian date such as
+/// JD2451545.0 or modified Julian date such as MJD51544.5, giving days since
+/// J2000 on whatever scale the input is on.
+pub fn parse_epoch(input: &str) -> Result<f64, String> {
+    let text = input.trim();
+    let number = |digits: &str| {
+        digits
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| format!("invalid Julian date '{input}'"))
+    };
+    let upper = text.to_ascii_uppercase();
+    if let Some(digits) = upper.strip_prefix("MJD") {
+        Ok(number(digits)? - J2000_MJD)
+    } else if let Some(digits) = upper.strip_prefix("JD") {
+        Ok(number(digits)? - J2000)
+    } else {
+        parse_utc(text)
+    }
+}
+
+/// The system clock as days since J2000 UTC.
+pub fn now() -> f64 {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0.0, |elapsed| elapsed.as_secs_f64());
+    civil_days(1970, 1, 1) as f64 - 0.5 + seconds / 86_400.0
+}
+
+/// Parse an ISO 8601 UTC time such as 2025-06-01T12:00:00Z, with optional
+/// seconds, fractional seconds and trailing Z, or a bare date for 0h. UTC
+/// days have no room for a leap second, so 23:59:60 reads as the midnight
+/// that ends it; `parse_utc_to_tai` keeps the second apart.
+pub fn parse_utc(input: &str) -> Result<f64, String> {
+    let (midnight, minutes, seconds) = parse_clock(input)?;
+    Ok(midnight + (minutes as f64 * 60.0 + seconds.min(60.0)) / 86_400.0)
+}
+
+/// Parse a YYYY-MM-DD date as `parse_utc` does, giving days since J2000 at
+/// noon UTC on it.
+pub fn parse_date(input: &str) -> Result<f64, String> {
+    let invalid = || format!("invalid date '{input}', expected YYYY-MM-DD");
+    if input.trim().contains(['T', 't', ' ']) {
+        return Err(invalid());
+    }
+    parse_utc(input)
+        .map(|midnight| midnight + 0.5)
+        .map_err(|_| invalid())
+}
+
+/// Parse a UTC time as `parse_utc` does, giving days since J2000 TAI, on
+/// which a leap second has an instant of its own: 2016-12-31T23:59:60Z is
+/// 2017-01-01T00:00:36 TAI.
+pub fn parse_utc_to_tai(input: &str) -> Result<f64, String> {
+    let (midnight, minutes, seconds) = parse_clock(input)?;
+    // leap seconds come at the end of a minute, so TAI - UTC at its start
+    // holds for all of it
+    let start = midnight + minutes as f64 / 1440.0;
+    Ok(start + (seconds + tai_minus_utc(start)) / 86_400.0)
+}
+
+/// Days since J2000 of 0h on the date of `input`, minutes since then and
+/// seconds into the minute. Second 60 is allowed only at 23:59 on the days
+/// that ended in a leap second.
+fn parse_clock(input: &str) -> Result<(f64, i64, f64), String> {
+    let invalid = || format!("invalid time '{input}', expected YYYY-MM-DDTHH:MM:SSZ");
+    let text = input.trim();
+    let text = text.strip_suffix(['Z', 'z']).unwrap_or(text);
+    let (date, clock) = text.split_once(['T', 't', ' ']).unwrap_or((text, "00:00"));
+
+    let number = |s: &str| s.parse::<i64>().map_err(|_| invalid());
+    let [year, month, day] = date.split('-').collect::<Vec<_>>()[..] else {
+        return Err(invalid());
+    };
+    let (year, month, day) = (number(year)?, number(month)?, number(day)?);
+    let (hour, minute, second) = match clock.split(':').collect::<Vec<_>>()[..] {
+        [hour, minute] => (number(hour)?, number(minute)?, 0.0),
+        [hour, minute, second] => (
+            number(hour)?,
+            number(minute)?,
+            second.parse::<f64>().map_err(|_| invalid())?,
+        ),
+        _ => return Err(invalid()),
+    };
+    if !(1..=12).contains(&month)
+        || !(1..=days_in_month(year, month)).contains(&day)
+        || !(0..24).contains(&hour)
+        || !(0..60).contains(&minute)
+    {
+        return Err(invalid());
+    }
+    let midnight = civil_days(year, month, day);
+    let leap = (hour, minute) == (23, 59) && ends_in_leap_second(midnight);
+    if !(0.0..if leap { 61.0 } else { 60.0 }).contains(&second) {
+        return Err(invalid());
+    }
+    Ok((midnight as f64 - 0.5, hour * 60 + minute, second))
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    let (next_year, next_month) = if month == 12 {
+        (year + 1, 1)
+    } else {
+        (year, month + 1)
+    };
+    civil_days(next_year, next_month, 1) - civil_days(year, month, 1)
+}
+
+/// Whether the day `days` after 2000-01-01 ended in a leap second. The
+/// first entry of `LEAP_SECONDS` starts the table rather than adding one.
+fn ends_in_leap_second(days: i64) -> bool {
+    LEAP_SECONDS[1..]
+        .iter()
+        .any(|&(year, month, _)| civil_days(year, month, 1) == days + 1)
+}
+
+/// The leap second that `tai` days since J2000 TAI falls in, as the UTC
+/// midnight that ends it and the seconds elapsed within it. The test is in
+/// whole milliseconds, since day fractions put the start of the leap second
+/// a rounding error either side of it.
+fn leap_second(tai: f64) -> Option<(f64, f64)> {
+    LEAP_SECONDS[1..].iter().find_map(|&(year, month, offset)| {
+        let midnight = civil_days(year, month, 1) as f64 - 0.5;
+        let elapsed = (tai - midnight) * 86_400.0 - (offset - 1.0);
+        let millis = (elapsed * 1000.0).round() as i64;
+        (0..1000)
+            .contains(&millis)
+            .then_some((midnight, elapsed.max(0.0)))
+    })
+}
+
+/// TAI - UTC in s at `tai` days since J2000 TAI, which unlike UTC days can
+/// tell a leap second from the second after it.
+pub fn tai_minus_utc_at_tai(tai: f64) -> f64 {
+    match leap_second(tai) {
+        Some((midnight, _)) => tai_minus_utc(midnight) - 1.0,
+        None => tai_minus_utc(convert(tai, Scale::Tai, Scale::Utc)),
+    }
+}
+
+/// Format days since J2000 TAI as the UTC time it was, like `format_iso`,
+/// writing a leap second as 23:59:60.
+pub fn format_iso_from_tai(tai: f64, decimals: usize) -> String {
+    let Some((midnight, elapsed)) = leap_second(tai) else {
+        return format_iso(convert(tai, Scale::Tai, Scale::Utc), decimals);
+    };
+    // held below 61 rather than rounded up into the next minute
+    let scale = 10i64.pow(decimals as u32);
+    let units = ((elapsed * scale as f64).round() as i64).min(scale - 1);
+    let fraction = match decimals {
+        0 => String::new(),
+        _ => format!(".{units:0decimals$}"),
+    };
+    let minute = format_iso(midnight - 60.0 / 86_400.0, 0);
+    format!("{}60{fraction}", &minute[..minute.len() - 2])
+}
+
+/// Greenwich mean sidereal time in rad at `days` since J2000 UT1 (IAU 1982,
+/// the angle between the TEME frame and the Earth-fixed frame).
+pub fn gmst(days: f64) -> f64 {
+    let t = days / 36_525.0;
+    let seconds =
+        67_310.548_41 + (876_600.0 * 3600.0 + 8_640_184.812_866) * t + 0.093_104 * t.powi(2)
+            - 6.2e-6 * t.powi(3);
+    (seconds / 240.0).to_radians().rem_euclid(2.0 * PI)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_utc() {
+        assert_eq!(parse_utc("2000-01-01T12:00:00Z"), Ok(0.0));
+        assert_eq!(parse_utc("2000-01-02"), Ok(0.5));
+        assert_eq!(parse_utc("1999-12-31T18:00Z"), Ok(-0.75));
+        assert!(parse_utc("2000-01-01T24:00:00Z").is_err());
+        assert!(parse_utc("noon").is_err());
+        assert!(parse_utc("2023-02-31T10:00:00Z").is_err());
+        assert!(parse_utc("2023-02-29").is_err());
+        assert_eq!(
+            parse_utc("2024-02-29"),
+            Ok(civil_days(2024, 2, 29) as f64 - 0.5)
+        );
+        assert!(parse_utc("2023-02-28T10:00:60Z").is_err());
+        assert_eq!(parse_date("2000-01-01"), Ok(0.0));
+        assert_eq!(parse_date("1999-12-31"), Ok(-1.0));
+        assert!(parse_date("2023-02-31").is_err());
+        assert!(parse_date("2000-01-01T12:00:00Z").is_err());
+        assert!(parse_utc("2016-12-31T23:58:60Z").is_err());
+        assert!(parse_utc("2017-06-30T23:59:60Z").is_err());
+    }
+
+    #[test]
+    fn test_leap_second() {
+        let midnight = parse_utc("2017-01-01").unwrap();
+        assert_eq!(parse_utc("2016-12-31T23:59:60.5Z"), Ok(midnight));
+        let tai = parse_utc_to_tai("2016-12-31T23:59:60.5Z").unwrap();
+        assert_eq!(format_iso(tai, 3), "2017-01-01T00:00:36.500");
+        assert_eq!(format_iso_from_tai(tai, 3), "2016-12-31T23:59:60.500");
+        assert_eq!(tai_minus_utc_at_tai(tai), 36.0);
+        for time in ["2016-12-31T23:59:60Z", "2015-06-30T23:59:60Z"] {
+            let tai = parse_utc_to_tai(time).unwrap();
+            assert_eq!(format_iso_from_tai(tai, 3), time.replace('Z', ".000"));
+        }
+        let before = parse_utc_to_tai("2016-12-31T23:59:59Z").unwrap();
+        assert_eq!(format_iso(before, 0), "2017-01-01T00:00:35");
+        let after = parse_utc_to_tai("2017-01-01T00:00:00Z").unwrap();
+        assert_eq!(format_iso(after, 0), "2017-01-01T00:00:37");
+        assert_eq!(format_iso_from_tai(after, 0), "2017-01-01T00:00:00");
+    }
+
+    #[test]
+    fn test_format_utc() {
+        assert_eq!(format_utc(0.0), "2000-01-01T12:00:00Z");
+        assert_eq!(format_utc(-0.75), "1999-12-31T18:00:00Z");
+        let days = parse_utc("2024-02-29T23:59:59.6Z").unwrap();
+        assert_eq!(format_utc(days), "2024-03-01T00:00:00Z");
+        for days in [-36_524, -1, 0, 59, 365, 9_000] {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(civil_days(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn test_time_scales() {
+        assert_eq!(tai_minus_utc(0.0), 32.0);
+        assert_eq!(
+            tai_minus_utc(parse_utc("2016-12-31T23:59:59Z").unwrap()),
+            36.0
+        );
+        assert_eq!(tai_minus_utc(parse_utc("2017-01-01").unwrap()), 37.0);
+        assert_eq!(tai_minus_utc(parse_utc("1960-01-01").unwrap()), 10.0);
+
+        // J2000 TT is 11:58:55.816 UTC
+        let utc = convert(0.0, Scale::Tt, Scale::Utc);
+        assert_eq!(format_iso(utc, 3), "2000-01-01T11:58:55.816");
+        assert!((convert(utc, Scale::Utc, Scale::Tt)).abs() < 1e-12);
+        let tai = convert(0.0, Scale::Utc, Scale::Tai);
+        assert!((tai * 86_400.0 - 32.0).abs() < 1e-6);
+
+        assert_eq!(parse_epoch("JD2451545.0"), Ok(0.0));
+        assert_eq!(parse_epoch("mjd 51544.5"), Ok(0.0));
+        assert!(parse_epoch("JDx").is_err());
+    }
+
+    #[test]
+    fn test_gmst() {
+        // Vallado, Example 3-5: 1992-08-20 12:14 UT1 gives 152.578788 deg
+        let days = parse_utc("1992-08-20T12:14:00Z").unwrap();
+        assert!((gmst(days).to_degrees() - 152.578_788).abs() < 1e-5);
+    }
+}
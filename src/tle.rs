@@ -0,0 +1,248 @@
+use std::f64::consts::PI;
+
+use crate::{dimension::Meters, Body, EARTH_MU};
+
+// Two-line element sets as published by CelesTrak and Space-Track. Fields
+// sit in fixed columns; the last character of each line is a modulo-10
+// checksum of its digits, with each minus sign counting as one. A set may be
+// preceded by a title line carrying the satellite name, and several sets may
+// follow one another in a file.
+//
+// The elements are SGP4 mean elements. Converting the mean motion with the
+// two-body relation gives a semi-major axis a few km off the osculating one,
+// which is fine for a summary but not for precise work.
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tle {
+    pub name: Option<String>,
+    /// NORAD catalog number, which may carry an Alpha-5 letter prefix.
+    pub catalog_number: String,
+    pub classification: char,
+    pub international_designator: String,
+    pub epoch_year: i32,
+    /// Day of the year including the fraction, 1.0 at 0h UTC on 1 January.
+    pub epoch_day: f64,
+    pub mean_motion_dot: f64,  // rev/day^2, first derivative divided by two
+    pub mean_motion_ddot: f64, // rev/day^3, second derivative divided by six
+    pub bstar: f64,            // 1/earth radii
+    pub element_set: u32,
+    pub inclination: f64,         // deg
+    pub raan: f64,                // deg
+    pub eccentricity: f64,        //
+    pub argument_of_perigee: f64, // deg
+    pub mean_anomaly: f64,        // deg
+    pub mean_motion: f64,         // rev/day
+    pub revolution_number: u32,
+}
+
+impl Tle {
+    /// Orbital period in s.
+    pub fn period(&self) -> f64 {
+        86_400.0 / self.mean_motion
+    }
+
+    /// Semi-major axis from the mean motion.
+    pub fn semi_major_axis(&self) -> Meters {
+        let mean_motion = self.mean_motion * 2.0 * PI / 86_400.0; // rad/s
+        Meters((EARTH_MU / mean_motion.powi(2)).cbrt())
+    }
+
+    /// Perigee and apogee altitudes in km above `earth`.
+    pub fn apsis_altitudes(&self, earth: &Body) -> (f64, f64) {
+        let axis = self.semi_major_axis();
+        (
+            (axis * (1.0 - self.eccentricity) - earth.radius).km(),
+            (axis * (1.0 + self.eccentricity) - earth.radius).km(),
+        )
+    }
+}
+
+fn checksum(line: &str) -> u32 {
+    line.chars()
+        .map(|c| match c {
+            '-' => 1,
+            _ => c.to_digit(10).unwrap_or(0),
+        })
+        .sum::<u32>()
+        % 10
+}
+
+/// Check the length, line number and checksum of one element line.
+fn check_line(line: &str, number: char, at: usize) -> Result<(), String> {
+    if !line.is_ascii() || line.len() != 69 {
+        return Err(format!(
+            "line {at}: expected 69 ASCII characters, found {}",
+            line.chars().count()
+        ));
+    }
+    if !line.starts_with(number) || line.as_bytes()[1] != b' ' {
+        return Err(format!(
+            "line {at}: expected line {number} of an element set"
+        ));
+    }
+    let found = line[68..].parse::<u32>().ok();
+    let expected = checksum(&line[..68]);
+    if found != Some(expected) {
+        return Err(format!(
+            "line {at}: checksum is {}, but the line sums to {expected}",
+            &line[68..]
+        ));
+    }
+    Ok(())
+}
+
+/// Read the field in columns `start..end` (zero-based) of a checked line.
+fn field<T: std::str::FromStr>(
+    line: &str,
+    start: usize,
+    end: usize,
+    name: &str,
+    at: usize,
+) -> Result<T, String> {
+    let text = line[start..end].trim();
+    text.parse()
+        .map_err(|_| format!("line {at}: invalid {name} {text:?}"))
+}
+
+/// Fields with an assumed leading decimal point and a signed exponent, such
+/// as " 12345-3" for 0.12345e-3.
+fn exponential(line: &str, start: usize, name: &str, at: usize) -> Result<f64, String> {
+    let text = &line[start..start + 8];
+    let (mantissa, exponent) = text.split_at(6);
+    let mantissa = mantissa.trim().replace(['+', ' '], "");
+    let (sign, digits) = match mantissa.strip_prefix('-') {
+        Some(digits) => (-1.0, digits.to_string()),
+        None => (1.0, mantissa),
+    };
+    let value = format!("0.{digits}e{}", exponent.replace('+', ""))
+        .parse::<f64>()
+        .map_err(|_| format!("line {at}: invalid {name} {:?}", text.trim()))?;
+    Ok(sign * value)
+}
+
+fn parse_set(
+    name: Option<String>,
+    first: (usize, &str),
+    second: (usize, &str),
+) -> Result<Tle, String> {
+    let ((a, line1), (b, line2)) = (first, second);
+    check_line(line1, '1', a)?;
+    check_line(line2, '2', b)?;
+    if line1[2..7] != line2[2..7] {
+        return Err(format!(
+            "line {b}: catalog number {} does not match {} on line {a}",
+            line2[2..7].trim(),
+            line1[2..7].trim()
+        ));
+    }
+
+    let two_digit_year: i32 = field(line1, 18, 20, "epoch year", a)?;
+    Ok(Tle {
+        name,
+        catalog_number: line1[2..7].trim().to_string(),
+        classification: line1.as_bytes()[7] as char,
+        international_designator: line1[9..17].trim().to_string(),
+        // the 57-to-56 break follows the first satellite launch
+        epoch_year: if two_digit_year < 57 {
+            2000 + two_digit_year
+        } else {
+            1900 + two_digit_year
+        },
+        epoch_day: field(line1, 20, 32, "epoch day", a)?,
+        mean_motion_dot: field(line1, 33, 43, "mean motion derivative", a)?,
+        mean_motion_ddot: exponential(line1, 44, "mean motion second derivative", a)?,
+        bstar: exponential(line1, 53, "B* drag term", a)?,
+        element_set: field(line1, 64, 68, "element set number", a)?,
+        inclination: field(line2, 8, 16, "inclination", b)?,
+        raan: field(line2, 17, 25, "right ascension of the ascending node", b)?,
+        eccentricity: format!("0.{}", line2[26..33].trim())
+            .parse()
+            .map_err(|_| format!("line {b}: invalid eccentricity {:?}", &line2[26..33]))?,
+        argument_of_perigee: field(line2, 34, 42, "argument of perigee", b)?,
+        mean_anomaly: field(line2, 43, 51, "mean anomaly", b)?,
+        mean_motion: field(line2, 52, 63, "mean motion", b)?,
+        revolution_number: field(line2, 63, 68, "revolution number", b)?,
+    })
+}
+
+/// Parse every element set in `text`, with or without title lines.
+pub fn parse(text: &str) -> Result<Vec<Tle>, String> {
+    // trailing spaces and CR line endings are common in pasted sets
+    let lines: Vec<(usize, &str)> = text
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim_end()))
+        .filter(|(_, line)| !line.is_empty())
+        .collect();
+
+    let mut sets = vec![];
+    let mut rest = &lines[..];
+    while let Some(&(at, line)) = rest.first() {
+        let (name, start) = if line.starts_with("1 ") {
+            (None, 0)
+        } else {
+            // titles are sometimes written with a leading "0 "
+            let name = line.strip_prefix("0 ").unwrap_or(line).trim();
+            (Some(name.to_string()), 1)
+        };
+        let (Some(&first), Some(&second)) = (rest.get(start), rest.get(start + 1)) else {
+            return Err(format!("line {at}: incomplete element set"));
+        };
+        sets.push(parse_set(name, first, second)?);
+        rest = &rest[start + 2..];
+    }
+    if sets.is_empty() {
+        return Err("no element sets found".to_string());
+    }
+    Ok(sets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ISS: &str = "ISS (ZARYA)
+1 25544U 98067A   08264.51782528 -.00002182  00000-0 -11606-4 0  2927
+2 25544  51.6416 247.4627 0006703 130.5360 325.0288 15.72125391563537
+";
+
+    #[test]
+    fn test_parse_iss() {
+        let sets = parse(ISS).unwrap();
+        assert_eq!(sets.len(), 1);
+        let iss = &sets[0];
+        assert_eq!(iss.name.as_deref(), Some("ISS (ZARYA)"));
+        assert_eq!(iss.catalog_number, "25544");
+        assert_eq!(iss.international_designator, "98067A");
+        assert_eq!(iss.epoch_year, 2008);
+        assert_eq!(iss.epoch_day, 264.517_825_28);
+        assert_eq!(iss.mean_motion_dot, -0.000_021_82);
+        assert_eq!(iss.mean_motion_ddot, 0.0);
+        assert!((iss.bstar + 0.116_06e-4).abs() < 1e-12);
+        assert_eq!(iss.eccentricity, 0.000_670_3);
+        assert_eq!(iss.revolution_number, 56_353);
+        assert!((iss.period() / 60.0 - 91.6).abs() < 0.05);
+
+        let (perigee, apogee) = iss.apsis_altitudes(&Body::earth());
+        assert!(perigee > 340.0 && apogee < 370.0 && perigee < apogee);
+    }
+
+    #[test]
+    fn test_parse_without_title() {
+        let bare: String = ISS
+            .lines()
+            .skip(1)
+            .map(|line| format!("{line}\r\n"))
+            .collect();
+        let sets = parse(&bare).unwrap();
+        assert_eq!(sets[0].name, None);
+    }
+
+    #[test]
+    fn test_reject_bad_checksum() {
+        let corrupted = ISS.replace("51.6416", "51.6417");
+        let error = parse(&corrupted).unwrap_err();
+        assert!(error.starts_with("line 3: checksum"), "{error}");
+        assert!(parse("ISS (ZARYA)\n").is_err());
+    }
+}
@@ -0,0 +1,337 @@
+use clap::ValueEnum;
+
+use crate::{
+    dimension::{Meters, MetersPerSecond, Seconds},
+    format::Style,
+    theme::Theme,
+    Body,
+};
+
+// Units of lengths and speeds in reports. Calculations stay in SI and each
+// value is converted on its way out, so a report reads the same in any
+// system, and only dimensioned quantities go in. Canonical units scale to
+// the central body: one distance unit (DU) is its equatorial radius and one
+// time unit (TU) the time for a circular orbit at that radius to sweep a
+// radian, so the circular speed there is 1 DU/TU and the gravitational
+// parameter is 1 DU^3/TU^2. Quantities known much better or worse than an
+// orbit, such as relative positions in a rendezvous or the distance to a
+// Lagrange point, give their own resolution instead of the usual decimals.
+
+const MILE: f64 = 1609.344; // m
+const HOUR: f64 = 3600.0; // s
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum System {
+    /// Metres and m/s
+    Si,
+    /// Kilometres and km/s
+    #[default]
+    Km,
+    /// Statute miles and mph
+    Imperial,
+    /// Distance and time units of the central body, DU and DU/TU
+    Canonical,
+}
+
+/// A unit system fixed to a central body.
+#[derive(Debug, Clone, Copy)]
+pub struct Units {
+    pub system: System,
+    pub style: Style,
+    pub theme: Theme,
+    distance_unit: Meters,
+    time_unit: Seconds,
+}
+
+impl Units {
+    pub fn new(system: System, body: &Body) -> Self {
+        Units {
+            system,
+            style: Style::default(),
+            theme: Theme::default(),
+            distance_unit: body.radius,
+            time_unit: Seconds((body.radius.0.powi(3) / body.mu.0).sqrt()),
+        }
+    }
+
+    /// The same units, writing numbers in `style`.
+    pub fn with_style(self, style: Style) -> Self {
+        Units { style, ..self }
+    }
+
+    /// The same units, writing unit symbols in `theme`.
+    pub fn with_theme(self, theme: Theme) -> Self {
+        Units { theme, ..self }
+    }
+
+    /// Size of one length unit.
+    pub fn length_scale(&self) -> Meters {
+        match self.system {
+            System::Si => Meters(1.0),
+            System::Km => Meters(1000.0),
+            System::Imperial => Meters(MILE),
+            System::Canonical => self.distance_unit,
+        }
+    }
+
+    /// Size of one speed unit.
+    pub fn speed_scale(&self) -> MetersPerSecond {
+        match self.system {
+            System::Si => MetersPerSecond(1.0),
+            System::Km => MetersPerSecond(1000.0),
+            System::Imperial => MetersPerSecond(MILE / HOUR),
+            System::Canonical => self.distance_unit / self.time_unit,
+        }
+    }
+
+    pub fn length_symbol(&self) -> &'static str {
+        match self.system {
+            System::Si => "m",
+            System::Km => "km",
+            System::Imperial => "mi",
+            System::Canonical => "DU",
+        }
+    }
+
+    pub fn speed_symbol(&self) -> &'static str {
+        match self.system {
+            System::Si => "m/s",
+            System::Km => "km/s",
+            System::Imperial => "mph",
+            System::Canonical => "DU/TU",
+        }
+    }
+
+    /// Symbol of the time in the speed unit.
+    fn time_symbol(&self) -> &'static str {
+        match self.system {
+            System::Si | System::Km => "s",
+            System::Imperial => "h",
+            System::Canonical => "TU",
+        }
+    }
+
+    /// Decimals for lengths and speeds, about a metre and a decimetre per
+    /// second, or six for canonical units.
+    fn decimals(&self) -> (usize, usize) {
+        match self.system {
+            System::Si => (0, 1),
+            System::Km => (3, 4),
+            System::Imperial => (3, 1),
+            System::Canonical => (6, 6),
+        }
+    }
+
+    /// A length as a number in this system, without the unit.
+    pub fn length_number(&self, length: Meters) -> String {
+        self.style
+            .number(length / self.length_scale(), self.decimals().0)
+    }
+
+    /// A speed as a number in this system, without the unit.
+    pub fn speed_number(&self, speed: MetersPerSecond) -> String {
+        self.style
+            .number(speed / self.speed_scale(), self.decimals().1)
+    }
+
+    /// A length as a number in this system to about `resolution`.
+    pub fn length_number_to(&self, length: Meters, resolution: Meters) -> String {
+        let scale = self.length_scale();
+        self.style
+            .number(length / scale, decimals_to(resolution / scale))
+    }
+
+    /// A speed as a number in this system to about `resolution`.
+    pub fn speed_number_to(&self, speed: MetersPerSecond, resolution: MetersPerSecond) -> String {
+        let scale = self.speed_scale();
+        self.style
+            .number(speed / scale, decimals_to(resolution / scale))
+    }
+
+    /// A length with its unit, such as "400.000 km".
+    pub fn length(&self, length: Meters) -> String {
+        let symbol = self.theme.unit(self.length_symbol());
+        format!("{} {symbol}", self.length_number(length))
+    }
+
+    /// A speed with its unit, such as "7.6686 km/s".
+    pub fn speed(&self, speed: MetersPerSecond) -> String {
+        let symbol = self.theme.unit(self.speed_symbol());
+        format!("{} {symbol}", self.speed_number(speed))
+    }
+
+    /// A length to about `resolution`, with its unit.
+    pub fn length_to(&self, length: Meters, resolution: Meters) -> String {
+        let symbol = self.theme.unit(self.length_symbol());
+        format!("{} {symbol}", self.length_number_to(length, resolution))
+    }
+
+    /// A speed to about `resolution`, with its unit.
+    pub fn speed_to(&self, speed: MetersPerSecond, resolution: MetersPerSecond) -> String {
+        let symbol = self.theme.unit(self.speed_symbol());
+        format!("{} {symbol}", self.speed_number_to(speed, resolution))
+    }
+
+    /// A position in m to about `resolution`, with the unit after the last
+    /// component, such as "6778.137, 0.000, 0.000 km".
+    pub fn position_to(&self, position: [f64; 3], resolution: Meters) -> String {
+        let symbol = self.theme.unit(self.length_symbol());
+        let components =
+            position.map(|component| self.length_number_to(Meters(component), resolution));
+        format!("{} {symbol}", components.join(", "))
+    }
+
+    /// A velocity in m/s to about `resolution`, with the unit after the
+    /// last component.
+    pub fn velocity_to(&self, velocity: [f64; 3], resolution: MetersPerSecond) -> String {
+        let symbol = self.theme.unit(self.speed_symbol());
+        let components =
+            velocity.map(|component| self.speed_number_to(MetersPerSecond(component), resolution));
+        format!("{} {symbol}", components.join(", "))
+    }
+
+    /// An area in m^2 in the square of this system's length unit, to about
+    /// `resolution` m^2.
+    pub fn area_to(&self, area: f64, resolution: f64) -> String {
+        let symbol = format!("{}^2", self.length_symbol());
+        self.scaled(area, self.length_scale().0.powi(2), resolution, &symbol)
+    }
+
+    /// A specific energy such as C3 in m^2/s^2, in the square of this
+    /// system's speed unit, to about `resolution` m^2/s^2.
+    pub fn energy_to(&self, energy: f64, resolution: f64) -> String {
+        let symbol = format!("{}^2/{}^2", self.length_symbol(), self.time_symbol());
+        self.scaled(energy, self.speed_scale().0.powi(2), resolution, &symbol)
+    }
+
+    /// A specific angular momentum in m^2/s, in this system's length unit
+    /// times its speed unit, to about `resolution` m^2/s.
+    pub fn angular_momentum_to(&self, momentum: f64, resolution: f64) -> String {
+        let symbol = format!("{}^2/{}", self.length_symbol(), self.time_symbol());
+        let scale = self.length_scale().0 * self.speed_scale().0;
+        self.scaled(momentum, scale, resolution, &symbol)
+    }
+
+    /// `value` in units of `scale`, to about `resolution`, with `symbol`.
+    fn scaled(&self, value: f64, scale: f64, resolution: f64, symbol: &str) -> String {
+        let number = self
+            .style
+            .number(value / scale, decimals_to(resolution / scale));
+        format!("{number} {}", self.theme.unit(symbol))
+    }
+
+    /// A length given in km, such as an altitude from the command line, as
+    /// a number without trailing zeros, so 400 km stays "400", or as set by
+    /// the style.
+    pub fn brief(&self, kilometres: f64) -> String {
+        let value = Meters::from_km(kilometres) / self.length_scale();
+        self.style.brief(value, self.decimals().0)
+    }
+
+    /// A duration in canonical time units, for the canonical system.
+    pub fn time_units(&self, duration: Seconds) -> Option<f64> {
+        (self.system == System::Canonical).then(|| duration / self.time_unit)
+    }
+}
+
+/// Decimals that resolve `resolution`, a fraction of one unit.
+fn decimals_to(resolution: f64) -> usize {
+    (-resolution.log10()).round().max(0.0) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{calculate_circular_orbital_velocity, calculate_orbital_period, format::Notation};
+
+    #[test]
+    fn test_conversions() {
+        let earth = Body::earth();
+        let speed = calculate_circular_orbital_velocity(earth.mu, earth.radius + Meters(400e3));
+        let km = Units::new(System::Km, &earth);
+        assert_eq!(km.length(Meters(400e3)), "400.000 km");
+        assert_eq!(km.speed(speed), "7.6686 km/s");
+        assert_eq!(km.brief(35_786.0), "35786");
+        assert_eq!(Units::new(System::Si, &earth).speed(speed), "7668.6 m/s");
+        let imperial = Units::new(System::Imperial, &earth);
+        assert_eq!(imperial.length(Meters(MILE * 250.0)), "250.000 mi");
+        assert_eq!(imperial.speed(speed), "17154.1 mph");
+        assert_eq!(km.time_units(Seconds(60.0)), None);
+        let engineering = km.with_style(Style {
+            notation: Notation::Engineering,
+            precision: Some(2),
+        });
+        assert_eq!(engineering.length(Meters(42_164e3)), "42.16e3 km");
+        assert_eq!(engineering.speed(speed), "7.67e0 km/s");
+        let colored = km.with_theme(Theme::new(true));
+        assert_eq!(colored.length(Meters(400e3)), "400.000 \x1b[36mkm\x1b[0m");
+    }
+
+    #[test]
+    fn test_resolution() {
+        let earth = Body::earth();
+        let km = Units::new(System::Km, &earth);
+        assert_eq!(km.length_to(Meters(384_400e3), Meters(1e3)), "384400 km");
+        assert_eq!(
+            km.speed_to(MetersPerSecond(12.345), MetersPerSecond(0.01)),
+            "0.01235 km/s"
+        );
+        let si = Units::new(System::Si, &earth);
+        assert_eq!(si.length_to(Meters(100.0), Meters(1e-3)), "100.000 m");
+        assert_eq!(
+            si.length_number_to(Meters(384_400e3), Meters(1e3)),
+            "384400000"
+        );
+        assert_eq!(
+            si.velocity_to([7668.55, -0.04, 0.0], MetersPerSecond(0.1)),
+            "7668.6, -0.0, 0.0 m/s"
+        );
+        assert_eq!(
+            km.position_to([6778e3, 0.0, -1.5], Meters(1.0)),
+            "6778.000, 0.000, -0.002 km"
+        );
+        let imperial = Units::new(System::Imperial, &earth);
+        assert_eq!(
+            imperial.length_number_to(Meters(MILE), Meters(1.0)),
+            "1.000"
+        );
+        assert_eq!(
+            imperial.speed_number_to(MetersPerSecond(10.0), MetersPerSecond(0.01)),
+            "22.37"
+        );
+    }
+
+    #[test]
+    fn test_energy_and_angular_momentum() {
+        let earth = Body::earth();
+        let km = Units::new(System::Km, &earth);
+        assert_eq!(km.energy_to(9e6, 1e3), "9.000 km^2/s^2");
+        assert_eq!(km.angular_momentum_to(5.2e10, 1e3), "52000.000 km^2/s");
+        assert_eq!(km.area_to(5.1e14, 1e6), "510000000 km^2");
+        let si = Units::new(System::Si, &earth);
+        assert_eq!(si.energy_to(9e6, 1e3), "9000000 m^2/s^2");
+        let imperial = Units::new(System::Imperial, &earth);
+        assert_eq!(imperial.energy_to((MILE / HOUR).powi(2), 1.0), "1 mi^2/h^2");
+        // one DU/TU squared is mu / DU
+        let canonical = Units::new(System::Canonical, &earth);
+        assert_eq!(
+            canonical.energy_to(earth.mu.0 / earth.radius.0, 1e2),
+            "1.000000 DU^2/TU^2"
+        );
+    }
+
+    #[test]
+    fn test_canonical_units() {
+        // a circular orbit at one DU moves at one DU/TU and takes 2 pi TU
+        let earth = Body::earth();
+        let canonical = Units::new(System::Canonical, &earth);
+        let speed = calculate_circular_orbital_velocity(earth.mu, earth.radius);
+        assert_eq!(canonical.speed(speed), "1.000000 DU/TU");
+        let period = calculate_orbital_period(earth.mu, earth.radius);
+        let turns = canonical.time_units(period).unwrap();
+        assert!((turns - 2.0 * std::f64::consts::PI).abs() < 1e-9);
+        // the Earth's TU is about 13.45 minutes
+        assert!(((period / turns).minutes() - 13.45).abs() < 0.01);
+        assert_eq!(canonical.brief(6378.1), "1");
+    }
+}
@@ -0,0 +1,36 @@
+use std::process::Command;
+
+fn orbit(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_orbit"))
+        .args(args)
+        .env("NO_COLOR", "1")
+        .output()
+        .expect("orbit runs");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).expect("output is UTF-8")
+}
+
+#[test]
+fn test_notation_reaches_every_command() {
+    let args = [
+        "eclipse",
+        "--altitude",
+        "400",
+        "--beta",
+        "20",
+        "--lang",
+        "en",
+    ];
+    let fixed = orbit(&args);
+    assert!(fixed.contains("Orbital Period: 92.6 minutes"));
+    assert!(fixed.contains("Eclipse: 38.3% (35.4 minutes)"));
+
+    let scientific = orbit(&[&["--notation", "scientific"][..], &args].concat());
+    assert!(scientific.contains("Orbital Period: 9.26e1 minutes"));
+    assert!(scientific.contains("Eclipse: 3.83e1% (3.54e1 minutes)"));
+    assert!(!scientific.contains("92.6"));
+}